@@ -0,0 +1,120 @@
+//! compares loading threads with and without a shared [`TemplatedPostCache`].
+//!
+//! the fixture is a large synthetic archive: one long thread whose ancestors are all shared by
+//! many descendant threads, which is the worst case for loading each thread independently.
+//!
+//! run with `cargo bench --bench thread_cache`.
+
+use std::{
+    env::{current_dir, set_current_dir, temp_dir},
+    fs::{create_dir_all, remove_dir_all, File},
+    io::Write,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use askama::Template;
+use autost::{path::PostsPath, PostMeta, TemplatedPost, TemplatedPostCache, Thread};
+use jane_eyre::eyre;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+const ANCESTOR_COUNT: usize = 50;
+const DESCENDANT_COUNT: usize = 500;
+const ITERATIONS: u32 = 3;
+
+fn main() -> eyre::Result<()> {
+    let fixture_dir = temp_dir().join(format!("autost-bench-{}", std::process::id()));
+    create_dir_all(&fixture_dir)?;
+    let original_dir = current_dir()?;
+    set_current_dir(&fixture_dir)?;
+    let result = run();
+    set_current_dir(original_dir)?;
+    remove_dir_all(fixture_dir)?;
+
+    result
+}
+
+fn run() -> eyre::Result<()> {
+    // `SETTINGS` is loaded from the current directory.
+    File::create("autost.toml")?.write_all(include_bytes!("../autost.toml.example"))?;
+    let post_paths = write_fixture()?;
+    println!(
+        "fixture: {ANCESTOR_COUNT} shared ancestors, {DESCENDANT_COUNT} descendant threads ({} posts per thread)",
+        ANCESTOR_COUNT + 1,
+    );
+
+    let uncached = measure(|| {
+        post_paths
+            .clone()
+            .into_par_iter()
+            .map(|path| Thread::try_from(TemplatedPost::load(&path)?))
+            .collect::<eyre::Result<Vec<_>>>()
+    })?;
+    println!("without cache: {uncached:?} per iteration");
+
+    let cached = measure(|| {
+        let cache = TemplatedPostCache::default();
+        post_paths
+            .clone()
+            .into_par_iter()
+            .map(|path| {
+                Thread::try_from_with_cache(Arc::unwrap_or_clone(cache.load(&path)?), &cache)
+            })
+            .collect::<eyre::Result<Vec<_>>>()
+    })?;
+    println!("with cache:    {cached:?} per iteration");
+    println!(
+        "speedup:       {:.1}x",
+        uncached.as_secs_f64() / cached.as_secs_f64()
+    );
+
+    Ok(())
+}
+
+/// writes the fixture posts, returning the paths of the descendant threads.
+fn write_fixture() -> eyre::Result<Vec<PostsPath>> {
+    create_dir_all(&*PostsPath::ROOT)?;
+    let body =
+        "some **markdown** with a [link](https://example.com) and a list:\n\n- one\n- two\n\n"
+            .repeat(20);
+
+    let mut references = vec![];
+    for post_id in 1..=ANCESTOR_COUNT {
+        let path = PostsPath::markdown_post_path(post_id);
+        write_post(&path, references.clone(), &body)?;
+        references.push(path);
+    }
+
+    let mut result = vec![];
+    for post_id in 1..=DESCENDANT_COUNT {
+        let path = PostsPath::markdown_post_path(ANCESTOR_COUNT + post_id);
+        write_post(&path, references.clone(), &body)?;
+        result.push(path);
+    }
+
+    Ok(result)
+}
+
+fn write_post(path: &PostsPath, references: Vec<PostsPath>, body: &str) -> eyre::Result<()> {
+    let meta = PostMeta {
+        references,
+        title: Some(format!("post {path:?}")),
+        published: Some("2024-01-01T00:00:00.000Z".to_owned()),
+        ..Default::default()
+    };
+    let mut file = File::create(path)?;
+    file.write_all(meta.render()?.as_bytes())?;
+    file.write_all(b"\n\n")?;
+    file.write_all(body.as_bytes())?;
+
+    Ok(())
+}
+
+fn measure<T>(mut f: impl FnMut() -> eyre::Result<T>) -> eyre::Result<Duration> {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f()?;
+    }
+
+    Ok(start.elapsed() / ITERATIONS)
+}
@@ -0,0 +1,1071 @@
+//! activitypub (<https://www.w3.org/TR/activitypub/>) for following the blog from the fediverse.
+//! the actor, outbox, and webfinger are static json written at render time. followers are
+//! added and removed by the inbox in `autost server`, and `autost activitypub deliver` sends
+//! new, updated, and deleted posts to their inboxes.
+//!
+//! - `site/activitypub/actor.json` is the actor, with the public key for our signatures
+//! - `site/activitypub/outbox.json` and `site/activitypub/outbox/<n>.json` list a `Create`
+//!   for each thread in the `index` collection, newest first
+//! - `site/activitypub/objects/<page>.json` is the `Note` (or `Article`, if titled) for a thread
+//! - `site/.well-known/webfinger` resolves `acct:<username>@<host>` to the actor
+//! - `activitypub/private-key.pem` is our private key, generated if missing
+//! - `activitypub/followers.json` lists our followers and their inboxes
+//! - `activitypub/delivered.json` has a hash of each object we delivered, so we only deliver
+//!   objects again when they change or get deleted
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs::{create_dir_all, read_dir, read_to_string, remove_dir_all, File},
+    io::{ErrorKind, Read, Write},
+    path::PathBuf,
+    sync::Mutex,
+};
+
+use base64::{prelude::BASE64_STANDARD, Engine};
+use chrono::Utc;
+use jane_eyre::eyre::{self, bail, eyre, Context, OptionExt};
+use markup5ever_rcdom::NodeData;
+use rsa::{
+    pkcs1::DecodeRsaPublicKey,
+    pkcs1v15::{Signature, SigningKey, VerifyingKey},
+    pkcs8::{DecodePrivateKey, DecodePublicKey, EncodePrivateKey, EncodePublicKey, LineEnding},
+    signature::{SignatureEncoding, Signer, Verifier},
+    RsaPrivateKey, RsaPublicKey,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sha2::{digest::generic_array::functional::FunctionalSequence, Digest, Sha256};
+use tracing::{info, warn};
+use url::Url;
+
+use crate::{
+    dom::{
+        html_attributes_with_urls, parse_html_fragment, serialize_html_fragment, BreadthTraverse,
+    },
+    net::{http_date, parse_http_date, PublicClient},
+    output::TagPages,
+    path::SitePath,
+    Thread, SETTINGS,
+};
+
+/// number of activities in each page of the outbox.
+const OUTBOX_PAGE_SIZE: usize = 20;
+
+/// signed requests with a `Date` further than this from now are rejected.
+const MAX_CLOCK_SKEW_SECONDS: i64 = 12 * 60 * 60;
+
+/// responses larger than this many bytes are not parsed.
+const MAX_RESPONSE_BYTES: u64 = 1 << 20;
+
+const KEY_BITS: usize = 2048;
+const PUBLIC: &str = "https://www.w3.org/ns/activitystreams#Public";
+const ACTIVITY_JSON: &str = "application/activity+json";
+
+/// held while reading and writing the follower and delivery files.
+static STORE_LOCK: Mutex<()> = Mutex::new(());
+
+/// fetches activitypub objects and posts activities to inboxes. implement this
+/// to stub out the network in tests.
+pub trait ActivityPubClient: Send + Sync {
+    fn get(&self, url: &str) -> eyre::Result<Value>;
+
+    /// posts a signed activity, returning the response status.
+    fn post(&self, url: &str, headers: &[(String, String)], body: &[u8]) -> eyre::Result<u16>;
+}
+
+/// fetches objects with reqwest, signing requests with our key, since some servers
+/// only answer signed requests.
+pub struct RealActivityPubClient {
+    pub signer: RequestSigner,
+}
+
+/// signs requests with http signatures (draft-cavage-http-signatures-12), as
+/// mastodon and friends expect.
+#[derive(Clone, Debug)]
+pub struct RequestSigner {
+    key_id: String,
+    key: RsaPrivateKey,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Follower {
+    pub actor: String,
+    pub inbox: String,
+    pub shared_inbox: Option<String>,
+}
+
+/// what the inbox did with an activity.
+#[derive(Debug, PartialEq)]
+pub enum InboxResult {
+    /// a new or existing follower, who should be sent this `Accept`.
+    Followed(Value),
+    Unfollowed,
+    Ignored,
+}
+
+/// stores our key, followers, and delivery log in a directory.
+#[derive(Clone, Debug)]
+pub struct ActivityPubStore {
+    dir: PathBuf,
+}
+
+impl ActivityPubClient for RealActivityPubClient {
+    fn get(&self, url: &str) -> eyre::Result<Value> {
+        let headers = self.signer.sign("get", &Url::parse(url)?, None)?;
+        let mut request = PublicClient::new()?
+            .get(url)?
+            .header("accept", ACTIVITY_JSON);
+        for (name, value) in headers {
+            request = request.header(name, value);
+        }
+        let response = request.send()?.error_for_status()?;
+        let mut body = vec![];
+        response
+            .take(MAX_RESPONSE_BYTES + 1)
+            .read_to_end(&mut body)?;
+        if body.len() as u64 > MAX_RESPONSE_BYTES {
+            bail!("response is too large: {url}");
+        }
+
+        Ok(serde_json::from_slice(&body)?)
+    }
+
+    fn post(&self, url: &str, headers: &[(String, String)], body: &[u8]) -> eyre::Result<u16> {
+        let mut request = PublicClient::new()?
+            .post(url)?
+            .header("content-type", ACTIVITY_JSON)
+            .body(body.to_owned());
+        for (name, value) in headers {
+            request = request.header(name, value);
+        }
+
+        Ok(request.send()?.status().as_u16())
+    }
+}
+
+impl RequestSigner {
+    pub fn new(key_id: &str, key: RsaPrivateKey) -> Self {
+        Self {
+            key_id: key_id.to_owned(),
+            key,
+        }
+    }
+
+    /// returns the `Host`, `Date`, `Digest` (if there is a body), and `Signature` headers
+    /// for a request. `method` is lowercase.
+    pub fn sign(
+        &self,
+        method: &str,
+        url: &Url,
+        body: Option<&[u8]>,
+    ) -> eyre::Result<Vec<(String, String)>> {
+        let host = url_host(url)?;
+        let mut headers = vec![
+            ("host".to_owned(), host),
+            ("date".to_owned(), http_date(Utc::now())),
+        ];
+        if let Some(body) = body {
+            headers.push(("digest".to_owned(), digest(body)));
+        }
+        let mut path = url.path().to_owned();
+        if let Some(query) = url.query() {
+            path = format!("{path}?{query}");
+        }
+        let names = ["(request-target)"]
+            .into_iter()
+            .chain(headers.iter().map(|(name, _)| &**name))
+            .collect::<Vec<_>>();
+        let signing_string = signing_string(method, &path, &names, &headers)?;
+        let signature = SigningKey::<Sha256>::new(self.key.clone())
+            .sign(signing_string.as_bytes())
+            .to_bytes();
+        headers.push((
+            "signature".to_owned(),
+            format!(
+                r#"keyId="{}",algorithm="rsa-sha256",headers="{}",signature="{}""#,
+                self.key_id,
+                names.join(" "),
+                BASE64_STANDARD.encode(signature),
+            ),
+        ));
+
+        Ok(headers)
+    }
+}
+
+/// verifies the signature of a request to our inbox, fetching the signer’s key and the actor
+/// that owns it. the request target, host, date, and digest must be signed, and the digest must
+/// match the body. returns the actor, whose document must list the key as its own.
+pub fn verify_request(
+    client: &dyn ActivityPubClient,
+    method: &str,
+    path: &str,
+    headers: &[(String, String)],
+    body: &[u8],
+) -> eyre::Result<String> {
+    let header = |name: &str| {
+        headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| &**value)
+    };
+    let signature = header("signature").ok_or_eyre("request is not signed")?;
+    let mut key_id = None;
+    let mut names = None;
+    let mut signature_base64 = None;
+    for param in signature.split(',') {
+        let Some((name, value)) = param.trim().split_once('=') else {
+            continue;
+        };
+        let value = value.trim_matches('"');
+        match name {
+            "keyId" => key_id = Some(value),
+            "headers" => names = Some(value.split(' ').collect::<Vec<_>>()),
+            "signature" => signature_base64 = Some(value),
+            _ => {}
+        }
+    }
+    let key_id = key_id.ok_or_eyre("signature has no keyId")?;
+    let names = names.unwrap_or_else(|| vec!["date"]);
+    let signature = BASE64_STANDARD.decode(signature_base64.ok_or_eyre("no signature")?)?;
+    for name in ["(request-target)", "host", "date", "digest"] {
+        if !names.contains(&name) {
+            bail!("signature must cover {name}");
+        }
+    }
+    if header("digest") != Some(&digest(body)) {
+        bail!("digest does not match body");
+    }
+    let date = header("date").ok_or_eyre("request has no date")?;
+    let date = parse_http_date(date).ok_or_eyre("bad date")?;
+    if (Utc::now() - date).num_seconds().abs() > MAX_CLOCK_SKEW_SECONDS {
+        bail!("date is too far from now");
+    }
+
+    // the key id may be the url of the key itself, or a fragment of the actor’s url. either way,
+    // anyone can claim any owner, so only trust the key if the owner’s own document lists it.
+    let document = client.get(key_id)?;
+    let key = match document.get("publicKey") {
+        Some(key) => key,
+        None => &document,
+    };
+    let owner = key["owner"].as_str().ok_or_eyre("key has no owner")?;
+    let actor = if key_id.split('#').next() == Some(owner) {
+        document.clone()
+    } else {
+        client.get(owner)?
+    };
+    if actor["id"].as_str() != Some(owner) {
+        bail!("actor has wrong id");
+    }
+    let key = &actor["publicKey"];
+    if key["id"].as_str() != Some(key_id) {
+        bail!("key is not listed by its owner");
+    }
+    let pem = key["publicKeyPem"].as_str().ok_or_eyre("key has no pem")?;
+    let key = RsaPublicKey::from_public_key_pem(pem)
+        .or_else(|_| RsaPublicKey::from_pkcs1_pem(pem))
+        .wrap_err("bad public key")?;
+    let signing_string = signing_string(method, path, &names, headers)?;
+    VerifyingKey::<Sha256>::new(key)
+        .verify(
+            signing_string.as_bytes(),
+            &Signature::try_from(&*signature)?,
+        )
+        .map_err(|_| eyre!("bad signature"))?;
+
+    Ok(owner.to_owned())
+}
+
+fn signing_string(
+    method: &str,
+    path: &str,
+    names: &[&str],
+    headers: &[(String, String)],
+) -> eyre::Result<String> {
+    let mut lines = vec![];
+    for name in names {
+        if *name == "(request-target)" {
+            lines.push(format!(
+                "(request-target): {} {path}",
+                method.to_lowercase()
+            ));
+            continue;
+        }
+        let values = headers
+            .iter()
+            .filter(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.trim())
+            .collect::<Vec<_>>();
+        if values.is_empty() {
+            bail!("signed header is missing: {name}");
+        }
+        lines.push(format!("{name}: {}", values.join(", ")));
+    }
+
+    Ok(lines.join("\n"))
+}
+
+fn digest(body: &[u8]) -> String {
+    format!("SHA-256={}", BASE64_STANDARD.encode(Sha256::digest(body)))
+}
+
+/// returns the host of a url, with the port if not the default.
+fn url_host(url: &Url) -> eyre::Result<String> {
+    let host = url.host_str().ok_or_eyre("url has no host")?;
+
+    Ok(match url.port() {
+        Some(port) => format!("{host}:{port}"),
+        None => host.to_owned(),
+    })
+}
+
+pub fn actor_id() -> String {
+    format!("{}activitypub/actor.json", SETTINGS.external_base_url)
+}
+
+pub fn inbox_url() -> String {
+    format!("{}activitypub/inbox", SETTINGS.external_base_url)
+}
+
+fn key_id() -> String {
+    format!("{}#main-key", actor_id())
+}
+
+fn followers_url() -> String {
+    format!("{}activitypub/followers.json", SETTINGS.external_base_url)
+}
+
+impl Default for ActivityPubStore {
+    fn default() -> Self {
+        Self::new("activitypub")
+    }
+}
+
+impl ActivityPubStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// loads our private key, generating it if it doesn’t exist yet.
+    pub fn load_or_generate_key(&self) -> eyre::Result<RsaPrivateKey> {
+        let _guard = STORE_LOCK.lock().unwrap();
+        let path = self.dir.join("private-key.pem");
+        match read_to_string(&path) {
+            Ok(pem) => return Ok(RsaPrivateKey::from_pkcs8_pem(&pem)?),
+            Err(error) if error.kind() == ErrorKind::NotFound => {}
+            Err(error) => return Err(error.into()),
+        }
+        info!("generating activitypub key in {path:?}");
+        let key = RsaPrivateKey::new(&mut rand::thread_rng(), KEY_BITS)?;
+        create_dir_all(&self.dir)?;
+        let mut options = File::options();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        options
+            .open(&path)?
+            .write_all(key.to_pkcs8_pem(LineEnding::LF)?.as_bytes())?;
+
+        Ok(key)
+    }
+
+    pub fn signer(&self) -> eyre::Result<RequestSigner> {
+        Ok(RequestSigner::new(&key_id(), self.load_or_generate_key()?))
+    }
+
+    pub fn followers(&self) -> eyre::Result<Vec<Follower>> {
+        let _guard = STORE_LOCK.lock().unwrap();
+        self.load_unlocked("followers.json")
+    }
+
+    /// adds or updates a follower.
+    pub fn add_follower(&self, follower: Follower) -> eyre::Result<()> {
+        let _guard = STORE_LOCK.lock().unwrap();
+        let mut followers = self.load_unlocked::<Vec<Follower>>("followers.json")?;
+        followers.retain(|other| other.actor != follower.actor);
+        followers.push(follower);
+        self.save_unlocked("followers.json", &followers)
+    }
+
+    /// removes a follower, returning false if they weren’t following.
+    pub fn remove_follower(&self, actor: &str) -> eyre::Result<bool> {
+        let _guard = STORE_LOCK.lock().unwrap();
+        let mut followers = self.load_unlocked::<Vec<Follower>>("followers.json")?;
+        let len = followers.len();
+        followers.retain(|follower| follower.actor != actor);
+        if followers.len() == len {
+            return Ok(false);
+        }
+        self.save_unlocked("followers.json", &followers)?;
+
+        Ok(true)
+    }
+
+    /// returns the hash of each object we delivered, keyed by object id, or None if
+    /// we never delivered anything.
+    pub fn load_delivered(&self) -> eyre::Result<Option<BTreeMap<String, String>>> {
+        let _guard = STORE_LOCK.lock().unwrap();
+        if !self.dir.join("delivered.json").exists() {
+            return Ok(None);
+        }
+        Ok(Some(self.load_unlocked("delivered.json")?))
+    }
+
+    pub fn save_delivered(&self, delivered: &BTreeMap<String, String>) -> eyre::Result<()> {
+        let _guard = STORE_LOCK.lock().unwrap();
+        self.save_unlocked("delivered.json", delivered)
+    }
+
+    fn load_unlocked<T: Default + for<'de> Deserialize<'de>>(
+        &self,
+        filename: &str,
+    ) -> eyre::Result<T> {
+        match read_to_string(self.dir.join(filename)) {
+            Ok(json) => Ok(serde_json::from_str(&json)?),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(T::default()),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    fn save_unlocked(&self, filename: &str, value: &impl Serialize) -> eyre::Result<()> {
+        create_dir_all(&self.dir)?;
+        let mut file = File::create(self.dir.join(filename))?;
+        writeln!(file, "{}", serde_json::to_string_pretty(value)?)?;
+
+        Ok(())
+    }
+}
+
+/// replaces the actor, outbox, objects, followers collection, and webfinger in the site
+/// output directory, returning the paths written. `threads` are newest first.
+pub fn write_static_files(
+    store: &ActivityPubStore,
+    threads: &[&Thread],
+    tag_pages: &TagPages,
+) -> eyre::Result<Vec<SitePath>> {
+    let dir = SitePath::ROOT.join("activitypub")?;
+    match remove_dir_all(&dir) {
+        Err(error) if error.kind() != ErrorKind::NotFound => Err(error)?,
+        _ => {}
+    }
+    create_dir_all(dir.join("objects")?)?;
+    create_dir_all(dir.join("outbox")?)?;
+    let mut result = vec![];
+    let mut write = |path: SitePath, value: &Value| -> eyre::Result<()> {
+        let mut file = File::create(&path)?;
+        serde_json::to_writer(&mut file, value)?;
+        writeln!(file)?;
+        result.push(path);
+        Ok(())
+    };
+
+    let key = store.load_or_generate_key()?;
+    write(dir.join("actor.json")?, &actor(&RsaPublicKey::from(&key))?)?;
+    write(
+        dir.join("followers.json")?,
+        &json!({
+            "@context": "https://www.w3.org/ns/activitystreams",
+            "id": followers_url(),
+            "type": "OrderedCollection",
+            "totalItems": store.followers()?.len(),
+        }),
+    )?;
+
+    let mut creates = vec![];
+    for thread in threads {
+        let Some((basename, object)) = object(thread, tag_pages)? else {
+            continue;
+        };
+        creates.push(create(&object));
+        write(
+            dir.join("objects")?.join(&format!("{basename}.json"))?,
+            &object,
+        )?;
+    }
+    for (outbox_path, outbox) in outbox_pages(&creates) {
+        write(dir.join(&outbox_path)?, &outbox)?;
+    }
+
+    let well_known = SitePath::ROOT.join(".well-known")?;
+    create_dir_all(&well_known)?;
+    write(well_known.join("webfinger")?, &webfinger()?)?;
+
+    Ok(result)
+}
+
+fn actor(key: &RsaPublicKey) -> eyre::Result<Value> {
+    Ok(json!({
+        "@context": [
+            "https://www.w3.org/ns/activitystreams",
+            "https://w3id.org/security/v1",
+        ],
+        "id": actor_id(),
+        "type": "Person",
+        "preferredUsername": SETTINGS.activitypub_username(),
+        "name": SETTINGS.site_title,
+        "url": SitePath::ROOT.external_url(),
+        "inbox": inbox_url(),
+        "outbox": format!("{}activitypub/outbox.json", SETTINGS.external_base_url),
+        "followers": followers_url(),
+        "publicKey": {
+            "id": key_id(),
+            "owner": actor_id(),
+            "publicKeyPem": key.to_public_key_pem(LineEnding::LF)?,
+        },
+    }))
+}
+
+fn webfinger() -> eyre::Result<Value> {
+    let host = url_host(&Url::parse(&SETTINGS.external_base_url)?)?;
+
+    Ok(json!({
+        "subject": format!("acct:{}@{host}", SETTINGS.activitypub_username()),
+        "aliases": [actor_id(), SitePath::ROOT.external_url()],
+        "links": [
+            {"rel": "self", "type": ACTIVITY_JSON, "href": actor_id()},
+            {
+                "rel": "http://webfinger.net/rel/profile-page",
+                "type": "text/html",
+                "href": SitePath::ROOT.external_url(),
+            },
+        ],
+    }))
+}
+
+/// returns the basename and `Note` or `Article` for a thread, or None if it has no page.
+fn object(thread: &Thread, tag_pages: &TagPages) -> eyre::Result<Option<(String, Value)>> {
+    let Some(path) = thread.path.as_ref() else {
+        return Ok(None);
+    };
+    let (Some(rendered_path), Some(default_rendered_path)) =
+        (thread.rendered_path(), path.default_rendered_path()?)
+    else {
+        return Ok(None);
+    };
+    // object ids stay the same if the post gets a slug or the site gets a `permalink_pattern`.
+    let basename = default_rendered_path
+        .filename()
+        .strip_suffix(".html")
+        .ok_or_eyre("rendered path is not html")?
+        .to_owned();
+    let id = format!(
+        "{}activitypub/objects/{basename}.json",
+        SETTINGS.external_base_url
+    );
+    let title = thread
+        .meta
+        .title
+        .as_deref()
+        .filter(|title| !title.is_empty());
+    let tags = thread
+        .meta
+        .tags
+        .iter()
+        .map(|tag| {
+            let mut result = json!({"type": "Hashtag", "name": format!("#{tag}")});
+            if tag_pages.has_page(tag) {
+                result["href"] = SitePath::tag_page_path(tag)?.external_url().into();
+            }
+            Ok(result)
+        })
+        .collect::<eyre::Result<Vec<_>>>()?;
+    let mut result = json!({
+        "@context": "https://www.w3.org/ns/activitystreams",
+        "id": id,
+        "type": if title.is_some() { "Article" } else { "Note" },
+        "attributedTo": actor_id(),
+        "content": absolute_html(thread.main_post()?.safe_html.trim())?,
+        "url": rendered_path.external_url(),
+        "to": [PUBLIC],
+        "cc": [followers_url()],
+        "tag": tags,
+    });
+    if let Some(title) = title {
+        result["name"] = title.into();
+    }
+    if let Some(published) = &thread.meta.published {
+        result["published"] = published.clone().into();
+    }
+    // mastodon and friends show the summary of a sensitive object as its content warning.
+    let content_warnings = thread.meta.content_warnings();
+    if !content_warnings.is_empty() {
+        result["sensitive"] = true.into();
+        result["summary"] = content_warnings.join(", ").into();
+    }
+
+    Ok(Some((basename, result)))
+}
+
+fn create(object: &Value) -> Value {
+    activity("Create", object, object["published"].clone())
+}
+
+fn activity(kind: &str, object: &Value, published: Value) -> Value {
+    let object_id = object["id"].as_str().unwrap_or_default();
+    let mut result = json!({
+        "@context": "https://www.w3.org/ns/activitystreams",
+        "id": format!("{object_id}#{}", kind.to_lowercase()),
+        "type": kind,
+        "actor": actor_id(),
+        "to": [PUBLIC],
+        "cc": [followers_url()],
+        "object": object,
+    });
+    if !published.is_null() {
+        result["published"] = published;
+    }
+
+    result
+}
+
+/// returns `outbox.json` and each `outbox/<n>.json`, relative to `site/activitypub/`.
+fn outbox_pages(creates: &[Value]) -> Vec<(String, Value)> {
+    let base = format!("{}activitypub/", SETTINGS.external_base_url);
+    let page_count = creates.len().div_ceil(OUTBOX_PAGE_SIZE).max(1);
+    let page_url = |page: usize| format!("{base}outbox/{page}.json");
+    let mut result = vec![(
+        "outbox.json".to_owned(),
+        json!({
+            "@context": "https://www.w3.org/ns/activitystreams",
+            "id": format!("{base}outbox.json"),
+            "type": "OrderedCollection",
+            "totalItems": creates.len(),
+            "first": page_url(1),
+            "last": page_url(page_count),
+        }),
+    )];
+    for page in 1..=page_count {
+        let start = (page - 1) * OUTBOX_PAGE_SIZE;
+        let items = &creates[start..creates.len().min(start + OUTBOX_PAGE_SIZE)];
+        let mut outbox = json!({
+            "@context": "https://www.w3.org/ns/activitystreams",
+            "id": page_url(page),
+            "type": "OrderedCollectionPage",
+            "partOf": format!("{base}outbox.json"),
+            "orderedItems": items,
+        });
+        if page > 1 {
+            outbox["prev"] = page_url(page - 1).into();
+        }
+        if page < page_count {
+            outbox["next"] = page_url(page + 1).into();
+        }
+        result.push((format!("outbox/{page}.json"), outbox));
+    }
+
+    result
+}
+
+/// resolves urls in a post against `external_base_url`, since other servers
+/// show the content without our base url.
+fn absolute_html(html: &str) -> eyre::Result<String> {
+    let base = Url::parse(&SETTINGS.external_base_url)?;
+    let dom = parse_html_fragment(html.as_bytes())?;
+    for node in BreadthTraverse::elements(dom.document.clone()) {
+        let NodeData::Element { name, attrs, .. } = &node.data else {
+            unreachable!()
+        };
+        let Some(attr_names) = html_attributes_with_urls().get(name) else {
+            continue;
+        };
+        for attr in attrs.borrow_mut().iter_mut() {
+            if attr_names.contains(&attr.name) {
+                if let Ok(url) = base.join(&attr.value) {
+                    attr.value = url.as_str().into();
+                }
+            }
+        }
+    }
+
+    serialize_html_fragment(dom)
+}
+
+/// loads the objects written by the last render, keyed by id.
+pub fn load_objects() -> eyre::Result<BTreeMap<String, Value>> {
+    let mut result = BTreeMap::default();
+    let dir = SitePath::ROOT.join("activitypub")?.join("objects")?;
+    let entries = match read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == ErrorKind::NotFound => return Ok(result),
+        Err(error) => return Err(error.into()),
+    };
+    for entry in entries {
+        let path = entry?.path();
+        // skip precompressed copies.
+        if !path.extension().is_some_and(|x| x == "json") {
+            continue;
+        }
+        let object: Value = serde_json::from_str(&read_to_string(path)?)?;
+        let id = object["id"].as_str().ok_or_eyre("object has no id")?;
+        result.insert(id.to_owned(), object);
+    }
+
+    Ok(result)
+}
+
+/// delivers a `Create`, `Update`, or `Delete` to every follower for each object that
+/// is new, changed, or gone since the last delivery. the first delivery only records
+/// the existing objects, so new followers don’t get flooded with old posts. returns
+/// the number of activities delivered.
+pub fn deliver(
+    client: &dyn ActivityPubClient,
+    signer: &RequestSigner,
+    store: &ActivityPubStore,
+    objects: &BTreeMap<String, Value>,
+    dry_run: bool,
+) -> eyre::Result<usize> {
+    let hashes = objects
+        .iter()
+        .map(|(id, object)| {
+            let hash = Sha256::digest(object.to_string().as_bytes());
+            (id.clone(), hash.map(|o| format!("{o:02x}")).join(""))
+        })
+        .collect::<BTreeMap<_, _>>();
+    let Some(mut delivered) = store.load_delivered()? else {
+        info!("recording {} existing objects as delivered", hashes.len());
+        if !dry_run {
+            store.save_delivered(&hashes)?;
+        }
+        return Ok(0);
+    };
+
+    let mut activities = vec![];
+    for (id, hash) in hashes.iter() {
+        match delivered.get(id) {
+            Some(previous) if previous == hash => {}
+            Some(_) => activities.push((id.clone(), activity("Update", &objects[id], Value::Null))),
+            None => activities.push((id.clone(), create(&objects[id]))),
+        }
+    }
+    for id in delivered.keys().filter(|id| !hashes.contains_key(*id)) {
+        let tombstone = json!({"id": id, "type": "Tombstone"});
+        activities.push((id.clone(), activity("Delete", &tombstone, Value::Null)));
+    }
+
+    // followers on the same server often share an inbox.
+    let inboxes = store
+        .followers()?
+        .into_iter()
+        .map(|follower| follower.shared_inbox.unwrap_or(follower.inbox))
+        .collect::<BTreeSet<_>>();
+    let mut result = 0;
+    for (id, activity) in activities {
+        let kind = activity["type"].as_str().unwrap_or_default();
+        if dry_run {
+            info!("would deliver {kind} of {id} to {} inboxes", inboxes.len());
+            result += 1;
+            continue;
+        }
+        info!("delivering {kind} of {id} to {} inboxes", inboxes.len());
+        let mut failed = false;
+        for inbox in inboxes.iter() {
+            if let Err(error) = post_activity(client, signer, inbox, &activity) {
+                warn!(?error, "failed to deliver {kind} of {id} to {inbox}");
+                failed = true;
+            }
+        }
+        // if anything failed, leave the log alone, so we try again next time.
+        if failed {
+            continue;
+        }
+        result += 1;
+        match hashes.get(&id) {
+            Some(hash) => delivered.insert(id, hash.clone()),
+            None => delivered.remove(&id),
+        };
+        store.save_delivered(&delivered)?;
+    }
+
+    Ok(result)
+}
+
+/// signs and posts an activity to an inbox.
+pub fn post_activity(
+    client: &dyn ActivityPubClient,
+    signer: &RequestSigner,
+    inbox: &str,
+    activity: &Value,
+) -> eyre::Result<()> {
+    let body = serde_json::to_vec(activity)?;
+    let headers = signer.sign("post", &Url::parse(inbox)?, Some(&body))?;
+    let status = client.post(inbox, &headers, &body)?;
+    if !(200..300).contains(&status) {
+        bail!("inbox returned status {status}");
+    }
+
+    Ok(())
+}
+
+/// handles a `Follow` of our actor, or an `Undo` of one, from the signed-in `actor`.
+/// other activities are ignored.
+pub fn receive(
+    client: &dyn ActivityPubClient,
+    store: &ActivityPubStore,
+    actor: &str,
+    activity: &Value,
+) -> eyre::Result<InboxResult> {
+    if activity["actor"].as_str() != Some(actor) {
+        bail!("activity actor does not match signature");
+    }
+    let id_of = |value: &Value| match value {
+        Value::String(id) => Some(id.clone()),
+        value => value["id"].as_str().map(|id| id.to_owned()),
+    };
+    match activity["type"].as_str() {
+        Some("Follow") if id_of(&activity["object"]).as_deref() == Some(&*actor_id()) => {
+            let document = client.get(actor)?;
+            if document["id"].as_str() != Some(actor) {
+                bail!("actor has wrong id");
+            }
+            let inbox = document["inbox"]
+                .as_str()
+                .ok_or_eyre("actor has no inbox")?;
+            let shared_inbox = document["endpoints"]["sharedInbox"].as_str();
+            info!("new follower: {actor}");
+            store.add_follower(Follower {
+                actor: actor.to_owned(),
+                inbox: inbox.to_owned(),
+                shared_inbox: shared_inbox.map(|inbox| inbox.to_owned()),
+            })?;
+            let accept = json!({
+                "@context": "https://www.w3.org/ns/activitystreams",
+                "id": format!("{}#accept-{}", actor_id(), uuid::Uuid::new_v4()),
+                "type": "Accept",
+                "actor": actor_id(),
+                "object": activity,
+            });
+            Ok(InboxResult::Followed(accept))
+        }
+        Some("Undo") if activity["object"]["type"].as_str() == Some("Follow") => {
+            if store.remove_follower(actor)? {
+                info!("removed follower: {actor}");
+            }
+            Ok(InboxResult::Unfollowed)
+        }
+        _ => Ok(InboxResult::Ignored),
+    }
+}
+
+/// returns the inbox of a follower, for sending them an `Accept`.
+pub fn follower_inbox(store: &ActivityPubStore, actor: &str) -> eyre::Result<String> {
+    store
+        .followers()?
+        .into_iter()
+        .find(|follower| follower.actor == actor)
+        .map(|follower| follower.inbox)
+        .ok_or_eyre("not a follower")
+}
+
+/// serves objects from memory, and records the activities posted to it.
+#[cfg(test)]
+#[derive(Default)]
+pub struct StaticActivityPubClient {
+    pub objects: BTreeMap<String, Value>,
+    pub posted: Mutex<Vec<(String, Vec<(String, String)>, Value)>>,
+}
+
+#[cfg(test)]
+impl ActivityPubClient for StaticActivityPubClient {
+    fn get(&self, url: &str) -> eyre::Result<Value> {
+        let url = url.split('#').next().unwrap_or_default();
+        self.objects
+            .get(url)
+            .cloned()
+            .ok_or_else(|| eyre!("not found: {url}"))
+    }
+
+    fn post(&self, url: &str, headers: &[(String, String)], body: &[u8]) -> eyre::Result<u16> {
+        let activity = serde_json::from_slice(body)?;
+        let posted = (url.to_owned(), headers.to_owned(), activity);
+        self.posted.lock().unwrap().push(posted);
+
+        Ok(202)
+    }
+}
+
+/// a remote actor with a small key, for tests.
+#[cfg(test)]
+pub fn test_remote_actor(actor: &str) -> eyre::Result<(RequestSigner, Value)> {
+    let key = RsaPrivateKey::new(&mut rand::thread_rng(), 1024)?;
+    let document = json!({
+        "id": actor,
+        "type": "Person",
+        "inbox": format!("{actor}/inbox"),
+        "endpoints": {"sharedInbox": "https://remote.example/inbox"},
+        "publicKey": {
+            "id": format!("{actor}#main-key"),
+            "owner": actor,
+            "publicKeyPem": RsaPublicKey::from(&key).to_public_key_pem(LineEnding::LF)?,
+        },
+    });
+
+    Ok((
+        RequestSigner::new(&format!("{actor}#main-key"), key),
+        document,
+    ))
+}
+
+#[test]
+fn test_verify_request() -> eyre::Result<()> {
+    let actor = "https://remote.example/users/a";
+    let (signer, document) = test_remote_actor(actor)?;
+    let client = StaticActivityPubClient {
+        objects: BTreeMap::from([(actor.to_owned(), document)]),
+        ..Default::default()
+    };
+    let url = Url::parse("https://example.com/activitypub/inbox")?;
+    let headers = signer.sign("post", &url, Some(b"{}"))?;
+    let verify = |headers: &[(String, String)], body: &[u8]| {
+        verify_request(&client, "POST", "/activitypub/inbox", headers, body)
+    };
+    assert_eq!(verify(&headers, b"{}")?, actor);
+
+    // the body, path, and signed headers must not change.
+    assert!(verify(&headers, b"{ }").is_err());
+    assert!(verify_request(&client, "POST", "/inbox", &headers, b"{}").is_err());
+    let mut changed = headers.clone();
+    changed[1].1 = http_date(Utc::now() - chrono::Duration::minutes(1));
+    assert!(verify(&changed, b"{}").is_err());
+    assert!(verify(&headers[..3], b"{}").is_err());
+
+    // the key must be from the actor.
+    let (other_signer, _) = test_remote_actor(actor)?;
+    assert!(verify(&other_signer.sign("post", &url, Some(b"{}"))?, b"{}").is_err());
+
+    // the actor must list the key, no matter what the key says its owner is.
+    let evil = "https://evil.example/key";
+    let (evil_signer, mut evil_key) = test_remote_actor("https://evil.example/users/e")?;
+    let mut evil_key = evil_key["publicKey"].take();
+    evil_key["id"] = json!(evil);
+    evil_key["owner"] = json!(actor);
+    let evil_signer = RequestSigner::new(evil, evil_signer.key);
+    let mut client = client;
+    client.objects.insert(evil.to_owned(), evil_key);
+    let verify = |headers: &[(String, String)], body: &[u8]| {
+        verify_request(&client, "POST", "/activitypub/inbox", headers, body)
+    };
+    assert!(verify(&evil_signer.sign("post", &url, Some(b"{}"))?, b"{}").is_err());
+
+    // the host and date must be signed.
+    for names in [
+        ["(request-target)", "date", "digest"],
+        ["(request-target)", "host", "digest"],
+    ] {
+        let mut unsigned = headers[..3].to_vec();
+        let signing_string = signing_string("post", url.path(), &names, &unsigned)?;
+        let signature = SigningKey::<Sha256>::new(signer.key.clone())
+            .sign(signing_string.as_bytes())
+            .to_bytes();
+        unsigned.push((
+            "signature".to_owned(),
+            format!(
+                r#"keyId="{actor}#main-key",headers="{}",signature="{}""#,
+                names.join(" "),
+                BASE64_STANDARD.encode(signature),
+            ),
+        ));
+        assert!(verify(&unsigned, b"{}").is_err());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_outbox_pages() {
+    let creates = (0..45).map(|i| json!(i)).collect::<Vec<_>>();
+    let pages = outbox_pages(&creates);
+    let paths = pages.iter().map(|(path, _)| &**path).collect::<Vec<_>>();
+    assert_eq!(
+        paths,
+        [
+            "outbox.json",
+            "outbox/1.json",
+            "outbox/2.json",
+            "outbox/3.json"
+        ]
+    );
+    assert_eq!(pages[0].1["totalItems"], 45);
+    assert!(pages[0].1["last"]
+        .as_str()
+        .unwrap()
+        .ends_with("outbox/3.json"));
+    assert_eq!(pages[1].1["orderedItems"][0], 0);
+    assert!(pages[1].1.get("prev").is_none());
+    assert!(pages[1].1["next"]
+        .as_str()
+        .unwrap()
+        .ends_with("outbox/2.json"));
+    assert_eq!(pages[3].1["orderedItems"].as_array().unwrap().len(), 5);
+    assert!(pages[3].1.get("next").is_none());
+
+    // an empty outbox still has a first page.
+    assert_eq!(outbox_pages(&[]).len(), 2);
+}
+
+#[test]
+fn test_receive_and_deliver() -> eyre::Result<()> {
+    let dir =
+        std::env::temp_dir().join(format!("autost-test-activitypub-{}", uuid::Uuid::new_v4()));
+    let store = ActivityPubStore::new(&dir);
+    let actor = "https://remote.example/users/a";
+    let (signer, document) = test_remote_actor(actor)?;
+    let client = StaticActivityPubClient {
+        objects: BTreeMap::from([(actor.to_owned(), document)]),
+        ..Default::default()
+    };
+    let follow = json!({
+        "id": "https://remote.example/follows/1",
+        "type": "Follow",
+        "actor": actor,
+        "object": actor_id(),
+    });
+
+    // activities must be from the actor that signed them.
+    assert!(receive(&client, &store, "https://remote.example/users/b", &follow).is_err());
+    let InboxResult::Followed(accept) = receive(&client, &store, actor, &follow)? else {
+        panic!("follow should be accepted");
+    };
+    assert_eq!(accept["object"]["id"], follow["id"]);
+    assert_eq!(follower_inbox(&store, actor)?, format!("{actor}/inbox"));
+
+    // the first delivery only records the existing objects.
+    let object = |content: &str| json!({"id": "https://example.com/1", "content": content});
+    let mut objects = BTreeMap::from([("https://example.com/1".to_owned(), object("a"))]);
+    let delivered = || {
+        client
+            .posted
+            .lock()
+            .unwrap()
+            .drain(..)
+            .map(|(inbox, _, activity)| (inbox, activity["type"].as_str().unwrap().to_owned()))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(deliver(&client, &signer, &store, &objects, false)?, 0);
+    objects.insert("https://example.com/2".to_owned(), object("b"));
+    assert_eq!(deliver(&client, &signer, &store, &objects, true)?, 1);
+    assert_eq!(deliver(&client, &signer, &store, &objects, false)?, 1);
+    let shared_inbox = "https://remote.example/inbox".to_owned();
+    assert_eq!(delivered(), [(shared_inbox.clone(), "Create".to_owned())]);
+    assert_eq!(deliver(&client, &signer, &store, &objects, false)?, 0);
+    objects.insert("https://example.com/2".to_owned(), object("c"));
+    objects.remove("https://example.com/1");
+    assert_eq!(deliver(&client, &signer, &store, &objects, false)?, 2);
+    assert_eq!(
+        delivered(),
+        [
+            (shared_inbox.clone(), "Update".to_owned()),
+            (shared_inbox, "Delete".to_owned())
+        ]
+    );
+
+    let undo = json!({"type": "Undo", "actor": actor, "object": follow});
+    assert_eq!(
+        receive(&client, &store, actor, &undo)?,
+        InboxResult::Unfollowed
+    );
+    assert_eq!(store.followers()?, []);
+    remove_dir_all(dir)?;
+
+    Ok(())
+}
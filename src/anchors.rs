@@ -0,0 +1,264 @@
+use std::{cell::RefCell, collections::BTreeSet};
+
+use html5ever::Attribute;
+use jane_eyre::eyre::{self, OptionExt};
+use markup5ever_rcdom::{Handle, Node, NodeData, RcDom};
+
+use crate::dom::{
+    create_element, text_content, AttrsMutExt, AttrsRefExt, DepthTraverse, HandleExt, QualName,
+    QualNameExt,
+};
+
+/// prefix that ammonia adds to every `id` in a post, for cohost compatibility.
+pub const ID_PREFIX: &str = "user-content-";
+
+struct Heading {
+    level: usize,
+    id: String,
+    text: String,
+}
+
+/// add slug ids and anchor links to headings, optionally insert a table of contents, and fix up
+/// in-page links (footnotes, back-references, etc) so they survive [`ID_PREFIX`].
+///
+/// must be called on the dom from [`crate::meta::extract_metadata`], before sanitising.
+pub fn process_anchors(dom: &mut RcDom, toc: bool) -> eyre::Result<()> {
+    let root = dom
+        .document
+        .children
+        .borrow()
+        .first()
+        .cloned()
+        .ok_or_eyre("expected a root element")?;
+
+    let mut ids = BTreeSet::default();
+    for node in DepthTraverse::elements(root.clone()) {
+        if let Some(id) = node
+            .attrs()
+            .and_then(|attrs| attrs.attr_str("id").ok().flatten().map(|id| id.to_owned()))
+        {
+            ids.insert(id);
+        }
+    }
+
+    let mut headings = vec![];
+    for node in DepthTraverse::elements(root.clone()) {
+        let Some(level) = heading_level(&node) else {
+            continue;
+        };
+        let text = text_content(node.clone())?.trim().to_owned();
+        let mut attrs = node.attrs().expect("guaranteed by heading_level");
+        let id = if let Some(id) = attrs.attr_str("id")? {
+            id.to_owned()
+        } else {
+            let id = unique_slug(&text, &ids);
+            ids.insert(id.clone());
+            attrs.push(Attribute {
+                name: QualName::attribute("id"),
+                value: id.clone().into(),
+            });
+            id
+        };
+        drop(attrs);
+
+        let anchor = create_link(dom, &format!("#{id}"), "#");
+        anchor
+            .attrs()
+            .expect("guaranteed by create_element")
+            .push(Attribute {
+                name: QualName::attribute("class"),
+                value: "heading-anchor".into(),
+            });
+        node.children.borrow_mut().push(anchor);
+        headings.push(Heading { level, id, text });
+    }
+
+    if toc && !headings.is_empty() {
+        let nav = create_toc(dom, &headings);
+        root.children.borrow_mut().insert(0, nav);
+    }
+
+    // ammonia prefixes every id, so rewrite links to those ids to match.
+    for node in DepthTraverse::elements(root) {
+        if node.attrs().is_none() {
+            continue;
+        }
+        let mut attrs = node.attrs().expect("checked above");
+        if let Some(href) = attrs.attr_mut("href") {
+            if let Some(id) = href.value.strip_prefix('#') {
+                if ids.contains(id) && !id.starts_with(ID_PREFIX) {
+                    href.value = format!("#{ID_PREFIX}{id}").into();
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// github-style slug for the given heading text.
+pub fn slug(text: &str) -> String {
+    let result = text
+        .trim()
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c),
+            _ => None,
+        })
+        .collect::<String>();
+
+    if result.is_empty() {
+        "section".to_owned()
+    } else {
+        result
+    }
+}
+
+fn unique_slug(text: &str, ids: &BTreeSet<String>) -> String {
+    let slug = slug(text);
+    if !ids.contains(&slug) {
+        return slug;
+    }
+    (1..)
+        .map(|i| format!("{slug}-{i}"))
+        .find(|id| !ids.contains(id))
+        .expect("guaranteed by infinite range")
+}
+
+fn heading_level(node: &Handle) -> Option<usize> {
+    let NodeData::Element { name, .. } = &node.data else {
+        return None;
+    };
+    (1..=6).find(|level| name == &QualName::html(&format!("h{level}")))
+}
+
+fn create_link(dom: &mut RcDom, href: &str, text: &str) -> Handle {
+    let result = create_element(dom, "a");
+    result
+        .attrs()
+        .expect("guaranteed by create_element")
+        .push(Attribute {
+            name: QualName::attribute("href"),
+            value: href.into(),
+        });
+    result.children.borrow_mut().push(Node::new(NodeData::Text {
+        contents: RefCell::new(text.into()),
+    }));
+
+    result
+}
+
+fn create_toc(dom: &mut RcDom, headings: &[Heading]) -> Handle {
+    let nav = create_element(dom, "nav");
+    nav.attrs()
+        .expect("guaranteed by create_element")
+        .push(Attribute {
+            name: QualName::attribute("class"),
+            value: "toc".into(),
+        });
+    let min_level = headings.iter().map(|h| h.level).min().unwrap_or(1);
+    let list = create_element(dom, "ol");
+    nav.children.borrow_mut().push(list.clone());
+
+    // stack of (level, <ol>), deepest last.
+    let mut stack = vec![(min_level, list)];
+    for heading in headings {
+        while stack.len() > 1
+            && stack
+                .last()
+                .is_some_and(|(level, _)| *level > heading.level)
+        {
+            stack.pop();
+        }
+        let (level, list) = stack.last().cloned().expect("never empty");
+        let list = if heading.level > level {
+            let last_item = list.children.borrow().last().cloned();
+            let last_item = last_item.unwrap_or_else(|| {
+                let item = create_element(dom, "li");
+                list.children.borrow_mut().push(item.clone());
+                item
+            });
+            let nested = create_element(dom, "ol");
+            last_item.children.borrow_mut().push(nested.clone());
+            stack.push((heading.level, nested.clone()));
+            nested
+        } else {
+            list
+        };
+        let item = create_element(dom, "li");
+        let link = create_link(dom, &format!("#{}", heading.id), &heading.text);
+        item.children.borrow_mut().push(link);
+        list.children.borrow_mut().push(item);
+    }
+
+    nav
+}
+
+#[test]
+fn test_slug() {
+    assert_eq!(slug("Hello, World!"), "hello-world");
+    assert_eq!(slug("  foo_bar-baz  "), "foo_bar-baz");
+    assert_eq!(slug("日本語 テキスト"), "日本語-テキスト");
+    assert_eq!(slug("???"), "section");
+
+    let ids = BTreeSet::from(["foo".to_owned(), "foo-1".to_owned()]);
+    assert_eq!(unique_slug("foo", &ids), "foo-2");
+    assert_eq!(unique_slug("bar", &ids), "bar");
+}
+
+#[test]
+fn test_process_anchors() -> eyre::Result<()> {
+    use crate::{dom::serialize_html_fragment, meta::extract_metadata, TemplatedPost};
+
+    fn process(html: &str, toc: bool) -> eyre::Result<String> {
+        let mut dom = extract_metadata(html)?.dom;
+        process_anchors(&mut dom, toc)?;
+        serialize_html_fragment(dom)
+    }
+
+    assert_eq!(
+        process("<h2>foo</h2><h2>foo</h2><h3 id=bar>bar</h3>", false)?,
+        concat!(
+            r##"<h2 id="foo">foo<a href="#user-content-foo" class="heading-anchor">#</a></h2>"##,
+            r##"<h2 id="foo-1">foo<a href="#user-content-foo-1" class="heading-anchor">#</a></h2>"##,
+            r##"<h3 id="bar">bar<a href="#user-content-bar" class="heading-anchor">#</a></h3>"##,
+        )
+    );
+    assert_eq!(
+        process("<h2>a</h2><h3>b</h3><h2>c</h2>", true)?,
+        concat!(
+            r##"<nav class="toc"><ol>"##,
+            r##"<li><a href="#user-content-a">a</a><ol><li><a href="#user-content-b">b</a></li></ol></li>"##,
+            r##"<li><a href="#user-content-c">c</a></li>"##,
+            r##"</ol></nav>"##,
+            r##"<h2 id="a">a<a href="#user-content-a" class="heading-anchor">#</a></h2>"##,
+            r##"<h3 id="b">b<a href="#user-content-b" class="heading-anchor">#</a></h3>"##,
+            r##"<h2 id="c">c<a href="#user-content-c" class="heading-anchor">#</a></h2>"##,
+        )
+    );
+    // links to ids that don’t exist are left alone, as are ids that are already prefixed.
+    assert_eq!(
+        process(
+            r##"<a href="#nope"></a><a href="#user-content-x"></a><p id="user-content-x"></p>"##,
+            false
+        )?,
+        r##"<a href="#nope"></a><a href="#user-content-x"></a><p id="user-content-x"></p>"##
+    );
+
+    // footnote references and back-references survive sanitising.
+    let post = TemplatedPost::filter(&crate::render_markdown("foo[^1]\n\n[^1]: bar"), None)?;
+    assert!(post.safe_html.contains(r##"href="#user-content-fn-1""##));
+    assert!(post.safe_html.contains(r##"id="user-content-fn-1""##));
+    assert!(post.safe_html.contains(r##"href="#user-content-fnref-1""##));
+    assert!(post.safe_html.contains(r##"id="user-content-fnref-1""##));
+
+    // the table of contents and heading anchors survive sanitising.
+    let post = TemplatedPost::filter("<meta name=toc><h2>foo</h2>", None)?;
+    assert_eq!(post.meta.toc, true);
+    assert!(post.safe_html.contains(r##"<nav class="toc"><ol><li><a href="#user-content-foo" rel="noopener noreferrer">foo</a></li></ol></nav>"##));
+    assert!(post.safe_html.contains(r##"<h2 id="user-content-foo">foo<a href="#user-content-foo" class="heading-anchor" rel="noopener noreferrer">#</a></h2>"##));
+
+    Ok(())
+}
@@ -0,0 +1,659 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs::read_to_string,
+};
+
+use chrono::DateTime;
+use jane_eyre::eyre::{self, bail};
+use markup5ever_rcdom::NodeData;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use tracing::{error, warn};
+use yaml_rust2::ScanError;
+
+use crate::{
+    dom::{parse_html_fragment, AttrsRefExt, DepthTraverse, QualName, QualNameExt},
+    front_matter::{parse_front_matter_value, split_front_matter, FrontMatter, FrontMatterStyle},
+    micropub::split_post_source,
+    path::PostsPath,
+    SETTINGS,
+};
+
+/// checks that `autost check` and `autost render` can report, with levels set in `[lints]`.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd)]
+#[serde(rename_all = "snake_case")]
+pub enum Lint {
+    /// `<meta name>` that autost doesn’t know about, which is ignored.
+    UnknownMeta,
+    /// title that another post already has.
+    DuplicateTitle,
+    /// `published` that isn’t an rfc 3339 date.
+    BadDate,
+    /// `references` to a post that doesn’t exist.
+    MissingReference,
+    /// `references` to the post itself.
+    SelfReference,
+    /// author with an `href` but no `display_name`.
+    AuthorWithoutDisplayName,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd)]
+#[serde(rename_all = "snake_case")]
+pub enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
+}
+
+impl Lint {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::UnknownMeta => "unknown_meta",
+            Self::DuplicateTitle => "duplicate_title",
+            Self::BadDate => "bad_date",
+            Self::MissingReference => "missing_reference",
+            Self::SelfReference => "self_reference",
+            Self::AuthorWithoutDisplayName => "author_without_display_name",
+        }
+    }
+
+    pub fn default_level(self) -> LintLevel {
+        match self {
+            // these would make the post fail to render anyway.
+            Self::MissingReference | Self::SelfReference => LintLevel::Deny,
+            Self::UnknownMeta
+            | Self::DuplicateTitle
+            | Self::BadDate
+            | Self::AuthorWithoutDisplayName => LintLevel::Warn,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Diagnostic {
+    pub path: PostsPath,
+    pub line: usize,
+    pub column: usize,
+    pub level: LintLevel,
+    /// None for errors that can’t be allowed, like front matter that fails to parse.
+    pub lint: Option<Lint>,
+    pub message: String,
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let level = match self.level {
+            LintLevel::Deny => "error",
+            LintLevel::Warn | LintLevel::Allow => "warning",
+        };
+        write!(
+            f,
+            "{}:{}:{}: {level}",
+            self.path.as_ref().display(),
+            self.line,
+            self.column
+        )?;
+        if let Some(lint) = self.lint {
+            write!(f, "[{}]", lint.name())?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Report {
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Report {
+    pub fn errors(&self) -> usize {
+        self.diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.level == LintLevel::Deny)
+            .count()
+    }
+
+    pub fn warnings(&self) -> usize {
+        self.diagnostics.len() - self.errors()
+    }
+
+    /// logs each diagnostic, then fails if any of them are errors.
+    pub fn log(&self) -> eyre::Result<()> {
+        for diagnostic in self.diagnostics.iter() {
+            match diagnostic.level {
+                LintLevel::Deny => error!("{diagnostic}"),
+                LintLevel::Warn | LintLevel::Allow => warn!("{diagnostic}"),
+            }
+        }
+        if self.errors() > 0 {
+            bail!(
+                "front matter has {} errors (see `autost check`)",
+                self.errors()
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// checks the front matter of the given posts, with lint levels from `[lints]`.
+pub fn check_posts(paths: &[PostsPath]) -> eyre::Result<Report> {
+    let mut sources = vec![];
+    for path in paths {
+        // skip files that aren’t posts.
+        if path.default_rendered_path()?.is_none() {
+            continue;
+        }
+        sources.push((path.clone(), read_to_string(path)?));
+    }
+
+    Ok(check_sources(&sources))
+}
+
+/// like [`check_posts`], but for posts that have already been read.
+pub fn check_sources(sources: &[(PostsPath, String)]) -> Report {
+    let mut checker = Checker::new(|lint| SETTINGS.lint_level(lint));
+    for (path, source) in sources {
+        checker.check_post(path, source, |path| path.as_ref().exists());
+    }
+
+    checker.finish()
+}
+
+pub struct Checker<F> {
+    lint_level: F,
+    diagnostics: Vec<Diagnostic>,
+    titles: BTreeMap<String, PostsPath>,
+}
+
+/// a value in the front matter, and where it was found.
+#[derive(Debug, PartialEq)]
+struct Field {
+    line: usize,
+    column: usize,
+    /// name of the equivalent `<meta name>` or `<link rel>`.
+    name: String,
+    value: Option<String>,
+}
+
+const KNOWN_FIELDS: &[&str] = &[
+    "adult_content",
+    "archived",
+    "author",
+    "author_display_handle",
+    "author_display_name",
+    "author_logged_out_post_visibility",
+    "author_name",
+    "author_privacy",
+    "cws",
+    "is_transparent_share",
+    "published",
+    "references",
+    "slug",
+    "tags",
+    "title",
+    "toc",
+];
+
+impl<F: Fn(Lint) -> LintLevel> Checker<F> {
+    pub fn new(lint_level: F) -> Self {
+        Self {
+            lint_level,
+            diagnostics: vec![],
+            titles: BTreeMap::default(),
+        }
+    }
+
+    pub fn finish(self) -> Report {
+        Report {
+            diagnostics: self.diagnostics,
+        }
+    }
+
+    fn lint(&mut self, path: &PostsPath, field: &Field, lint: Lint, message: String) {
+        let level = (self.lint_level)(lint);
+        if level == LintLevel::Allow {
+            return;
+        }
+        self.diagnostics.push(Diagnostic {
+            path: path.clone(),
+            line: field.line,
+            column: field.column,
+            level,
+            lint: Some(lint),
+            message,
+        });
+    }
+
+    fn error(&mut self, path: &PostsPath, line: usize, column: usize, message: String) {
+        self.diagnostics.push(Diagnostic {
+            path: path.clone(),
+            line,
+            column,
+            level: LintLevel::Deny,
+            lint: None,
+            message,
+        });
+    }
+
+    pub fn check_post(
+        &mut self,
+        path: &PostsPath,
+        source: &str,
+        exists: impl Fn(&PostsPath) -> bool,
+    ) {
+        let (style, front_matter, _) = if path.is_markdown_post() {
+            match split_front_matter(source) {
+                Ok(result) => result,
+                Err(error) => return self.error(path, 1, 1, format!("{error}")),
+            }
+        } else {
+            let (meta, body) = split_post_source(source);
+            (FrontMatterStyle::Html, meta, body)
+        };
+        let fields = match style {
+            FrontMatterStyle::Html => html_fields(front_matter),
+            FrontMatterStyle::Toml | FrontMatterStyle::Yaml => {
+                structured_fields(style, front_matter)
+            }
+        };
+        let fields = match fields {
+            Ok(fields) => fields,
+            Err(error) => {
+                let (line, column) = syntax_error_position(&error, front_matter).unwrap_or((1, 1));
+                return self.error(path, line + 1, column, format!("{error:#}"));
+            }
+        };
+
+        let mut unknown_keys = false;
+        let mut author = None;
+        let mut author_has_display_name = false;
+        for field in fields.iter() {
+            let value = field.value.as_deref().unwrap_or_default();
+            if !KNOWN_FIELDS.contains(&&*field.name) {
+                if style == FrontMatterStyle::Html {
+                    let message = format!("unknown <meta name={:?}>", field.name);
+                    self.lint(path, field, Lint::UnknownMeta, message);
+                } else {
+                    let message = format!("unknown key in front matter: {:?}", field.name);
+                    self.error(path, field.line, field.column, message);
+                    unknown_keys = true;
+                }
+                continue;
+            }
+            match &*field.name {
+                "title" if !value.is_empty() => {
+                    if let Some(other) = self.titles.get(value) {
+                        let message = format!(
+                            "duplicate title {value:?}, also used by {}",
+                            other.as_ref().display()
+                        );
+                        self.lint(path, field, Lint::DuplicateTitle, message);
+                    } else {
+                        self.titles.insert(value.to_owned(), path.clone());
+                    }
+                }
+                "published" => {
+                    if DateTime::parse_from_rfc3339(value).is_err() {
+                        let message = format!("published is not an rfc 3339 date: {value:?}");
+                        self.lint(path, field, Lint::BadDate, message);
+                    }
+                }
+                "references" => match PostsPath::from_references_url(value) {
+                    Ok(reference) if reference == *path => {
+                        let message = format!("post references itself: {value:?}");
+                        self.lint(path, field, Lint::SelfReference, message);
+                    }
+                    Ok(reference) if !exists(&reference) => {
+                        let message = format!("referenced post does not exist: {value:?}");
+                        self.lint(path, field, Lint::MissingReference, message);
+                    }
+                    Ok(_) => {}
+                    Err(error) => {
+                        let message = format!("bad reference {value:?}: {error}");
+                        self.error(path, field.line, field.column, message);
+                    }
+                },
+                "author" if !value.is_empty() => author = Some(field),
+                "author_display_name" if !value.is_empty() => author_has_display_name = true,
+                _ => {}
+            }
+        }
+        if let Some(author) = author.filter(|_| !author_has_display_name) {
+            let message = "author has an href but no display name".to_owned();
+            self.lint(path, author, Lint::AuthorWithoutDisplayName, message);
+        }
+
+        // catch anything else, like values with the wrong type.
+        if matches!(style, FrontMatterStyle::Toml | FrontMatterStyle::Yaml) && !unknown_keys {
+            if let Err(error) = FrontMatter::parse(style, front_matter) {
+                let (line, column) = type_error_position(style, front_matter).unwrap_or((1, 1));
+                self.error(path, line + 1, column, format!("{error:#}"));
+            }
+        }
+    }
+}
+
+/// finds the `<meta>` and `<link>` elements in html front matter.
+///
+/// the elements come from parsing the whole front matter, like [`crate::meta::extract_metadata`]
+/// does, and their positions come from the `<meta` and `<link` tags in the same order.
+fn html_fields(front_matter: &str) -> eyre::Result<Vec<Field>> {
+    let dom = parse_html_fragment(front_matter.as_bytes())?;
+    let mut positions = tag_positions(front_matter, &["meta", "link"]).into_iter();
+    let mut result = vec![];
+    for node in DepthTraverse::elements(dom.document.clone()) {
+        let NodeData::Element { name, attrs, .. } = &node.data else {
+            continue;
+        };
+        let attrs = attrs.borrow();
+        let field = if name == &QualName::html("meta") {
+            attrs.attr_str("name")?.map(|name| {
+                let value = attrs.attr_str("content").ok().flatten();
+                (name.to_owned(), value.map(ToOwned::to_owned))
+            })
+        } else if name == &QualName::html("link") {
+            let href = attrs.attr_str("href")?.map(ToOwned::to_owned);
+            match attrs.attr_str("rel")? {
+                Some(rel @ ("archived" | "references" | "author")) => Some((rel.to_owned(), href)),
+                _ => None,
+            }
+        } else {
+            continue;
+        };
+        // take a position for every element, even if it’s not a field.
+        let (line, column) = positions.next().unwrap_or((1, 1));
+        if let Some((name, value)) = field {
+            result.push(Field {
+                line,
+                column,
+                name,
+                value,
+            });
+        }
+    }
+
+    Ok(result)
+}
+
+/// finds the line and column of each start tag with one of the given names, skipping over
+/// quoted attribute values and comments.
+fn tag_positions(html: &str, names: &[&str]) -> Vec<(usize, usize)> {
+    let mut result = vec![];
+    let (mut line, mut column) = (1, 1);
+    let mut in_tag = false;
+    let mut quote = None;
+    for (i, c) in html.char_indices() {
+        match (in_tag, quote, c) {
+            (true, Some(q), c) if c == q => quote = None,
+            (true, Some(_), _) => {}
+            (true, None, '"' | '\'') => quote = Some(c),
+            (true, None, '>') => in_tag = false,
+            (false, _, '<') => {
+                in_tag = true;
+                let rest = &html[i + 1..];
+                if names.iter().any(|name| {
+                    rest.get(..name.len())
+                        .is_some_and(|tag| tag.eq_ignore_ascii_case(name))
+                        && rest[name.len()..]
+                            .starts_with(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/')
+                }) {
+                    result.push((line, column));
+                }
+            }
+            _ => {}
+        }
+        if c == '\n' {
+            (line, column) = (line + 1, 1);
+        } else {
+            column += 1;
+        }
+    }
+
+    result
+}
+
+/// finds where toml or yaml front matter failed to parse, if the parser says.
+fn syntax_error_position(error: &eyre::Report, front_matter: &str) -> Option<(usize, usize)> {
+    if let Some(error) = error.downcast_ref::<toml::de::Error>() {
+        let before = front_matter.get(..error.span()?.start)?;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        return Some((
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+        ));
+    }
+    if let Some(error) = error.downcast_ref::<ScanError>() {
+        let marker = error.marker();
+        return Some((marker.line(), marker.col() + 1));
+    }
+
+    None
+}
+
+/// finds the first key whose value has the wrong type, since the errors don’t say which key.
+fn type_error_position(style: FrontMatterStyle, front_matter: &str) -> Option<(usize, usize)> {
+    let Value::Object(object) = parse_front_matter_value(style, front_matter).ok()? else {
+        return None;
+    };
+    let (key, _) = object.into_iter().find(|(key, value)| {
+        let single = Map::from_iter([(key.clone(), value.clone())]);
+        serde_json::from_value::<FrontMatter>(Value::Object(single)).is_err()
+    })?;
+
+    Some(find_position(front_matter, &key, ""))
+}
+
+/// finds the keys and values in toml or yaml front matter, which starts on line 2.
+fn structured_fields(style: FrontMatterStyle, front_matter: &str) -> eyre::Result<Vec<Field>> {
+    let Value::Object(object) = parse_front_matter_value(style, front_matter)? else {
+        bail!("front matter is not a table");
+    };
+    let mut result = vec![];
+    let mut push = |key: &str, name: String, value: &Value| {
+        let values = match value {
+            Value::Array(values) => values.iter().collect(),
+            value => vec![value],
+        };
+        for value in values {
+            let value = match value {
+                Value::String(value) => value.clone(),
+                value => value.to_string(),
+            };
+            // point at unknown keys, but at the values of known keys.
+            let needle = if KNOWN_FIELDS.contains(&&*name) {
+                &*value
+            } else {
+                ""
+            };
+            let (line, column) = find_position(front_matter, key, needle);
+            result.push(Field {
+                line: line + 1,
+                column,
+                name: name.clone(),
+                value: Some(value),
+            });
+        }
+    };
+    for (key, value) in object.iter() {
+        match (&**key, value) {
+            ("author", Value::Object(author)) => {
+                for (key, value) in author.iter() {
+                    let name = match &**key {
+                        "href" => "author".to_owned(),
+                        "name"
+                        | "display_name"
+                        | "display_handle"
+                        | "privacy"
+                        | "logged_out_post_visibility" => format!("author_{key}"),
+                        _ => format!("author.{key}"),
+                    };
+                    push(key, name, value);
+                }
+            }
+            _ => push(key, key.clone(), value),
+        }
+    }
+    result.sort_by_key(|field| (field.line, field.column));
+
+    Ok(result)
+}
+
+/// finds the line and column of a value, or failing that its key, or failing that the first line.
+fn find_position(text: &str, key: &str, value: &str) -> (usize, usize) {
+    let lines = text.lines().collect::<Vec<_>>();
+    let key_line = lines.iter().position(|line| {
+        line.trim_start()
+            .strip_prefix(key)
+            .is_some_and(|rest| rest.trim_start().starts_with(['=', ':']))
+    });
+    let Some(key_line) = key_line else {
+        return (1, 1);
+    };
+    for (i, line) in lines.iter().enumerate().skip(key_line) {
+        if let Some(column) = line.find(value).filter(|_| !value.is_empty()) {
+            return (i + 1, line[..column].chars().count() + 1);
+        }
+    }
+    let line = lines[key_line];
+    let column = line.len() - line.trim_start().len();
+
+    (key_line + 1, line[..column].chars().count() + 1)
+}
+
+#[test]
+fn test_check_post() -> eyre::Result<()> {
+    fn check(
+        posts: &[(&str, &str)],
+        lint_level: impl Fn(Lint) -> LintLevel,
+    ) -> eyre::Result<Vec<String>> {
+        let mut checker = Checker::new(lint_level);
+        for (path, source) in posts {
+            let path = PostsPath::from_site_root_relative_path(path)?;
+            checker.check_post(&path, source, |path| {
+                posts
+                    .iter()
+                    .any(|(other, _)| path.as_ref().to_str() == Some(other))
+            });
+        }
+        let report = checker.finish();
+        Ok(report
+            .diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.to_string())
+            .collect())
+    }
+
+    let html = r#"<meta name="title" content="foo">
+<meta name="publsihed" content="2024-10-01T00:00:00Z">
+<meta name="published" content="yesterday">
+<link rel="references" href="2.md"> <link rel="references" href="1.md">
+<link rel="author" href="https://example.com" name="a">
+
+body
+<meta name="unchecked">
+"#;
+    assert_eq!(
+        check(&[("posts/1.md", html)], Lint::default_level)?,
+        [
+            r#"posts/1.md:2:1: warning[unknown_meta]: unknown <meta name="publsihed">"#,
+            r#"posts/1.md:3:1: warning[bad_date]: published is not an rfc 3339 date: "yesterday""#,
+            r#"posts/1.md:4:1: error[missing_reference]: referenced post does not exist: "2.md""#,
+            r#"posts/1.md:4:37: error[self_reference]: post references itself: "1.md""#,
+            r#"posts/1.md:5:1: warning[author_without_display_name]: author has an href but no display name"#,
+        ]
+    );
+
+    let toml = "+++\ntitle = \"foo\"\npublished = 2024-10-01T00:00:00Z\nreferences = [\n  \"1.md\",\n  \"3.md\",\n]\n+++\n";
+    let yaml = "---\ntitle: foo\npublsihed: 2024-10-01T00:00:00Z\n---\n";
+    assert_eq!(
+        check(
+            &[
+                ("posts/1.md", html),
+                ("posts/2.md", toml),
+                ("posts/3.md", yaml)
+            ],
+            |lint| match lint {
+                Lint::DuplicateTitle => LintLevel::Deny,
+                _ => LintLevel::Allow,
+            }
+        )?,
+        [
+            r#"posts/2.md:2:10: error[duplicate_title]: duplicate title "foo", also used by posts/1.md"#,
+            r#"posts/3.md:2:8: error[duplicate_title]: duplicate title "foo", also used by posts/1.md"#,
+            r#"posts/3.md:3:1: error: unknown key in front matter: "publsihed""#,
+        ]
+    );
+
+    assert_eq!(
+        check(&[("posts/1.md", "+++\ntitle = [1]\n")], Lint::default_level)?,
+        ["posts/1.md:1:1: error: unterminated toml front matter: missing closing `+++`"]
+    );
+    assert_eq!(
+        check(
+            &[("posts/1.md", "+++\ntags = \"foo\"\n+++\n")],
+            Lint::default_level
+        )?,
+        [
+            r#"posts/1.md:2:1: error: bad toml front matter: invalid type: string "foo", expected a sequence"#
+        ]
+    );
+
+    // errors point at the key with the wrong type, or where the parser gave up.
+    assert_eq!(
+        check(
+            &[
+                (
+                    "posts/1.md",
+                    "+++\ntitle = \"foo\"\n\ntags = \"foo\"\n+++\n"
+                ),
+                ("posts/2.md", "---\ntitle: bar\ntoc: [yes]\n---\n"),
+                (
+                    "posts/3.md",
+                    "+++\ntitle = \"baz\"\ntags = [\"foo\",, ]\n+++\n"
+                ),
+                ("posts/4.md", "---\ntitle: qux\ntags: [foo\n---\n"),
+            ],
+            Lint::default_level
+        )?
+        .iter()
+        .map(|diagnostic| diagnostic.split(": error").next().unwrap_or_default())
+        .collect::<Vec<_>>(),
+        [
+            "posts/1.md:4:1",
+            "posts/2.md:3:1",
+            "posts/3.md:3:15",
+            "posts/4.md:4:1"
+        ]
+    );
+
+    // html front matter is parsed as a whole, so `>` in attribute values is fine.
+    assert_eq!(
+        check(
+            &[(
+                "posts/1.md",
+                "<meta name=\"title\" content=\"a > b\"> <meta name=\"publsihed\">\n"
+            )],
+            Lint::default_level
+        )?,
+        [r#"posts/1.md:1:37: warning[unknown_meta]: unknown <meta name="publsihed">"#]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_tag_positions() {
+    let html = "<meta name=\"a\" content=\"<link>\">\n  <LINK rel=\"b\"><!-- <meta> --><metadata>";
+    assert_eq!(tag_positions(html, &["meta", "link"]), [(1, 1), (2, 3)]);
+}
+
+#[test]
+fn test_find_position() {
+    let text = "title = \"foo\"\n  references = [\n  \"1.md\",\n]";
+    assert_eq!(find_position(text, "title", "foo"), (1, 10));
+    assert_eq!(find_position(text, "references", "1.md"), (3, 4));
+    assert_eq!(find_position(text, "references", "2.md"), (2, 3));
+    assert_eq!(find_position(text, "tags", "bar"), (1, 1));
+}
@@ -24,6 +24,11 @@ pub struct Post {
     pub publishedAt: String,
     pub headline: String,
     pub tags: Vec<String>,
+    #[serde(default)]
+    pub cws: Vec<String>,
+    /// whether the post was 18+, either by itself or because its project was.
+    #[serde(default)]
+    pub effectiveAdultContent: bool,
     pub postingProject: PostingProject,
     pub shareTree: Vec<Post>,
 
@@ -226,6 +231,28 @@ pub enum Ast {
     Text { value: String },
 }
 
+impl Ast {
+    /// returns true if a paragraph has a newline that is not after a `<br>`, which
+    /// means the chost was rendered without hardbreaks, like older chosts.
+    pub fn has_soft_line_breaks(&self) -> bool {
+        fn visit(node: &Ast, in_paragraph: bool) -> bool {
+            match node {
+                Ast::Root { children } => children.iter().any(|kid| visit(kid, in_paragraph)),
+                Ast::Element {
+                    tagName, children, ..
+                } => {
+                    let in_paragraph = in_paragraph || tagName == "p";
+                    children.iter().any(|kid| visit(kid, in_paragraph))
+                }
+                // with hardbreaks, `first\nsecond` yields `first`, `<br>`, and `\nsecond`.
+                Ast::Text { value } => in_paragraph && value.trim_end().find('\n') > Some(0),
+            }
+        }
+
+        visit(self, false)
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub enum Cacheable<'url> {
     /// cohost attachment (staging.cohostcdn.org/attachment or an equivalent redirect)
@@ -0,0 +1,66 @@
+use jane_eyre::eyre::{self, bail};
+use tracing::info;
+
+use crate::{
+    activitypub::{self, ActivityPubStore, RealActivityPubClient},
+    command::render::render_all,
+    SETTINGS,
+};
+
+#[derive(clap::Args, Debug)]
+pub struct Activitypub {
+    #[command(subcommand)]
+    command: ActivitypubCommand,
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum ActivitypubCommand {
+    /// list the actors following your blog.
+    Followers,
+    /// render the site, then deliver your new, changed, and deleted posts to your followers.
+    Deliver {
+        #[arg(
+            short = 'n',
+            long,
+            help = "show what would be delivered, without delivering anything"
+        )]
+        dry_run: bool,
+    },
+}
+
+pub async fn main(args: Activitypub) -> eyre::Result<()> {
+    if !SETTINGS.activitypub() {
+        bail!("activitypub is not enabled (set `activitypub = true` in autost.toml)");
+    }
+    let store = ActivityPubStore::default();
+    match args.command {
+        ActivitypubCommand::Followers => {
+            for follower in store.followers()? {
+                println!("{}\t{}", follower.actor, follower.inbox);
+            }
+        }
+        ActivitypubCommand::Deliver { dry_run } => {
+            render_all()?;
+            // reqwest::blocking can’t be used on an async runtime thread.
+            let count = tokio::task::spawn_blocking(move || deliver(&store, dry_run)).await??;
+            if dry_run {
+                info!("would deliver {count} activities");
+            } else {
+                info!("delivered {count} activities");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// delivers the objects from the last render, for `autost activitypub deliver` and `autost server`.
+pub fn deliver(store: &ActivityPubStore, dry_run: bool) -> eyre::Result<usize> {
+    let signer = store.signer()?;
+    let client = RealActivityPubClient {
+        signer: signer.clone(),
+    };
+    let objects = activitypub::load_objects()?;
+
+    activitypub::deliver(&client, &signer, store, &objects, dry_run)
+}
@@ -0,0 +1,37 @@
+use jane_eyre::eyre::{self, bail};
+use tracing::info;
+
+use crate::{check::check_posts, path::PostsPath};
+
+#[derive(clap::Args, Debug)]
+pub struct Check {
+    /// posts to check (default: all posts in `posts`)
+    specific_post_paths: Vec<String>,
+}
+
+pub fn main(args: Check) -> eyre::Result<()> {
+    let post_paths = if args.specific_post_paths.is_empty() {
+        PostsPath::list_posts()?
+    } else {
+        args.specific_post_paths
+            .iter()
+            .map(|path| PostsPath::from_site_root_relative_path(path))
+            .collect::<eyre::Result<Vec<_>>>()?
+    };
+
+    let report = check_posts(&post_paths)?;
+    for diagnostic in report.diagnostics.iter() {
+        println!("{diagnostic}");
+    }
+    info!(
+        "checked {} posts: {} errors, {} warnings",
+        post_paths.len(),
+        report.errors(),
+        report.warnings()
+    );
+    if report.errors() > 0 {
+        bail!("front matter has {} errors", report.errors());
+    }
+
+    Ok(())
+}
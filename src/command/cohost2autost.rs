@@ -24,9 +24,10 @@ use crate::{
         debug_not_known_good_attributes_seen, html_attributes_with_urls, parse_html_fragment,
         serialize_html_fragment, AttrsMutExt, AttrsRefExt, QualNameExt, TendrilExt, Transform,
     },
+    markdown::{render_cohost_markdown, CohostMarkdownOptions, MarkdownOptions},
     migrations::run_migrations,
     path::{PostsPath, SitePath},
-    render_markdown, PostMeta,
+    PostMeta,
 };
 
 #[derive(clap::Args, Debug)]
@@ -135,8 +136,16 @@ fn convert_single_chost(
         title: Some(post.headline),
         published: Some(post.publishedAt),
         author: Some((&post.postingProject).into()),
+        author_privacy: Some(post.postingProject.privacy.clone()),
+        author_logged_out_post_visibility: Some(
+            post.postingProject.loggedOutPostVisibility.clone(),
+        ),
         tags: post.tags,
         is_transparent_share: post.transparentShareOfPostId.is_some(),
+        toc: false,
+        slug: None,
+        cws: post.cws,
+        adult_content: post.effectiveAdultContent,
     };
     output.write_all(meta.render()?.as_bytes())?;
     output.write_all(b"\n\n")?;
@@ -160,6 +169,8 @@ fn convert_single_chost(
         })
         .collect::<Result<Vec<_>, _>>()?;
     spans.sort_by_key(|(_ast, start, end)| (*start, *end));
+    // older chosts were rendered without hardbreaks. we can only tell from their `astMap`.
+    let hardbreaks = !spans.iter().any(|(ast, _, _)| ast.has_soft_line_breaks());
     let mut spans = VecDeque::from(spans);
 
     for (i, block) in post.blocks.into_iter().enumerate() {
@@ -225,7 +236,7 @@ fn convert_single_chost(
 
         match block {
             Block::Markdown { markdown } => {
-                let html = render_markdown_block(&markdown.content, context)?;
+                let html = render_markdown_block(&markdown.content, hardbreaks, context)?;
                 output.write_all(html.as_bytes())?;
                 continue;
             }
@@ -238,7 +249,7 @@ fn convert_single_chost(
                         ..
                     },
             } => {
-                let html = render_markdown_block(&content, context)?;
+                let html = render_markdown_block(&content, hardbreaks, context)?;
                 let template = AskTemplate {
                     author: askingProject,
                     content: html,
@@ -352,8 +363,16 @@ struct AskTemplate {
     content: String,
 }
 
-fn render_markdown_block(markdown: &str, context: &dyn AttachmentsContext) -> eyre::Result<String> {
-    let html = render_markdown(markdown);
+fn render_markdown_block(
+    markdown: &str,
+    hardbreaks: bool,
+    context: &dyn AttachmentsContext,
+) -> eyre::Result<String> {
+    let html = render_cohost_markdown(
+        markdown,
+        &CohostMarkdownOptions::from_settings(hardbreaks),
+        &MarkdownOptions::from_settings(),
+    );
     let dom = parse_html_fragment(html.as_bytes())?;
 
     process_chost_fragment(dom, context)
@@ -500,59 +519,165 @@ fn process_chost_fragment(
     Ok(serialize_html_fragment(dom)?)
 }
 
-#[test]
-fn test_render_markdown_block() -> eyre::Result<()> {
-    use crate::path::AttachmentsPath;
-    struct TestAttachmentsContext {}
-    impl AttachmentsContext for TestAttachmentsContext {
-        fn store(&self, _input_path: &Path) -> eyre::Result<AttachmentsPath> {
-            unreachable!()
-        }
-        fn cache_imported(
-            &self,
-            _url: &str,
-            _post_basename: &str,
-        ) -> eyre::Result<AttachmentsPath> {
-            unreachable!();
-        }
-        fn cache_cohost_resource(
-            &self,
-            cacheable: &Cacheable,
-        ) -> eyre::Result<CachedFileResult<AttachmentsPath>> {
-            Ok(CachedFileResult::CachedPath(match cacheable {
-                Cacheable::Attachment { id, .. } => AttachmentsPath::ROOT.join(&format!("{id}"))?,
-                Cacheable::Static { filename, .. } => {
-                    AttachmentsPath::COHOST_STATIC.join(&format!("{filename}"))?
-                }
-                Cacheable::Avatar { filename, .. } => {
-                    AttachmentsPath::COHOST_AVATAR.join(&format!("{filename}"))?
-                }
-                Cacheable::Header { filename, .. } => {
-                    AttachmentsPath::COHOST_HEADER.join(&format!("{filename}"))?
-                }
-            }))
-        }
-        fn cache_cohost_thumb(&self, id: &str) -> eyre::Result<CachedFileResult<AttachmentsPath>> {
-            Ok(CachedFileResult::CachedPath(
-                AttachmentsPath::THUMBS.join(&format!("{id}"))?,
-            ))
-        }
+#[cfg(test)]
+use crate::path::AttachmentsPath;
+
+#[cfg(test)]
+struct TestAttachmentsContext {}
+
+#[cfg(test)]
+impl AttachmentsContext for TestAttachmentsContext {
+    fn store(&self, _input_path: &Path) -> eyre::Result<AttachmentsPath> {
+        unreachable!()
+    }
+    fn cache_imported(&self, _url: &str, _post_basename: &str) -> eyre::Result<AttachmentsPath> {
+        unreachable!();
+    }
+    fn cache_cohost_resource(
+        &self,
+        cacheable: &Cacheable,
+    ) -> eyre::Result<CachedFileResult<AttachmentsPath>> {
+        Ok(CachedFileResult::CachedPath(match cacheable {
+            Cacheable::Attachment { id, .. } => AttachmentsPath::ROOT.join(&format!("{id}"))?,
+            Cacheable::Static { filename, .. } => {
+                AttachmentsPath::COHOST_STATIC.join(&format!("{filename}"))?
+            }
+            Cacheable::Avatar { filename, .. } => {
+                AttachmentsPath::COHOST_AVATAR.join(&format!("{filename}"))?
+            }
+            Cacheable::Header { filename, .. } => {
+                AttachmentsPath::COHOST_HEADER.join(&format!("{filename}"))?
+            }
+        }))
+    }
+    fn cache_cohost_thumb(&self, id: &str) -> eyre::Result<CachedFileResult<AttachmentsPath>> {
+        Ok(CachedFileResult::CachedPath(
+            AttachmentsPath::THUMBS.join(&format!("{id}"))?,
+        ))
     }
+}
 
+#[test]
+fn test_render_markdown_block() -> eyre::Result<()> {
     let n = "\n";
     let context = TestAttachmentsContext {};
     assert_eq!(
-        render_markdown_block("text", &context)?,
+        render_markdown_block("text", true, &context)?,
         format!(r#"<p>text</p>{n}"#)
     );
-    assert_eq!(render_markdown_block("![text](https://cohost.org/rc/attachment-redirect/44444444-4444-4444-4444-444444444444)", &context)?,
+    assert_eq!(render_markdown_block("![text](https://cohost.org/rc/attachment-redirect/44444444-4444-4444-4444-444444444444)", true, &context)?,
         format!(r#"<p><img src="attachments/44444444-4444-4444-4444-444444444444" alt="text" data-cohost-src="https://cohost.org/rc/attachment-redirect/44444444-4444-4444-4444-444444444444" loading="lazy"></p>{n}"#));
-    assert_eq!(render_markdown_block("<img src=https://cohost.org/rc/attachment-redirect/44444444-4444-4444-4444-444444444444>", &context)?,
+    assert_eq!(render_markdown_block("<img src=https://cohost.org/rc/attachment-redirect/44444444-4444-4444-4444-444444444444>", true, &context)?,
         format!(r#"<img src="attachments/44444444-4444-4444-4444-444444444444" data-cohost-src="https://cohost.org/rc/attachment-redirect/44444444-4444-4444-4444-444444444444" loading="lazy">{n}"#));
-    assert_eq!(render_markdown_block("[text](https://cohost.org/rc/attachment-redirect/44444444-4444-4444-4444-444444444444)", &context)?,
+    assert_eq!(render_markdown_block("[text](https://cohost.org/rc/attachment-redirect/44444444-4444-4444-4444-444444444444)", true, &context)?,
         format!(r#"<p><a href="attachments/44444444-4444-4444-4444-444444444444" data-cohost-href="https://cohost.org/rc/attachment-redirect/44444444-4444-4444-4444-444444444444">text</a></p>{n}"#));
-    assert_eq!(render_markdown_block("<a href=https://cohost.org/rc/attachment-redirect/44444444-4444-4444-4444-444444444444>text</a>", &context)?,
+    assert_eq!(render_markdown_block("<a href=https://cohost.org/rc/attachment-redirect/44444444-4444-4444-4444-444444444444>text</a>", true, &context)?,
         format!(r#"<p><a href="attachments/44444444-4444-4444-4444-444444444444" data-cohost-href="https://cohost.org/rc/attachment-redirect/44444444-4444-4444-4444-444444444444">text</a></p>{n}"#));
 
     Ok(())
 }
+
+/// compares our rendering of cohost markdown with cohost’s own, for each chost in
+/// `tests/cohost-markdown`. to add a chost, copy its `post.json` from a cohost archive, and
+/// optionally remove everything but `blocks` and `astMap`. only add real exported chosts,
+/// since the point is to compare with what cohost actually did.
+#[test]
+fn test_cohost_markdown_golden() -> eyre::Result<()> {
+    use std::{collections::BTreeMap, fs::read_to_string};
+
+    use crate::{cohost::AstMap, dom::BreadthTraverse};
+
+    #[derive(Deserialize)]
+    #[allow(non_snake_case)]
+    struct GoldenChost {
+        blocks: Vec<Block>,
+        astMap: AstMap,
+    }
+
+    // cohost separates top-level blocks with newlines, and its emotes are cached in
+    // `cohost-static` with their original urls and `loading`, but ours come from
+    // `attachments/emoji`. so ignore whitespace between top-level blocks, and compare emotes
+    // by filename only.
+    fn normalise(html: &str) -> eyre::Result<String> {
+        let dom = parse_html_fragment(html.as_bytes())?;
+        let root = dom.document.children.borrow()[0].clone();
+        root.children.borrow_mut().retain(|kid| match &kid.data {
+            NodeData::Text { contents } => !contents.borrow().trim().is_empty(),
+            _ => true,
+        });
+        for node in BreadthTraverse::nodes(dom.document.clone()) {
+            if let NodeData::Element { name, attrs, .. } = &node.data {
+                let mut attrs = attrs.borrow_mut();
+                attrs.sort_by(|p, q| p.name.local.cmp(&q.name.local));
+                let is_emote = name == &QualName::html("img")
+                    && attrs
+                        .attr_str("alt")
+                        .ok()
+                        .flatten()
+                        .is_some_and(|alt| alt.starts_with(':') && alt.ends_with(':'));
+                if is_emote {
+                    attrs
+                        .retain(|attr| !matches!(&*attr.name.local, "data-cohost-url" | "loading"));
+                    if let Some(src) = attrs.attr_mut("src") {
+                        let filename = src.value.rsplit('/').next().unwrap_or_default();
+                        src.value = filename.to_owned().into();
+                    }
+                }
+            }
+        }
+
+        serialize_html_fragment(dom)
+    }
+
+    let emoji = BTreeMap::from([
+        ("eggbug".to_owned(), "eggbug.svg".to_owned()),
+        ("host-joy".to_owned(), "host-joy.png".to_owned()),
+    ]);
+    let context = TestAttachmentsContext {};
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/cohost-markdown");
+    let mut paths = read_dir(dir)?
+        .map(|entry| Ok(entry?.path()))
+        .collect::<eyre::Result<Vec<_>>>()?;
+    paths.retain(|path| path.extension().is_some_and(|x| x == "json"));
+    paths.sort();
+    assert!(
+        !paths.is_empty(),
+        "no golden chosts in tests/cohost-markdown (see README.md there)"
+    );
+    for path in paths {
+        let chost: GoldenChost = serde_json::from_str(&read_to_string(&path)?)?;
+        let spans = chost
+            .astMap
+            .spans
+            .iter()
+            .map(|span| Ok((serde_json::from_str::<Ast>(&span.ast)?, span)))
+            .collect::<eyre::Result<Vec<_>>>()?;
+        let hardbreaks = !spans.iter().any(|(ast, _)| ast.has_soft_line_breaks());
+        for (ast, span) in spans {
+            let markdown = chost.blocks[span.startIndex..span.endIndex]
+                .iter()
+                .filter_map(|block| match block {
+                    Block::Markdown { markdown } => Some(&*markdown.content),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+                .join("\n\n");
+            let options = CohostMarkdownOptions {
+                hardbreaks,
+                emoji: &emoji,
+            };
+            let html = render_cohost_markdown(&markdown, &options, &MarkdownOptions::default());
+            let actual = process_chost_fragment(parse_html_fragment(html.as_bytes())?, &context)?;
+            let expected = process_chost_fragment(process_ast(ast), &context)?;
+            assert_eq!(
+                normalise(&actual)?,
+                normalise(&expected)?,
+                "{path:?} blocks {}..{}",
+                span.startIndex,
+                span.endIndex
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use jane_eyre::eyre::{self, bail};
+use tracing::info;
+
+use crate::{
+    micropub::find_referencing_posts,
+    path::PostsPath,
+    tombstone::{delete_post, TombstoneStore},
+};
+
+#[derive(clap::Args, Debug)]
+pub struct Delete {
+    /// posts to delete, leaving a tombstone page in their place
+    #[arg(required = true)]
+    post_paths: Vec<String>,
+}
+
+pub fn main(args: Delete) -> eyre::Result<()> {
+    let post_paths = args
+        .post_paths
+        .iter()
+        .map(|path| PostsPath::from_site_root_relative_path(path))
+        .collect::<eyre::Result<Vec<_>>>()?;
+
+    // check all of the posts first, so we don’t delete only some of them.
+    for path in post_paths.iter() {
+        if !path.as_ref().exists() || path.default_rendered_path()?.is_none() {
+            bail!("not a post: {path:?}");
+        }
+        let referencing_posts = find_referencing_posts(path)?
+            .into_iter()
+            .filter(|other| !post_paths.contains(other))
+            .collect::<Vec<_>>();
+        if !referencing_posts.is_empty() {
+            bail!("{path:?} cannot be deleted, because other posts refer to it: {referencing_posts:?}");
+        }
+    }
+
+    let store = TombstoneStore::default();
+    for path in post_paths {
+        delete_post(&store, &path)?;
+        info!("deleted {path:?}");
+    }
+    info!("run `autost render` to write the tombstone pages");
+
+    Ok(())
+}
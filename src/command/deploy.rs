@@ -0,0 +1,288 @@
+use std::{
+    collections::BTreeMap,
+    fs::{copy, create_dir_all, File},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use clap::ArgGroup;
+use flate2::{write::GzEncoder, Compression};
+use jane_eyre::eyre::{self, Context, OptionExt};
+use serde::{Deserialize, Serialize};
+use sha2::{digest::generic_array::functional::FunctionalSequence, Digest, Sha256};
+use tracing::info;
+use zip::{write::SimpleFileOptions, CompressionMethod, ZipWriter};
+
+use crate::{command::render::render_all, path::SitePath};
+
+#[derive(clap::Args, Debug)]
+#[command(group(ArgGroup::new("target").required(true).args(["to", "tar", "zip"])))]
+pub struct Deploy {
+    #[arg(long, help = "copy new and changed files into this directory")]
+    to: Option<PathBuf>,
+    #[arg(
+        long,
+        help = "write all files to this tar archive (.tar, .tar.gz, or .tgz)"
+    )]
+    tar: Option<PathBuf>,
+    #[arg(long, help = "write all files to this zip archive")]
+    zip: Option<PathBuf>,
+    #[arg(
+        short = 'n',
+        long,
+        help = "show what would be published, without writing anything"
+    )]
+    dry_run: bool,
+}
+
+/// written to the target directory or archive, listing what was published.
+pub const MANIFEST_FILENAME: &str = "autost-manifest.json";
+
+/// the files in a deploy, keyed by their path relative to the site output
+/// directory (with `/` separators), with the sha-256 of their contents.
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Manifest {
+    pub files: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct DirDeployResult {
+    /// files that were not in the previous manifest.
+    pub added: Vec<String>,
+    /// files whose hashes changed, or that were missing from the target.
+    pub changed: Vec<String>,
+    pub unchanged: usize,
+    /// files in the previous manifest that are no longer published. these are
+    /// not deleted from the target.
+    pub stale: Vec<String>,
+}
+
+pub fn main(args: Deploy) -> eyre::Result<()> {
+    let output = render_all()?;
+    let paths = output
+        .deploy_paths()
+        .into_iter()
+        .map(|path| path.deploy_path())
+        .collect::<Vec<_>>();
+    let site_root = &*SitePath::ROOT;
+    let site_root = site_root.as_ref();
+    let manifest = Manifest::new(site_root, paths)?;
+    info!("publishing {} files", manifest.files.len());
+
+    if let Some(target) = args.to {
+        let result = deploy_to_dir(site_root, &manifest, &target, args.dry_run)?;
+        let verb = if args.dry_run { "would copy" } else { "copied" };
+        for path in result.added.iter() {
+            info!("{verb} new file: {path}");
+        }
+        for path in result.changed.iter() {
+            info!("{verb} changed file: {path}");
+        }
+        for path in result.stale.iter() {
+            info!("not deleting file that is no longer published: {path}");
+        }
+        info!(
+            "{verb} {} new and {} changed files to {target:?}, skipped {} unchanged files",
+            result.added.len(),
+            result.changed.len(),
+            result.unchanged,
+        );
+    } else if let Some(target) = args.tar {
+        if args.dry_run {
+            log_dry_run_bundle(&manifest, &target);
+        } else {
+            write_tar(site_root, &manifest, &target)?;
+            info!("wrote {} files to {target:?}", manifest.files.len());
+        }
+    } else if let Some(target) = args.zip {
+        if args.dry_run {
+            log_dry_run_bundle(&manifest, &target);
+        } else {
+            write_zip(site_root, &manifest, &target)?;
+            info!("wrote {} files to {target:?}", manifest.files.len());
+        }
+    }
+
+    Ok(())
+}
+
+impl Manifest {
+    /// hashes the given files, which are relative to `root`.
+    pub fn new(root: &Path, paths: impl IntoIterator<Item = String>) -> eyre::Result<Self> {
+        let mut files = BTreeMap::default();
+        for path in paths {
+            let hash = hash_file(&root.join(&path))
+                .wrap_err_with(|| format!("failed to hash file: {path}"))?;
+            files.insert(path, hash);
+        }
+
+        Ok(Self { files })
+    }
+
+    pub fn load(path: &Path) -> eyre::Result<Option<Self>> {
+        match File::open(path) {
+            Ok(file) => Ok(Some(serde_json::from_reader(file)?)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(error)?,
+        }
+    }
+
+    fn to_json(&self) -> eyre::Result<Vec<u8>> {
+        let mut result = serde_json::to_vec_pretty(self)?;
+        result.push(b'\n');
+
+        Ok(result)
+    }
+}
+
+/// copies files from `root` that are new or changed since the manifest in
+/// `target` (if any), then replaces that manifest.
+pub fn deploy_to_dir(
+    root: &Path,
+    manifest: &Manifest,
+    target: &Path,
+    dry_run: bool,
+) -> eyre::Result<DirDeployResult> {
+    let manifest_path = target.join(MANIFEST_FILENAME);
+    let previous = Manifest::load(&manifest_path)?.unwrap_or_default();
+    let mut result = DirDeployResult::default();
+    for (path, hash) in manifest.files.iter() {
+        let target_path = target.join(path);
+        match previous.files.get(path) {
+            Some(previous_hash) if previous_hash == hash && target_path.exists() => {
+                result.unchanged += 1;
+                continue;
+            }
+            Some(_) => result.changed.push(path.clone()),
+            None => result.added.push(path.clone()),
+        }
+        if !dry_run {
+            let parent = target_path.parent().ok_or_eyre("path has no parent")?;
+            create_dir_all(parent)?;
+            copy(root.join(path), &target_path)
+                .wrap_err_with(|| format!("failed to copy file: {path}"))?;
+        }
+    }
+    result.stale = previous
+        .files
+        .keys()
+        .filter(|path| !manifest.files.contains_key(*path))
+        .cloned()
+        .collect();
+    if !dry_run {
+        File::create(manifest_path)?.write_all(&manifest.to_json()?)?;
+    }
+
+    Ok(result)
+}
+
+/// writes every file in the manifest, plus the manifest, to a tar archive.
+/// the archive is gzipped if `target` ends with `.gz` or `.tgz`.
+pub fn write_tar(root: &Path, manifest: &Manifest, target: &Path) -> eyre::Result<()> {
+    let file = File::create(target)?;
+    let is_gzip = target.extension().is_some_and(|x| x == "gz" || x == "tgz");
+    if is_gzip {
+        let encoder = write_tar_entries(root, manifest, GzEncoder::new(file, Compression::best()))?;
+        encoder.finish()?;
+    } else {
+        write_tar_entries(root, manifest, file)?;
+    }
+
+    Ok(())
+}
+
+fn write_tar_entries<W: Write>(root: &Path, manifest: &Manifest, writer: W) -> eyre::Result<W> {
+    let mut builder = tar::Builder::new(writer);
+    for path in manifest.files.keys() {
+        builder.append_path_with_name(root.join(path), path)?;
+    }
+    let manifest_json = manifest.to_json()?;
+    let mut header = tar::Header::new_gnu();
+    header.set_size(manifest_json.len().try_into()?);
+    header.set_mode(0o644);
+    builder.append_data(&mut header, MANIFEST_FILENAME, &*manifest_json)?;
+
+    Ok(builder.into_inner()?)
+}
+
+/// writes every file in the manifest, plus the manifest, to a zip archive.
+pub fn write_zip(root: &Path, manifest: &Manifest, target: &Path) -> eyre::Result<()> {
+    let mut writer = ZipWriter::new(File::create(target)?);
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    for path in manifest.files.keys() {
+        writer.start_file(&**path, options)?;
+        io::copy(&mut File::open(root.join(path))?, &mut writer)?;
+    }
+    writer.start_file(MANIFEST_FILENAME, options)?;
+    writer.write_all(&manifest.to_json()?)?;
+    writer.finish()?;
+
+    Ok(())
+}
+
+fn log_dry_run_bundle(manifest: &Manifest, target: &Path) {
+    for path in manifest.files.keys() {
+        info!("would write file: {path}");
+    }
+    info!("would write {} files to {target:?}", manifest.files.len());
+}
+
+fn hash_file(path: &Path) -> eyre::Result<String> {
+    let mut hash = Sha256::new();
+    io::copy(&mut File::open(path)?, &mut hash)?;
+
+    Ok(hash.finalize().map(|o| format!("{o:02x}")).join(""))
+}
+
+#[test]
+fn test_deploy_to_dir() -> eyre::Result<()> {
+    use std::{
+        env::temp_dir,
+        fs::{read_to_string, remove_dir_all},
+    };
+
+    let dir = temp_dir().join(format!("autost-test-deploy-{}", uuid::Uuid::new_v4()));
+    let (root, target) = (dir.join("site"), dir.join("target"));
+    create_dir_all(root.join("tagged"))?;
+    File::create(root.join("index.html"))?.write_all(b"index")?;
+    File::create(root.join("tagged/foo.html"))?.write_all(b"foo")?;
+    let paths = || ["index.html".to_owned(), "tagged/foo.html".to_owned()];
+
+    // dry runs don’t write anything.
+    let manifest = Manifest::new(&root, paths())?;
+    let result = deploy_to_dir(&root, &manifest, &target, true)?;
+    assert_eq!(result.added, paths());
+    assert!(!target.exists());
+
+    let result = deploy_to_dir(&root, &manifest, &target, false)?;
+    assert_eq!(result.added, paths());
+    assert_eq!(read_to_string(target.join("tagged/foo.html"))?, "foo");
+    assert_eq!(
+        Manifest::load(&target.join(MANIFEST_FILENAME))?,
+        Some(manifest)
+    );
+
+    // only changed files are copied.
+    File::create(root.join("tagged/foo.html"))?.write_all(b"bar")?;
+    let manifest = Manifest::new(&root, paths())?;
+    let result = deploy_to_dir(&root, &manifest, &target, false)?;
+    assert_eq!(
+        result,
+        DirDeployResult {
+            changed: vec!["tagged/foo.html".to_owned()],
+            unchanged: 1,
+            ..Default::default()
+        }
+    );
+    assert_eq!(read_to_string(target.join("tagged/foo.html"))?, "bar");
+
+    // files that are no longer published are reported, but not deleted.
+    let manifest = Manifest::new(&root, ["index.html".to_owned()])?;
+    let result = deploy_to_dir(&root, &manifest, &target, false)?;
+    assert_eq!(result.stale, ["tagged/foo.html"]);
+    assert!(target.join("tagged/foo.html").exists());
+
+    remove_dir_all(dir)?;
+
+    Ok(())
+}
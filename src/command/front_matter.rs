@@ -0,0 +1,111 @@
+use std::fs::{read_to_string, File};
+use std::io::Write;
+
+use jane_eyre::eyre::{self, bail};
+use tracing::info;
+
+use crate::{
+    front_matter::{front_matter_to_html, post_source, split_front_matter, FrontMatterStyle},
+    meta::extract_metadata,
+    path::PostsPath,
+    render_markdown,
+};
+
+#[derive(clap::Args, Debug)]
+pub struct FrontMatter {
+    #[arg(long, value_enum, help = "front matter style to convert to")]
+    to: FrontMatterStyle,
+
+    #[arg(
+        short = 'n',
+        long,
+        help = "show what would be converted, without writing anything"
+    )]
+    dry_run: bool,
+
+    /// markdown posts to convert (default: all markdown posts in `posts`)
+    paths: Vec<String>,
+}
+
+pub fn main(args: FrontMatter) -> eyre::Result<()> {
+    let paths = if args.paths.is_empty() {
+        PostsPath::list_posts()?
+    } else {
+        args.paths
+            .iter()
+            .map(|path| PostsPath::from_site_root_relative_path(path))
+            .collect::<eyre::Result<Vec<_>>>()?
+    };
+
+    let mut count = 0;
+    for path in paths {
+        if !path.is_markdown_post() {
+            continue;
+        }
+        let source = read_to_string(&path)?;
+        let Some(converted) = convert(&source, args.to)? else {
+            continue;
+        };
+        if args.dry_run {
+            info!("would convert {path:?}");
+        } else {
+            info!("converting {path:?}");
+            File::create(&path)?.write_all(converted.as_bytes())?;
+        }
+        count += 1;
+    }
+    if args.dry_run {
+        info!(
+            "would convert {count} posts to {} front matter",
+            args.to.name()
+        );
+    } else {
+        info!("converted {count} posts to {} front matter", args.to.name());
+    }
+
+    Ok(())
+}
+
+/// converts the front matter of a markdown post, or returns None if already in that style.
+pub fn convert(source: &str, to: FrontMatterStyle) -> eyre::Result<Option<String>> {
+    let (from, _, body) = split_front_matter(source)?;
+    if from == to {
+        return Ok(None);
+    }
+    let meta = extract_metadata(&render_markdown(&front_matter_to_html(source)?))?.meta;
+    let result = post_source(to, &meta, body)?;
+
+    // never lose metadata that the new style can’t represent.
+    let new_meta = extract_metadata(&render_markdown(&front_matter_to_html(&result)?))?.meta;
+    if new_meta != meta {
+        bail!("metadata would change: {meta:?} -> {new_meta:?}");
+    }
+
+    Ok(Some(result))
+}
+
+#[test]
+fn test_convert() -> eyre::Result<()> {
+    let html = "<meta name=\"title\" content=\"foo\">\n<meta name=\"tags\" content=\"bar\">\n\npost *body*\n";
+    let toml = convert(html, FrontMatterStyle::Toml)?.expect("not already toml");
+    assert_eq!(
+        toml,
+        "+++\ntitle = \"foo\"\ntags = [\"bar\"]\n+++\n\npost *body*\n"
+    );
+    assert_eq!(convert(&toml, FrontMatterStyle::Toml)?, None);
+
+    let yaml = convert(&toml, FrontMatterStyle::Yaml)?.expect("not already yaml");
+    assert_eq!(
+        yaml,
+        "---\ntitle: foo\ntags:\n  - bar\n---\n\npost *body*\n"
+    );
+
+    let html = convert(&yaml, FrontMatterStyle::Html)?.expect("not already html");
+    assert_eq!(
+        extract_metadata(&render_markdown(&html))?.meta.tags,
+        ["bar"]
+    );
+    assert_eq!(split_front_matter(&html)?.2, "post *body*\n");
+
+    Ok(())
+}
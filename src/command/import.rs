@@ -101,18 +101,7 @@ async fn fetch_post(url: &str) -> eyre::Result<FetchPostResult> {
     let client = reqwest::Client::new();
     let response = client.get(url).send().await?;
     let dom = parse_html_document(&response.bytes().await?)?;
-    let mut base_href = Url::parse(&url)?;
-    for node in BreadthTraverse::elements(dom.document.clone()) {
-        let NodeData::Element { name, attrs, .. } = &node.data else {
-            unreachable!()
-        };
-        if name == &QualName::html("base") {
-            if let Some(href) = attrs.borrow().attr_str("href")? {
-                base_href = base_href.join(href)?;
-                break;
-            }
-        }
-    }
+    let base_href = document_base_href(dom.document.clone(), &Url::parse(url)?)?;
 
     let h_entry = mf2_find(dom.document.clone(), "h-entry").ok_or_eyre("no .h-entry found")?;
     let e_content =
@@ -177,8 +166,14 @@ async fn fetch_post(url: &str) -> eyre::Result<FetchPostResult> {
         title: p_name,
         published: dt_published,
         author: Some(author),
+        author_privacy: None,
+        author_logged_out_post_visibility: None,
         tags,
         is_transparent_share: false,
+        toc: false,
+        slug: None,
+        cws: vec![],
+        adult_content: false,
     };
     debug!(?meta);
 
@@ -296,7 +291,23 @@ fn process_content(
     Ok(serialize_html_fragment(dom)?)
 }
 
-fn mf2_e(node: Handle, class: &str) -> eyre::Result<Option<String>> {
+/// returns the url that relative urls in the document are resolved against.
+pub fn document_base_href(document: Handle, url: &Url) -> eyre::Result<Url> {
+    for node in BreadthTraverse::elements(document) {
+        let NodeData::Element { name, attrs, .. } = &node.data else {
+            unreachable!()
+        };
+        if name == &QualName::html("base") {
+            if let Some(href) = attrs.borrow().attr_str("href")? {
+                return Ok(url.join(href)?);
+            }
+        }
+    }
+
+    Ok(url.clone())
+}
+
+pub fn mf2_e(node: Handle, class: &str) -> eyre::Result<Option<String>> {
     // TODO: handle full return value in <https://microformats.org/wiki/microformats2-parsing#parsing_an_e-_property>
     let Some(node) = mf2_find(node, class) else {
         return Ok(None);
@@ -307,7 +318,7 @@ fn mf2_e(node: Handle, class: &str) -> eyre::Result<Option<String>> {
 }
 
 /// <https://microformats.org/wiki/index.php?title=microformats2-parsing&oldid=70607#parsing_a_p-_property>
-fn mf2_p(node: Handle, class: &str) -> eyre::Result<Option<String>> {
+pub fn mf2_p(node: Handle, class: &str) -> eyre::Result<Option<String>> {
     // TODO: handle other cases in <https://microformats.org/wiki/microformats2-parsing#parsing_a_p-_property>
     let Some(node) = mf2_find(node, class) else {
         return Ok(None);
@@ -342,7 +353,7 @@ fn mf2_p(node: Handle, class: &str) -> eyre::Result<Option<String>> {
     Ok(Some(result))
 }
 
-fn mf2_u(node: Handle, class: &str, base_href: &Url) -> eyre::Result<Option<Url>> {
+pub fn mf2_u(node: Handle, class: &str, base_href: &Url) -> eyre::Result<Option<Url>> {
     // TODO: handle other cases in <https://microformats.org/wiki/microformats2-parsing#parsing_a_u-_property>
     let Some(element) = mf2_find(node.clone(), class) else {
         return Ok(None);
@@ -362,7 +373,7 @@ fn mf2_u(node: Handle, class: &str, base_href: &Url) -> eyre::Result<Option<Url>
     }
 }
 
-fn mf2_dt(node: Handle, class: &str) -> eyre::Result<Option<String>> {
+pub fn mf2_dt(node: Handle, class: &str) -> eyre::Result<Option<String>> {
     // TODO: handle other cases in <https://microformats.org/wiki/microformats2-parsing#parsing_a_dt-_property>
     let Some(element) = mf2_find(node.clone(), class) else {
         return Ok(None);
@@ -379,20 +390,20 @@ fn mf2_dt(node: Handle, class: &str) -> eyre::Result<Option<String>> {
     Ok(Some(result))
 }
 
-fn mf2_find(node: Handle, class: &str) -> Option<Handle> {
+pub fn mf2_find(node: Handle, class: &str) -> Option<Handle> {
     // TODO: handle errors from has_class()
     BreadthTraverse::elements(node.clone())
         .find(|node| has_class(node.clone(), class).unwrap_or(false))
 }
 
-fn mf2_find_all(node: Handle, class: &str) -> Vec<Handle> {
+pub fn mf2_find_all(node: Handle, class: &str) -> Vec<Handle> {
     // TODO: handle errors from has_class()
     BreadthTraverse::elements(node.clone())
         .filter(|node| has_class(node.clone(), class).unwrap_or(false))
         .collect()
 }
 
-fn has_class(node: Handle, class: &str) -> eyre::Result<bool> {
+pub fn has_class(node: Handle, class: &str) -> eyre::Result<bool> {
     if let NodeData::Element { attrs, .. } = &node.data {
         if let Some(node_class) = attrs.borrow().attr_str("class")? {
             if node_class.split(" ").find(|&c| c == class).is_some() {
@@ -1,20 +1,36 @@
 use std::{
     collections::{BTreeMap, BTreeSet, HashMap},
-    fs::{create_dir_all, read_dir, File},
+    fs::{create_dir_all, read_dir, read_to_string, remove_file, File},
     io::Write,
+    sync::Arc,
 };
 
-use chrono::{SecondsFormat, Utc};
+use brotli::{enc::BrotliEncoderParams, BrotliCompress};
+use chrono::{DateTime, Datelike, NaiveDate, SecondsFormat, Utc};
+use flate2::{write::GzEncoder, Compression};
 use jane_eyre::eyre::{self, bail, OptionExt};
-use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
+use sha2::{digest::generic_array::functional::FunctionalSequence, Digest, Sha256};
 use tracing::{debug, info};
 
 use crate::{
+    activitypub::{write_static_files, ActivityPubStore},
+    check::check_sources,
     meta::hard_link_attachments_into_site,
     migrations::run_migrations,
-    output::{AtomFeedTemplate, ThreadsContentTemplate, ThreadsPageTemplate},
-    path::{PostsPath, SitePath},
-    TemplatedPost, Thread, SETTINGS,
+    output::{
+        ArchiveHeaderTemplate, ArchiveIndexTemplate, ArchivePageLink, ArchiveYearLink,
+        AtomFeedTemplate, AuthorHeaderTemplate, AuthorIndexTemplate, AuthorPageLink,
+        RedirectTemplate, RobotsTxtTemplate, SearchTemplate, SitemapIndexTemplate, SitemapTemplate,
+        SitemapUrl, StaticFileUrls, TagIndexTemplate, TagLink, TagPages, ThreadsContentTemplate,
+        ThreadsPageTemplate, TombstoneTemplate, WebmentionsTemplate,
+    },
+    path::{path_safe_filename_component, PostsPath, SitePath},
+    redirect::RedirectTarget,
+    search::{SearchIndex, SearchQuery},
+    tombstone::{Tombstone, TombstoneStore},
+    webmention::WebmentionStore,
+    Author, TemplatedPostCache, Thread, SETTINGS,
 };
 
 #[derive(clap::Args, Debug)]
@@ -29,34 +45,38 @@ pub fn main(args: Render) -> eyre::Result<()> {
             .into_iter()
             .map(|path| PostsPath::from_site_root_relative_path(&path))
             .collect::<eyre::Result<Vec<_>>>()?;
-        render(specific_post_paths)
+        render(specific_post_paths, false)?;
     } else {
-        render_all()
+        render_all()?;
     }
+
+    Ok(())
 }
 
-pub fn render_all() -> eyre::Result<()> {
-    let mut post_paths = vec![];
+/// maximum number of urls in a sitemap, according to <https://www.sitemaps.org/protocol.html>.
+const SITEMAP_URL_LIMIT: usize = 50_000;
 
+pub fn render_all() -> eyre::Result<RenderOutput> {
     create_dir_all(&*PostsPath::ROOT)?;
-    for entry in read_dir(&*PostsPath::ROOT)? {
-        let entry = entry?;
-        let metadata = entry.metadata()?;
-        // cohost2autost creates directories for chost thread ancestors.
-        if metadata.is_dir() {
-            continue;
-        }
 
-        let path = PostsPath::ROOT.join_dir_entry(&entry)?;
-        post_paths.push(path);
-    }
-
-    render(post_paths)
+    render(PostsPath::list_posts()?, true)
 }
 
-pub fn render<'posts>(post_paths: Vec<PostsPath>) -> eyre::Result<()> {
+/// renders the given posts, and the pages that list them. `is_all_posts` is false if other
+/// posts may have pages too, like when rendering specific posts.
+pub fn render<'posts>(
+    post_paths: Vec<PostsPath>,
+    is_all_posts: bool,
+) -> eyre::Result<RenderOutput> {
     run_migrations()?;
 
+    // read each post once, then report any front matter problems, and fail fast on errors.
+    let sources = post_paths
+        .par_iter()
+        .map(|path| Ok((path.clone(), read_to_string(path)?)))
+        .collect::<eyre::Result<Vec<_>>>()?;
+    check_sources(&sources).log()?;
+
     let now = Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true);
     create_dir_all(&*SitePath::ROOT)?;
     create_dir_all(&*SitePath::TAGGED)?;
@@ -96,9 +116,17 @@ pub fn render<'posts>(post_paths: Vec<PostsPath>) -> eyre::Result<()> {
             include_bytes!("../../static/Atkinson-Hyperlegible-BoldItalic-102.woff2"),
         ),
     ];
+    let mut static_output_paths = BTreeSet::default();
     for file in static_files.iter() {
         copy_static(&*SitePath::ROOT, file)?;
+        // deploy.sh is for deploying the site, not part of it.
+        if file.0 != "deploy.sh" {
+            static_output_paths.insert(SitePath::ROOT.join(file.0)?);
+        }
     }
+    // write content-hashed copies of the static files that pages refer to, so
+    // they can be cached forever.
+    let static_file_urls = write_hashed_static_files(&mut static_output_paths)?;
     #[cfg(unix)]
     {
         use std::os::unix::fs::PermissionsExt;
@@ -109,74 +137,269 @@ pub fn render<'posts>(post_paths: Vec<PostsPath>) -> eyre::Result<()> {
         std::fs::set_permissions(deploy_path, permissions)?;
     }
 
+    // share loaded posts between threads, since thread ancestors are often shared many times.
+    let post_cache = TemplatedPostCache::with_sources(sources.into_iter().collect());
     let results = post_paths
         .into_par_iter()
-        .map(render_single_post)
+        .map(|path| load_single_post(path, &post_cache))
         .collect::<Vec<_>>();
+    debug!("loaded {} distinct posts", post_cache.len());
 
     let RenderResult {
         mut tags,
         mut collections,
         mut interesting_output_paths,
-        mut threads_by_interesting_tag,
+        mut interesting_attachment_paths,
+        mut threads_by_tag,
+        mut threads_by_author,
     } = RenderResult::default()?;
-    let mut threads_cache = HashMap::default();
+    let mut threads = vec![];
     for result in results {
-        let CacheableRenderResult {
+        let LoadedThread {
             render_result: result,
-            cached_thread,
+            thread,
         } = result?;
         for (tag, count) in result.tags {
             *tags.entry(tag).or_insert(0) += count;
         }
         collections.merge(result.collections);
         interesting_output_paths.extend(result.interesting_output_paths);
-        for (tag, threads) in result.threads_by_interesting_tag {
-            threads_by_interesting_tag
-                .entry(tag)
-                .or_default()
-                .extend(threads);
+        interesting_attachment_paths.extend(result.interesting_attachment_paths);
+        for (tag, threads) in result.threads_by_tag {
+            threads_by_tag.entry(tag).or_default().extend(threads);
         }
-        let path = cached_thread
-            .thread
-            .path
-            .clone()
-            .ok_or_eyre("thread has no path")?;
-        debug_assert!(!threads_cache.contains_key(&path));
-        threads_cache.insert(path, cached_thread);
+        for (href, other) in result.threads_by_author {
+            if let Some(author_threads) = threads_by_author.get_mut(&href) {
+                author_threads.merge(other);
+            } else {
+                threads_by_author.insert(href, other);
+            }
+        }
+        threads.push(thread);
     }
 
+    // posts with slugs or a `permalink_pattern` can be rendered to the same path.
+    let mut post_paths_by_rendered_path = BTreeMap::default();
+    for thread in threads.iter() {
+        let path = thread.path.as_ref().ok_or_eyre("thread has no path")?;
+        let rendered_path = thread
+            .rendered_path()
+            .ok_or_eyre("post has no rendered path")?;
+        if let Some(other) = post_paths_by_rendered_path.insert(rendered_path.clone(), path.clone())
+        {
+            bail!("posts {other:?} and {path:?} are both rendered to {rendered_path:?}");
+        }
+    }
+    let rendered_urls = post_paths_by_rendered_path
+        .keys()
+        .map(|path| path.base_relative_url())
+        .collect::<BTreeSet<_>>();
+    let is_post_page = |url: &str| -> eyre::Result<bool> {
+        Ok(rendered_urls.contains(url)
+            || (!is_all_posts && PostsPath::find_by_rendered_url(url)?.is_some()))
+    };
+
+    // deleted posts get tombstones, unless another post is rendered to the same page now.
+    let mut tombstones = vec![];
+    for tombstone in TombstoneStore::default().load_all()?.into_values() {
+        if !is_post_page(&tombstone.page)? {
+            tombstones.push(tombstone);
+        }
+    }
+
+    // decide which tags get tag pages, before rendering any threads that link to them.
+    let tag_pages = TagPages::new(
+        threads_by_tag
+            .iter()
+            .filter(|(tag, threads)| SETTINGS.tag_gets_page(tag, threads.len()))
+            .map(|(tag, _)| tag.to_owned()),
+    );
+
+    // reader step: generate post pages.
+    let threads_cache = threads
+        .into_par_iter()
+        .map(|thread| render_single_post(thread, &tag_pages, &static_file_urls))
+        .collect::<eyre::Result<HashMap<_, _>>>()?;
+
     // author step: generate atom feeds.
-    let atom_feed_path =
-        collections.write_atom_feed("index", &SitePath::ROOT, &now, &threads_cache)?;
+    let atom_feed_path = collections.write_atom_feed(
+        "index",
+        &SitePath::ROOT,
+        &now,
+        &threads_cache,
+        tombstones.iter().collect(),
+        &tag_pages,
+    )?;
     interesting_output_paths.insert(atom_feed_path);
 
     // generate /tagged/<tag>.feed.xml and /tagged/<tag>.html.
-    for (tag, threads) in threads_by_interesting_tag {
-        let atom_feed_path = SitePath::TAGGED.join(&format!("{tag}.feed.xml"))?;
+    for (tag, threads) in threads_by_tag.iter() {
+        if !tag_pages.has_page(tag) {
+            continue;
+        }
+        let atom_feed_path = SitePath::tag_feed_path(tag)?;
         let thread_refs = threads
             .iter()
             .map(|thread| &threads_cache[&thread.path].thread)
             .collect::<Vec<_>>();
+        let deleted_entries = tombstones
+            .iter()
+            .filter(|tombstone| tombstone.tags.contains(tag))
+            .collect();
         let atom_feed = AtomFeedTemplate::render(
             thread_refs,
+            deleted_entries,
+            &tag_pages,
             &format!("{} — {tag}", SETTINGS.site_title),
             &now,
         )?;
         writeln!(File::create(&atom_feed_path)?, "{}", atom_feed,)?;
-        interesting_output_paths.insert(atom_feed_path);
-        let threads_content = render_cached_threads_content(&threads_cache, &threads);
+        let threads_content = render_cached_threads_content(&threads_cache, threads);
         let threads_page = ThreadsPageTemplate::render(
             &threads_content,
             &format!("#{tag} — {}", SETTINGS.site_title),
-            &Some(SitePath::TAGGED.join(&format!("{tag}.feed.xml"))?),
+            &Some(atom_feed_path.clone()),
+            &static_file_urls,
         )?;
-        // TODO: move this logic into path module and check for slashes
-        let threads_page_path = SitePath::TAGGED.join(&format!("{tag}.html"))?;
+        let threads_page_path = SitePath::tag_page_path(tag)?;
         writeln!(File::create(&threads_page_path)?, "{}", threads_page)?;
+        interesting_output_paths.insert(atom_feed_path);
         interesting_output_paths.insert(threads_page_path);
     }
 
+    // generate /tagged/index.html.
+    if SETTINGS.all_tag_pages() {
+        let groups = tag_index_groups(&threads_by_tag, &tag_pages)?;
+        info!(
+            "writing tag index ({} tags)",
+            groups.iter().map(|group| group.len()).sum::<usize>()
+        );
+        let index_path = SitePath::TAGGED.join("index.html")?;
+        let index_page = ThreadsPageTemplate::render(
+            &TagIndexTemplate::render_without_fixing_relative_urls(&groups)?,
+            &format!("tags — {}", SETTINGS.site_title),
+            &None,
+            &static_file_urls,
+        )?;
+        writeln!(File::create(&index_path)?, "{}", index_page)?;
+        interesting_output_paths.insert(index_path);
+    }
+
+    // generate /authors/<handle>.feed.xml, /authors/<handle>.html, and /authors/index.html.
+    if !threads_by_author.is_empty() {
+        create_dir_all(&*SitePath::AUTHORS)?;
+        let mut links = vec![];
+        let mut basenames = BTreeSet::from(["index".to_owned()]);
+        // iterate in href order, so that basenames are stable between renders.
+        for author_threads in threads_by_author.into_values() {
+            let is_interesting = author_threads.is_interesting;
+            let threads = author_threads.page_threads();
+            let author = &author_threads.author;
+            let basename = author_page_basename(author, &mut basenames);
+            let title = format!("{} — {}", author_page_title(author), SETTINGS.site_title);
+            let atom_feed_path = SitePath::AUTHORS.join(&format!("{basename}.feed.xml"))?;
+            let thread_refs = threads
+                .iter()
+                .map(|thread| &threads_cache[&thread.path].thread)
+                .collect::<Vec<_>>();
+            let atom_feed =
+                AtomFeedTemplate::render(thread_refs, vec![], &tag_pages, &title, &now)?;
+            writeln!(File::create(&atom_feed_path)?, "{}", atom_feed)?;
+            let threads_content = format!(
+                "{}{}",
+                AuthorHeaderTemplate::render_without_fixing_relative_urls(
+                    author,
+                    threads.len(),
+                    &atom_feed_path,
+                )?,
+                render_cached_threads_content(&threads_cache, threads),
+            );
+            let threads_page_path = SitePath::AUTHORS.join(&format!("{basename}.html"))?;
+            let threads_page = ThreadsPageTemplate::render(
+                &threads_content,
+                &title,
+                &Some(atom_feed_path.clone()),
+                &static_file_urls,
+            )?;
+            writeln!(File::create(&threads_page_path)?, "{}", threads_page)?;
+            // only publish author pages with interesting threads, which are all that those pages
+            // show. the index only links to published author pages, so it never links to a page
+            // that would 404 once deployed, or reveals who the unpublished threads were by.
+            if is_interesting {
+                interesting_output_paths.insert(atom_feed_path);
+                interesting_output_paths.insert(threads_page_path.clone());
+                links.push(AuthorPageLink {
+                    author: author.clone(),
+                    page_href: threads_page_path,
+                    thread_count: threads.len(),
+                });
+            }
+        }
+        links.sort_by(|p, q| {
+            p.thread_count
+                .cmp(&q.thread_count)
+                .reverse()
+                .then(p.page_href.cmp(&q.page_href))
+        });
+        info!("writing author index ({} authors)", links.len());
+        let index_path = SitePath::AUTHORS.join("index.html")?;
+        let index_page = ThreadsPageTemplate::render(
+            &AuthorIndexTemplate::render_without_fixing_relative_urls(&links)?,
+            &format!("authors — {}", SETTINGS.site_title),
+            &None,
+            &static_file_urls,
+        )?;
+        writeln!(File::create(&index_path)?, "{}", index_page)?;
+        if !links.is_empty() {
+            interesting_output_paths.insert(index_path);
+        }
+    }
+
+    // generate /archive/<year>.html, /archive/<year>/<month>.html, and /archive/index.html.
+    if SETTINGS.archive_pages() {
+        let archive_paths = write_archive_pages(
+            collections.threads("index"),
+            &threads_cache,
+            &static_file_urls,
+        )?;
+        interesting_output_paths.extend(archive_paths);
+    }
+
+    // generate /search.html and the search index in /search/.
+    if SETTINGS.search() {
+        let mut index = SearchIndex::default();
+        for thread in collections.threads("index") {
+            index.add_thread(&threads_cache[&thread.path].thread)?;
+        }
+        info!("writing search index ({} threads)", index.len());
+        interesting_output_paths.extend(index.write()?);
+        let search_path = SitePath::ROOT.join("search.html")?;
+        let search_page = ThreadsPageTemplate::render(
+            &SearchTemplate::render_without_fixing_relative_urls(
+                "search.html",
+                &SearchQuery::default(),
+                None,
+            )?,
+            &format!("search — {}", SETTINGS.site_title),
+            &None,
+            &static_file_urls,
+        )?;
+        writeln!(File::create(&search_path)?, "{}", search_page)?;
+        interesting_output_paths.insert(search_path);
+    }
+
+    // generate the activitypub actor, outbox, and objects in /activitypub/, and /.well-known/webfinger.
+    if SETTINGS.activitypub() {
+        let threads = collections
+            .threads("index")
+            .iter()
+            .map(|thread| &threads_cache[&thread.path].thread)
+            .collect::<Vec<_>>();
+        info!("writing activitypub outbox ({} threads)", threads.len());
+        let store = ActivityPubStore::default();
+        interesting_output_paths.extend(write_static_files(&store, &threads, &tag_pages)?);
+    }
+
     let mut tags = tags.into_iter().collect::<Vec<_>>();
     tags.sort_by(|p, q| p.1.cmp(&q.1).reverse().then(p.0.cmp(&q.0)));
     info!("all tags: {tags:?}");
@@ -194,40 +417,180 @@ pub fn render<'posts>(post_paths: Vec<PostsPath>) -> eyre::Result<()> {
             collections.len(key),
         );
         // TODO: write internal collections to another dir?
-        let threads_page_path =
-            collections.write_threads_page(key, &SitePath::ROOT, &threads_cache)?;
+        let threads_page_path = collections.write_threads_page(
+            key,
+            &SitePath::ROOT,
+            &threads_cache,
+            &static_file_urls,
+        )?;
         if collections.is_interesting(key) {
             interesting_output_paths.insert(threads_page_path);
         }
     }
 
-    let interesting_output_paths = interesting_output_paths
-        .into_iter()
-        .map(|path| format!("{}\n", path.rsync_deploy_line()))
-        .collect::<Vec<_>>()
-        .join("");
+    // generate /sitemap.xml (or a sitemap index and /sitemap-<n>.xml) and /robots.txt.
+    let mut lastmods = BTreeMap::default();
+    for cached_thread in threads_cache.values() {
+        let Some(rendered_path) = cached_thread.thread.rendered_path().cloned() else {
+            continue;
+        };
+        if let Some(lastmod) = cached_thread
+            .thread
+            .meta
+            .published
+            .as_deref()
+            .and_then(sitemap_lastmod)
+        {
+            lastmods.insert(rendered_path, lastmod);
+        }
+    }
+    let urls = interesting_output_paths
+        .iter()
+        .filter(|path| path.as_ref().extension().is_some_and(|x| x == "html"))
+        .map(|path| SitemapUrl {
+            loc: path.external_url(),
+            lastmod: lastmods.get(path).cloned(),
+        })
+        .collect::<Vec<_>>();
+    info!("writing sitemap ({} urls)", urls.len());
+    for (sitemap_path, sitemap) in render_sitemaps(&urls, SITEMAP_URL_LIMIT)? {
+        writeln!(File::create(&sitemap_path)?, "{}", sitemap)?;
+        interesting_output_paths.insert(sitemap_path);
+    }
+    let robots_txt_path = SitePath::ROOT.join("robots.txt")?;
+    let sitemap_url = SitePath::ROOT.join("sitemap.xml")?.external_url();
+    writeln!(
+        File::create(&robots_txt_path)?,
+        "{}",
+        RobotsTxtTemplate::render(&sitemap_url)?
+    )?;
+    interesting_output_paths.insert(robots_txt_path);
+
+    // generate redirects from the `[redirects]` setting, and from the default paths of posts
+    // with custom paths, so old links keep working. these stay out of the sitemap, since search
+    // engines should index the new paths.
+    let mut redirects = BTreeMap::default();
+    for (from, to) in SETTINGS.redirects() {
+        let from_path = SitePath::from_base_relative_url(from)?;
+        if is_post_page(from)? {
+            bail!("redirect from {from} would overwrite the page of a post");
+        }
+        if let Some(parent) = from_path.parent() {
+            create_dir_all(parent)?;
+        }
+        debug!("writing redirect: {from_path:?} -> {to}");
+        let target = RedirectTarget::new(to);
+        writeln!(
+            File::create(&from_path)?,
+            "{}",
+            RedirectTemplate::render(&target)?
+        )?;
+        interesting_output_paths.insert(from_path.clone());
+        redirects.insert(from_path, target);
+    }
+    for (rendered_path, path) in post_paths_by_rendered_path.iter() {
+        let Some(default_path) = path.default_rendered_path()? else {
+            continue;
+        };
+        if default_path == *rendered_path
+            || post_paths_by_rendered_path.contains_key(&default_path)
+            || redirects.contains_key(&default_path)
+        {
+            continue;
+        }
+        debug!("writing redirect: {default_path:?} -> {rendered_path:?}");
+        let target = RedirectTarget {
+            url: rendered_path.internal_url(),
+            canonical_url: rendered_path.external_url(),
+        };
+        writeln!(
+            File::create(&default_path)?,
+            "{}",
+            RedirectTemplate::render(&target)?
+        )?;
+        if interesting_output_paths.contains(rendered_path) {
+            interesting_output_paths.insert(default_path.clone());
+        }
+        redirects.insert(default_path, target);
+    }
+
+    // generate tombstone pages for deleted posts, which also stay out of the sitemap.
+    let mut tombstone_pages = BTreeSet::default();
+    for tombstone in tombstones.iter() {
+        let tombstone_path = SitePath::from_base_relative_url(&tombstone.page)?;
+        if redirects.contains_key(&tombstone_path) {
+            continue;
+        }
+        if let Some(parent) = tombstone_path.parent() {
+            create_dir_all(parent)?;
+        }
+        debug!("writing tombstone: {tombstone_path:?}");
+        let tombstone_page = ThreadsPageTemplate::render(
+            &TombstoneTemplate::render_without_fixing_relative_urls(tombstone)?,
+            &SETTINGS.page_title(tombstone.title.as_deref()),
+            &None,
+            &static_file_urls,
+        )?;
+        writeln!(File::create(&tombstone_path)?, "{}", tombstone_page)?;
+        interesting_output_paths.insert(tombstone_path);
+        tombstone_pages.insert(tombstone.page.clone());
+    }
+
+    if SETTINGS.precompress() {
+        // pages and feeds are rewritten on every render, so trade some size for speed.
+        let precompressed_paths = write_precompressed_files(&interesting_output_paths, 9)?;
+        info!("wrote {} precompressed files", precompressed_paths.len());
+        interesting_output_paths.extend(precompressed_paths);
+        static_output_paths.extend(write_precompressed_files(&static_output_paths, 11)?);
+    }
+
     if let Some(path) = &SETTINGS.interesting_output_filenames_list_path {
-        File::create(path)?.write_all(interesting_output_paths.as_bytes())?;
+        let list = interesting_output_paths
+            .iter()
+            .map(|path| format!("{}\n", path.rsync_deploy_line()))
+            .collect::<Vec<_>>()
+            .join("");
+        File::create(path)?.write_all(list.as_bytes())?;
     }
 
-    Ok(())
+    Ok(RenderOutput {
+        interesting_output_paths,
+        interesting_attachment_paths,
+        static_output_paths,
+        redirects: redirects
+            .into_iter()
+            .map(|(path, target)| (path.base_relative_url(), target))
+            .collect(),
+        tombstone_pages,
+    })
 }
 
-fn render_single_post(path: PostsPath) -> eyre::Result<CacheableRenderResult> {
+fn load_single_post(
+    path: PostsPath,
+    post_cache: &TemplatedPostCache,
+) -> eyre::Result<LoadedThread> {
     let mut result = RenderResult::default()?;
 
-    let post = TemplatedPost::load(&path)?;
-    let Some(rendered_path) = path.rendered_path()? else {
+    let post = post_cache.load(&path)?;
+    let Some(rendered_path) = post.rendered_path.clone() else {
         bail!("post has no rendered path");
     };
-    let thread = Thread::try_from(post)?;
+    // the thread changes the tags of its main post, so that post needs its own copy.
+    let thread = Thread::try_from_with_cache(Arc::unwrap_or_clone(post), post_cache)?;
     hard_link_attachments_into_site(thread.needs_attachments())?;
-    for tag in thread.meta.tags.iter() {
-        *result.tags.entry(tag.clone()).or_insert(0usize) += 1;
+    // threads with posts from private or logged-in-only cohost projects are never “interesting”,
+    // and stay off author pages, tag counts, and `all`, so they don’t end up in any feeds.
+    let is_private = SETTINGS.thread_is_private(&thread);
+    if !is_private {
+        for tag in thread.meta.tags.iter() {
+            *result.tags.entry(tag.clone()).or_insert(0usize) += 1;
+        }
+        result.collections.push("all", &path, &thread);
     }
-    result.collections.push("all", &path, &thread);
     let mut was_interesting = false;
-    if thread.meta.is_main_self_author(&SETTINGS) {
+    if is_private {
+        result.collections.push("private", &path, &thread);
+    } else if thread.meta.is_main_self_author(&SETTINGS) {
         was_interesting = true;
     } else if SETTINGS.thread_is_on_excluded_archived_list(&thread) {
         result.collections.push("excluded", &path, &thread);
@@ -244,22 +607,43 @@ fn render_single_post(path: PostsPath) -> eyre::Result<CacheableRenderResult> {
             }
         }
     }
+    if let Some(author) = thread
+        .meta
+        .author
+        .as_ref()
+        .filter(|author| !is_private && SETTINGS.author_has_page(author))
+    {
+        result.threads_by_author.insert(
+            author.href.clone(),
+            AuthorThreads::new(
+                author.clone(),
+                ThreadInCollection {
+                    published: thread.meta.published.clone(),
+                    path: path.clone(),
+                },
+                was_interesting,
+            ),
+        );
+    }
     if was_interesting {
         result
             .interesting_output_paths
             .insert(rendered_path.clone());
-        result.collections.push("index", &path, &thread);
+        result
+            .interesting_attachment_paths
+            .extend(thread.needs_attachments().cloned());
+        if !(thread.meta.adult_content && SETTINGS.exclude_adult_content_from_index()) {
+            result.collections.push("index", &path, &thread);
+        }
         for tag in thread.meta.tags.iter() {
-            if SETTINGS.tag_is_interesting(tag) {
-                result
-                    .threads_by_interesting_tag
-                    .entry(tag.clone())
-                    .or_default()
-                    .insert(ThreadInCollection {
-                        published: thread.meta.published.clone(),
-                        path: path.clone(),
-                    });
-            }
+            result
+                .threads_by_tag
+                .entry(tag.clone())
+                .or_default()
+                .insert(ThreadInCollection {
+                    published: thread.meta.published.clone(),
+                    path: path.clone(),
+                });
         }
         if thread.meta.tags.is_empty() {
             result
@@ -284,39 +668,95 @@ fn render_single_post(path: PostsPath) -> eyre::Result<CacheableRenderResult> {
         }
     }
 
+    let result = LoadedThread {
+        render_result: result,
+        thread,
+    };
+
+    Ok(result)
+}
+
+fn render_single_post(
+    thread: Thread,
+    tag_pages: &TagPages,
+    static_file_urls: &StaticFileUrls,
+) -> eyre::Result<(PostsPath, CachedThread)> {
+    let path = thread.path.clone().ok_or_eyre("thread has no path")?;
+    let Some(rendered_path) = thread.rendered_path().cloned() else {
+        bail!("post has no rendered path");
+    };
     let threads_content =
-        ThreadsContentTemplate::render_normal_without_fixing_relative_urls(&thread)?;
+        ThreadsContentTemplate::render_normal_without_fixing_relative_urls(&thread, tag_pages)?;
+
+    // mentions are only shown on the thread’s own page, so they stay out of the cached content.
+    let mentions = WebmentionStore::default().approved(&rendered_path.base_relative_url())?;
+    let page_content = if mentions.is_empty() {
+        threads_content.clone()
+    } else {
+        let webmentions = WebmentionsTemplate::render_without_fixing_relative_urls(&mentions)?;
+        format!("{threads_content}{webmentions}")
+    };
 
     debug!("writing post page: {rendered_path:?}");
     let threads_page = ThreadsPageTemplate::render_single_thread(
         &thread,
-        &threads_content,
+        &page_content,
         &SETTINGS.page_title(thread.meta.title.as_deref()),
         &None,
+        static_file_urls,
     )?;
+    if let Some(parent) = rendered_path.parent() {
+        create_dir_all(parent)?;
+    }
     writeln!(File::create(rendered_path)?, "{}", threads_page)?;
 
-    let result = CacheableRenderResult {
-        render_result: result,
-        cached_thread: CachedThread {
+    Ok((
+        path,
+        CachedThread {
             thread,
             threads_content,
         },
-    };
-
-    Ok(result)
+    ))
 }
 
-struct CacheableRenderResult {
+struct LoadedThread {
     render_result: RenderResult,
-    cached_thread: CachedThread,
+    thread: Thread,
+}
+
+/// the files written by a render, so commands like `autost deploy` don’t need to
+/// read `interesting_output_filenames_list_path`.
+#[derive(Debug, Default)]
+pub struct RenderOutput {
+    /// pages, feeds, and other generated files for interesting threads.
+    pub interesting_output_paths: BTreeSet<SitePath>,
+    /// attachments needed by interesting threads.
+    pub interesting_attachment_paths: BTreeSet<SitePath>,
+    /// static files like style.css.
+    pub static_output_paths: BTreeSet<SitePath>,
+    /// redirect pages, keyed by url relative to `base_url`, like `1.html`.
+    pub redirects: BTreeMap<String, RedirectTarget>,
+    /// tombstone pages for deleted posts, as urls relative to `base_url`.
+    pub tombstone_pages: BTreeSet<String>,
 }
 
 struct RenderResult {
     tags: HashMap<String, usize>,
     collections: Collections,
     interesting_output_paths: BTreeSet<SitePath>,
-    threads_by_interesting_tag: HashMap<String, BTreeSet<ThreadInCollection>>,
+    interesting_attachment_paths: BTreeSet<SitePath>,
+    /// interesting threads only.
+    threads_by_tag: BTreeMap<String, BTreeSet<ThreadInCollection>>,
+    /// keyed by author href.
+    threads_by_author: BTreeMap<String, AuthorThreads>,
+}
+
+struct AuthorThreads {
+    author: Author,
+    threads: BTreeSet<ThreadInCollection>,
+    interesting_threads: BTreeSet<ThreadInCollection>,
+    /// true iff any of the threads are interesting.
+    is_interesting: bool,
 }
 
 struct CachedThread {
@@ -334,7 +774,7 @@ struct Collection {
     threads: BTreeSet<ThreadInCollection>,
 }
 
-#[derive(Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 struct ThreadInCollection {
     published: Option<String>,
     path: PostsPath,
@@ -346,11 +786,57 @@ impl RenderResult {
             tags: Default::default(),
             collections: Collections::default()?,
             interesting_output_paths: Default::default(),
-            threads_by_interesting_tag: Default::default(),
+            interesting_attachment_paths: Default::default(),
+            threads_by_tag: Default::default(),
+            threads_by_author: Default::default(),
         })
     }
 }
 
+impl RenderOutput {
+    /// all of the files that need to be published.
+    pub fn deploy_paths(&self) -> BTreeSet<SitePath> {
+        let mut result = self.interesting_output_paths.clone();
+        result.extend(self.interesting_attachment_paths.iter().cloned());
+        result.extend(self.static_output_paths.iter().cloned());
+
+        result
+    }
+}
+
+impl AuthorThreads {
+    fn new(author: Author, thread: ThreadInCollection, is_interesting: bool) -> Self {
+        let interesting_threads = if is_interesting {
+            BTreeSet::from([thread.clone()])
+        } else {
+            BTreeSet::default()
+        };
+
+        Self {
+            author,
+            threads: BTreeSet::from([thread]),
+            interesting_threads,
+            is_interesting,
+        }
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.threads.extend(other.threads);
+        self.interesting_threads.extend(other.interesting_threads);
+        self.is_interesting |= other.is_interesting;
+    }
+
+    /// the threads to show on the author page. pages with any interesting threads get
+    /// published, so they only show those.
+    fn page_threads(&self) -> &BTreeSet<ThreadInCollection> {
+        if self.is_interesting {
+            &self.interesting_threads
+        } else {
+            &self.threads
+        }
+    }
+}
+
 impl Collections {
     fn default() -> eyre::Result<Self> {
         Ok(Self {
@@ -368,6 +854,13 @@ impl Collections {
                     "excluded",
                     Collection::new(None, "archived posts that were marked excluded"),
                 ),
+                (
+                    "private",
+                    Collection::new(
+                        None,
+                        "archived posts from private or logged-in-only projects",
+                    ),
+                ),
                 (
                     "marked_interesting",
                     Collection::new(None, "archived posts that were marked interesting"),
@@ -413,6 +906,10 @@ impl Collections {
         self.inner[key].threads.len()
     }
 
+    fn threads(&self, key: &str) -> &BTreeSet<ThreadInCollection> {
+        &self.inner[key].threads
+    }
+
     fn push(&mut self, key: &str, path: &PostsPath, thread: &Thread) {
         self.inner
             .get_mut(key)
@@ -433,9 +930,10 @@ impl Collections {
         key: &str,
         output_dir: &SitePath,
         threads_cache: &HashMap<PostsPath, CachedThread>,
+        static_file_urls: &StaticFileUrls,
     ) -> eyre::Result<SitePath> {
         let path = output_dir.join(&format!("{key}.html"))?;
-        self.inner[key].write_threads_page(&path, threads_cache)?;
+        self.inner[key].write_threads_page(&path, threads_cache, static_file_urls)?;
 
         Ok(path)
     }
@@ -446,9 +944,11 @@ impl Collections {
         output_dir: &SitePath,
         now: &str,
         threads_cache: &HashMap<PostsPath, CachedThread>,
+        deleted_entries: Vec<&Tombstone>,
+        tag_pages: &TagPages,
     ) -> eyre::Result<SitePath> {
         let path = output_dir.join(&format!("{key}.feed.xml"))?;
-        self.inner[key].write_atom_feed(&path, now, threads_cache)?;
+        self.inner[key].write_atom_feed(&path, now, threads_cache, deleted_entries, tag_pages)?;
 
         Ok(path)
     }
@@ -472,6 +972,7 @@ impl Collection {
         &self,
         posts_page_path: &SitePath,
         threads_cache: &HashMap<PostsPath, CachedThread>,
+        static_file_urls: &StaticFileUrls,
     ) -> eyre::Result<()> {
         let threads_content = render_cached_threads_content(threads_cache, &self.threads);
         writeln!(
@@ -481,6 +982,7 @@ impl Collection {
                 &threads_content,
                 &format!("{} — {}", self.title, SETTINGS.site_title),
                 &self.feed_href,
+                static_file_urls,
             )?
         )?;
 
@@ -492,6 +994,8 @@ impl Collection {
         atom_feed_path: &SitePath,
         now: &str,
         threads_cache: &HashMap<PostsPath, CachedThread>,
+        deleted_entries: Vec<&Tombstone>,
+        tag_pages: &TagPages,
     ) -> eyre::Result<()> {
         let thread_refs = self
             .threads
@@ -501,7 +1005,13 @@ impl Collection {
         writeln!(
             File::create(atom_feed_path)?,
             "{}",
-            AtomFeedTemplate::render(thread_refs, &SETTINGS.site_title, now)?
+            AtomFeedTemplate::render(
+                thread_refs,
+                deleted_entries,
+                tag_pages,
+                &SETTINGS.site_title,
+                now
+            )?
         )?;
 
         Ok(())
@@ -523,14 +1033,468 @@ impl PartialOrd for ThreadInCollection {
     }
 }
 
-fn render_cached_threads_content(
+fn render_cached_threads_content<'threads>(
     cache: &HashMap<PostsPath, CachedThread>,
-    threads: &BTreeSet<ThreadInCollection>,
+    threads: impl IntoIterator<Item = &'threads ThreadInCollection>,
 ) -> String {
     let threads_contents = threads
-        .iter()
+        .into_iter()
         .map(|thread| &*cache[&thread.path].threads_content)
         .collect::<Vec<_>>();
 
     threads_contents.join("")
 }
+
+fn author_page_title(author: &Author) -> &str {
+    [&author.display_name, &author.display_handle, &author.name]
+        .into_iter()
+        .find(|name| !name.is_empty())
+        .map_or("untitled author", |name| name)
+}
+
+/// returns a unique basename for the given author’s page and feed, and adds it to `basenames`.
+fn author_page_basename(author: &Author, basenames: &mut BTreeSet<String>) -> String {
+    let handle = [&author.display_handle, &author.name]
+        .into_iter()
+        .find(|name| !name.is_empty())
+        .map_or("author", |name| name);
+    let handle = path_safe_filename_component(handle);
+    let mut result = handle.clone();
+    for i in 2.. {
+        if basenames.insert(result.clone()) {
+            break;
+        }
+        result = format!("{handle}-{i}");
+    }
+
+    result
+}
+
+/// groups tags for the tag index: one group per group of interesting tags, then
+/// one group for all other tags, most used first.
+fn tag_index_groups(
+    threads_by_tag: &BTreeMap<String, BTreeSet<ThreadInCollection>>,
+    tag_pages: &TagPages,
+) -> eyre::Result<Vec<Vec<TagLink>>> {
+    let max_count = threads_by_tag.values().map(|threads| threads.len()).max();
+    let tag_link = |tag: &str| -> eyre::Result<Option<TagLink>> {
+        let Some(threads) = threads_by_tag.get(tag) else {
+            return Ok(None);
+        };
+        let page_href = if tag_pages.has_page(tag) {
+            Some(SitePath::tag_page_path(tag)?)
+        } else {
+            None
+        };
+        Ok(Some(TagLink {
+            tag: tag.to_owned(),
+            page_href,
+            thread_count: threads.len(),
+            font_size_percent: tag_font_size_percent(threads.len(), max_count.unwrap_or(1)),
+        }))
+    };
+
+    let mut result = vec![];
+    let mut seen = BTreeSet::default();
+    for group in SETTINGS.interesting_tags.iter() {
+        let mut links = vec![];
+        for tag in group {
+            if seen.insert(&**tag) {
+                links.extend(tag_link(tag)?);
+            }
+        }
+        if !links.is_empty() {
+            result.push(links);
+        }
+    }
+    let mut other_tags = threads_by_tag
+        .iter()
+        .filter(|(tag, _)| !seen.contains(&***tag))
+        .collect::<Vec<_>>();
+    other_tags.sort_by(|(p, p_threads), (q, q_threads)| {
+        q_threads.len().cmp(&p_threads.len()).then_with(|| p.cmp(q))
+    });
+    let mut links = vec![];
+    for (tag, _) in other_tags {
+        links.extend(tag_link(tag)?);
+    }
+    if !links.is_empty() {
+        result.push(links);
+    }
+
+    Ok(result)
+}
+
+/// scales font sizes logarithmically from 100% (one thread) to 200% (`max_count` threads).
+fn tag_font_size_percent(thread_count: usize, max_count: usize) -> usize {
+    if max_count <= 1 {
+        return 100;
+    }
+    let scale = (thread_count.max(1) as f64).ln() / (max_count as f64).ln();
+
+    100 + (100.0 * scale.min(1.0)).round() as usize
+}
+
+/// writes content-hashed copies of the static files that pages refer to, if
+/// `hashed_static_filenames` is set, and deletes any stale copies from earlier renders.
+fn write_hashed_static_files(
+    static_output_paths: &mut BTreeSet<SitePath>,
+) -> eyre::Result<StaticFileUrls> {
+    let mut result = StaticFileUrls::default();
+    let mut hashed_filenames = BTreeSet::default();
+    for filename in ["style.css", "script.js"] {
+        if SETTINGS.hashed_static_filenames() {
+            let hashed_path = write_hashed_static_file(filename)?;
+            result.insert(filename, hashed_path.filename());
+            hashed_filenames.insert(hashed_path.filename().to_owned());
+            static_output_paths.insert(hashed_path);
+        }
+        let (stem, extension) = filename
+            .rsplit_once('.')
+            .ok_or_eyre("filename has no extension")?;
+        for entry in read_dir(&*SitePath::ROOT)? {
+            let entry = entry?;
+            let Some(entry_filename) = entry.file_name().to_str().map(str::to_owned) else {
+                continue;
+            };
+            // also delete precompressed copies, which are rewritten if needed.
+            let hashed_filename = entry_filename
+                .strip_suffix(".gz")
+                .or_else(|| entry_filename.strip_suffix(".br"))
+                .unwrap_or(&entry_filename);
+            let is_hashed = hashed_filename
+                .strip_prefix(stem)
+                .and_then(|x| x.strip_prefix('.'))
+                .and_then(|x| x.strip_suffix(extension))
+                .and_then(|x| x.strip_suffix('.'))
+                .is_some_and(|x| x.len() == 16 && x.bytes().all(|b| b.is_ascii_hexdigit()));
+            if is_hashed && !hashed_filenames.contains(hashed_filename) {
+                debug!("deleting stale static file: {entry_filename}");
+                remove_file(entry.path())?;
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// copies a static file in the site output directory to `<stem>.<hash>.<extension>`.
+fn write_hashed_static_file(filename: &str) -> eyre::Result<SitePath> {
+    let path = SitePath::ROOT.join(filename)?;
+    let content = std::fs::read(&path)?;
+    let hash = Sha256::digest(&content)
+        .map(|o| format!("{o:02x}"))
+        .join("");
+    let (stem, extension) = filename
+        .rsplit_once('.')
+        .ok_or_eyre("filename has no extension")?;
+    let hashed_path = SitePath::ROOT.join(&format!("{stem}.{}.{extension}", &hash[..16]))?;
+    File::create(&hashed_path)?.write_all(&content)?;
+
+    Ok(hashed_path)
+}
+
+/// writes `.gz` and `.br` siblings for any html, css, js, xml, and json files in
+/// `paths`, returning the paths written.
+fn write_precompressed_files(
+    paths: &BTreeSet<SitePath>,
+    brotli_quality: i32,
+) -> eyre::Result<Vec<SitePath>> {
+    let results = paths
+        .iter()
+        .filter(|path| {
+            let extension = path.as_ref().extension().and_then(|x| x.to_str());
+            matches!(extension, Some("html" | "css" | "js" | "xml" | "json"))
+        })
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .map(|path| -> eyre::Result<[SitePath; 2]> {
+            let content = std::fs::read(path)?;
+            let parent = path.parent().ok_or_eyre("path has no parent")?;
+            let gz_path = parent.join(&format!("{}.gz", path.filename()))?;
+            let mut gz = GzEncoder::new(File::create(&gz_path)?, Compression::best());
+            gz.write_all(&content)?;
+            gz.finish()?;
+            let br_path = parent.join(&format!("{}.br", path.filename()))?;
+            let params = BrotliEncoderParams {
+                quality: brotli_quality,
+                lgwin: 22,
+                ..Default::default()
+            };
+            BrotliCompress(&mut &*content, &mut File::create(&br_path)?, &params)?;
+
+            Ok([gz_path, br_path])
+        })
+        .collect::<eyre::Result<Vec<_>>>()?;
+
+    Ok(results.into_iter().flatten().collect())
+}
+
+/// renders `sitemap.xml`, or if there are more than `limit` urls, a sitemap index
+/// in `sitemap.xml` plus `sitemap-<n>.xml` with up to `limit` urls each.
+fn render_sitemaps(urls: &[SitemapUrl], limit: usize) -> eyre::Result<Vec<(SitePath, String)>> {
+    let index_path = SitePath::ROOT.join("sitemap.xml")?;
+    if urls.len() <= limit {
+        return Ok(vec![(index_path, SitemapTemplate::render(urls)?)]);
+    }
+
+    let mut result = vec![];
+    let mut sitemap_locs = vec![];
+    for (i, chunk) in urls.chunks(limit).enumerate() {
+        let path = SitePath::ROOT.join(&format!("sitemap-{}.xml", i + 1))?;
+        sitemap_locs.push(path.external_url());
+        result.push((path, SitemapTemplate::render(chunk)?));
+    }
+    result.push((index_path, SitemapIndexTemplate::render(&sitemap_locs)?));
+
+    Ok(result)
+}
+
+/// converts a `published` date to a w3c datetime for sitemaps, or returns
+/// none if it can’t be parsed.
+fn sitemap_lastmod(published: &str) -> Option<String> {
+    if let Ok(published) = DateTime::parse_from_rfc3339(published) {
+        return Some(published.to_rfc3339_opts(SecondsFormat::Secs, true));
+    }
+    let date = NaiveDate::parse_from_str(published.get(..10)?, "%Y-%m-%d").ok()?;
+
+    Some(date.format("%Y-%m-%d").to_string())
+}
+
+/// writes archive pages for the given threads, returning the paths written.
+fn write_archive_pages(
+    threads: &BTreeSet<ThreadInCollection>,
+    threads_cache: &HashMap<PostsPath, CachedThread>,
+    static_file_urls: &StaticFileUrls,
+) -> eyre::Result<Vec<SitePath>> {
+    let mut threads_by_month: BTreeMap<i32, BTreeMap<u32, Vec<&ThreadInCollection>>> =
+        BTreeMap::default();
+    let mut undated_threads = vec![];
+    for thread in threads {
+        match thread
+            .published
+            .as_deref()
+            .and_then(published_year_and_month)
+        {
+            Some((year, month)) => threads_by_month
+                .entry(year)
+                .or_default()
+                .entry(month)
+                .or_default()
+                .push(thread),
+            None => undated_threads.push(thread),
+        }
+    }
+
+    create_dir_all(&*SitePath::ARCHIVE)?;
+    let index_path = SitePath::ARCHIVE.join("index.html")?;
+    let mut result = vec![];
+    let mut years = vec![];
+    // newest first, like the threads on each page.
+    for (year, months) in threads_by_month.iter().rev() {
+        let year_dir = SitePath::ARCHIVE.join(&format!("{year}"))?;
+        create_dir_all(&year_dir)?;
+        let mut month_links: [Option<ArchivePageLink>; 12] = Default::default();
+        for (month, threads) in months.iter() {
+            let path = year_dir.join(&format!("{month:02}.html"))?;
+            let title = format!("{year}-{month:02}");
+            month_links[*month as usize - 1] = Some(write_archive_page(
+                &path,
+                &title,
+                threads,
+                &index_path,
+                threads_cache,
+                static_file_urls,
+            )?);
+            result.push(path);
+        }
+        let year_threads = months.values().rev().flatten().copied().collect::<Vec<_>>();
+        let path = SitePath::ARCHIVE.join(&format!("{year}.html"))?;
+        let ArchivePageLink {
+            page_href,
+            thread_count,
+        } = write_archive_page(
+            &path,
+            &format!("{year}"),
+            &year_threads,
+            &index_path,
+            threads_cache,
+            static_file_urls,
+        )?;
+        years.push(ArchiveYearLink {
+            year: *year,
+            page_href,
+            thread_count,
+            months: month_links,
+        });
+        result.push(path);
+    }
+    let undated = if !undated_threads.is_empty() {
+        let path = SitePath::ARCHIVE.join("undated.html")?;
+        let link = write_archive_page(
+            &path,
+            "undated",
+            &undated_threads,
+            &index_path,
+            threads_cache,
+            static_file_urls,
+        )?;
+        result.push(path);
+        Some(link)
+    } else {
+        None
+    };
+
+    info!("writing archive index ({} years)", years.len());
+    let index_page = ThreadsPageTemplate::render(
+        &ArchiveIndexTemplate::render_without_fixing_relative_urls(&years, &undated)?,
+        &format!("archive — {}", SETTINGS.site_title),
+        &None,
+        static_file_urls,
+    )?;
+    writeln!(File::create(&index_path)?, "{}", index_page)?;
+    result.push(index_path);
+
+    Ok(result)
+}
+
+fn write_archive_page(
+    path: &SitePath,
+    title: &str,
+    threads: &[&ThreadInCollection],
+    index_path: &SitePath,
+    threads_cache: &HashMap<PostsPath, CachedThread>,
+    static_file_urls: &StaticFileUrls,
+) -> eyre::Result<ArchivePageLink> {
+    let threads_content = format!(
+        "{}{}",
+        ArchiveHeaderTemplate::render_without_fixing_relative_urls(
+            title,
+            threads.len(),
+            index_path,
+        )?,
+        render_cached_threads_content(threads_cache, threads.iter().copied()),
+    );
+    let threads_page = ThreadsPageTemplate::render(
+        &threads_content,
+        &format!("{title} — {}", SETTINGS.site_title),
+        &None,
+        static_file_urls,
+    )?;
+    writeln!(File::create(path)?, "{}", threads_page)?;
+
+    Ok(ArchivePageLink {
+        page_href: path.clone(),
+        thread_count: threads.len(),
+    })
+}
+
+/// returns the year and month of the given `published` date, in its own time zone.
+fn published_year_and_month(published: &str) -> Option<(i32, u32)> {
+    let date = NaiveDate::parse_from_str(published.get(..10)?, "%Y-%m-%d").ok()?;
+
+    Some((date.year(), date.month()))
+}
+
+#[test]
+fn test_tag_font_size_percent() {
+    assert_eq!(tag_font_size_percent(1, 1), 100);
+    assert_eq!(tag_font_size_percent(1, 100), 100);
+    assert_eq!(tag_font_size_percent(10, 100), 150);
+    assert_eq!(tag_font_size_percent(100, 100), 200);
+}
+
+#[test]
+fn test_author_threads() -> eyre::Result<()> {
+    let author = Author {
+        href: "https://cohost.org/example".to_owned(),
+        name: "example".to_owned(),
+        display_name: "example".to_owned(),
+        display_handle: "@example".to_owned(),
+    };
+    let thread = |id: usize| -> eyre::Result<ThreadInCollection> {
+        Ok(ThreadInCollection {
+            published: Some(format!("2024-10-0{id}T00:00:00Z")),
+            path: PostsPath::ROOT.join(&format!("{id}.html"))?,
+        })
+    };
+
+    // uninteresting threads stay off published author pages.
+    let mut mixed = AuthorThreads::new(author.clone(), thread(1)?, false);
+    mixed.merge(AuthorThreads::new(author.clone(), thread(2)?, true));
+    mixed.merge(AuthorThreads::new(author.clone(), thread(3)?, false));
+    assert!(mixed.is_interesting);
+    assert_eq!(mixed.page_threads(), &BTreeSet::from([thread(2)?]));
+
+    // unpublished author pages show all of the threads.
+    let mut uninteresting = AuthorThreads::new(author.clone(), thread(1)?, false);
+    uninteresting.merge(AuthorThreads::new(author, thread(3)?, false));
+    assert!(!uninteresting.is_interesting);
+    assert_eq!(
+        uninteresting.page_threads(),
+        &BTreeSet::from([thread(1)?, thread(3)?])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_render_sitemaps() -> eyre::Result<()> {
+    let urls = ["1", "2", "3"]
+        .into_iter()
+        .map(|name| SitemapUrl {
+            loc: format!("https://example.com/{name}.html"),
+            lastmod: None,
+        })
+        .collect::<Vec<_>>();
+
+    let sitemaps = render_sitemaps(&urls, 3)?;
+    assert_eq!(sitemaps.len(), 1);
+    assert_eq!(sitemaps[0].0, SitePath::ROOT.join("sitemap.xml")?);
+    assert!(sitemaps[0].1.contains("<urlset"));
+
+    let sitemaps = render_sitemaps(&urls, 2)?;
+    let paths = sitemaps.iter().map(|(path, _)| path).collect::<Vec<_>>();
+    assert_eq!(
+        paths,
+        [
+            &SitePath::ROOT.join("sitemap-1.xml")?,
+            &SitePath::ROOT.join("sitemap-2.xml")?,
+            &SitePath::ROOT.join("sitemap.xml")?,
+        ]
+    );
+    assert_eq!(sitemaps[1].1.matches("<url>").count(), 1);
+    assert!(sitemaps[2]
+        .1
+        .contains("<sitemap><loc>https://example.com/sitemap-2.xml</loc></sitemap>"));
+
+    Ok(())
+}
+
+#[test]
+fn test_sitemap_lastmod() {
+    assert_eq!(
+        sitemap_lastmod("2024-10-01T06:00:00.000+10:00").as_deref(),
+        Some("2024-10-01T06:00:00+10:00")
+    );
+    assert_eq!(
+        sitemap_lastmod("2024-10-01T06:00Z").as_deref(),
+        Some("2024-10-01")
+    );
+    assert_eq!(sitemap_lastmod("yesterday"), None);
+}
+
+#[test]
+fn test_published_year_and_month() {
+    assert_eq!(
+        published_year_and_month("2024-10-01T04:30Z"),
+        Some((2024, 10))
+    );
+    assert_eq!(
+        published_year_and_month("2023-07-31T23:59:59.000-04:00"),
+        Some((2023, 7))
+    );
+    assert_eq!(published_year_and_month("2023-13-01T00:00Z"), None);
+    assert_eq!(published_year_and_month("yesterday"), None);
+    assert_eq!(published_year_and_month(""), None);
+}
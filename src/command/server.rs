@@ -1,47 +1,119 @@
 use std::{
     collections::{BTreeMap, HashMap},
-    fs::File,
-    io::{self, Read, Write},
-    net::IpAddr,
+    env::temp_dir,
+    fs::{create_dir_all, read_to_string, remove_dir_all, File},
+    io::{self, SeekFrom, Write},
+    net::{IpAddr, SocketAddr},
     path::{Path, PathBuf},
     str::FromStr,
+    sync::{Arc, RwLock},
+    time::Duration,
 };
 
 use askama::Template;
-use chrono::{SecondsFormat, Utc};
-use http::{Response, StatusCode, Uri};
-use jane_eyre::eyre::{self, eyre, Context, OptionExt};
+use base64::{prelude::BASE64_URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, SecondsFormat, Utc};
+use futures_util::TryStreamExt;
+use hmac::{Hmac, Mac};
+use http::{
+    header::{
+        AsHeaderName, ACCEPT_ENCODING, ACCEPT_RANGES, AUTHORIZATION, CONTENT_ENCODING,
+        CONTENT_LENGTH, CONTENT_RANGE, CONTENT_TYPE, COOKIE, ETAG, HOST, IF_MODIFIED_SINCE,
+        IF_NONE_MATCH, IF_RANGE, LAST_MODIFIED, ORIGIN, RANGE, VARY,
+    },
+    HeaderMap, Method, Response, StatusCode, Uri,
+};
+use jane_eyre::eyre::{self, bail, eyre, Context, OptionExt};
+use rand::RngCore;
+use serde_json::json;
+use sha2::Sha256;
+use tokio::{
+    io::{AsyncReadExt, AsyncSeekExt},
+    sync::Semaphore,
+};
+use tokio_util::io::ReaderStream;
 use tracing::{error, info, warn};
+use url::{form_urlencoded, Url};
+use uuid::Uuid;
 use warp::{
-    filters::{any::any, path::Peek, reply::header},
+    filters::{
+        any::any,
+        path::{FullPath, Peek, Tail},
+        reply::header,
+    },
+    hyper::{body::Buf, Body},
+    multipart::FormData,
     path,
-    redirect::{see_other, temporary},
+    redirect::{redirect, see_other, temporary},
     reject::{custom, Reject, Rejection},
-    reply::{self, Reply},
+    reply::{self, reply, Reply},
     Filter,
 };
 
-use crate::{output::ThreadsContentTemplate, path::AttachmentsPath, SETTINGS};
 use crate::{
+    activitypub::{
+        self, ActivityPubClient, ActivityPubStore, InboxResult, RealActivityPubClient,
+        RequestSigner,
+    },
+    attachments::{AttachmentsContext, RealAttachmentsContext},
+    front_matter::{self, split_front_matter},
+    micropub::{
+        self, MicropubEntry, MicropubError, MicropubRequest, StaticTokenVerifier, TokenVerifier,
+    },
+    net::{http_date, parse_http_date},
+    output::{
+        SearchTemplate, StaticFileUrls, TagPages, ThreadsContentTemplate, ThreadsPageTemplate,
+    },
+    path::{path_safe_filename_component, site_base_relative_url, AttachmentsPath},
+    redirect::RedirectTarget,
+    search::{self, SearchQuery},
+    tombstone::{delete_post, TombstoneStore},
+    webmention::{self, is_http_url, RealWebmentionFetcher, WebmentionFetcher, WebmentionStore},
+    SETTINGS,
+};
+use crate::{
+    constant_time_eq,
     path::{PostsPath, SitePath},
-    render_markdown, PostMeta, TemplatedPost, Thread,
+    render_markdown_post, PostMeta, TemplatedPost, Thread,
 };
 
-use crate::command::render::render_all;
+use crate::command::{
+    activitypub::deliver,
+    render::{render_all, RenderOutput},
+};
 
 #[derive(clap::Args, Debug)]
 pub struct Server {
     #[arg(short, long)]
     port: Option<u16>,
+    #[arg(short, long)]
+    bind: Option<IpAddr>,
 }
 
 static HTML: &'static str = "text/html; charset=utf-8";
+const SESSION_COOKIE_NAME: &str = "autost_session";
+const SESSION_MAX_AGE_SECONDS: u64 = 30 * 24 * 60 * 60;
 
 pub async fn main(args: Server) -> eyre::Result<()> {
-    render_all()?;
+    let bind_address = args.bind.unwrap_or(SETTINGS.server_bind_address());
+    let auth = if bind_address.is_loopback() {
+        Auth::default()
+    } else {
+        let password = SETTINGS.server_password().ok_or_eyre(
+            "refusing to listen on a non-loopback address without a password; set server_password or AUTOST_SERVER_PASSWORD",
+        )?;
+        Auth::new(
+            password,
+            load_or_generate_session_key(Path::new("session.key"))?,
+        )
+    };
+
+    let site = RenderedSite::default();
+    site.render_all()?;
 
     let compose_route = warp::path!("compose")
         .and(warp::filters::method::get())
+        .and(protected(auth.clone()))
         .and(warp::filters::query::query())
         .and_then(|mut query: BTreeMap<String, String>| async move {
             let now = Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true);
@@ -49,11 +121,7 @@ pub async fn main(args: Server) -> eyre::Result<()> {
                 let reply_to = PostsPath::ROOT.join(&reply_to).map_err(BadRequest)?;
                 let post = TemplatedPost::load(&reply_to).map_err(InternalError)?;
                 let thread = Thread::try_from(post).map_err(InternalError)?;
-                thread
-                    .posts
-                    .into_iter()
-                    .flat_map(|post| post.path)
-                    .collect()
+                thread.reply_references()
             } else {
                 vec![]
             };
@@ -63,8 +131,14 @@ pub async fn main(args: Server) -> eyre::Result<()> {
                 title: Some("headline".to_owned()),
                 published: Some(now),
                 author: SETTINGS.self_author.clone(),
+                author_privacy: None,
+                author_logged_out_post_visibility: None,
                 tags: vec![],
                 is_transparent_share: false,
+                toc: false,
+                slug: None,
+                cws: vec![],
+                adult_content: false,
             };
             let meta = meta
                 .render()
@@ -83,16 +157,17 @@ pub async fn main(args: Server) -> eyre::Result<()> {
     // POST /preview with urlencoded body: source=...
     let preview_route = warp::path!("preview")
         .and(warp::filters::method::post())
+        .and(protected(auth.clone()))
         .and(warp::filters::body::form())
         .and_then(|mut form: HashMap<String, String>| async move {
             let unsafe_source = form
                 .remove("source")
                 .ok_or_eyre("form field missing: source")
                 .map_err(BadRequest)?;
-            let unsafe_html = render_markdown(&unsafe_source);
+            let unsafe_html = render_markdown_post(&unsafe_source).map_err(BadRequest)?;
             let post = TemplatedPost::filter(&unsafe_html, None).map_err(InternalError)?;
             let thread = Thread::try_from(post).map_err(InternalError)?;
-            let result = ThreadsContentTemplate::render_normal(&thread)
+            let result = ThreadsContentTemplate::render_normal(&thread, &TagPages::interesting())
                 .wrap_err("failed to render template")
                 .map_err(InternalError)?;
             Ok::<_, Rejection>(result)
@@ -100,42 +175,24 @@ pub async fn main(args: Server) -> eyre::Result<()> {
         .with(header("Content-Type", HTML));
 
     // POST /publish[?js] with urlencoded body: source=...
+    let publish_site = site.clone();
     let publish_route = warp::path!("publish")
         .and(warp::filters::method::post())
+        .and(protected(auth.clone()))
+        .and(any().map(move || publish_site.clone()))
         .and(warp::filters::query::query())
         .and(warp::filters::body::form())
         .and_then(
-            |query: HashMap<String, String>, mut form: HashMap<String, String>| async move {
+            |site: RenderedSite,
+             query: HashMap<String, String>,
+             mut form: HashMap<String, String>| async move {
                 let unsafe_source = form
                     .remove("source")
                     .ok_or_eyre("form field missing: source")
                     .map_err(BadRequest)?;
-
-                // try rendering the post before writing it, to catch any errors.
-                let unsafe_html = render_markdown(&unsafe_source);
-                let post = TemplatedPost::filter(&unsafe_html, None).map_err(InternalError)?;
-                let _thread = Thread::try_from(post).map_err(InternalError)?;
-
-                // cohost post ids are all less than 10000000.
-                let (mut file, path) = (10000000..)
-                    .map(|id| {
-                        let path = PostsPath::markdown_post_path(id);
-                        File::create_new(&path).map(|file| (file, path))
-                    })
-                    .filter(|file| !matches!(file, Err(error) if error.kind() == io::ErrorKind::AlreadyExists))
-                    .next()
-                    .expect("too many posts :(")
-                    .wrap_err("failed to create post")
-                    .map_err(InternalError)?;
-
-                file.write_all(unsafe_source.as_bytes())
-                    .wrap_err("failed to write post file")
-                    .map_err(InternalError)?;
-                render_all().map_err(InternalError)?;
-
-                let post = TemplatedPost::load(&path).map_err(InternalError)?;
-                let _thread = Thread::try_from(post).map_err(InternalError)?;
-                let url = path.rendered_path()
+                let path = publish_post(&site, &unsafe_source).map_err(InternalError)?;
+                let url = path
+                    .rendered_path()
                     .map_err(InternalError)?
                     .ok_or_eyre("path has no rendered path")
                     .map_err(InternalError)?
@@ -157,92 +214,126 @@ pub async fn main(args: Server) -> eyre::Result<()> {
         )
         .with(header("Content-Type", HTML));
 
-    let default_route = warp::filters::method::get()
-        .and(warp::filters::path::peek())
-        .and_then(|peek: Peek| async move {
-            let mut segments = peek.segments().peekable();
-            // serve attachments out of main attachment store, in case we need to preview a post
-            // that refers to an attachment for the first time. otherwise they will 404, since
-            // render won’t have hard-linked it into the site output dir.
-            let mut path: PathBuf = if segments.peek() == Some(&"attachments") {
-                segments.next();
-                (&*AttachmentsPath::ROOT).as_ref().to_owned()
-            } else {
-                (&*SitePath::ROOT).as_ref().to_owned()
-            };
-            for component in segments {
-                let component = urlencoding::decode(component)
-                    .wrap_err("failed to decode url path component")
-                    .map_err(BadRequest)?;
-                if component == ".." {
-                    return Err(custom(BadRequest(eyre!("path component not allowed: .."))));
-                } else if component == "." {
-                    continue;
-                }
-                path.push(&*component);
-            }
+    let micropub_route = micropub_route(
+        SETTINGS
+            .micropub_token()
+            .map(|token| Arc::new(StaticTokenVerifier::new(token)) as Arc<dyn TokenVerifier>),
+        site.clone(),
+    );
 
-            enum Error {
-                Internal(eyre::Report),
-                NotFound,
-            }
-            fn read_file_or_index(body: &mut Vec<u8>, path: &Path) -> Result<&'static str, Error> {
-                if let Ok(mut file) = File::open(path) {
-                    let metadata = file.metadata()
-                        .wrap_err("failed to get file metadata")
-                        .map_err(Error::Internal)?;
-                    if metadata.is_dir() {
-                        return read_file_or_index(body, &path.join("index.html"))
-                    } else {
-                        file.read_to_end(body)
-                            .wrap_err("failed to read file")
-                            .map_err(Error::Internal)?;
-                        let extension = path.extension().and_then(|x| x.to_str());
-                        let extension = extension.map(|x| x.to_ascii_lowercase());
-                        let content_type = match extension.as_deref() {
-                            Some("css") => "text/css; charset=utf-8",
-                            Some("gif") => "image/gif",
-                            Some("html") => HTML,
-                            Some("jpg" | "jpeg") => "image/jpeg",
-                            Some("js") => "text/javascript; charset=utf-8",
-                            Some("mp3") => "audio/mpeg",
-                            Some("mp4") => "video/mp4",
-                            Some("png") => "image/png",
-                            Some("svg") => "image/svg+xml",
-                            Some("webp") => "image/webp",
-                            Some("woff2") => "font/woff2",
-                            Some("xml") => "text/xml",
-                            Some(other) => {
-                                warn!("unknown file extension {other}; treating as application/octet-stream");
-                                "application/octet-stream"
-                            },
-                            None => {
-                                warn!("no file extension; treating as application/octet-stream");
-                                "application/octet-stream"
-                            },
-                        };
-                        return Ok(content_type);
-                    }
-                } else {
-                    return Err(Error::NotFound);
+    let webmention_route =
+        webmention_route(WebmentionStore::default(), Arc::new(RealWebmentionFetcher));
+    let tombstone_site = site.clone();
+    let deleted_posts_route = deleted_posts_route(move |page| tombstone_site.is_tombstone(page));
+    let redirects_route = redirects_route(move |page| site.redirect_target(page));
+
+    let activitypub_inbox_route = activitypub_inbox_route(if SETTINGS.activitypub_inbox() {
+        let store = ActivityPubStore::default();
+        let signer = store.signer()?;
+        Some(ActivityPubInbox {
+            store,
+            client: Arc::new(RealActivityPubClient {
+                signer: signer.clone(),
+            }),
+            signer,
+        })
+    } else {
+        None
+    });
+
+    // GET /login[?next=...]
+    let login_page_route = warp::path!("login")
+        .and(warp::filters::method::get())
+        .and(warp::filters::query::query())
+        .and_then(|mut query: HashMap<String, String>| async move {
+            let next = query.remove("next").unwrap_or_default();
+            let result = LoginTemplate::new(&next, false)
+                .render()
+                .wrap_err("failed to render template")
+                .map_err(InternalError)?;
+            Ok::<_, Rejection>(result)
+        })
+        .with(header("Content-Type", HTML));
+
+    // POST /login with urlencoded body: password=...&next=...
+    let login_route = warp::path!("login")
+        .and(warp::filters::method::post())
+        .and(warp::filters::header::headers_cloned())
+        .and(warp::filters::body::form())
+        .and_then(move |headers: HeaderMap, mut form: HashMap<String, String>| {
+            let auth = auth.clone();
+            async move {
+                if !is_same_origin(&headers) {
+                    return Err(custom(Forbidden(eyre!("cross-origin request"))));
                 }
+                let password = form.remove("password").unwrap_or_default();
+                let next = form.remove("next").unwrap_or_default();
+                let Some(session) = auth.log_in(&password) else {
+                    warn!("failed login attempt");
+                    // slow down password guessing.
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                    let result = LoginTemplate::new(&next, true)
+                        .render()
+                        .wrap_err("failed to render template")
+                        .map_err(InternalError)?;
+                    let result = reply::with_status(result, StatusCode::UNAUTHORIZED);
+                    let result = reply::with_header(result, "Content-Type", HTML);
+                    return Ok::<_, Rejection>(Box::new(result) as Box<dyn Reply>);
+                };
+                let url = Uri::from_str(&LoginTemplate::new(&next, false).next)
+                    .wrap_err("failed to build Uri")
+                    .map_err(InternalError)?;
+                let cookie = format!(
+                    "{SESSION_COOKIE_NAME}={session}; Path={}; Max-Age={SESSION_MAX_AGE_SECONDS}; HttpOnly; SameSite=Lax",
+                    SETTINGS.base_url,
+                );
+                let result = reply::with_header(see_other(url), "Set-Cookie", cookie);
+                Ok::<_, Rejection>(Box::new(result) as Box<dyn Reply>)
             }
+        });
 
-            let mut body = Vec::default();
-            let content_type = match read_file_or_index(&mut body, &path) {
-                Ok(result) => Ok(result),
-                Err(Error::Internal(error)) => Err(custom(InternalError(error))),
-                Err(Error::NotFound) => Err(custom(NotFound(peek.as_str().to_owned()))),
-            }?;
-
-            let response = Response::builder()
-                .header("Content-Type", content_type)
-                .body(body)
-                .wrap_err("failed to build response")
+    // GET /search?q=...[&tag=...][&author=...]
+    let search_route = warp::path!("search")
+        .and(warp::filters::method::get())
+        .and(warp::filters::query::query())
+        .and_then(|mut query: HashMap<String, String>| async move {
+            if !SETTINGS.search() {
+                return Err(custom(NotFound("search is not enabled".to_owned())));
+            }
+            let mut filter = |name: &str| query.remove(name).filter(|value| !value.is_empty());
+            let query = SearchQuery {
+                tag: filter("tag"),
+                author: filter("author"),
+                q: filter("q").unwrap_or_default(),
+            };
+            let documents = search::load_documents()
+                .wrap_err("failed to load search index")
                 .map_err(InternalError)?;
+            let results =
+                search::search(&query, &documents, search::load_shard).map_err(InternalError)?;
+            let content = SearchTemplate::render_without_fixing_relative_urls(
+                "search",
+                &query,
+                Some(&results),
+            )
+            .wrap_err("failed to render template")
+            .map_err(InternalError)?;
+            let result = ThreadsPageTemplate::render(
+                &content,
+                &format!("search — {}", SETTINGS.site_title),
+                &None,
+                &StaticFileUrls::default(),
+            )
+            .wrap_err("failed to render template")
+            .map_err(InternalError)?;
+            Ok::<_, Rejection>(result)
+        })
+        .with(header("Content-Type", HTML));
 
-            Ok(response)
-        });
+    let default_route = static_files_route(
+        (&*SitePath::ROOT).as_ref().to_owned(),
+        (&*AttachmentsPath::ROOT).as_ref().to_owned(),
+    );
 
     // successful responses are in their own types. error responses are in plain text.
     let mut site_routes = any().boxed();
@@ -253,6 +344,14 @@ pub async fn main(args: Server) -> eyre::Result<()> {
         compose_route
             .or(preview_route)
             .or(publish_route)
+            .or(search_route)
+            .or(login_page_route)
+            .or(login_route)
+            .or(micropub_route)
+            .or(webmention_route)
+            .or(activitypub_inbox_route)
+            .or(redirects_route)
+            .or(deleted_posts_route)
             .or(default_route),
     );
 
@@ -270,10 +369,1762 @@ pub async fn main(args: Server) -> eyre::Result<()> {
     let routes = routes.recover(recover);
 
     let port = args.port.unwrap_or(SETTINGS.server_port());
-    info!("starting server on http://[::1]:{}", port);
-    warp::serve(routes)
-        .run(("::1".parse::<IpAddr>()?, port))
-        .await;
+    let address = SocketAddr::new(bind_address, port);
+    info!("starting server on http://{address}");
+    warp::serve(routes).run(address).await;
+
+    Ok(())
+}
+
+/// authentication for the routes that can see or change your posts. there is no
+/// password when listening on a loopback address, so every request is allowed.
+#[derive(Clone, Debug, Default)]
+struct Auth {
+    password: Option<Arc<str>>,
+    /// key for signing session cookies, which never leaves the server.
+    session_key: Arc<[u8]>,
+}
+
+impl Auth {
+    fn new(password: String, session_key: Vec<u8>) -> Self {
+        Self {
+            password: Some(password.into()),
+            session_key: session_key.into(),
+        }
+    }
+
+    /// returns a new session cookie value, if the password is correct.
+    fn log_in(&self, password: &str) -> Option<String> {
+        let Some(expected) = &self.password else {
+            return Some(String::new());
+        };
+        if !constant_time_eq(password.as_bytes(), expected.as_bytes()) {
+            return None;
+        }
+        let nonce = Uuid::new_v4().simple().to_string();
+        let signature = self.session_mac(expected, &nonce).finalize().into_bytes();
+
+        Some(format!(
+            "{nonce}.{}",
+            BASE64_URL_SAFE_NO_PAD.encode(signature)
+        ))
+    }
+
+    /// checks for a valid session cookie, or the password as a bearer token.
+    fn is_authenticated(&self, headers: &HeaderMap) -> bool {
+        let Some(password) = &self.password else {
+            return true;
+        };
+        let bearer = header_str(headers, AUTHORIZATION)
+            .and_then(|authorization| authorization.strip_prefix("Bearer "));
+        if let Some(token) = bearer {
+            return constant_time_eq(token.trim().as_bytes(), password.as_bytes());
+        }
+
+        headers
+            .get_all(COOKIE)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(';'))
+            .filter_map(|cookie| cookie.trim().split_once('='))
+            .filter(|(name, _)| *name == SESSION_COOKIE_NAME)
+            .filter_map(|(_, value)| value.split_once('.'))
+            .filter_map(|(nonce, signature)| {
+                Some((nonce, BASE64_URL_SAFE_NO_PAD.decode(signature).ok()?))
+            })
+            .any(|(nonce, signature)| {
+                // `verify_slice` compares in constant time.
+                self.session_mac(password, nonce)
+                    .verify_slice(&signature)
+                    .is_ok()
+            })
+    }
+
+    /// sessions are stateless, so they survive server restarts, but changing the
+    /// password or the session key logs out every session.
+    fn session_mac(&self, password: &str, nonce: &str) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.session_key)
+            .expect("hmac accepts keys of any length");
+        mac.update(format!("autost session\0{nonce}\0{password}").as_bytes());
+
+        mac
+    }
+}
+
+/// loads the key for signing session cookies, generating it if it doesn’t exist yet.
+fn load_or_generate_session_key(path: &Path) -> eyre::Result<Vec<u8>> {
+    match read_to_string(path) {
+        Ok(key) => return Ok(BASE64_URL_SAFE_NO_PAD.decode(key.trim())?),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+        Err(error) => return Err(error.into()),
+    }
+    info!("generating session key in {path:?}");
+    let mut key = vec![0; 32];
+    rand::thread_rng().fill_bytes(&mut key);
+    let mut options = File::options();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    writeln!(
+        options.open(path)?,
+        "{}",
+        BASE64_URL_SAFE_NO_PAD.encode(&key)
+    )?;
+
+    Ok(key)
+}
+
+/// returns false for requests from other origins, to protect against csrf.
+/// requests with neither `Sec-Fetch-Site` nor `Origin` (like those from curl)
+/// are allowed, since they still need to be authenticated.
+fn is_same_origin(headers: &HeaderMap) -> bool {
+    if let Some(site) = header_str(headers, "sec-fetch-site") {
+        return site == "same-origin" || site == "none";
+    }
+    if let Some(origin) = header_str(headers, ORIGIN) {
+        let host = header_str(headers, HOST);
+        return origin
+            .split_once("://")
+            .is_some_and(|(_, authority)| Some(authority) == host);
+    }
+
+    true
+}
+
+/// rejects requests that are unauthenticated or cross-origin. unauthenticated
+/// GET requests get a login page that returns to the same url.
+fn protected(auth: Auth) -> impl Filter<Extract = (), Error = Rejection> + Clone {
+    let query = warp::filters::query::raw()
+        .or(any().map(String::new))
+        .unify();
+
+    warp::filters::method::method()
+        .and(warp::filters::path::full())
+        .and(query)
+        .and(warp::filters::header::headers_cloned())
+        .and_then(
+            move |method: Method, path: FullPath, query: String, headers: HeaderMap| {
+                let auth = auth.clone();
+                async move {
+                    if !is_same_origin(&headers) {
+                        return Err(custom(Forbidden(eyre!("cross-origin request"))));
+                    }
+                    if !auth.is_authenticated(&headers) {
+                        let next = (method == Method::GET).then(|| match &*query {
+                            "" => path.as_str().to_owned(),
+                            query => format!("{}?{query}", path.as_str()),
+                        });
+                        return Err(custom(Unauthorized { next }));
+                    }
+                    Ok(())
+                }
+            },
+        )
+        .untuple_one()
+}
+
+#[test]
+fn test_auth() {
+    let auth = Auth::new("hunter2".to_owned(), b"key".to_vec());
+    let headers = |pairs: &[(&'static str, &str)]| {
+        let mut result = HeaderMap::new();
+        for (name, value) in pairs {
+            result.insert(*name, value.parse().unwrap());
+        }
+        result
+    };
+    assert!(Auth::default().is_authenticated(&headers(&[])));
+    assert!(!auth.is_authenticated(&headers(&[])));
+    assert_eq!(auth.log_in("hunter3"), None);
+
+    let session = auth.log_in("hunter2").unwrap();
+    let cookie = format!("foo=bar; {SESSION_COOKIE_NAME}={session}");
+    assert!(auth.is_authenticated(&headers(&[("cookie", &cookie)])));
+    let forged = format!(
+        "{SESSION_COOKIE_NAME}=0123.{}",
+        session.split_once('.').unwrap().1
+    );
+    assert!(!auth.is_authenticated(&headers(&[("cookie", &forged)])));
+    assert!(!Auth::new("hunter3".to_owned(), b"key".to_vec())
+        .is_authenticated(&headers(&[("cookie", &cookie)])));
+    assert!(!Auth::new("hunter2".to_owned(), b"other key".to_vec())
+        .is_authenticated(&headers(&[("cookie", &cookie)])));
+
+    assert!(auth.is_authenticated(&headers(&[("authorization", "Bearer hunter2")])));
+    assert!(!auth.is_authenticated(&headers(&[("authorization", "Bearer hunter3")])));
+
+    assert!(is_same_origin(&headers(&[])));
+    assert!(is_same_origin(&headers(&[(
+        "sec-fetch-site",
+        "same-origin"
+    )])));
+    assert!(!is_same_origin(&headers(&[(
+        "sec-fetch-site",
+        "cross-site"
+    )])));
+    assert!(is_same_origin(&headers(&[
+        ("origin", "http://192.0.2.1:8420"),
+        ("host", "192.0.2.1:8420"),
+    ])));
+    assert!(!is_same_origin(&headers(&[
+        ("origin", "https://evil.example"),
+        ("host", "192.0.2.1:8420"),
+    ])));
+}
+
+/// the redirects and tombstones written by the last render, so requests for them can be
+/// answered without reading the settings, posts, or `tombstones.json` again.
+#[derive(Clone, Default)]
+struct RenderedSite {
+    output: Arc<RwLock<RenderOutput>>,
+}
+
+impl RenderedSite {
+    /// renders the whole site, and remembers what was written.
+    fn render_all(&self) -> eyre::Result<()> {
+        let output = render_all()?;
+        *self.output.write().unwrap() = output;
+
+        Ok(())
+    }
+
+    fn redirect_target(&self, page: &str) -> Option<RedirectTarget> {
+        self.output.read().unwrap().redirects.get(page).cloned()
+    }
+
+    /// returns true if `page` is the tombstone page of a deleted post. pages reused by
+    /// another post don’t get tombstones.
+    fn is_tombstone(&self, page: &str) -> bool {
+        self.output.read().unwrap().tombstone_pages.contains(page)
+    }
+}
+
+/// checks that a new post renders, then writes it as a new markdown post and
+/// re-renders the site.
+fn publish_post(site: &RenderedSite, unsafe_source: &str) -> eyre::Result<PostsPath> {
+    // try rendering the post before writing it, to catch any errors.
+    let unsafe_html = render_markdown_post(unsafe_source)?;
+    let post = TemplatedPost::filter(&unsafe_html, None)?;
+    let _thread = Thread::try_from(post)?;
+
+    // cohost post ids are all less than 10000000.
+    let (mut file, path) = (10000000..)
+        .map(|id| {
+            let path = PostsPath::markdown_post_path(id);
+            File::create_new(&path).map(|file| (file, path))
+        })
+        .filter(|file| !matches!(file, Err(error) if error.kind() == io::ErrorKind::AlreadyExists))
+        .next()
+        .expect("too many posts :(")
+        .wrap_err("failed to create post")?;
+
+    file.write_all(unsafe_source.as_bytes())
+        .wrap_err("failed to write post file")?;
+    site.render_all()?;
+
+    let post = TemplatedPost::load(&path)?;
+    let _thread = Thread::try_from(post)?;
+    send_webmentions_in_background(path.clone());
+    deliver_activities_in_background();
+
+    Ok(path)
+}
+
+/// sends webmentions for a new or updated post, if `webmention_send_on_publish` is set.
+fn send_webmentions_in_background(path: PostsPath) {
+    if !SETTINGS.webmention_send_on_publish() {
+        return;
+    }
+    tokio::task::spawn_blocking(move || {
+        let store = WebmentionStore::default();
+        if let Err(error) = webmention::send_for_post(&RealWebmentionFetcher, &store, &path, false)
+        {
+            warn!(?error, "failed to send webmentions for {path:?}");
+        }
+    });
+}
+
+/// sends webmentions for the page of a deleted post, if `webmention_send_on_publish` is set.
+fn send_deletion_webmentions_in_background(page: String) {
+    if !SETTINGS.webmention_send_on_publish() {
+        return;
+    }
+    tokio::task::spawn_blocking(move || {
+        let store = WebmentionStore::default();
+        if let Err(error) = webmention::send(&RealWebmentionFetcher, &store, &page, None, false) {
+            warn!(?error, "failed to send webmentions for deleted page {page}");
+        }
+    });
+}
+
+/// delivers new, updated, or deleted posts to activitypub followers, if
+/// `activitypub_deliver_on_publish` is set.
+fn deliver_activities_in_background() {
+    if !SETTINGS.activitypub_deliver_on_publish() {
+        return;
+    }
+    tokio::task::spawn_blocking(|| {
+        let store = ActivityPubStore::default();
+        if let Err(error) = deliver(&store, false) {
+            warn!(?error, "failed to deliver activities");
+        }
+    });
+}
+
+/// the micropub endpoint at `/micropub`, and its media endpoint at `/micropub/media`.
+/// without a token verifier, every request is unauthorized.
+fn micropub_route(
+    verifier: Option<Arc<dyn TokenVerifier>>,
+    site: RenderedSite,
+) -> impl Filter<Extract = (Box<dyn Reply>,), Error = Rejection> + Clone {
+    let host = warp::filters::header::optional::<String>("host");
+    let with_verifier = any().map(move || verifier.clone());
+    let with_site = any().map(move || site.clone());
+
+    // GET /micropub?q=config|source|syndicate-to
+    let query_route = warp::path!("micropub")
+        .and(warp::filters::method::get())
+        .and(with_verifier.clone())
+        .and(warp::filters::header::headers_cloned())
+        .and(host)
+        .and(
+            warp::filters::query::raw()
+                .or(any().map(String::new))
+                .unify(),
+        )
+        .then(
+            |verifier: Option<Arc<dyn TokenVerifier>>,
+             headers: HeaderMap,
+             host: Option<String>,
+             query: String| async move {
+                let pairs = form_urlencoded::parse(query.as_bytes())
+                    .into_owned()
+                    .collect::<Vec<_>>();
+                micropub_reply(
+                    micropub_query(verifier.as_deref(), &headers, host.as_deref(), pairs).await,
+                )
+            },
+        );
+
+    // POST /micropub/media with multipart body: file=...
+    let media_route = warp::path!("micropub" / "media")
+        .and(warp::filters::method::post())
+        .and(with_verifier.clone())
+        .and(warp::filters::header::headers_cloned())
+        .and(warp::filters::multipart::form().max_length(MICROPUB_MAX_UPLOAD_LENGTH))
+        .then(
+            |verifier: Option<Arc<dyn TokenVerifier>>, headers: HeaderMap, form: FormData| async move {
+                micropub_reply(micropub_media(verifier.as_deref(), &headers, form).await)
+            },
+        );
+
+    // POST /micropub with multipart body (which may have photo files)
+    let multipart_route = warp::path!("micropub")
+        .and(warp::filters::method::post())
+        .and(with_verifier.clone())
+        .and(with_site.clone())
+        .and(warp::filters::header::headers_cloned())
+        .and(host)
+        .and(warp::filters::multipart::form().max_length(MICROPUB_MAX_UPLOAD_LENGTH))
+        .then(
+            |verifier: Option<Arc<dyn TokenVerifier>>,
+             site: RenderedSite,
+             headers: HeaderMap,
+             host: Option<String>,
+             form: FormData| async move {
+                let result = async {
+                    let form = read_multipart_form(form).await?;
+                    // only store uploads once we know the token can create posts, so
+                    // unauthorized requests can’t fill the attachments dir.
+                    if form.has_files() {
+                        let access_token = form.field("access_token");
+                        micropub_check_scope(
+                            verifier.as_deref(),
+                            &headers,
+                            access_token,
+                            "create",
+                        )?;
+                    }
+                    let pairs = form.store_files()?;
+                    micropub_post(
+                        verifier.as_deref(),
+                        &site,
+                        &headers,
+                        host.as_deref(),
+                        pairs,
+                        None,
+                    )
+                    .await
+                };
+                micropub_reply(result.await)
+            },
+        );
+
+    // POST /micropub with urlencoded or json body
+    let post_route = warp::path!("micropub")
+        .and(warp::filters::method::post())
+        .and(with_verifier)
+        .and(with_site)
+        .and(warp::filters::header::headers_cloned())
+        .and(host)
+        .and(warp::filters::body::content_length_limit(
+            MICROPUB_MAX_BODY_LENGTH,
+        ))
+        .and(warp::filters::body::bytes())
+        .then(
+            |verifier: Option<Arc<dyn TokenVerifier>>,
+             site: RenderedSite,
+             headers: HeaderMap,
+             host: Option<String>,
+             body: warp::hyper::body::Bytes| async move {
+                let is_json = header_str(&headers, CONTENT_TYPE)
+                    .is_some_and(|content_type| content_type.starts_with("application/json"));
+                let result = async {
+                    let (pairs, json) = if is_json {
+                        let json = serde_json::from_slice(&body).map_err(|error| {
+                            MicropubError::invalid_request(format!("bad json: {error}"))
+                        })?;
+                        (vec![], Some(json))
+                    } else {
+                        (form_urlencoded::parse(&body).into_owned().collect(), None)
+                    };
+                    micropub_post(
+                        verifier.as_deref(),
+                        &site,
+                        &headers,
+                        host.as_deref(),
+                        pairs,
+                        json,
+                    )
+                    .await
+                };
+                micropub_reply(result.await)
+            },
+        );
+
+    query_route
+        .or(media_route)
+        .unify()
+        .or(multipart_route)
+        .unify()
+        .or(post_route)
+        .unify()
+}
+
+const MICROPUB_MAX_BODY_LENGTH: u64 = 1024 * 1024;
+const MICROPUB_MAX_UPLOAD_LENGTH: u64 = 64 * 1024 * 1024;
+
+fn micropub_reply(result: Result<Box<dyn Reply>, MicropubError>) -> Box<dyn Reply> {
+    match result {
+        Ok(reply) => reply,
+        Err(error) => {
+            warn!(?error, "micropub: responding with error");
+            Box::new(reply::with_status(
+                reply::json(&error.to_json()),
+                error.status,
+            ))
+        }
+    }
+}
+
+/// checks the token in the `Authorization` header or `access_token` field.
+fn micropub_check_scope(
+    verifier: Option<&dyn TokenVerifier>,
+    headers: &HeaderMap,
+    access_token: Option<&str>,
+    scope: &str,
+) -> Result<(), MicropubError> {
+    let token = header_str(headers, AUTHORIZATION)
+        .and_then(|authorization| authorization.strip_prefix("Bearer "))
+        .or(access_token)
+        .ok_or_else(|| MicropubError::unauthorized("missing access token"))?;
+    let verifier =
+        verifier.ok_or_else(|| MicropubError::unauthorized("no micropub token is configured"))?;
+    let scopes = verifier
+        .verify(token.trim())?
+        .ok_or_else(|| MicropubError::unauthorized("invalid access token"))?;
+    if !scopes.contains(scope) {
+        return Err(MicropubError::insufficient_scope(scope));
+    }
+
+    Ok(())
+}
+
+async fn micropub_query(
+    verifier: Option<&dyn TokenVerifier>,
+    headers: &HeaderMap,
+    host: Option<&str>,
+    pairs: Vec<(String, String)>,
+) -> Result<Box<dyn Reply>, MicropubError> {
+    let field = |name: &str| {
+        pairs
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    };
+    // any valid token can query, but we need to check that it is valid.
+    micropub_check_scope(verifier, headers, field("access_token"), "create").or_else(|error| {
+        match error.error {
+            "insufficient_scope" => Ok(()),
+            _ => Err(error),
+        }
+    })?;
+
+    match field("q") {
+        Some("config") => {
+            let media_endpoint = format!("{}micropub/media", SETTINGS.external_base_url);
+            Ok(Box::new(reply::json(&json!({
+                "media-endpoint": media_endpoint,
+                "syndicate-to": [],
+            }))))
+        }
+        Some("syndicate-to") => Ok(Box::new(reply::json(&json!({ "syndicate-to": [] })))),
+        Some("source") => {
+            let url = field("url").ok_or_else(|| MicropubError::invalid_request("missing url"))?;
+            let path = micropub_find_post(url, host)?;
+            let post = TemplatedPost::load(&path)?;
+            let source = read_to_string(&path).wrap_err("failed to read post")?;
+            let (_, _, body) = split_front_matter(&source)?;
+            let in_reply_to = post
+                .meta
+                .references
+                .last()
+                .map(micropub::post_url)
+                .transpose()?
+                .flatten();
+            let names = pairs
+                .iter()
+                .filter(|(key, _)| key == "properties[]" || key == "properties")
+                .map(|(_, value)| value.clone())
+                .collect::<Vec<_>>();
+            let entry = MicropubEntry::from_post(&post.meta, body);
+            Ok(Box::new(reply::json(&entry.source(in_reply_to, &names))))
+        }
+        Some(other) => Err(MicropubError::invalid_request(format!(
+            "unsupported query: {other}"
+        ))),
+        None => Err(MicropubError::invalid_request("missing q")),
+    }
+}
+
+async fn micropub_post(
+    verifier: Option<&dyn TokenVerifier>,
+    site: &RenderedSite,
+    headers: &HeaderMap,
+    host: Option<&str>,
+    pairs: Vec<(String, String)>,
+    json: Option<serde_json::Value>,
+) -> Result<Box<dyn Reply>, MicropubError> {
+    let access_token = pairs
+        .iter()
+        .find(|(key, _)| key == "access_token")
+        .map(|(_, value)| value.clone());
+    let request = match json {
+        Some(json) => MicropubRequest::from_json(json),
+        None => MicropubRequest::from_form(pairs),
+    };
+    // check the token before reporting any errors in the request.
+    let scope = match &request {
+        Ok(MicropubRequest::Create(_)) | Err(_) => "create",
+        Ok(MicropubRequest::Update { .. }) => "update",
+        Ok(MicropubRequest::Delete { .. }) => "delete",
+    };
+    micropub_check_scope(verifier, headers, access_token.as_deref(), scope)?;
+
+    match request? {
+        MicropubRequest::Create(mut entry) => {
+            let references = match entry.in_reply_to.take() {
+                Some(url) => {
+                    let path = match site_base_relative_url(&url, host) {
+                        Some(_) => micropub_find_post(&url, host)?,
+                        None => micropub::find_imported_post(&url)?.ok_or_else(|| {
+                            MicropubError::invalid_request(format!(
+                                "to reply to a post on another site, import it first: autost import {url}"
+                            ))
+                        })?,
+                    };
+                    let post = TemplatedPost::load(&path)?;
+                    Thread::try_from(post)?.reply_references()
+                }
+                None => vec![],
+            };
+            // photos uploaded to the media endpoint have absolute urls, but posts refer to
+            // attachments with urls relative to base_url.
+            for photo in entry.photo.iter_mut() {
+                if let Some(url) = site_base_relative_url(&photo.url, host) {
+                    photo.url = url;
+                }
+            }
+            let published = entry
+                .published
+                .clone()
+                .unwrap_or_else(|| Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true));
+            let meta = PostMeta {
+                archived: None,
+                references,
+                title: entry.name.clone(),
+                published: Some(published),
+                author: SETTINGS.self_author.clone(),
+                author_privacy: None,
+                author_logged_out_post_visibility: None,
+                tags: entry.category.clone(),
+                is_transparent_share: false,
+                toc: false,
+                slug: None,
+                cws: vec![],
+                adult_content: false,
+            };
+            let source = micropub::post_source(&meta, &entry.body())?;
+            let path = publish_post(site, &source)?;
+            let url = path
+                .rendered_path()?
+                .ok_or_eyre("path has no rendered path")?
+                .external_url();
+            info!("micropub: created {path:?} at <{url}>");
+
+            Ok(Box::new(reply::with_header(
+                reply::with_status(reply(), StatusCode::CREATED),
+                "Location",
+                url,
+            )))
+        }
+        MicropubRequest::Update { url, update } => {
+            let path = micropub_find_post(&url, host)?;
+            if !path.is_markdown_post() {
+                return Err(MicropubError::invalid_request(
+                    "only markdown posts can be updated",
+                ));
+            }
+            let mut meta = TemplatedPost::load(&path)?.meta;
+            let source = read_to_string(&path).wrap_err("failed to read post")?;
+            // keep the post’s front matter style.
+            let (style, _, body) = split_front_matter(&source)?;
+            let mut entry = MicropubEntry::from_post(&meta, body);
+            update.apply(&mut entry)?;
+            meta.title = entry.name.clone();
+            meta.tags = entry.category.clone();
+            let source = front_matter::post_source(style, &meta, &entry.body())?;
+
+            // try rendering the post before writing it, to catch any errors.
+            let post = TemplatedPost::filter(&render_markdown_post(&source)?, Some(path.clone()))?;
+            let _thread = Thread::try_from(post)?;
+            File::create(&path)
+                .and_then(|mut file| file.write_all(source.as_bytes()))
+                .wrap_err("failed to write post file")?;
+            site.render_all()?;
+            info!("micropub: updated {path:?}");
+            send_webmentions_in_background(path);
+            deliver_activities_in_background();
+
+            Ok(Box::new(StatusCode::NO_CONTENT))
+        }
+        MicropubRequest::Delete { url } => {
+            let path = micropub_find_post(&url, host)?;
+            let referencing_posts = micropub::find_referencing_posts(&path)?;
+            if !referencing_posts.is_empty() {
+                let referencing_posts = referencing_posts
+                    .iter()
+                    .map(|path| path.references_url())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                return Err(MicropubError::invalid_request(format!(
+                    "post cannot be deleted, because other posts refer to it: {referencing_posts}"
+                )));
+            }
+            let page = delete_post(&TombstoneStore::default(), &path)?;
+            site.render_all()?;
+            info!("micropub: deleted {path:?}");
+            if let Some(page) = page {
+                send_deletion_webmentions_in_background(page);
+            }
+            deliver_activities_in_background();
+
+            Ok(Box::new(StatusCode::NO_CONTENT))
+        }
+    }
+}
+
+async fn micropub_media(
+    verifier: Option<&dyn TokenVerifier>,
+    headers: &HeaderMap,
+    form: FormData,
+) -> Result<Box<dyn Reply>, MicropubError> {
+    micropub_check_scope(verifier, headers, None, "media")?;
+    let pairs = read_multipart_form(form).await?.store_files()?;
+    let (_, url) = pairs
+        .into_iter()
+        .find(|(name, _)| name == "file")
+        .ok_or_else(|| MicropubError::invalid_request("missing file"))?;
+
+    Ok(Box::new(reply::with_header(
+        reply::with_status(reply(), StatusCode::CREATED),
+        "Location",
+        url,
+    )))
+}
+
+fn micropub_find_post(url: &str, host: Option<&str>) -> Result<PostsPath, MicropubError> {
+    site_base_relative_url(url, host)
+        .map(|url| PostsPath::find_by_rendered_url(&url))
+        .transpose()?
+        .flatten()
+        .ok_or_else(|| MicropubError::not_found(url))
+}
+
+/// a multipart form, whose files are held in memory until they are stored as attachments.
+struct MultipartForm {
+    fields: Vec<(String, MultipartValue)>,
+}
+
+enum MultipartValue {
+    Text(String),
+    File { filename: String, data: Vec<u8> },
+}
+
+impl MultipartForm {
+    fn field(&self, name: &str) -> Option<&str> {
+        self.fields.iter().find_map(|(key, value)| match value {
+            MultipartValue::Text(value) if key == name => Some(value.as_str()),
+            _ => None,
+        })
+    }
+
+    fn has_files(&self) -> bool {
+        self.fields
+            .iter()
+            .any(|(_, value)| matches!(value, MultipartValue::File { .. }))
+    }
+
+    /// stores any files as attachments. the values of file fields are the external urls of
+    /// those attachments.
+    fn store_files(self) -> Result<Vec<(String, String)>, MicropubError> {
+        let mut result = vec![];
+        for (name, value) in self.fields {
+            let value = match value {
+                MultipartValue::Text(value) => value,
+                MultipartValue::File { filename, data } => {
+                    let dir = temp_dir().join(format!("autost-upload-{}", Uuid::new_v4()));
+                    create_dir_all(&dir).wrap_err("failed to create upload dir")?;
+                    let upload_path = dir.join(filename);
+                    let attachment = File::create(&upload_path)
+                        .and_then(|mut file| file.write_all(&data))
+                        .wrap_err("failed to write upload")
+                        .and_then(|()| RealAttachmentsContext.store(&upload_path));
+                    remove_dir_all(&dir).wrap_err("failed to remove upload dir")?;
+                    let attachment = attachment?;
+                    info!("micropub: stored attachment {attachment:?}");
+                    attachment.site_path()?.external_url()
+                }
+            };
+            result.push((name, value));
+        }
+
+        Ok(result)
+    }
+}
+
+/// reads a multipart form, without storing any files yet.
+async fn read_multipart_form(mut form: FormData) -> Result<MultipartForm, MicropubError> {
+    let mut fields = vec![];
+    while let Some(part) = form
+        .try_next()
+        .await
+        .map_err(|error| MicropubError::invalid_request(format!("bad multipart body: {error}")))?
+    {
+        let name = part
+            .name()
+            .strip_suffix("[]")
+            .unwrap_or(part.name())
+            .to_owned();
+        let filename = part.filename().map(path_safe_filename_component);
+        let mut data = vec![];
+        let mut stream = part.stream();
+        while let Some(chunk) = stream.try_next().await.map_err(|error| {
+            MicropubError::invalid_request(format!("bad multipart body: {error}"))
+        })? {
+            data.extend_from_slice(chunk.chunk());
+        }
+        let value = match filename {
+            Some(filename) => MultipartValue::File { filename, data },
+            None => MultipartValue::Text(
+                String::from_utf8(data)
+                    .map_err(|_| MicropubError::invalid_request("form field is not utf-8"))?,
+            ),
+        };
+        fields.push((name, value));
+    }
+
+    Ok(MultipartForm { fields })
+}
+
+#[tokio::test]
+async fn test_micropub_route() -> eyre::Result<()> {
+    use std::collections::BTreeSet;
+
+    use warp::hyper::body::to_bytes;
+
+    struct MediaOnly;
+    impl TokenVerifier for MediaOnly {
+        fn verify(&self, token: &str) -> eyre::Result<Option<BTreeSet<String>>> {
+            Ok((token == "media").then(|| BTreeSet::from(["media".to_owned()])))
+        }
+    }
+    async fn json(response: Response<Body>) -> eyre::Result<serde_json::Value> {
+        Ok(serde_json::from_slice(
+            &to_bytes(response.into_body()).await?,
+        )?)
+    }
+    let route = micropub_route(Some(Arc::new(MediaOnly)), RenderedSite::default());
+    let request = |path: &str| {
+        warp::test::request()
+            .path(path)
+            .header("host", "[::1]:8420")
+    };
+    let response = |request: warp::test::RequestBuilder| async {
+        Ok::<_, eyre::Report>(
+            request
+                .filter(&route)
+                .await
+                .map_err(|error| eyre!("{error:?}"))?
+                .into_response(),
+        )
+    };
+
+    let unauthorized = response(request("/micropub?q=config")).await?;
+    assert_eq!(unauthorized.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(json(unauthorized).await?["error"], "unauthorized");
+    let wrong_token = request("/micropub?q=config&access_token=nope");
+    assert_eq!(
+        response(wrong_token).await?.status(),
+        StatusCode::UNAUTHORIZED
+    );
+
+    let config = response(request("/micropub?q=config").header("authorization", "Bearer media"));
+    let config = config.await?;
+    assert_eq!(config.status(), StatusCode::OK);
+    assert_eq!(
+        json(config).await?["media-endpoint"],
+        "https://example.com/micropub/media"
+    );
+
+    let create = request("/micropub")
+        .method("POST")
+        .header("content-type", "application/x-www-form-urlencoded")
+        .body("h=entry&content=hello&access_token=media");
+    let create = response(create).await?;
+    assert_eq!(create.status(), StatusCode::FORBIDDEN);
+    assert_eq!(json(create).await?["error"], "insufficient_scope");
+
+    // uploads are not stored unless the token can create posts.
+    let attachments_count =
+        || std::fs::read_dir(&*AttachmentsPath::ROOT).map_or(0, |entries| entries.count());
+    let before = attachments_count();
+    let create = request("/micropub")
+        .method("POST")
+        .header("content-type", "multipart/form-data; boundary=x")
+        .body(concat!(
+            "--x\r\n",
+            "content-disposition: form-data; name=\"access_token\"\r\n\r\n",
+            "media\r\n",
+            "--x\r\n",
+            "content-disposition: form-data; name=\"photo\"; filename=\"a.png\"\r\n\r\n",
+            "png\r\n",
+            "--x--\r\n",
+        ));
+    assert_eq!(response(create).await?.status(), StatusCode::FORBIDDEN);
+    assert_eq!(attachments_count(), before);
+
+    let update = request("/micropub")
+        .method("POST")
+        .header("content-type", "application/json")
+        .header("authorization", "Bearer nope")
+        .body(r#"{"action":"update","url":"/1.html","replace":{"name":["x"]}}"#);
+    assert_eq!(response(update).await?.status(), StatusCode::UNAUTHORIZED);
+
+    // without a verifier, every request is unauthorized.
+    let route = micropub_route(None, RenderedSite::default());
+    let config = request("/micropub?q=config").header("authorization", "Bearer media");
+    let config = config
+        .filter(&route)
+        .await
+        .map_err(|error| eyre!("{error:?}"))?;
+    assert_eq!(config.into_response().status(), StatusCode::UNAUTHORIZED);
+
+    Ok(())
+}
+
+/// the webmention endpoint at `/webmention`. mentions of posts are accepted
+/// right away, then verified in the background and held for approval.
+fn webmention_route(
+    store: WebmentionStore,
+    fetcher: Arc<dyn WebmentionFetcher>,
+) -> impl Filter<Extract = (Box<dyn Reply>,), Error = Rejection> + Clone {
+    let verifications = Arc::new(Semaphore::new(WEBMENTION_MAX_PENDING_VERIFICATIONS));
+
+    // POST /webmention with urlencoded body: source=...&target=...
+    warp::path!("webmention")
+        .and(warp::filters::method::post())
+        .and(warp::filters::header::optional::<String>("host"))
+        .and(warp::filters::body::content_length_limit(
+            WEBMENTION_MAX_BODY_LENGTH,
+        ))
+        .and(warp::filters::body::form())
+        .and_then(
+            move |host: Option<String>, mut form: HashMap<String, String>| {
+                let store = store.clone();
+                let fetcher = fetcher.clone();
+                let verifications = verifications.clone();
+                async move {
+                    let source = form.remove("source").unwrap_or_default();
+                    let target = form.remove("target").unwrap_or_default();
+                    let page = webmention_target_page(&source, &target, host.as_deref())
+                        .map_err(BadRequest)?;
+                    // each verification fetches the source, so don’t let senders queue up
+                    // unlimited fetches.
+                    let Ok(permit) = verifications.try_acquire_owned() else {
+                        warn!("too many pending webmentions, rejecting webmention from {source}");
+                        let result = reply::with_status(
+                            "too many pending webmentions, try again later\n",
+                            StatusCode::SERVICE_UNAVAILABLE,
+                        );
+                        let result = reply::with_header(result, "Retry-After", "60");
+                        return Ok::<_, Rejection>(Box::new(result) as Box<dyn Reply>);
+                    };
+                    info!("received webmention of {page} from {source}");
+                    tokio::task::spawn_blocking(move || {
+                        let _permit = permit;
+                        let result = webmention::verify(&*fetcher, &source, &target)
+                            .and_then(|verified| store.receive(&page, &source, &target, verified));
+                        if let Err(error) = result {
+                            warn!(?error, "failed to verify webmention from {source}");
+                        }
+                    });
+                    let result = reply::with_status("accepted\n", StatusCode::ACCEPTED);
+                    Ok::<_, Rejection>(Box::new(result) as Box<dyn Reply>)
+                }
+            },
+        )
+}
+
+const WEBMENTION_MAX_BODY_LENGTH: u64 = 64 * 1024;
+const WEBMENTION_MAX_PENDING_VERIFICATIONS: usize = 16;
+
+/// answers 410 gone with the tombstone page for deleted posts, so that other sites
+/// know to remove our mentions.
+fn deleted_posts_route(
+    is_tombstone: impl Fn(&str) -> bool + Clone + Send + Sync,
+) -> impl Filter<Extract = (Box<dyn Reply>,), Error = Rejection> + Clone {
+    warp::filters::method::get()
+        .or(warp::filters::method::head())
+        .unify()
+        .and(warp::path::tail())
+        .and_then(move |page: Tail| {
+            let is_tombstone = is_tombstone.clone();
+            async move {
+                let page = page.as_str();
+                if !is_tombstone(page) {
+                    return Err(warp::reject::not_found());
+                }
+                let result = SitePath::from_base_relative_url(page)
+                    .and_then(|path| Ok(read_to_string(path)?))
+                    .unwrap_or_else(|_| "gone\n".to_owned());
+                let result = reply::with_status(result, StatusCode::GONE);
+                let result = reply::with_header(result, "Content-Type", HTML);
+                Ok::<_, Rejection>(Box::new(result) as Box<dyn Reply>)
+            }
+        })
+}
+
+/// answers 301 moved permanently for the `[redirects]` setting, and for the old paths of
+/// posts with custom paths, instead of serving their meta refresh pages.
+fn redirects_route(
+    redirect_target: impl Fn(&str) -> Option<RedirectTarget> + Clone + Send + Sync,
+) -> impl Filter<Extract = (Box<dyn Reply>,), Error = Rejection> + Clone {
+    warp::filters::method::get()
+        .or(warp::filters::method::head())
+        .unify()
+        .and(warp::path::tail())
+        .and_then(move |page: Tail| {
+            let redirect_target = redirect_target.clone();
+            async move {
+                let Some(target) = redirect_target(page.as_str()) else {
+                    return Err(warp::reject::not_found());
+                };
+                let url = Uri::from_str(&target.url)
+                    .wrap_err("failed to build Uri")
+                    .map_err(InternalError)?;
+                Ok::<_, Rejection>(Box::new(redirect(url)) as Box<dyn Reply>)
+            }
+        })
+}
+
+#[derive(Clone)]
+struct ActivityPubInbox {
+    store: ActivityPubStore,
+    client: Arc<dyn ActivityPubClient>,
+    /// signs the `Accept` for new followers.
+    signer: RequestSigner,
+}
+
+/// the activitypub inbox at `/activitypub/inbox`, for follows and unfollows.
+/// activities must be signed by their actor. without an inbox, every request is
+/// not found.
+fn activitypub_inbox_route(
+    inbox: Option<ActivityPubInbox>,
+) -> impl Filter<Extract = (Box<dyn Reply>,), Error = Rejection> + Clone {
+    // POST /activitypub/inbox with json body
+    warp::path!("activitypub" / "inbox")
+        .and(warp::filters::method::post())
+        .and(warp::filters::path::full())
+        .and(warp::filters::header::headers_cloned())
+        .and(warp::filters::body::content_length_limit(
+            ACTIVITYPUB_MAX_BODY_LENGTH,
+        ))
+        .and(warp::filters::body::bytes())
+        .and_then(
+            move |path: FullPath, headers: HeaderMap, body: warp::hyper::body::Bytes| {
+                let inbox = inbox.clone();
+                async move {
+                    let Some(inbox) = inbox else {
+                        return Err(custom(NotFound(
+                            "activitypub inbox is not enabled".to_owned(),
+                        )));
+                    };
+                    let activity = serde_json::from_slice::<serde_json::Value>(&body)
+                        .wrap_err("bad activity")
+                        .map_err(BadRequest)?;
+                    let headers = headers
+                        .iter()
+                        .filter_map(|(name, value)| {
+                            Some((name.to_string(), value.to_str().ok()?.to_owned()))
+                        })
+                        .collect::<Vec<_>>();
+                    // reqwest::blocking can’t be used on an async runtime thread.
+                    let (inbox, accept) = tokio::task::spawn_blocking(move || {
+                        let client = &*inbox.client;
+                        let actor = activitypub::verify_request(
+                            client,
+                            "post",
+                            path.as_str(),
+                            &headers,
+                            &body,
+                        )
+                        .map_err(Forbidden)?;
+                        let result = activitypub::receive(client, &inbox.store, &actor, &activity)
+                            .map_err(BadRequest)?;
+                        let accept = match result {
+                            InboxResult::Followed(accept) => Some((actor, accept)),
+                            InboxResult::Unfollowed | InboxResult::Ignored => None,
+                        };
+                        Ok::<_, Rejection>((inbox, accept))
+                    })
+                    .await
+                    .wrap_err("failed to join task")
+                    .map_err(InternalError)??;
+                    if let Some((actor, accept)) = accept {
+                        tokio::task::spawn_blocking(move || {
+                            let result = activitypub::follower_inbox(&inbox.store, &actor)
+                                .and_then(|url| {
+                                    activitypub::post_activity(
+                                        &*inbox.client,
+                                        &inbox.signer,
+                                        &url,
+                                        &accept,
+                                    )
+                                });
+                            if let Err(error) = result {
+                                warn!(?error, "failed to accept follow from {actor}");
+                            }
+                        });
+                    }
+                    let result = reply::with_status("accepted\n", StatusCode::ACCEPTED);
+                    Ok::<_, Rejection>(Box::new(result) as Box<dyn Reply>)
+                }
+            },
+        )
+}
+
+const ACTIVITYPUB_MAX_BODY_LENGTH: u64 = 1024 * 1024;
+
+#[tokio::test]
+async fn test_activitypub_inbox_route() -> eyre::Result<()> {
+    use crate::activitypub::{test_remote_actor, StaticActivityPubClient};
+
+    let dir = temp_dir().join(format!("autost-test-activitypub-inbox-{}", Uuid::new_v4()));
+    let store = ActivityPubStore::new(&dir);
+    let actor = "https://remote.example/users/a";
+    let (remote_signer, document) = test_remote_actor(actor)?;
+    let (signer, _) = test_remote_actor("https://example.com/activitypub/actor.json")?;
+    let client = Arc::new(StaticActivityPubClient {
+        objects: BTreeMap::from([(actor.to_owned(), document)]),
+        ..Default::default()
+    });
+    let route = activitypub_inbox_route(Some(ActivityPubInbox {
+        store: store.clone(),
+        client: client.clone(),
+        signer,
+    }));
+    let post = |activity: serde_json::Value, signed: bool| {
+        let body = serde_json::to_vec(&activity).unwrap();
+        let url = Url::parse("https://example.com/activitypub/inbox").unwrap();
+        let mut request = warp::test::request()
+            .method("POST")
+            .path("/activitypub/inbox");
+        if signed {
+            for (name, value) in remote_signer.sign("post", &url, Some(&body)).unwrap() {
+                request = request.header(name, value);
+            }
+        }
+        let route = &route;
+        async move { request.body(body).filter(route).await }
+    };
+    let follow = json!({
+        "id": "https://remote.example/follows/1",
+        "type": "Follow",
+        "actor": actor,
+        "object": activitypub::actor_id(),
+    });
+
+    let rejection = post(follow.clone(), false).await.err();
+    assert!(rejection.is_some_and(|rejection| rejection.find::<Forbidden>().is_some()));
+    let response = post(follow.clone(), true).await;
+    let response = response.map_err(|error| eyre!("{error:?}"))?;
+    assert_eq!(response.into_response().status(), StatusCode::ACCEPTED);
+    assert_eq!(store.followers()?.len(), 1);
+
+    // the accept is sent in the background.
+    for _ in 0..100 {
+        if !client.posted.lock().unwrap().is_empty() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    let posted = client.posted.lock().unwrap().drain(..).collect::<Vec<_>>();
+    assert_eq!(posted.len(), 1);
+    assert_eq!(posted[0].0, format!("{actor}/inbox"));
+    assert_eq!(posted[0].2["type"], "Accept");
+
+    let undo = json!({"type": "Undo", "actor": actor, "object": follow});
+    let response = post(undo, true).await;
+    let response = response.map_err(|error| eyre!("{error:?}"))?;
+    assert_eq!(response.into_response().status(), StatusCode::ACCEPTED);
+    assert_eq!(store.followers()?, []);
+    remove_dir_all(dir)?;
+
+    Ok(())
+}
+
+/// checks that a webmention is for a post on this site, and returns its rendered page.
+fn webmention_target_page(source: &str, target: &str, host: Option<&str>) -> eyre::Result<String> {
+    let source_url = Url::parse(source).wrap_err("bad source")?;
+    let mut target_url = Url::parse(target).wrap_err("bad target")?;
+    if !is_http_url(&source_url) || !is_http_url(&target_url) {
+        bail!("source and target must be http or https urls");
+    }
+    target_url.set_fragment(None);
+    target_url.set_query(None);
+    if source_url.as_str().split('#').next() == Some(target_url.as_str()) {
+        bail!("source and target must be different");
+    }
+    let page = site_base_relative_url(target_url.as_str(), host)
+        .ok_or_eyre("target is not on this site")?;
+    if PostsPath::find_by_rendered_url(&page)?.is_none() {
+        bail!("target is not a post");
+    }
+
+    Ok(page)
+}
+
+#[tokio::test]
+async fn test_webmention_route() -> eyre::Result<()> {
+    use crate::webmention::StaticWebmentionFetcher;
+
+    let route = webmention_route(
+        WebmentionStore::new(temp_dir().join("autost-test-webmention-route")),
+        Arc::new(StaticWebmentionFetcher::default()),
+    );
+    let rejection = |body: &'static str| {
+        let request = warp::test::request()
+            .method("POST")
+            .path("/webmention")
+            .header("host", "[::1]:8420")
+            .header("content-type", "application/x-www-form-urlencoded")
+            .body(body);
+        let route = &route;
+        async move { request.filter(route).await.err() }
+    };
+
+    for body in [
+        "source=https://a.example/&target=",
+        "source=ftp://a.example/&target=https://example.com/1.html",
+        "source=https://example.com/1.html%23x&target=https://example.com/1.html",
+        "source=https://a.example/&target=https://example.net/1.html",
+        "source=https://a.example/&target=https://example.com/style.css",
+        "source=https://a.example/&target=http://[::1]:8420/999999.html",
+    ] {
+        let rejection = rejection(body).await.expect("request should be rejected");
+        assert!(rejection.find::<BadRequest>().is_some(), "{body}");
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deleted_posts_route() -> eyre::Result<()> {
+    let route = deleted_posts_route(|page| ["1.html", "2024/10/4.html"].contains(&page));
+    let request = |path: &str| warp::test::request().path(path);
+
+    for path in ["/1.html", "/2024/10/4.html"] {
+        let response = request(path).filter(&route).await;
+        let response = response.map_err(|error| eyre!("{error:?}"))?;
+        assert_eq!(
+            response.into_response().status(),
+            StatusCode::GONE,
+            "{path}"
+        );
+    }
+    for path in ["/2.html", "/..html", "/1.html/x"] {
+        assert!(request(path).filter(&route).await.is_err(), "{path}");
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_redirects_route() -> eyre::Result<()> {
+    let route = redirects_route(|page| {
+        (page == "1.html").then(|| RedirectTarget {
+            url: "/2024/10/foo.html".to_owned(),
+            canonical_url: "https://example.com/2024/10/foo.html".to_owned(),
+        })
+    });
+    let request = |path: &str| warp::test::request().path(path);
+
+    let response = request("/1.html").filter(&route).await;
+    let response = response
+        .map_err(|error| eyre!("{error:?}"))?
+        .into_response();
+    assert_eq!(response.status(), StatusCode::MOVED_PERMANENTLY);
+    assert_eq!(response.headers()["Location"], "/2024/10/foo.html");
+    assert!(request("/2.html").filter(&route).await.is_err());
+
+    Ok(())
+}
+
+/// serves files out of the site output directory (or attachments out of the
+/// attachment store), with support for precompressed variants, conditional
+/// requests, single byte ranges, and HEAD. file contents are streamed.
+fn static_files_route(
+    site_root: PathBuf,
+    attachments_root: PathBuf,
+) -> impl Filter<Extract = (Response<Body>,), Error = Rejection> + Clone {
+    let is_head = warp::filters::method::get()
+        .map(|| false)
+        .or(warp::filters::method::head().map(|| true))
+        .unify();
+
+    is_head
+        .and(warp::filters::path::peek())
+        .and(warp::filters::header::headers_cloned())
+        .and_then(move |is_head: bool, peek: Peek, headers: HeaderMap| {
+            let site_root = site_root.clone();
+            let attachments_root = attachments_root.clone();
+            async move {
+                serve_static_file(&site_root, &attachments_root, &peek, is_head, &headers).await
+            }
+        })
+}
+
+async fn serve_static_file(
+    site_root: &Path,
+    attachments_root: &Path,
+    peek: &Peek,
+    is_head: bool,
+    headers: &HeaderMap,
+) -> Result<Response<Body>, Rejection> {
+    let mut segments = peek.segments().peekable();
+    // serve attachments out of main attachment store, in case we need to preview a post
+    // that refers to an attachment for the first time. otherwise they will 404, since
+    // render won’t have hard-linked it into the site output dir.
+    let mut path = if segments.peek() == Some(&"attachments") {
+        segments.next();
+        attachments_root.to_owned()
+    } else {
+        site_root.to_owned()
+    };
+    for component in segments {
+        let component = urlencoding::decode(component)
+            .wrap_err("failed to decode url path component")
+            .map_err(BadRequest)?;
+        if component == ".." {
+            return Err(custom(BadRequest(eyre!("path component not allowed: .."))));
+        } else if component == "." {
+            continue;
+        }
+        path.push(&*component);
+    }
+
+    let not_found = || custom(NotFound(peek.as_str().to_owned()));
+    let mut metadata = tokio::fs::metadata(&path).await.map_err(|_| not_found())?;
+    if metadata.is_dir() {
+        path.push("index.html");
+        metadata = tokio::fs::metadata(&path).await.map_err(|_| not_found())?;
+    }
+    let content_type = content_type_for_path(&path);
+
+    // byte ranges are only served from the original file, to keep things simple.
+    let range = header_str(headers, RANGE);
+    let mut file_path = path.clone();
+    let mut content_encoding = None;
+    if range.is_none() {
+        let accept_encoding = header_str(headers, ACCEPT_ENCODING).unwrap_or_default();
+        for encoding in accepted_precompressed_encodings(accept_encoding) {
+            let mut variant_path = path.as_os_str().to_owned();
+            variant_path.push(encoding.suffix);
+            let Ok(variant_metadata) = tokio::fs::metadata(&variant_path).await else {
+                continue;
+            };
+            // ignore stale variants, like those left over from older renders.
+            let is_fresh = match (variant_metadata.modified(), metadata.modified()) {
+                (Ok(variant_modified), Ok(modified)) => variant_modified >= modified,
+                _ => false,
+            };
+            if is_fresh {
+                file_path = variant_path.into();
+                metadata = variant_metadata;
+                content_encoding = Some(encoding.name);
+                break;
+            }
+        }
+    }
+
+    let len = metadata.len();
+    let modified = metadata.modified().ok().map(DateTime::<Utc>::from);
+    let etag = entity_tag(len, modified, content_encoding);
+    let mut response = Response::builder()
+        .header(CONTENT_TYPE, content_type)
+        .header(VARY, "Accept-Encoding")
+        .header(ACCEPT_RANGES, "bytes")
+        .header(ETAG, &etag);
+    if let Some(modified) = modified {
+        response = response.header(LAST_MODIFIED, http_date(modified));
+    }
+    if let Some(content_encoding) = content_encoding {
+        response = response.header(CONTENT_ENCODING, content_encoding);
+    }
+
+    let build = |response: http::response::Builder, body| {
+        response
+            .body(body)
+            .wrap_err("failed to build response")
+            .map_err(|error| custom(InternalError(error)))
+    };
+    if is_not_modified(headers, &etag, modified) {
+        return build(response.status(StatusCode::NOT_MODIFIED), Body::empty());
+    }
+    let range = range
+        .filter(|_| if_range_matches(headers, &etag, modified))
+        .map_or(ByteRange::Full, |range| parse_byte_range(range, len));
+    let (start, length) = match range {
+        ByteRange::Full => (0, len),
+        ByteRange::Partial { start, end } => {
+            response = response
+                .status(StatusCode::PARTIAL_CONTENT)
+                .header(CONTENT_RANGE, format!("bytes {start}-{end}/{len}"));
+            (start, end - start + 1)
+        }
+        ByteRange::Unsatisfiable => {
+            let response = response
+                .status(StatusCode::RANGE_NOT_SATISFIABLE)
+                .header(CONTENT_RANGE, format!("bytes */{len}"));
+            return build(response, Body::empty());
+        }
+    };
+    let response = response.header(CONTENT_LENGTH, length);
+    if is_head {
+        return build(response, Body::empty());
+    }
+
+    let mut file = tokio::fs::File::open(&file_path)
+        .await
+        .wrap_err("failed to open file")
+        .map_err(InternalError)?;
+    file.seek(SeekFrom::Start(start))
+        .await
+        .wrap_err("failed to seek file")
+        .map_err(InternalError)?;
+
+    build(
+        response,
+        Body::wrap_stream(ReaderStream::new(file.take(length))),
+    )
+}
+
+fn content_type_for_path(path: &Path) -> &'static str {
+    let parent = path.parent().and_then(|parent| parent.file_name());
+    if parent.is_some_and(|parent| parent == ".well-known")
+        && path.file_name().is_some_and(|name| name == "webfinger")
+    {
+        return "application/jrd+json";
+    }
+    if path.extension().is_some_and(|x| x == "json")
+        && path.components().any(|c| c.as_os_str() == "activitypub")
+    {
+        return "application/activity+json";
+    }
+    let extension = path.extension().and_then(|x| x.to_str());
+    let extension = extension.map(|x| x.to_ascii_lowercase());
+    match extension.as_deref() {
+        Some("css") => "text/css; charset=utf-8",
+        Some("gif") => "image/gif",
+        Some("html") => HTML,
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("js") => "text/javascript; charset=utf-8",
+        Some("json") => "application/json",
+        Some("mp3") => "audio/mpeg",
+        Some("mp4") => "video/mp4",
+        Some("png") => "image/png",
+        Some("svg") => "image/svg+xml",
+        Some("webp") => "image/webp",
+        Some("woff2") => "font/woff2",
+        Some("xml") => "text/xml",
+        Some(other) => {
+            warn!("unknown file extension {other}; treating as application/octet-stream");
+            "application/octet-stream"
+        }
+        None => {
+            warn!("no file extension; treating as application/octet-stream");
+            "application/octet-stream"
+        }
+    }
+}
+
+fn header_str(headers: &HeaderMap, name: impl AsHeaderName) -> Option<&str> {
+    headers.get(name).and_then(|value| value.to_str().ok())
+}
+
+/// a strong entity tag based on the file size and modification time, which
+/// differs between precompressed variants.
+fn entity_tag(len: u64, modified: Option<DateTime<Utc>>, encoding: Option<&str>) -> String {
+    let modified = modified.map_or(0, |modified| modified.timestamp_micros());
+    let encoding = encoding.map_or(String::new(), |encoding| format!("-{encoding}"));
+
+    format!("\"{len:x}-{modified:x}{encoding}\"")
+}
+
+/// checks `If-None-Match`, or if absent, `If-Modified-Since`.
+fn is_not_modified(headers: &HeaderMap, etag: &str, modified: Option<DateTime<Utc>>) -> bool {
+    if let Some(if_none_match) = header_str(headers, IF_NONE_MATCH) {
+        // weak comparison, so ignore any W/ prefixes.
+        return if_none_match
+            .split(',')
+            .map(|tag| tag.trim())
+            .any(|tag| tag == "*" || tag.trim_start_matches("W/") == etag);
+    }
+    let if_modified_since = header_str(headers, IF_MODIFIED_SINCE).and_then(parse_http_date);
+    if let (Some(if_modified_since), Some(modified)) = (if_modified_since, modified) {
+        // http dates have a resolution of one second.
+        return modified.timestamp() <= if_modified_since.timestamp();
+    }
+
+    false
+}
+
+/// checks `If-Range`, which says to ignore `Range` if the file has changed.
+fn if_range_matches(headers: &HeaderMap, etag: &str, modified: Option<DateTime<Utc>>) -> bool {
+    let Some(if_range) = header_str(headers, IF_RANGE) else {
+        return true;
+    };
+    if if_range.starts_with('"') {
+        // strong comparison.
+        return if_range == etag;
+    }
+    match (parse_http_date(if_range), modified) {
+        (Some(if_range), Some(modified)) => modified.timestamp() == if_range.timestamp(),
+        _ => false,
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum ByteRange {
+    Full,
+    /// `end` is inclusive.
+    Partial {
+        start: u64,
+        end: u64,
+    },
+    Unsatisfiable,
+}
+
+/// parses a `Range` header with a single byte range. any other kind of range,
+/// including multiple byte ranges, is ignored.
+fn parse_byte_range(range: &str, len: u64) -> ByteRange {
+    let Some(range) = range.trim().strip_prefix("bytes=") else {
+        return ByteRange::Full;
+    };
+    let Some((start, end)) = range.split_once('-').filter(|_| !range.contains(',')) else {
+        return ByteRange::Full;
+    };
+    let (start, end) = (start.trim(), end.trim());
+    if start.is_empty() {
+        // suffix range, for the last `end` bytes.
+        let Ok(suffix_len) = end.parse::<u64>() else {
+            return ByteRange::Full;
+        };
+        if suffix_len == 0 || len == 0 {
+            return ByteRange::Unsatisfiable;
+        }
+        return ByteRange::Partial {
+            start: len.saturating_sub(suffix_len),
+            end: len - 1,
+        };
+    }
+    let Ok(start) = start.parse::<u64>() else {
+        return ByteRange::Full;
+    };
+    let end = if end.is_empty() {
+        None
+    } else if let Ok(end) = end.parse::<u64>() {
+        Some(end)
+    } else {
+        return ByteRange::Full;
+    };
+    if end.is_some_and(|end| end < start) {
+        return ByteRange::Full;
+    }
+    if start >= len {
+        return ByteRange::Unsatisfiable;
+    }
+
+    ByteRange::Partial {
+        start,
+        end: end.map_or(len - 1, |end| end.min(len - 1)),
+    }
+}
+
+#[test]
+fn test_parse_byte_range() {
+    use ByteRange::*;
+    assert_eq!(
+        parse_byte_range("bytes=0-4", 10),
+        Partial { start: 0, end: 4 }
+    );
+    assert_eq!(
+        parse_byte_range("bytes=5-", 10),
+        Partial { start: 5, end: 9 }
+    );
+    assert_eq!(
+        parse_byte_range("bytes=5-100", 10),
+        Partial { start: 5, end: 9 }
+    );
+    assert_eq!(
+        parse_byte_range("bytes=-3", 10),
+        Partial { start: 7, end: 9 }
+    );
+    assert_eq!(
+        parse_byte_range("bytes=-100", 10),
+        Partial { start: 0, end: 9 }
+    );
+    assert_eq!(parse_byte_range("bytes=10-", 10), Unsatisfiable);
+    assert_eq!(parse_byte_range("bytes=-0", 10), Unsatisfiable);
+    assert_eq!(parse_byte_range("bytes=0-1,3-4", 10), Full);
+    assert_eq!(parse_byte_range("bytes=4-3", 10), Full);
+    assert_eq!(parse_byte_range("items=0-4", 10), Full);
+}
+
+/// a content encoding that `autost render` can write files for, if `precompress` is set.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct PrecompressedEncoding {
+    name: &'static str,
+    suffix: &'static str,
+}
+
+/// in order of preference.
+const PRECOMPRESSED_ENCODINGS: [PrecompressedEncoding; 2] = [
+    PrecompressedEncoding {
+        name: "br",
+        suffix: ".br",
+    },
+    PrecompressedEncoding {
+        name: "gzip",
+        suffix: ".gz",
+    },
+];
+
+/// returns the precompressed encodings allowed by an `Accept-Encoding` header,
+/// in order of preference. we ignore the client’s preferences, other than q=0.
+fn accepted_precompressed_encodings(accept_encoding: &str) -> Vec<PrecompressedEncoding> {
+    let mut qvalues = BTreeMap::default();
+    for item in accept_encoding.split(',') {
+        let mut params = item.split(';');
+        let coding = params
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        if coding.is_empty() {
+            continue;
+        }
+        let qvalue = params
+            .filter_map(|param| param.trim().strip_prefix("q="))
+            .filter_map(|qvalue| qvalue.trim().parse::<f32>().ok())
+            .next()
+            .unwrap_or(1.0);
+        qvalues.insert(coding, qvalue);
+    }
+
+    PRECOMPRESSED_ENCODINGS
+        .into_iter()
+        .filter(|encoding| {
+            let qvalue = qvalues
+                .get(encoding.name)
+                .or_else(|| qvalues.get("*"))
+                .copied()
+                .unwrap_or(0.0);
+            qvalue > 0.0
+        })
+        .collect()
+}
+
+#[test]
+fn test_accepted_precompressed_encodings() {
+    let names = |accept_encoding| {
+        accepted_precompressed_encodings(accept_encoding)
+            .into_iter()
+            .map(|encoding| encoding.name)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(names(""), Vec::<&str>::new());
+    assert_eq!(names("gzip, deflate, br, zstd"), ["br", "gzip"]);
+    assert_eq!(names("GZIP;q=0.5"), ["gzip"]);
+    assert_eq!(names("br;q=0, *"), ["gzip"]);
+    assert_eq!(names("identity"), Vec::<&str>::new());
+}
+
+#[tokio::test]
+async fn test_static_files_route() -> eyre::Result<()> {
+    use std::{
+        env::temp_dir,
+        fs::{create_dir_all, remove_dir_all},
+        time::Duration,
+    };
+
+    use warp::hyper::body::to_bytes;
+
+    let dir = temp_dir().join(format!("autost-test-static-{}", uuid::Uuid::new_v4()));
+    let (site_root, attachments_root) = (dir.join("site"), dir.join("attachments"));
+    create_dir_all(site_root.join("tagged"))?;
+    create_dir_all(&attachments_root)?;
+    File::create(site_root.join("tagged/index.html"))?.write_all(b"0123456789")?;
+    File::create(attachments_root.join("cat.png"))?.write_all(b"meow")?;
+    let route = static_files_route(site_root.clone(), attachments_root.clone());
+    let request = |path: &str| warp::test::request().path(path);
+    async fn body(response: Response<Body>) -> eyre::Result<Vec<u8>> {
+        Ok(to_bytes(response.into_body()).await?.to_vec())
+    }
+
+    let response = request("/tagged/")
+        .filter(&route)
+        .await
+        .map_err(|error| eyre!("{error:?}"))?;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[CONTENT_TYPE], HTML);
+    assert_eq!(response.headers()[CONTENT_LENGTH], "10");
+    let etag = response.headers()[ETAG].clone();
+    let last_modified = response.headers()[LAST_MODIFIED].clone();
+    assert_eq!(body(response).await?, b"0123456789");
+
+    let response = request("/attachments/cat.png")
+        .filter(&route)
+        .await
+        .map_err(|error| eyre!("{error:?}"))?;
+    assert_eq!(response.headers()[CONTENT_TYPE], "image/png");
+    assert_eq!(body(response).await?, b"meow");
+    assert!(request("/nope.html").filter(&route).await.is_err());
+    assert!(request("/tagged/%2e%2e/x").filter(&route).await.is_err());
+
+    // HEAD has the same headers as GET, but no body.
+    let response = request("/tagged/index.html")
+        .method("HEAD")
+        .filter(&route)
+        .await
+        .map_err(|error| eyre!("{error:?}"))?;
+    assert_eq!(response.headers()[CONTENT_LENGTH], "10");
+    assert_eq!(body(response).await?, b"");
+
+    // single byte ranges.
+    let response = request("/tagged/index.html")
+        .header(RANGE, "bytes=2-4")
+        .filter(&route)
+        .await
+        .map_err(|error| eyre!("{error:?}"))?;
+    assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+    assert_eq!(response.headers()[CONTENT_RANGE], "bytes 2-4/10");
+    assert_eq!(response.headers()[CONTENT_LENGTH], "3");
+    assert_eq!(body(response).await?, b"234");
+    let response = request("/tagged/index.html")
+        .header(RANGE, "bytes=-2")
+        .filter(&route)
+        .await
+        .map_err(|error| eyre!("{error:?}"))?;
+    assert_eq!(body(response).await?, b"89");
+    let response = request("/tagged/index.html")
+        .header(RANGE, "bytes=10-")
+        .filter(&route)
+        .await
+        .map_err(|error| eyre!("{error:?}"))?;
+    assert_eq!(response.status(), StatusCode::RANGE_NOT_SATISFIABLE);
+    assert_eq!(response.headers()[CONTENT_RANGE], "bytes */10");
+    let response = request("/tagged/index.html")
+        .header(RANGE, "bytes=2-4")
+        .header(IF_RANGE, "\"stale\"")
+        .filter(&route)
+        .await
+        .map_err(|error| eyre!("{error:?}"))?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // conditional requests.
+    let response = request("/tagged/index.html")
+        .header(IF_NONE_MATCH, etag.clone())
+        .filter(&route)
+        .await
+        .map_err(|error| eyre!("{error:?}"))?;
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(body(response).await?, b"");
+    let response = request("/tagged/index.html")
+        .header(IF_MODIFIED_SINCE, last_modified)
+        .filter(&route)
+        .await
+        .map_err(|error| eyre!("{error:?}"))?;
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    let response = request("/tagged/index.html")
+        .header(IF_NONE_MATCH, "\"stale\"")
+        .filter(&route)
+        .await
+        .map_err(|error| eyre!("{error:?}"))?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // fresh precompressed variants are served, except for byte ranges.
+    std::thread::sleep(Duration::from_millis(10));
+    File::create(site_root.join("tagged/index.html.gz"))?.write_all(b"gzipped")?;
+    let response = request("/tagged/index.html")
+        .header(ACCEPT_ENCODING, "gzip")
+        .filter(&route)
+        .await
+        .map_err(|error| eyre!("{error:?}"))?;
+    assert_eq!(response.headers()[CONTENT_ENCODING], "gzip");
+    assert_ne!(response.headers()[ETAG], etag);
+    assert_eq!(body(response).await?, b"gzipped");
+    let response = request("/tagged/index.html")
+        .header(ACCEPT_ENCODING, "gzip")
+        .header(RANGE, "bytes=0-0")
+        .filter(&route)
+        .await
+        .map_err(|error| eyre!("{error:?}"))?;
+    assert!(response.headers().get(CONTENT_ENCODING).is_none());
+    assert_eq!(body(response).await?, b"0");
+
+    remove_dir_all(dir)?;
 
     Ok(())
 }
@@ -295,14 +2146,96 @@ impl Reject for BadRequest {}
 struct NotFound(String);
 impl Reject for NotFound {}
 
+#[derive(Debug)]
+struct Unauthorized {
+    /// for GET requests, the url to return to after logging in.
+    next: Option<String>,
+}
+impl Reject for Unauthorized {}
+
+#[derive(Debug)]
+struct Forbidden(eyre::Report);
+impl Reject for Forbidden {}
+
 #[derive(Template)]
 #[template(path = "compose.html")]
 struct ComposeTemplate {
     source: String,
 }
 
-async fn recover(error: Rejection) -> Result<impl Reply, std::convert::Infallible> {
-    Ok(if let Some(error) = error.find::<BadRequest>() {
+#[derive(Template)]
+#[template(path = "login.html")]
+struct LoginTemplate {
+    next: String,
+    failed: bool,
+}
+
+impl LoginTemplate {
+    /// only allows returning to urls on this site, defaulting to /compose.
+    fn new(next: &str, failed: bool) -> Self {
+        let next = if is_login_next_on_site(next) {
+            next.to_owned()
+        } else {
+            format!("{}compose", SETTINGS.base_url)
+        };
+
+        Self { next, failed }
+    }
+}
+
+/// returns true if `next` is a relative reference to a page under `base_url`, with no scheme
+/// or authority. browsers treat `\` like `/`, so `/\example.net` would leave the site.
+fn is_login_next_on_site(next: &str) -> bool {
+    if next.contains('\\')
+        || next.to_ascii_lowercase().contains("%5c")
+        || next.chars().any(|c| c.is_control())
+        || next.starts_with("//")
+        || Url::parse(next).is_ok()
+        || !next.starts_with(&SETTINGS.base_url)
+    {
+        return false;
+    }
+    let base = Url::parse("http://autost.invalid/").expect("guaranteed by argument");
+    let Ok(url) = base.join(next) else {
+        return false;
+    };
+
+    url.host_str() == Some("autost.invalid") && url.path().starts_with(&SETTINGS.base_url)
+}
+
+#[test]
+fn test_is_login_next_on_site() {
+    // example settings have base_url "/".
+    assert!(is_login_next_on_site("/compose"));
+    assert!(is_login_next_on_site("/compose?reply_to=1.html"));
+    assert!(!is_login_next_on_site("//example.net/"));
+    assert!(!is_login_next_on_site("/\\example.net/"));
+    assert!(!is_login_next_on_site("/%5Cexample.net/"));
+    assert!(!is_login_next_on_site("/%5cexample.net/"));
+    assert!(!is_login_next_on_site("https://example.net/"));
+    assert!(!is_login_next_on_site("javascript:alert(1)"));
+    assert!(!is_login_next_on_site("/\t/example.net/"));
+    assert!(!is_login_next_on_site("compose"));
+    assert!(!is_login_next_on_site(""));
+    assert_eq!(
+        LoginTemplate::new("/\\example.net/", false).next,
+        "/compose"
+    );
+}
+
+async fn recover(error: Rejection) -> Result<Box<dyn Reply>, std::convert::Infallible> {
+    if let Some(Unauthorized { next: Some(next) }) = error.find::<Unauthorized>() {
+        info!("Unauthorized: responding with http 401 and login page");
+        match LoginTemplate::new(next, false).render() {
+            Ok(result) => {
+                let result = reply::with_status(result, StatusCode::UNAUTHORIZED);
+                return Ok(Box::new(reply::with_header(result, "Content-Type", HTML)));
+            }
+            Err(error) => error!(?error, "failed to render login page"),
+        }
+    }
+
+    Ok(Box::new(if let Some(error) = error.find::<BadRequest>() {
         error!(
             ?error,
             "BadRequest: responding with http 400 bad request: {}", error.0,
@@ -317,6 +2250,18 @@ async fn recover(error: Rejection) -> Result<impl Reply, std::convert::Infallibl
             "NotFound: responding with http 404 not found: {}", error.0,
         );
         reply::with_status(format!("not found: {:?}", error.0), StatusCode::NOT_FOUND)
+    } else if error.find::<Unauthorized>().is_some() {
+        info!("Unauthorized: responding with http 401 unauthorized");
+        reply::with_status(
+            "unauthorized: log in again".to_owned(),
+            StatusCode::UNAUTHORIZED,
+        )
+    } else if let Some(error) = error.find::<Forbidden>() {
+        error!(
+            ?error,
+            "Forbidden: responding with http 403 forbidden: {}", error.0,
+        );
+        reply::with_status(format!("forbidden: {:?}", error.0), StatusCode::FORBIDDEN)
     } else if let Some(error) = error.find::<InternalError>() {
         error!(
             ?error,
@@ -335,5 +2280,5 @@ async fn recover(error: Rejection) -> Result<impl Reply, std::convert::Infallibl
             format!("unknown error: {error:?}"),
             StatusCode::INTERNAL_SERVER_ERROR,
         )
-    })
+    }))
 }
@@ -0,0 +1,147 @@
+use std::collections::BTreeSet;
+
+use jane_eyre::eyre::{self, bail};
+use tracing::{info, warn};
+
+use crate::{
+    migrations::run_migrations,
+    path::PostsPath,
+    webmention::{self, RealWebmentionFetcher, WebmentionStatus, WebmentionStore},
+};
+
+#[derive(clap::Args, Debug)]
+pub struct Webmention {
+    #[command(subcommand)]
+    command: WebmentionCommand,
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum WebmentionCommand {
+    /// list received webmentions (pending only, by default).
+    List {
+        #[arg(long, help = "also list approved and rejected webmentions")]
+        all: bool,
+    },
+    /// show webmentions from this source on your posts.
+    Approve {
+        source: String,
+        #[arg(help = "only approve the webmention of this target")]
+        target: Option<String>,
+    },
+    /// hide webmentions from this source on your posts.
+    Reject {
+        source: String,
+        #[arg(help = "only reject the webmention of this target")]
+        target: Option<String>,
+    },
+    /// send webmentions for the links in your new, changed, and deleted posts.
+    Send {
+        #[arg(
+            short = 'n',
+            long,
+            help = "show what would be sent, without sending anything"
+        )]
+        dry_run: bool,
+        #[arg(help = "only send for these posts, like posts/10000000.md")]
+        specific_post_paths: Vec<String>,
+    },
+}
+
+pub async fn main(args: Webmention) -> eyre::Result<()> {
+    let store = WebmentionStore::default();
+    let (source, target, status) = match args.command {
+        WebmentionCommand::List { all } => {
+            for (page, mentions) in store.load_all()? {
+                for mention in mentions {
+                    if !all && mention.status != WebmentionStatus::Pending {
+                        continue;
+                    }
+                    let author = mention
+                        .author
+                        .and_then(|author| author.name.or(author.url))
+                        .unwrap_or_else(|| "someone".to_owned());
+                    println!(
+                        "{page}\t{:?}\t{author} {}\t{}",
+                        mention.status,
+                        mention.kind.verb(),
+                        mention.source,
+                    );
+                }
+            }
+            return Ok(());
+        }
+        WebmentionCommand::Approve { source, target } => {
+            (source, target, WebmentionStatus::Approved)
+        }
+        WebmentionCommand::Reject { source, target } => {
+            (source, target, WebmentionStatus::Rejected)
+        }
+        WebmentionCommand::Send {
+            dry_run,
+            specific_post_paths,
+        } => {
+            let specific_post_paths = specific_post_paths
+                .into_iter()
+                .map(|path| PostsPath::from_site_root_relative_path(&path))
+                .collect::<eyre::Result<Vec<_>>>()?;
+            // reqwest::blocking can’t be used on an async runtime thread.
+            let count =
+                tokio::task::spawn_blocking(move || send(&store, specific_post_paths, dry_run))
+                    .await??;
+            if dry_run {
+                info!("would send {count} webmentions");
+            } else {
+                info!("sent {count} webmentions");
+            }
+            return Ok(());
+        }
+    };
+
+    let count = store.set_status(&source, target.as_deref(), status)?;
+    if count == 0 {
+        bail!("no webmentions found from {source}");
+    }
+    info!("marked {count} webmentions as {status:?}");
+    info!("run `autost render` to update your pages");
+
+    Ok(())
+}
+
+fn send(
+    store: &WebmentionStore,
+    specific_post_paths: Vec<PostsPath>,
+    dry_run: bool,
+) -> eyre::Result<usize> {
+    run_migrations()?;
+
+    let mut result = 0;
+    let mut post_paths = specific_post_paths;
+    if post_paths.is_empty() {
+        post_paths = PostsPath::list_posts()?;
+        // pages we sent webmentions for, but whose posts have since been deleted.
+        let pages = post_paths
+            .iter()
+            .map(|path| path.rendered_path())
+            .collect::<eyre::Result<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .map(|path| path.base_relative_url())
+            .collect::<BTreeSet<_>>();
+        for page in store.sent_pages()? {
+            if !pages.contains(&page) {
+                match webmention::send(&RealWebmentionFetcher, store, &page, None, dry_run) {
+                    Ok(count) => result += count,
+                    Err(error) => warn!(?error, "failed to send webmentions for {page}"),
+                }
+            }
+        }
+    }
+    for path in post_paths {
+        match webmention::send_for_post(&RealWebmentionFetcher, store, &path, dry_run) {
+            Ok(count) => result += count,
+            Err(error) => warn!(?error, "failed to send webmentions for {path:?}"),
+        }
+    }
+
+    Ok(result)
+}
@@ -50,7 +50,8 @@ fn parse<'i>(parser: &'i mut Parser) -> Vec<InlineStyleToken> {
             Token::Function(name) => Some(&**name),
             _ => None,
         };
-        if function_name == Some("url") {
+        // css function names are case-insensitive, so `URL("…")` is a url too.
+        if function_name.is_some_and(|name| name.eq_ignore_ascii_case("url")) {
             assert!(matches!(token, Token::Function(..)));
             let nested_result = parser
                 .parse_nested_block(|p| Ok::<_, ParseError<()>>(parse(p)))
@@ -0,0 +1,557 @@
+use std::borrow::Cow;
+
+use askama::Template;
+use jane_eyre::eyre::{self, bail, Context};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Number, Value};
+use yaml_rust2::{yaml::Hash, Yaml, YamlEmitter, YamlLoader};
+
+use crate::{
+    meta::extract_metadata, micropub::split_post_source, path::PostsPath, Author, PostMeta,
+};
+
+/// how the metadata of a markdown post is written.
+#[derive(Clone, Copy, Debug, PartialEq, clap::ValueEnum)]
+pub enum FrontMatterStyle {
+    /// `<meta>` and `<link>` elements.
+    Html,
+    /// a toml block between `+++` lines.
+    Toml,
+    /// a yaml block between `---` lines.
+    Yaml,
+}
+
+/// toml or yaml front matter, which maps onto [`PostMeta`].
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct FrontMatter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub published: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// same as `<link rel="references" href>`, like `123.md` or `imported/1.html`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<String>,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub is_transparent_share: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub toc: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cws: Vec<String>,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub adult_content: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<FrontMatterAuthor>,
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct FrontMatterAuthor {
+    #[serde(default)]
+    pub href: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub display_name: String,
+    #[serde(default)]
+    pub display_handle: String,
+    /// same as `<meta name="author_privacy">`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub privacy: Option<String>,
+    /// same as `<meta name="author_logged_out_post_visibility">`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logged_out_post_visibility: Option<String>,
+}
+
+/// top-level keys of [`FrontMatter`].
+const FRONT_MATTER_KEYS: &[&str] = &[
+    "title",
+    "published",
+    "tags",
+    "references",
+    "archived",
+    "is_transparent_share",
+    "toc",
+    "slug",
+    "cws",
+    "adult_content",
+    "author",
+];
+
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
+impl FrontMatterStyle {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Html => "html",
+            Self::Toml => "toml",
+            Self::Yaml => "yaml",
+        }
+    }
+
+    fn delimiter(self) -> Option<&'static str> {
+        match self {
+            Self::Html => None,
+            Self::Toml => Some("+++"),
+            Self::Yaml => Some("---"),
+        }
+    }
+}
+
+/// splits a markdown post into its style, its front matter, and its body.
+///
+/// for toml and yaml, the front matter excludes the delimiters. for html, it’s the leading
+/// `<meta>` and `<link>` lines, which may be empty.
+///
+/// `---` is also a thematic break, so it only starts yaml front matter if the block is a mapping
+/// with at least one known key, or if its first line starts with a known key (so that mistakes
+/// are errors). otherwise the post has html front matter, like any other post.
+pub fn split_front_matter(source: &str) -> eyre::Result<(FrontMatterStyle, &str, &str)> {
+    'style: for style in [FrontMatterStyle::Toml, FrontMatterStyle::Yaml] {
+        let delimiter = style.delimiter().expect("guaranteed by style");
+        let mut lines = source.split_inclusive('\n');
+        if lines.next().map(|line| line.trim_end()) != Some(delimiter) {
+            continue;
+        }
+        let start = source.find('\n').expect("guaranteed by split_inclusive") + 1;
+        let mut end = start;
+        for line in lines {
+            if line.trim_end() == delimiter {
+                let front_matter = &source[start..end];
+                if style == FrontMatterStyle::Yaml && !is_yaml_front_matter(front_matter) {
+                    continue 'style;
+                }
+                // like html front matter, skip any blank lines before the body.
+                let mut body = &source[end + line.len()..];
+                while let Some(line) = body.split_inclusive('\n').next() {
+                    if !line.trim().is_empty() {
+                        break;
+                    }
+                    body = &body[line.len()..];
+                }
+                return Ok((style, front_matter, body));
+            }
+            end += line.len();
+        }
+        if style == FrontMatterStyle::Yaml {
+            continue;
+        }
+        bail!(
+            "unterminated {} front matter: missing closing `{delimiter}`",
+            style.name()
+        );
+    }
+
+    let (meta, body) = split_post_source(source);
+
+    Ok((FrontMatterStyle::Html, meta, body))
+}
+
+fn is_yaml_front_matter(front_matter: &str) -> bool {
+    let first_line = front_matter.lines().find(|line| !line.trim().is_empty());
+    if first_line.is_some_and(|line| {
+        line.split_once(':')
+            .is_some_and(|(key, _)| FRONT_MATTER_KEYS.contains(&key.trim_end()))
+    }) {
+        return true;
+    }
+    let Ok(docs) = YamlLoader::load_from_str(front_matter) else {
+        return false;
+    };
+    let Some(Yaml::Hash(hash)) = docs.first() else {
+        return false;
+    };
+
+    hash.keys().any(|key| {
+        key.as_str()
+            .is_some_and(|key| FRONT_MATTER_KEYS.contains(&key))
+    })
+}
+
+/// replaces any toml or yaml front matter in a markdown post with the equivalent html.
+pub fn front_matter_to_html(source: &str) -> eyre::Result<Cow<str>> {
+    match split_front_matter(source)? {
+        (FrontMatterStyle::Html, _, _) => Ok(Cow::Borrowed(source)),
+        (style, front_matter, body) => {
+            let meta = FrontMatter::parse(style, front_matter)?.to_post_meta()?;
+            Ok(Cow::Owned(post_source(
+                FrontMatterStyle::Html,
+                &meta,
+                body,
+            )?))
+        }
+    }
+}
+
+/// reads the metadata of a post from its front matter, without rendering the post.
+pub fn front_matter_meta(path: &PostsPath, source: &str) -> eyre::Result<PostMeta> {
+    let (style, front_matter) = if path.is_markdown_post() {
+        let (style, front_matter, _) = split_front_matter(source)?;
+        (style, front_matter)
+    } else {
+        (FrontMatterStyle::Html, split_post_source(source).0)
+    };
+    match style {
+        FrontMatterStyle::Html => Ok(extract_metadata(front_matter)?.meta),
+        style => FrontMatter::parse(style, front_matter)?.to_post_meta(),
+    }
+}
+
+/// writes a markdown post with the given metadata in the given style.
+pub fn post_source(style: FrontMatterStyle, meta: &PostMeta, body: &str) -> eyre::Result<String> {
+    let front_matter = FrontMatter::from_post_meta(meta);
+    Ok(match style {
+        FrontMatterStyle::Html => format!("{}\n\n{body}", meta.render()?),
+        FrontMatterStyle::Toml => format!("+++\n{}+++\n\n{body}", toml::to_string(&front_matter)?),
+        FrontMatterStyle::Yaml => {
+            let mut result = String::default();
+            YamlEmitter::new(&mut result).dump(&front_matter.to_yaml())?;
+            // the emitter writes the opening `---`, but not the closing one.
+            format!("{result}\n---\n\n{body}")
+        }
+    })
+}
+
+impl FrontMatter {
+    pub fn parse(style: FrontMatterStyle, front_matter: &str) -> eyre::Result<Self> {
+        let value = parse_front_matter_value(style, front_matter)?;
+
+        serde_json::from_value(value).wrap_err_with(|| format!("bad {} front matter", style.name()))
+    }
+
+    pub fn from_post_meta(meta: &PostMeta) -> Self {
+        Self {
+            title: meta.title.clone(),
+            published: meta.published.clone(),
+            tags: meta.tags.clone(),
+            references: meta.references.iter().map(|p| p.references_url()).collect(),
+            archived: meta.archived.clone(),
+            is_transparent_share: meta.is_transparent_share,
+            toc: meta.toc,
+            slug: meta.slug.clone(),
+            cws: meta.cws.clone(),
+            adult_content: meta.adult_content,
+            author: meta.author.as_ref().map(|author| FrontMatterAuthor {
+                href: author.href.clone(),
+                name: author.name.clone(),
+                display_name: author.display_name.clone(),
+                display_handle: author.display_handle.clone(),
+                privacy: meta.author_privacy.clone(),
+                logged_out_post_visibility: meta.author_logged_out_post_visibility.clone(),
+            }),
+        }
+    }
+
+    pub fn to_post_meta(&self) -> eyre::Result<PostMeta> {
+        Ok(PostMeta {
+            archived: self.archived.clone(),
+            references: self
+                .references
+                .iter()
+                .map(|url| PostsPath::from_references_url(url))
+                .collect::<eyre::Result<_>>()?,
+            title: self.title.clone(),
+            published: self.published.clone(),
+            author: self.author.as_ref().map(|author| Author {
+                href: author.href.clone(),
+                name: author.name.clone(),
+                display_name: author.display_name.clone(),
+                display_handle: author.display_handle.clone(),
+            }),
+            author_privacy: self
+                .author
+                .as_ref()
+                .and_then(|author| author.privacy.clone()),
+            author_logged_out_post_visibility: self
+                .author
+                .as_ref()
+                .and_then(|author| author.logged_out_post_visibility.clone()),
+            tags: self.tags.clone(),
+            is_transparent_share: self.is_transparent_share,
+            toc: self.toc,
+            slug: self.slug.clone(),
+            cws: self.cws.clone(),
+            adult_content: self.adult_content,
+        })
+    }
+
+    fn to_yaml(&self) -> Yaml {
+        fn string(value: &str) -> Yaml {
+            Yaml::String(value.to_owned())
+        }
+        fn strings(values: &[String]) -> Yaml {
+            Yaml::Array(values.iter().map(|value| string(value)).collect())
+        }
+        let mut result = Hash::default();
+        let mut insert = |key: &str, value: Yaml| result.insert(string(key), value);
+        if let Some(title) = &self.title {
+            insert("title", string(title));
+        }
+        if let Some(published) = &self.published {
+            insert("published", string(published));
+        }
+        if !self.tags.is_empty() {
+            insert("tags", strings(&self.tags));
+        }
+        if !self.references.is_empty() {
+            insert("references", strings(&self.references));
+        }
+        if let Some(archived) = &self.archived {
+            insert("archived", string(archived));
+        }
+        if self.is_transparent_share {
+            insert("is_transparent_share", Yaml::Boolean(true));
+        }
+        if self.toc {
+            insert("toc", Yaml::Boolean(true));
+        }
+        if let Some(slug) = &self.slug {
+            insert("slug", string(slug));
+        }
+        if !self.cws.is_empty() {
+            insert("cws", strings(&self.cws));
+        }
+        if self.adult_content {
+            insert("adult_content", Yaml::Boolean(true));
+        }
+        if let Some(author) = &self.author {
+            let mut hash = Hash::default();
+            hash.insert(string("href"), string(&author.href));
+            hash.insert(string("name"), string(&author.name));
+            hash.insert(string("display_name"), string(&author.display_name));
+            hash.insert(string("display_handle"), string(&author.display_handle));
+            if let Some(privacy) = &author.privacy {
+                hash.insert(string("privacy"), string(privacy));
+            }
+            if let Some(visibility) = &author.logged_out_post_visibility {
+                hash.insert(string("logged_out_post_visibility"), string(visibility));
+            }
+            insert("author", Yaml::Hash(hash));
+        }
+
+        Yaml::Hash(result)
+    }
+}
+
+/// parses toml or yaml front matter into a json value, without checking its keys.
+pub fn parse_front_matter_value(
+    style: FrontMatterStyle,
+    front_matter: &str,
+) -> eyre::Result<Value> {
+    Ok(match style {
+        FrontMatterStyle::Html => bail!("html front matter is handled by extract_metadata"),
+        FrontMatterStyle::Toml => toml_to_json(toml::Value::Table(
+            toml::from_str(front_matter).wrap_err("failed to parse toml front matter")?,
+        )),
+        FrontMatterStyle::Yaml => {
+            let docs = YamlLoader::load_from_str(front_matter)
+                .wrap_err("failed to parse yaml front matter")?;
+            match docs.into_iter().next() {
+                Some(doc) => yaml_to_json(doc)?,
+                None => Value::Object(Map::default()),
+            }
+        }
+    })
+}
+
+fn toml_to_json(value: toml::Value) -> Value {
+    match value {
+        toml::Value::String(value) => Value::String(value),
+        toml::Value::Integer(value) => Value::Number(value.into()),
+        toml::Value::Float(value) => Number::from_f64(value).map_or(Value::Null, Value::Number),
+        toml::Value::Boolean(value) => Value::Bool(value),
+        // keep unquoted dates and times as strings, like `<meta name="published" content>`.
+        toml::Value::Datetime(value) => Value::String(value.to_string()),
+        toml::Value::Array(values) => Value::Array(values.into_iter().map(toml_to_json).collect()),
+        toml::Value::Table(table) => Value::Object(
+            table
+                .into_iter()
+                .map(|(key, value)| (key, toml_to_json(value)))
+                .collect(),
+        ),
+    }
+}
+
+fn yaml_to_json(value: Yaml) -> eyre::Result<Value> {
+    Ok(match value {
+        Yaml::String(value) => Value::String(value),
+        Yaml::Integer(value) => Value::Number(value.into()),
+        Yaml::Real(value) => value
+            .parse()
+            .ok()
+            .and_then(Number::from_f64)
+            .map_or(Value::Null, Value::Number),
+        Yaml::Boolean(value) => Value::Bool(value),
+        Yaml::Null => Value::Null,
+        Yaml::Array(values) => Value::Array(
+            values
+                .into_iter()
+                .map(yaml_to_json)
+                .collect::<eyre::Result<_>>()?,
+        ),
+        Yaml::Hash(hash) => {
+            let mut result = Map::default();
+            for (key, value) in hash {
+                let Yaml::String(key) = key else {
+                    bail!("yaml front matter keys must be strings, but got {key:?}");
+                };
+                result.insert(key, yaml_to_json(value)?);
+            }
+            Value::Object(result)
+        }
+        Yaml::Alias(_) | Yaml::BadValue => bail!("unsupported value in yaml front matter"),
+    })
+}
+
+#[test]
+fn test_split_front_matter() -> eyre::Result<()> {
+    assert_eq!(
+        split_front_matter("+++\ntitle = \"foo\"\n+++\nbody\n")?,
+        (FrontMatterStyle::Toml, "title = \"foo\"\n", "body\n")
+    );
+    assert_eq!(
+        split_front_matter("---\r\ntitle: foo\r\n---\r\n\r\nbody")?,
+        (FrontMatterStyle::Yaml, "title: foo\r\n", "body")
+    );
+    assert_eq!(
+        split_front_matter("<meta name=\"title\" content=\"foo\">\n\nbody")?,
+        (
+            FrontMatterStyle::Html,
+            "<meta name=\"title\" content=\"foo\">\n\n",
+            "body"
+        )
+    );
+    // a thematic break later in the post is not front matter.
+    assert_eq!(
+        split_front_matter("body\n---\n")?,
+        (FrontMatterStyle::Html, "", "body\n---\n")
+    );
+    assert!(split_front_matter("+++\ntitle = \"foo\"\n").is_err());
+
+    // a thematic break at the start of the post is only yaml if it has known keys.
+    for source in [
+        "---\nbody\n---\n",
+        "---\nnote: body\n---\n",
+        "---\n- body\n---\n",
+        "---\n\n---\n",
+        "---\nbody\n",
+    ] {
+        assert_eq!(
+            split_front_matter(source)?,
+            (FrontMatterStyle::Html, "", source),
+            "{source:?}"
+        );
+    }
+    // but once it is, unknown keys and bad yaml are still errors.
+    let (style, front_matter, _) = split_front_matter("---\ntitle: foo\ntitel: bar\n---\n")?;
+    assert_eq!(style, FrontMatterStyle::Yaml);
+    assert!(FrontMatter::parse(style, front_matter).is_err());
+    let (style, front_matter, _) = split_front_matter("---\ntitle: foo\ntags: [bar\n---\n")?;
+    assert_eq!(style, FrontMatterStyle::Yaml);
+    assert!(FrontMatter::parse(style, front_matter).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_front_matter() -> eyre::Result<()> {
+    let meta = PostMeta {
+        archived: Some("https://example.com/foo".to_owned()),
+        references: vec![PostsPath::from_references_url("1.md")?],
+        title: Some("foo".to_owned()),
+        published: Some("2024-10-01T00:00:00Z".to_owned()),
+        author: Some(Author {
+            href: "https://example.com".to_owned(),
+            name: "a".to_owned(),
+            display_name: "b".to_owned(),
+            display_handle: "example.com".to_owned(),
+        }),
+        author_privacy: Some("private".to_owned()),
+        author_logged_out_post_visibility: Some("none".to_owned()),
+        tags: vec!["bar".to_owned(), "baz".to_owned()],
+        is_transparent_share: true,
+        toc: true,
+        slug: Some("foo-bar".to_owned()),
+        cws: vec!["qux".to_owned()],
+        adult_content: true,
+    };
+    let toml = r#"
+        title = "foo"
+        published = 2024-10-01T00:00:00Z
+        tags = ["bar", "baz"]
+        references = ["1.md"]
+        archived = "https://example.com/foo"
+        is_transparent_share = true
+        toc = true
+        slug = "foo-bar"
+        cws = ["qux"]
+        adult_content = true
+        author = { href = "https://example.com", name = "a", display_name = "b", display_handle = "example.com", privacy = "private", logged_out_post_visibility = "none" }
+    "#;
+    let yaml = r#"
+        title: foo
+        published: 2024-10-01T00:00:00Z
+        tags: [bar, baz]
+        references:
+          - 1.md
+        archived: https://example.com/foo
+        is_transparent_share: true
+        toc: true
+        slug: foo-bar
+        cws: [qux]
+        adult_content: true
+        author:
+          href: https://example.com
+          name: a
+          display_name: b
+          display_handle: example.com
+          privacy: private
+          logged_out_post_visibility: none
+    "#;
+    assert_eq!(
+        FrontMatter::parse(FrontMatterStyle::Toml, toml)?.to_post_meta()?,
+        meta
+    );
+    assert_eq!(
+        FrontMatter::parse(FrontMatterStyle::Yaml, yaml)?.to_post_meta()?,
+        meta
+    );
+    assert_eq!(
+        FrontMatter::parse(FrontMatterStyle::Yaml, "")?,
+        FrontMatter::default()
+    );
+
+    // unknown keys are errors.
+    assert!(FrontMatter::parse(FrontMatterStyle::Toml, "titel = \"foo\"").is_err());
+    assert!(FrontMatter::parse(FrontMatterStyle::Yaml, "titel: foo").is_err());
+    assert!(FrontMatter::parse(FrontMatterStyle::Yaml, "author: {nmae: a}").is_err());
+
+    // every style round-trips through post_source.
+    for style in [
+        FrontMatterStyle::Html,
+        FrontMatterStyle::Toml,
+        FrontMatterStyle::Yaml,
+    ] {
+        let source = post_source(style, &meta, "post *body*\n")?;
+        let html = front_matter_to_html(&source)?;
+        let post = crate::TemplatedPost::filter(&crate::render_markdown(&html), None)?;
+        assert_eq!(post.meta, meta, "{style:?}");
+        assert_eq!(
+            post.safe_html.trim(),
+            "<p>post <em>body</em></p>",
+            "{style:?}"
+        );
+        let (actual_style, _, body) = split_front_matter(&source)?;
+        assert_eq!((actual_style, body), (style, "post *body*\n"));
+    }
+
+    Ok(())
+}
@@ -1,4 +1,10 @@
-use std::{cmp::Ordering, collections::BTreeSet, fs::File, io::Read, sync::LazyLock};
+use std::{
+    cmp::Ordering,
+    collections::{BTreeSet, HashMap},
+    fs::File,
+    io::Read,
+    sync::{Arc, LazyLock, RwLock},
+};
 
 use askama::Template;
 use jane_eyre::eyre::{self, Context, OptionExt};
@@ -7,32 +13,55 @@ use serde::Deserialize;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
 use crate::{
+    anchors::{process_anchors, ID_PREFIX},
     dom::serialize_html_fragment,
+    front_matter::front_matter_to_html,
+    markdown::{render_cohost_markdown, CohostMarkdownOptions, MarkdownOptions},
     meta::extract_metadata,
     path::{PostsPath, SitePath},
+    sanitise::PostSource,
     settings::Settings,
 };
 
 pub mod command {
+    pub mod activitypub;
     pub mod attach;
+    pub mod check;
     pub mod cohost2autost;
     pub mod cohost2json;
     pub mod cohost_archive;
+    pub mod delete;
+    pub mod deploy;
+    pub mod front_matter;
     pub mod import;
     pub mod new;
     pub mod render;
     pub mod server;
+    pub mod webmention;
 }
 
+pub mod activitypub;
+pub mod anchors;
 pub mod attachments;
+pub mod check;
 pub mod cohost;
 pub mod css;
 pub mod dom;
+pub mod front_matter;
+pub mod markdown;
 pub mod meta;
+pub mod micropub;
 pub mod migrations;
+pub mod net;
 pub mod output;
 pub mod path;
+pub mod permalink;
+pub mod redirect;
+pub mod sanitise;
+pub mod search;
 pub mod settings;
+pub mod tombstone;
+pub mod webmention;
 
 pub static SETTINGS: LazyLock<Settings> = LazyLock::new(|| {
     #[cfg(test)]
@@ -52,8 +81,18 @@ pub struct PostMeta {
     pub title: Option<String>,
     pub published: Option<String>,
     pub author: Option<Author>,
+    /// `privacy` of the author’s cohost project, like `public` or `private`.
+    pub author_privacy: Option<String>,
+    /// `loggedOutPostVisibility` of the author’s cohost project, like `public` or `none`.
+    pub author_logged_out_post_visibility: Option<String>,
     pub tags: Vec<String>,
     pub is_transparent_share: bool,
+    pub toc: bool,
+    pub slug: Option<String>,
+    /// content warnings, which collapse the post until the reader opens it.
+    pub cws: Vec<String>,
+    /// whether the post is 18+, which also collapses the post.
+    pub adult_content: bool,
 }
 
 #[derive(Clone, Debug, Deserialize, PartialEq)]
@@ -75,7 +114,7 @@ pub struct ExtractedPost {
 #[derive(Clone, Debug)]
 pub struct Thread {
     pub path: Option<PostsPath>,
-    pub posts: Vec<TemplatedPost>,
+    pub posts: Vec<Arc<TemplatedPost>>,
     pub meta: PostMeta,
     pub needs_attachments: BTreeSet<SitePath>,
     pub og_image: Option<String>,
@@ -85,6 +124,8 @@ pub struct Thread {
 #[derive(Clone, Debug)]
 pub struct TemplatedPost {
     pub path: Option<PostsPath>,
+    /// the page this post is rendered to, if it has a path.
+    pub rendered_path: Option<SitePath>,
     pub meta: PostMeta,
     pub original_html: String,
     pub safe_html: String,
@@ -93,6 +134,17 @@ pub struct TemplatedPost {
     pub og_description: String,
 }
 
+/// memo of loaded posts, keyed by path, that can be shared between threads.
+///
+/// the ancestors of long threads get shared many times, so without this, every descendant thread
+/// would need to parse, render, and filter the same ancestor posts all over again.
+#[derive(Debug, Default)]
+pub struct TemplatedPostCache {
+    inner: RwLock<HashMap<PostsPath, Arc<TemplatedPost>>>,
+    /// sources that were already read, so they don’t need to be read again.
+    sources: HashMap<PostsPath, String>,
+}
+
 impl PostMeta {
     pub fn is_main_self_author(&self, settings: &Settings) -> bool {
         self.author
@@ -110,6 +162,33 @@ impl PostMeta {
             .as_ref()
             .map_or(no_self_authors, |a| settings.is_any_self_author(a))
     }
+
+    /// returns true iff the author’s cohost project was private, or only visible when logged in.
+    pub fn is_author_private(&self) -> bool {
+        self.author_privacy
+            .as_deref()
+            .is_some_and(|p| p != "public")
+            || self
+                .author_logged_out_post_visibility
+                .as_deref()
+                .is_some_and(|v| v != "public")
+    }
+
+    /// content warnings to show before the post, starting with “18+” if it’s adult content.
+    pub fn content_warnings(&self) -> Vec<&str> {
+        self.adult_content
+            .then_some("18+")
+            .into_iter()
+            .chain(self.cws.iter().map(|cw| cw.as_str()))
+            .collect()
+    }
+
+    /// a summary that warns about the content of the post, if it has any content warnings.
+    pub fn content_warning_summary(&self) -> Option<String> {
+        let content_warnings = self.content_warnings();
+        (!content_warnings.is_empty())
+            .then(|| format!("content warning: {}", content_warnings.join(", ")))
+    }
 }
 
 #[test]
@@ -160,35 +239,28 @@ impl Thread {
         Ok(result)
     }
 
-    pub fn url_for_html_permalink(&self) -> eyre::Result<Option<String>> {
-        let result = self
-            .path
-            .as_ref()
-            .map(|path| path.rendered_path())
-            .transpose()?
-            .flatten()
-            .map(|path| path.internal_url());
+    /// the page this thread is rendered to, which is the page of its last post.
+    pub fn rendered_path(&self) -> Option<&SitePath> {
+        self.posts
+            .last()
+            .and_then(|post| post.rendered_path.as_ref())
+    }
 
-        Ok(result)
+    pub fn url_for_html_permalink(&self) -> eyre::Result<Option<String>> {
+        Ok(self.rendered_path().map(|path| path.internal_url()))
     }
 
     pub fn url_for_atom_permalink(&self) -> eyre::Result<Option<String>> {
-        let result = self
-            .path
-            .as_ref()
-            .map(|path| path.rendered_path())
-            .transpose()?
-            .flatten()
-            .map(|path| path.external_url());
-
-        Ok(result)
+        Ok(self.rendered_path().map(|path| path.external_url()))
     }
 
     pub fn atom_feed_entry_id(&self) -> eyre::Result<Option<String>> {
         let result = self
             .path
             .as_ref()
-            .map(|path| path.rendered_path())
+            // entry ids stay the same if the post gets a slug or the site gets a
+            // `permalink_pattern`, so feed readers don’t see it as a new entry.
+            .map(|path| path.default_rendered_path())
             .transpose()?
             .flatten()
             .map(|path| path.atom_feed_entry_id());
@@ -223,19 +295,40 @@ impl Thread {
     }
 
     pub fn main_post(&self) -> eyre::Result<&TemplatedPost> {
-        self.posts.last().ok_or_eyre("thread has no posts")
+        self.posts
+            .last()
+            .map(|post| &**post)
+            .ok_or_eyre("thread has no posts")
+    }
+
+    /// the references for a new post that replies to this thread.
+    pub fn reply_references(&self) -> Vec<PostsPath> {
+        self.posts
+            .iter()
+            .flat_map(|post| post.path.clone())
+            .collect()
     }
 }
 
 pub struct PostInThread {
-    inner: TemplatedPost,
+    inner: Arc<TemplatedPost>,
     is_main_post: bool,
 }
 
 impl TryFrom<TemplatedPost> for Thread {
     type Error = eyre::Report;
 
-    fn try_from(mut post: TemplatedPost) -> eyre::Result<Self> {
+    fn try_from(post: TemplatedPost) -> eyre::Result<Self> {
+        Self::try_from_with_cache(post, &TemplatedPostCache::default())
+    }
+}
+
+impl Thread {
+    /// like [`Thread::try_from`], but loads referenced posts through the given cache.
+    pub fn try_from_with_cache(
+        mut post: TemplatedPost,
+        cache: &TemplatedPostCache,
+    ) -> eyre::Result<Self> {
         let path = post.path.clone();
         let extra_tags = SETTINGS
             .extra_archived_thread_tags(&post)
@@ -255,13 +348,21 @@ impl TryFrom<TemplatedPost> for Thread {
             .meta
             .references
             .iter()
-            .map(|path| TemplatedPost::load(path))
+            .map(|path| cache.load(path))
             .collect::<Result<Vec<_>, _>>()?;
-        posts.push(post);
+        posts.push(Arc::new(post));
 
         // TODO: skip threads with other authors?
-        // TODO: skip threads with private or logged-in-only authors?
-        // TODO: gate sensitive posts behind an interaction?
+
+        // each post is collapsed behind its own content warnings, but the thread as a whole has
+        // the content warnings of all of its posts, for feeds and previews.
+        meta.cws = vec![];
+        for cw in posts.iter().flat_map(|post| post.meta.cws.iter()) {
+            if !meta.cws.contains(cw) {
+                meta.cws.push(cw.clone());
+            }
+        }
+        meta.adult_content = posts.iter().any(|post| post.meta.adult_content);
 
         // for thread metadata, take the last post that is not a transparent share (which MAY have
         // tags, but SHOULD NOT have a title and MUST NOT have a body), and use its metadata if any.
@@ -278,11 +379,16 @@ impl TryFrom<TemplatedPost> for Thread {
                 "untitled post".to_owned()
             }
         });
-        let og_image = last_non_transparent_share_post
+        let mut og_image = last_non_transparent_share_post
             .and_then(|post| post.og_image.as_deref())
             .map(|og_image| SETTINGS.base_url_relativise(og_image));
-        let og_description =
+        let mut og_description =
             last_non_transparent_share_post.map(|post| post.og_description.to_owned());
+        // don’t let link previews show what the content warnings are hiding.
+        if let Some(summary) = meta.content_warning_summary() {
+            og_image = None;
+            og_description = Some(summary);
+        }
 
         let needs_attachments = posts
             .iter()
@@ -301,27 +407,78 @@ impl TryFrom<TemplatedPost> for Thread {
     }
 }
 
+impl TemplatedPostCache {
+    pub fn with_sources(sources: HashMap<PostsPath, String>) -> Self {
+        Self {
+            inner: RwLock::default(),
+            sources,
+        }
+    }
+
+    /// loads a post, or returns the post that was already loaded, which is shared.
+    pub fn load(&self, path: &PostsPath) -> eyre::Result<Arc<TemplatedPost>> {
+        if let Some(post) = self.inner.read().expect("poisoned").get(path) {
+            return Ok(post.clone());
+        }
+
+        // load without holding the lock, so other threads can load other posts in the meantime.
+        // if two threads race to load the same post, both results are equivalent.
+        let post = Arc::new(match self.sources.get(path) {
+            Some(source) => TemplatedPost::load_source(path, source)?,
+            None => TemplatedPost::load(path)?,
+        });
+        self.inner
+            .write()
+            .expect("poisoned")
+            .insert(path.to_owned(), post.clone());
+
+        Ok(post)
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.read().expect("poisoned").len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
 impl TemplatedPost {
     pub fn load(path: &PostsPath) -> eyre::Result<Self> {
         let mut file = File::open(path)?;
         let mut unsafe_source = String::default();
         file.read_to_string(&mut unsafe_source)?;
 
+        Self::load_source(path, &unsafe_source)
+    }
+
+    /// like [`TemplatedPost::load`], but for a post that was already read.
+    pub fn load_source(path: &PostsPath, unsafe_source: &str) -> eyre::Result<Self> {
         let unsafe_html = if path.is_markdown_post() {
             // author step: render markdown to html.
-            render_markdown(&unsafe_source)
+            render_markdown_post(unsafe_source)?
         } else {
-            unsafe_source
+            unsafe_source.to_owned()
         };
 
         Self::filter(&unsafe_html, Some(path.to_owned()))
+            .wrap_err_with(|| format!("failed to load post: {path:?}"))
     }
 
     pub fn filter(unsafe_html: &str, path: Option<PostsPath>) -> eyre::Result<Self> {
         // reader step: extract metadata.
-        let post = extract_metadata(unsafe_html)?;
+        let mut post = extract_metadata(unsafe_html)?;
+
+        // reader step: add heading anchors and table of contents, and fix in-page links, but only
+        // in your own posts. archived posts keep the ids and links they had where they came from.
+        let source = PostSource::of(path.as_ref(), &post.meta);
+        if source == PostSource::OwnPosts {
+            process_anchors(&mut post.dom, post.meta.toc)?;
+        }
 
-        // reader step: filter html.
+        // reader step: filter html, with a policy for inline styles that depends on the source.
+        let policy = SETTINGS.sanitisation_policy(source);
         let extracted_html = serialize_html_fragment(post.dom)?;
         let safe_html = ammonia::Builder::default()
             .add_generic_attributes(["style", "id"])
@@ -334,12 +491,28 @@ impl TemplatedPost {
             .add_tag_attributes("video", ["controls", "src"])
             .add_tags(["audio", "meta", "video"])
             .add_tag_attributes("meta", ["name", "content"])
-            .id_prefix(Some("user-content-")) // cohost compatibility
+            .add_tags(["nav"])
+            .add_allowed_classes("a", ["heading-anchor"])
+            .add_allowed_classes("nav", ["toc"])
+            .id_prefix(Some(ID_PREFIX)) // cohost compatibility
+            .attribute_filter(move |_, attribute, value| match attribute {
+                "style" => {
+                    Some(policy.filter_inline_style(value)).filter(|style| !style.is_empty())
+                }
+                _ => Some(value.into()),
+            })
             .clean(&extracted_html)
             .to_string();
 
+        let rendered_path = path
+            .as_ref()
+            .map(|path| path.rendered_path_with_meta(&post.meta))
+            .transpose()?
+            .flatten();
+
         Ok(TemplatedPost {
             path,
+            rendered_path,
             meta: post.meta,
             original_html: unsafe_html.to_owned(),
             safe_html,
@@ -350,6 +523,66 @@ impl TemplatedPost {
     }
 }
 
+#[test]
+fn test_templated_post_anchors() -> eyre::Result<()> {
+    let post = TemplatedPost::filter("<meta name=\"toc\"><h2>hi</h2>", None)?;
+    assert!(post.safe_html.contains("<nav class=\"toc\">"));
+    assert!(post.safe_html.contains("<h2 id=\"user-content-hi\">hi<a href=\"#user-content-hi\" class=\"heading-anchor\" rel=\"noopener noreferrer\">#</a></h2>"));
+
+    let post = TemplatedPost::filter(
+        "<link rel=\"archived\" href=\"https://cohost.org/staff/post/1-hi\"><meta name=\"toc\"><h2>hi</h2>",
+        None,
+    )?;
+    assert!(!post.safe_html.contains("<nav"));
+    assert!(post.safe_html.contains("<h2>hi</h2>"));
+
+    Ok(())
+}
+
+#[test]
+fn test_templated_post_cache() -> eyre::Result<()> {
+    let p = PostsPath::ROOT.join("10000000.md")?;
+    let q = PostsPath::ROOT.join("10000001.md")?;
+    let cache = TemplatedPostCache::with_sources(HashMap::from([
+        (p.clone(), "**p**".to_owned()),
+        (q.clone(), "**q**".to_owned()),
+    ]));
+    assert!(cache.is_empty());
+
+    // loading a post again returns the same post, without loading it again.
+    let post = cache.load(&p)?;
+    assert_eq!(post.safe_html, "<p><strong>p</strong></p>\n");
+    assert!(Arc::ptr_eq(&post, &cache.load(&p)?));
+    assert_eq!(cache.len(), 1);
+
+    // posts are cached separately for each path.
+    let other = cache.load(&q)?;
+    assert_eq!(other.safe_html, "<p><strong>q</strong></p>\n");
+    assert!(!Arc::ptr_eq(&post, &other));
+    assert_eq!(cache.len(), 2);
+
+    Ok(())
+}
+
+/// compares secrets like passwords and tokens in constant time, so the time taken doesn’t reveal
+/// how much of a guess was correct.
+pub fn constant_time_eq(actual: &[u8], expected: &[u8]) -> bool {
+    actual.len() == expected.len()
+        && actual
+            .iter()
+            .zip(expected)
+            .fold(0, |result, (p, q)| result | (p ^ q))
+            == 0
+}
+
+#[test]
+fn test_constant_time_eq() {
+    assert!(constant_time_eq(b"hunter2", b"hunter2"));
+    assert!(!constant_time_eq(b"hunter3", b"hunter2"));
+    assert!(!constant_time_eq(b"hunter", b"hunter2"));
+    assert!(constant_time_eq(b"", b""));
+}
+
 pub fn cli_init() -> eyre::Result<()> {
     jane_eyre::install()?;
     tracing_subscriber::registry()
@@ -364,23 +597,38 @@ pub fn cli_init() -> eyre::Result<()> {
     Ok(())
 }
 
-/// render markdown in a cohost-compatible way.
+/// render a markdown post, converting any toml or yaml front matter to html first.
+pub fn render_markdown_post(markdown: &str) -> eyre::Result<String> {
+    let markdown = front_matter_to_html(markdown)?;
+
+    Ok(render_markdown(&markdown))
+}
+
+/// render markdown for a post.
 ///
-/// known discrepancies:
-/// - `~~strikethrough~~` not handled
-/// - @mentions not handled
-/// - :emotes: not handled
-/// - single newline always yields `<br>`
-///   (this was not the case for older chosts, as reflected in their `.astMap`)
-/// - blank lines in `<details>` close the element in some situations?
-/// - spaced numbered lists yield separate `<ol start>` instead of `<li><p>`
+/// for cohost-compatible rendering, use [`render_cohost_markdown`] (see [`markdown`] for how it
+/// differs), which this uses with `cohost_markdown = true`, and `autost cohost2autost` always uses.
 pub fn render_markdown(markdown: &str) -> String {
+    render_markdown_with_options(markdown, &MarkdownOptions::from_settings())
+}
+
+fn render_markdown_with_options(markdown: &str, markdown_options: &MarkdownOptions) -> String {
+    if SETTINGS.cohost_markdown() {
+        return render_cohost_markdown(
+            markdown,
+            &CohostMarkdownOptions::from_settings(true),
+            markdown_options,
+        );
+    }
+
     let mut options = comrak::Options::default();
     options.render.unsafe_ = true;
     options.extension.table = true;
     options.extension.autolink = true;
+    options.extension.footnotes = true;
     options.render.hardbreaks = true;
-    let unsafe_html = comrak::markdown_to_html(&markdown, &options);
+    let unsafe_html =
+        comrak::markdown_to_html_with_plugins(&markdown, &options, &markdown_options.plugins());
 
     unsafe_html
 }
@@ -392,3 +640,30 @@ fn test_render_markdown() {
         "<p>first<br />\nsecond</p>\n"
     );
 }
+
+#[test]
+fn test_render_markdown_with_syntax_highlighting() -> eyre::Result<()> {
+    // this is the path taken by `autost server` /preview, minus the settings lookup.
+    let syntax_highlighter = comrak::plugins::syntect::SyntectAdapter::new(Some("InspiredGitHub"));
+    let options = MarkdownOptions {
+        syntax_highlighter: Some(&syntax_highlighter),
+    };
+    let html = render_markdown_with_options("```rust\nfn main() {}\n```", &options);
+    let post = TemplatedPost::filter(&html, None)?;
+    assert!(post
+        .safe_html
+        .contains("<pre style=\"background-color:#ffffff;\">"));
+    assert!(post
+        .safe_html
+        .contains("<span style=\"font-weight:bold;color:#a71d5d;\">fn </span>"));
+
+    // without a highlighter, code blocks are left alone.
+    let html =
+        render_markdown_with_options("```rust\nfn main() {}\n```", &MarkdownOptions::default());
+    assert_eq!(
+        html,
+        "<pre><code class=\"language-rust\">fn main() {}\n</code></pre>\n"
+    );
+
+    Ok(())
+}
@@ -2,14 +2,20 @@ use autost::{
     cli_init,
     command::{
         self,
+        activitypub::Activitypub,
         attach::Attach,
+        check::Check,
         cohost2autost::Cohost2autost,
         cohost2json::Cohost2json,
         cohost_archive::CohostArchive,
+        delete::Delete,
+        deploy::Deploy,
+        front_matter::FrontMatter,
         import::{Import, Reimport},
         new::New,
         render::Render,
         server::Server,
+        webmention::Webmention,
     },
     SETTINGS,
 };
@@ -18,15 +24,21 @@ use jane_eyre::eyre;
 
 #[derive(clap::Parser, Debug)]
 enum Command {
+    Activitypub(Activitypub),
     Attach(Attach),
+    Check(Check),
     Cohost2autost(Cohost2autost),
     Cohost2json(Cohost2json),
     CohostArchive(CohostArchive),
+    Delete(Delete),
+    Deploy(Deploy),
+    FrontMatter(FrontMatter),
     Import(Import),
     New(New),
     Reimport(Reimport),
     Render(Render),
     Server(Server),
+    Webmention(Webmention),
 }
 
 #[tokio::main]
@@ -37,26 +49,38 @@ async fn main() -> eyre::Result<()> {
 
     if matches!(
         command,
-        Command::Attach { .. }
+        Command::Activitypub { .. }
+            | Command::Attach { .. }
+            | Command::Check { .. }
             | Command::Cohost2autost { .. }
+            | Command::Delete { .. }
+            | Command::Deploy { .. }
+            | Command::FrontMatter { .. }
             | Command::Import { .. }
             | Command::Reimport { .. }
             | Command::Render { .. }
             | Command::Server { .. }
+            | Command::Webmention { .. }
     ) {
         // fail fast if there are any settings errors.
         let _ = &*SETTINGS;
     }
 
     match command {
+        Command::Activitypub(args) => command::activitypub::main(args).await,
         Command::Attach(args) => command::attach::main(args).await,
+        Command::Check(args) => command::check::main(args),
         Command::Cohost2autost(args) => command::cohost2autost::main(args),
         Command::Cohost2json(args) => command::cohost2json::main(args).await,
         Command::CohostArchive(args) => command::cohost_archive::main(args).await,
+        Command::Delete(args) => command::delete::main(args),
+        Command::Deploy(args) => command::deploy::main(args),
+        Command::FrontMatter(args) => command::front_matter::main(args),
         Command::Import(args) => command::import::main(args).await,
         Command::New(args) => command::new::main(args),
         Command::Reimport(args) => command::import::reimport(args).await,
         Command::Render(args) => command::render::main(args),
         Command::Server(args) => command::server::main(args).await,
+        Command::Webmention(args) => command::webmention::main(args).await,
     }
 }
@@ -0,0 +1,458 @@
+//! markdown rendering, with optional syntax highlighting for fenced code blocks.
+//!
+//! cohost-compatible markdown, for `cohost_markdown = true` and `autost cohost2autost`, differs
+//! because cohost rendered markdown with an older, more lenient parser, plus some extensions of
+//! its own:
+//! - `~~strikethrough~~` (and `~strikethrough~`)
+//! - `@handle` mentions, which link to the project on cohost
+//! - `:emote:` custom emoji, which we resolve with the `[emoji]` setting
+//! - single newlines only became `<br>` in newer chosts
+//! - blocks in `<details>` could be indented without becoming code blocks
+//! - paragraphs in numbered lists could be underindented without ending the list
+
+use std::{collections::BTreeMap, sync::LazyLock};
+
+use comrak::{
+    adapters::SyntaxHighlighterAdapter,
+    nodes::{AstNode, NodeLink, NodeValue},
+    plugins::syntect::SyntectAdapter,
+    Arena, Plugins,
+};
+use tracing::warn;
+
+use crate::{path::AttachmentsPath, SETTINGS};
+
+/// mentions must have at least this many characters after the `@`.
+const MIN_HANDLE_LEN: usize = 3;
+
+static NO_EMOJI: LazyLock<BTreeMap<String, String>> = LazyLock::new(BTreeMap::default);
+
+/// highlights fenced code blocks with inline styles, which survive the html filter.
+/// loading the syntaxes and themes is slow, so we only do it once.
+static SYNTAX_HIGHLIGHTER: LazyLock<Option<SyntectAdapter>> = LazyLock::new(|| {
+    SETTINGS
+        .syntax_highlighting_theme()
+        .map(|theme| SyntectAdapter::new(Some(theme)))
+});
+
+/// options for rendering any markdown, cohost-compatible or not.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MarkdownOptions<'a> {
+    pub syntax_highlighter: Option<&'a SyntectAdapter>,
+}
+
+#[derive(Clone, Debug)]
+pub struct CohostMarkdownOptions<'a> {
+    /// whether single newlines yield `<br>`, which was not the case for older chosts.
+    pub hardbreaks: bool,
+    /// emote names (without colons) to filenames in `attachments/emoji/`.
+    pub emoji: &'a BTreeMap<String, String>,
+}
+
+impl MarkdownOptions<'static> {
+    /// highlights code with `syntax_highlighting_theme`, if set.
+    pub fn from_settings() -> Self {
+        Self {
+            syntax_highlighter: SYNTAX_HIGHLIGHTER.as_ref(),
+        }
+    }
+}
+
+impl<'a> MarkdownOptions<'a> {
+    pub fn plugins(&self) -> Plugins<'a> {
+        let mut result = Plugins::default();
+        result.render.codefence_syntax_highlighter = self
+            .syntax_highlighter
+            .map(|adapter| adapter as &dyn SyntaxHighlighterAdapter);
+
+        result
+    }
+}
+
+impl CohostMarkdownOptions<'static> {
+    pub fn from_settings(hardbreaks: bool) -> Self {
+        Self {
+            hardbreaks,
+            emoji: SETTINGS.emoji.as_ref().unwrap_or(&NO_EMOJI),
+        }
+    }
+}
+
+/// render markdown the way cohost did.
+pub fn render_cohost_markdown(
+    markdown: &str,
+    cohost_options: &CohostMarkdownOptions,
+    options: &MarkdownOptions,
+) -> String {
+    let mut comrak_options = comrak::Options::default();
+    comrak_options.render.unsafe_ = true;
+    comrak_options.extension.table = true;
+    comrak_options.extension.autolink = true;
+    comrak_options.extension.strikethrough = true;
+    comrak_options.extension.footnotes = true;
+    comrak_options.render.hardbreaks = cohost_options.hardbreaks;
+
+    let markdown = normalise_indentation(markdown);
+    let arena = Arena::new();
+    let root = comrak::parse_document(&arena, &markdown, &comrak_options);
+    merge_adjacent_text(root);
+    let texts = root
+        .descendants()
+        .filter(|node| matches!(node.data.borrow().value, NodeValue::Text(_)))
+        .filter(|node| !node.ancestors().any(is_link_or_image))
+        .collect::<Vec<_>>();
+    for text in texts {
+        let NodeValue::Text(value) = &text.data.borrow().value else {
+            unreachable!("guaranteed by filter");
+        };
+        let pieces = split_mentions_and_emotes(value, cohost_options.emoji);
+        if let [Piece::Text(_)] = &pieces[..] {
+            continue;
+        }
+        for piece in pieces {
+            let node: &AstNode = match piece {
+                Piece::Text(value) => arena.alloc(NodeValue::Text(value).into()),
+                Piece::Mention(handle) => {
+                    let link: &AstNode = arena.alloc(
+                        NodeValue::Link(NodeLink {
+                            url: format!("https://cohost.org/{handle}"),
+                            title: String::default(),
+                        })
+                        .into(),
+                    );
+                    link.append(arena.alloc(NodeValue::Text(format!("@{handle}")).into()));
+                    link
+                }
+                Piece::Emote { name, url } => {
+                    let image: &AstNode = arena.alloc(
+                        NodeValue::Image(NodeLink {
+                            url,
+                            title: format!(":{name}:"),
+                        })
+                        .into(),
+                    );
+                    image.append(arena.alloc(NodeValue::Text(format!(":{name}:")).into()));
+                    image
+                }
+            };
+            text.insert_before(node);
+        }
+        text.detach();
+    }
+
+    let mut result = vec![];
+    comrak::format_html_with_plugins(root, &comrak_options, &mut result, &options.plugins())
+        .expect("writing to vec never fails");
+
+    String::from_utf8(result).expect("comrak output is always utf-8")
+}
+
+fn is_link_or_image(node: &AstNode) -> bool {
+    matches!(
+        node.data.borrow().value,
+        NodeValue::Link(_) | NodeValue::Image(_)
+    )
+}
+
+/// comrak splits text at characters that might start other inlines, like `:` and `@`.
+fn merge_adjacent_text<'a>(root: &'a AstNode<'a>) {
+    for node in root.descendants().collect::<Vec<_>>() {
+        while let Some(next) = node.next_sibling() {
+            let mut data = node.data.borrow_mut();
+            let NodeValue::Text(value) = &mut data.value else {
+                break;
+            };
+            let NodeValue::Text(next_value) = &next.data.borrow().value else {
+                break;
+            };
+            value.push_str(next_value);
+            next.detach();
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Piece {
+    Text(String),
+    Mention(String),
+    Emote { name: String, url: String },
+}
+
+fn split_mentions_and_emotes(text: &str, emoji: &BTreeMap<String, String>) -> Vec<Piece> {
+    let mut result = vec![];
+    let mut start = 0;
+    let mut i = 0;
+    while i < text.len() {
+        let rest = &text[i..];
+        let found = if let Some(after) = rest
+            .strip_prefix('@')
+            .filter(|_| !text[..i].ends_with(is_word_char))
+        {
+            let len = after.find(|c| !is_handle_char(c)).unwrap_or(after.len());
+            let handle = &after[..len];
+            (handle.len() >= MIN_HANDLE_LEN).then(|| (1 + len, Piece::Mention(handle.to_owned())))
+        } else if let Some(after) = rest.strip_prefix(':') {
+            after
+                .split_once(':')
+                .map(|(name, _)| name)
+                .filter(|name| !name.is_empty() && name.chars().all(is_emote_char))
+                .and_then(|name| Some((name, emote_url(name, emoji.get(name)?)?)))
+                .map(|(name, url)| {
+                    let name = name.to_owned();
+                    (name.len() + 2, Piece::Emote { name, url })
+                })
+        } else {
+            None
+        };
+        match found {
+            Some((len, piece)) => {
+                if start < i {
+                    result.push(Piece::Text(text[start..i].to_owned()));
+                }
+                result.push(piece);
+                i += len;
+                start = i;
+            }
+            None => i += rest.chars().next().map_or(1, char::len_utf8),
+        }
+    }
+    if start < text.len() || result.is_empty() {
+        result.push(Piece::Text(text[start..].to_owned()));
+    }
+
+    result
+}
+
+fn emote_url(name: &str, filename: &str) -> Option<String> {
+    // `EMOJI` is only deprecated for cohost’s own emotes, which are in `COHOST_STATIC`.
+    #[allow(deprecated)]
+    let path = AttachmentsPath::EMOJI
+        .join(filename)
+        .and_then(|path| path.site_path());
+    match path {
+        Ok(path) => Some(path.base_relative_url()),
+        Err(error) => {
+            warn!(?error, "bad filename for emote :{name}:");
+            None
+        }
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn is_handle_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '-'
+}
+
+fn is_emote_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '-' || c == '_' || c == '+'
+}
+
+/// rewrites indentation that cohost’s parser accepted, but commonmark does not:
+/// - the contents of `<details>` are dedented by the indentation of their first line,
+///   so they don’t become code blocks after a blank line
+/// - underindented paragraphs after a blank line in a numbered list item are indented
+///   to the item’s content, so they don’t end the list
+fn normalise_indentation(markdown: &str) -> String {
+    let mut result = vec![];
+    let mut fence: Option<(String, usize)> = None;
+    let mut details_depth = 0usize;
+    let mut details_indent = None;
+    let mut list_indent = None;
+    let mut after_blank = false;
+
+    for line in markdown.lines() {
+        if let Some((marker, extra)) = fence.clone() {
+            if line.trim_start().starts_with(&marker) {
+                fence = None;
+            }
+            result.push(format!("{}{line}", " ".repeat(extra)));
+            continue;
+        }
+        if line.trim().is_empty() {
+            after_blank = true;
+            result.push(line.to_owned());
+            continue;
+        }
+
+        let mut line = line.to_owned();
+        if details_depth > 0 {
+            let base = *details_indent.get_or_insert_with(|| indent(&line));
+            line.drain(..base.min(indent(&line)));
+        }
+        let lowercase = line.to_ascii_lowercase();
+        let opened = lowercase.matches("<details").count();
+        let closed = lowercase.matches("</details>").count();
+        details_depth = (details_depth + opened).saturating_sub(closed);
+        if opened > 0 || details_depth == 0 {
+            details_indent = None;
+        }
+
+        let mut extra = 0;
+        if let Some(width) = ordered_list_marker(&line) {
+            list_indent = Some(indent(&line) + width);
+        } else if let Some(content_indent) = list_indent {
+            let line_indent = indent(&line);
+            if line_indent == 0 && after_blank {
+                list_indent = None;
+            } else if after_blank && line_indent < content_indent {
+                extra = content_indent - line_indent;
+            }
+        }
+        if extra > 0 {
+            line = format!("{}{line}", " ".repeat(extra));
+        }
+
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            let marker = &trimmed[..3];
+            fence = Some((marker.to_owned(), extra));
+        }
+        after_blank = false;
+        result.push(line);
+    }
+
+    let mut result = result.join("\n");
+    if markdown.ends_with('\n') {
+        result.push('\n');
+    }
+
+    result
+}
+
+fn indent(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+/// returns the width of a numbered list marker and its spaces, like `1. ` or `10) `.
+fn ordered_list_marker(line: &str) -> Option<usize> {
+    let line_indent = indent(line);
+    if line_indent > 3 {
+        return None;
+    }
+    let rest = &line[line_indent..];
+    let digits = rest.find(|c: char| !c.is_ascii_digit())?;
+    if digits == 0 || digits > 9 || !rest[digits..].starts_with(['.', ')']) {
+        return None;
+    }
+    let after = &rest[digits + 1..];
+    let spaces = indent(after);
+    if spaces == 0 && !after.is_empty() {
+        return None;
+    }
+    // five or more spaces start an indented code block in the item.
+    let spaces = if spaces > 4 || after.trim().is_empty() {
+        1
+    } else {
+        spaces
+    };
+
+    Some(digits + 1 + spaces)
+}
+
+#[test]
+fn test_render_cohost_markdown() {
+    let emoji = BTreeMap::from([("eggbug".to_owned(), "eggbug.png".to_owned())]);
+    let options = CohostMarkdownOptions {
+        hardbreaks: true,
+        emoji: &emoji,
+    };
+    let render = |markdown| render_cohost_markdown(markdown, &options, &MarkdownOptions::default());
+    assert_eq!(render("first\nsecond"), "<p>first<br />\nsecond</p>\n");
+    assert_eq!(
+        render_cohost_markdown(
+            "first\nsecond",
+            &CohostMarkdownOptions {
+                hardbreaks: false,
+                ..options.clone()
+            },
+            &MarkdownOptions::default(),
+        ),
+        "<p>first\nsecond</p>\n"
+    );
+    assert_eq!(render("~~gone~~"), "<p><del>gone</del></p>\n");
+    assert_eq!(
+        render("hi @staff!"),
+        "<p>hi <a href=\"https://cohost.org/staff\">@staff</a>!</p>\n"
+    );
+    assert_eq!(
+        render(":eggbug: :unknown:"),
+        "<p><img src=\"attachments/emoji/eggbug.png\" alt=\":eggbug:\" title=\":eggbug:\" /> :unknown:</p>\n"
+    );
+
+    // not in emails, links, or code.
+    assert_eq!(render("me@example"), "<p>me@example</p>\n");
+    assert_eq!(
+        render("[@staff](https://example.com)"),
+        "<p><a href=\"https://example.com\">@staff</a></p>\n"
+    );
+    assert_eq!(render("`@staff`"), "<p><code>@staff</code></p>\n");
+    assert_eq!(render("@ab"), "<p>@ab</p>\n");
+
+    // blank lines in indented `<details>`, and underindented paragraphs in numbered lists.
+    assert_eq!(
+        render(
+            "<details>\n  <summary>spoilers</summary>\n\n    it was eggbug all along\n\n</details>"
+        ),
+        "<details>\n<summary>spoilers</summary>\n<p>it was eggbug all along</p>\n</details>\n"
+    );
+    assert_eq!(
+        render("1. first\n\n  more about first\n\n2. second"),
+        "<ol>\n<li>\n<p>first</p>\n<p>more about first</p>\n</li>\n<li>\n<p>second</p>\n</li>\n</ol>\n"
+    );
+}
+
+#[test]
+fn test_normalise_indentation() {
+    assert_eq!(
+        normalise_indentation("<details>\n  <summary>s</summary>\n\n    text\n\n  </details>\n"),
+        "<details>\n<summary>s</summary>\n\n  text\n\n</details>\n"
+    );
+    assert_eq!(
+        normalise_indentation("1. first\n\n  more\n\n2. second\n\nafter"),
+        "1. first\n\n   more\n\n2. second\n\nafter"
+    );
+    assert_eq!(
+        normalise_indentation("1. list\n\n ```\n code\n ```"),
+        "1. list\n\n   ```\n   code\n   ```"
+    );
+    // not in fenced code.
+    assert_eq!(
+        normalise_indentation("```\n<details>\n    code\n```"),
+        "```\n<details>\n    code\n```"
+    );
+}
+
+#[test]
+fn test_syntax_highlighting() -> jane_eyre::eyre::Result<()> {
+    use crate::TemplatedPost;
+
+    let emoji = BTreeMap::default();
+    let cohost_options = CohostMarkdownOptions {
+        hardbreaks: true,
+        emoji: &emoji,
+    };
+    let syntax_highlighter = SyntectAdapter::new(Some("InspiredGitHub"));
+    let options = MarkdownOptions {
+        syntax_highlighter: Some(&syntax_highlighter),
+    };
+    let html = render_cohost_markdown("```rust\nfn main() {}\n```", &cohost_options, &options);
+    let post = TemplatedPost::filter(&html, None)?;
+    // the inline styles survive the html filter, but the classes don’t.
+    assert!(post
+        .safe_html
+        .starts_with(r#"<pre style="background-color:#ffffff;"><code><span"#));
+    assert!(post
+        .safe_html
+        .contains(r#"<span style="font-weight:bold;color:#a71d5d;">fn </span>"#));
+
+    // unknown languages are highlighted as plain text, which only gets the theme’s colour.
+    let html = render_cohost_markdown("```nonsense\n<a>\n```", &cohost_options, &options);
+    assert!(
+        html.contains(r#"<code class="language-nonsense"><span style="color:#323232;">&lt;a&gt;"#)
+    );
+
+    Ok(())
+}
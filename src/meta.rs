@@ -46,6 +46,12 @@ pub fn extract_metadata(unsafe_html: &str) -> eyre::Result<ExtractedPost> {
                         Some("author_display_handle") => {
                             author_display_handle = content;
                         }
+                        Some("author_privacy") => {
+                            meta.author_privacy = content;
+                        }
+                        Some("author_logged_out_post_visibility") => {
+                            meta.author_logged_out_post_visibility = content;
+                        }
                         Some("tags") => {
                             if let Some(tag) = content {
                                 meta.tags.push(tag);
@@ -54,6 +60,20 @@ pub fn extract_metadata(unsafe_html: &str) -> eyre::Result<ExtractedPost> {
                         Some("is_transparent_share") => {
                             meta.is_transparent_share = true;
                         }
+                        Some("toc") => {
+                            meta.toc = true;
+                        }
+                        Some("slug") => {
+                            meta.slug = content;
+                        }
+                        Some("cws") => {
+                            if let Some(cw) = content {
+                                meta.cws.push(cw);
+                            }
+                        }
+                        Some("adult_content") => {
+                            meta.adult_content = true;
+                        }
                         _ => {}
                     }
                     continue;
@@ -0,0 +1,623 @@
+//! micropub (<https://www.w3.org/TR/micropub/>) requests, and how they map onto posts.
+//!
+//! - `h=entry` creates become markdown posts, like those from `/publish`
+//! - `name` is the title, `category` is the tags, and `content` is the body
+//! - `photo` is appended to the body as markdown images
+//! - `in-reply-to` must be a post on this site, or a post imported with `autost import`
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs::{read_dir, read_to_string},
+};
+
+use askama::Template;
+use http::StatusCode;
+use jane_eyre::eyre;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::{constant_time_eq, path::PostsPath, PostMeta, TemplatedPost};
+
+/// scopes granted to tokens from [`StaticTokenVerifier`].
+pub const ALL_SCOPES: [&str; 4] = ["create", "update", "delete", "media"];
+
+/// properties that can be changed by updates.
+const UPDATABLE_PROPERTIES: [&str; 3] = ["name", "content", "category"];
+
+/// mf2 json properties, like `{"name": ["hello"]}`.
+pub type Properties = BTreeMap<String, Vec<Value>>;
+
+/// checks micropub access tokens. implement this to verify tokens with an
+/// indieauth token endpoint, for example.
+pub trait TokenVerifier: Send + Sync {
+    /// returns the scopes granted to the token, or None if the token is invalid.
+    fn verify(&self, token: &str) -> eyre::Result<Option<BTreeSet<String>>>;
+}
+
+/// accepts a single token, which has every scope.
+pub struct StaticTokenVerifier {
+    token: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum MicropubRequest {
+    Create(MicropubEntry),
+    Update { url: String, update: MicropubUpdate },
+    Delete { url: String },
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MicropubEntry {
+    pub name: Option<String>,
+    /// markdown or html, like the body of a post from `/publish`.
+    pub content: Option<String>,
+    pub category: Vec<String>,
+    pub in_reply_to: Option<String>,
+    pub photo: Vec<MicropubPhoto>,
+    pub published: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MicropubPhoto {
+    pub url: String,
+    pub alt: Option<String>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct MicropubUpdate {
+    pub replace: Properties,
+    pub add: Properties,
+    /// properties to delete entirely.
+    pub delete_properties: Vec<String>,
+    /// values to delete from properties.
+    pub delete_values: Properties,
+}
+
+/// an error response, as defined in <https://www.w3.org/TR/micropub/#error-response>.
+#[derive(Debug)]
+pub struct MicropubError {
+    pub status: StatusCode,
+    pub error: &'static str,
+    pub description: String,
+}
+
+impl StaticTokenVerifier {
+    pub fn new(token: String) -> Self {
+        Self { token }
+    }
+}
+
+impl TokenVerifier for StaticTokenVerifier {
+    fn verify(&self, token: &str) -> eyre::Result<Option<BTreeSet<String>>> {
+        let is_valid = constant_time_eq(token.as_bytes(), self.token.as_bytes());
+
+        Ok(is_valid.then(|| ALL_SCOPES.iter().map(|&scope| scope.to_owned()).collect()))
+    }
+}
+
+impl MicropubRequest {
+    /// parses a form-encoded request, where properties like `category[]` can be
+    /// repeated. updates are only supported in json.
+    pub fn from_form(
+        pairs: impl IntoIterator<Item = (String, String)>,
+    ) -> Result<Self, MicropubError> {
+        let mut properties = Properties::default();
+        let (mut h, mut action, mut url) = (None, None, None);
+        for (key, value) in pairs {
+            let key = key.strip_suffix("[]").unwrap_or(&key);
+            match key {
+                "h" => h = Some(value),
+                "action" => action = Some(value),
+                "url" => url = Some(value),
+                "access_token" => {}
+                key if key.starts_with("mp-") => {}
+                key => properties
+                    .entry(key.to_owned())
+                    .or_default()
+                    .push(Value::String(value)),
+            }
+        }
+        let url = || {
+            url.clone()
+                .ok_or_else(|| MicropubError::invalid_request("missing url"))
+        };
+
+        match action.as_deref() {
+            None | Some("create") => {
+                if h.as_deref().is_some_and(|h| h != "entry") {
+                    return Err(MicropubError::invalid_request("only h=entry is supported"));
+                }
+                Ok(Self::Create(MicropubEntry::from_properties(&properties)?))
+            }
+            Some("delete") => Ok(Self::Delete { url: url()? }),
+            Some("update") => Err(MicropubError::invalid_request(
+                "updates must be sent as json",
+            )),
+            Some(other) => Err(MicropubError::invalid_request(format!(
+                "unsupported action: {other}"
+            ))),
+        }
+    }
+
+    pub fn from_json(value: Value) -> Result<Self, MicropubError> {
+        #[derive(Deserialize)]
+        struct Request {
+            #[serde(rename = "type", default)]
+            kind: Vec<String>,
+            #[serde(default)]
+            properties: Properties,
+            action: Option<String>,
+            url: Option<String>,
+            #[serde(default)]
+            replace: Properties,
+            #[serde(default)]
+            add: Properties,
+            delete: Option<Value>,
+        }
+        let request = serde_json::from_value::<Request>(value)
+            .map_err(|error| MicropubError::invalid_request(error.to_string()))?;
+        let url = || {
+            request
+                .url
+                .clone()
+                .ok_or_else(|| MicropubError::invalid_request("missing url"))
+        };
+
+        match request.action.as_deref() {
+            None | Some("create") => {
+                if !request.kind.iter().any(|kind| kind == "h-entry") {
+                    return Err(MicropubError::invalid_request("only h-entry is supported"));
+                }
+                Ok(Self::Create(MicropubEntry::from_properties(
+                    &request.properties,
+                )?))
+            }
+            Some("update") => {
+                let mut update = MicropubUpdate {
+                    replace: request.replace.clone(),
+                    add: request.add.clone(),
+                    ..Default::default()
+                };
+                match request.delete.clone() {
+                    None => {}
+                    Some(Value::Array(names)) => {
+                        for name in names {
+                            let Value::String(name) = name else {
+                                return Err(MicropubError::invalid_request(
+                                    "delete must be an array of property names",
+                                ));
+                            };
+                            update.delete_properties.push(name);
+                        }
+                    }
+                    Some(values @ Value::Object(_)) => {
+                        update.delete_values = serde_json::from_value(values)
+                            .map_err(|error| MicropubError::invalid_request(error.to_string()))?;
+                    }
+                    Some(_) => {
+                        return Err(MicropubError::invalid_request(
+                            "delete must be an array or object",
+                        ))
+                    }
+                }
+                Ok(Self::Update {
+                    url: url()?,
+                    update,
+                })
+            }
+            Some("delete") => Ok(Self::Delete { url: url()? }),
+            Some(other) => Err(MicropubError::invalid_request(format!(
+                "unsupported action: {other}"
+            ))),
+        }
+    }
+}
+
+impl MicropubEntry {
+    /// unsupported properties are ignored.
+    pub fn from_properties(properties: &Properties) -> Result<Self, MicropubError> {
+        let mut result = Self::default();
+        for (name, values) in properties {
+            result.replace(name, values)?;
+        }
+
+        Ok(result)
+    }
+
+    /// the parts of an existing post that can be updated.
+    pub fn from_post(meta: &PostMeta, body: &str) -> Self {
+        Self {
+            name: meta.title.clone().filter(|title| !title.is_empty()),
+            content: Some(body.to_owned()).filter(|body| !body.is_empty()),
+            category: meta.tags.clone(),
+            published: meta.published.clone(),
+            ..Default::default()
+        }
+    }
+
+    fn replace(&mut self, name: &str, values: &[Value]) -> Result<(), MicropubError> {
+        match name {
+            "name" => self.name = first_string(values),
+            "content" => {
+                self.content = values
+                    .first()
+                    .map(|value| match value {
+                        Value::String(text) => Ok(text.clone()),
+                        Value::Object(object) => object
+                            .get("html")
+                            .or_else(|| object.get("value"))
+                            .and_then(|value| value.as_str())
+                            .map(|content| content.to_owned())
+                            .ok_or_else(|| {
+                                MicropubError::invalid_request("content must have html or value")
+                            }),
+                        _ => Err(MicropubError::invalid_request("content must be a string")),
+                    })
+                    .transpose()?
+            }
+            "category" => self.category = strings(values),
+            "in-reply-to" => self.in_reply_to = first_string(values),
+            "photo" => {
+                self.photo = values
+                    .iter()
+                    .map(|value| match value {
+                        Value::String(url) => Ok(MicropubPhoto {
+                            url: url.clone(),
+                            alt: None,
+                        }),
+                        Value::Object(object) => Ok(MicropubPhoto {
+                            url: object
+                                .get("value")
+                                .and_then(|value| value.as_str())
+                                .ok_or_else(|| {
+                                    MicropubError::invalid_request("photo must have a value")
+                                })?
+                                .to_owned(),
+                            alt: object
+                                .get("alt")
+                                .and_then(|alt| alt.as_str())
+                                .map(|alt| alt.to_owned()),
+                        }),
+                        _ => Err(MicropubError::invalid_request("photo must be a url")),
+                    })
+                    .collect::<Result<_, _>>()?
+            }
+            "published" => self.published = first_string(values),
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// the body of the post, in markdown.
+    pub fn body(&self) -> String {
+        let mut result = self.content.clone().unwrap_or_default();
+        for photo in self.photo.iter() {
+            if !result.is_empty() {
+                result.push_str("\n\n");
+            }
+            let alt = photo.alt.as_deref().unwrap_or_default();
+            let alt = alt
+                .replace('\\', "\\\\")
+                .replace('[', "\\[")
+                .replace(']', "\\]");
+            let url = photo.url.replace('<', "%3C").replace('>', "%3E");
+            result.push_str(&format!("![{alt}](<{url}>)"));
+        }
+
+        result
+    }
+
+    /// the properties for `q=source`, optionally only those in `names`.
+    pub fn source(&self, in_reply_to: Option<String>, names: &[String]) -> Value {
+        let mut properties = serde_json::Map::default();
+        let mut insert = |name: &str, values: Vec<String>| {
+            if !values.is_empty() && (names.is_empty() || names.iter().any(|n| n == name)) {
+                properties.insert(name.to_owned(), json!(values));
+            }
+        };
+        insert("name", self.name.iter().cloned().collect());
+        insert("content", self.content.iter().cloned().collect());
+        insert("category", self.category.clone());
+        insert("published", self.published.iter().cloned().collect());
+        insert("in-reply-to", in_reply_to.into_iter().collect());
+
+        // “If [specific properties are requested], then the type is omitted from the response.”
+        if names.is_empty() {
+            json!({ "type": ["h-entry"], "properties": properties })
+        } else {
+            json!({ "properties": properties })
+        }
+    }
+}
+
+impl MicropubUpdate {
+    pub fn apply(&self, entry: &mut MicropubEntry) -> Result<(), MicropubError> {
+        let names = self
+            .replace
+            .keys()
+            .chain(self.add.keys())
+            .chain(self.delete_properties.iter())
+            .chain(self.delete_values.keys());
+        for name in names {
+            if !UPDATABLE_PROPERTIES.contains(&&**name) {
+                return Err(MicropubError::invalid_request(format!(
+                    "property cannot be updated: {name}"
+                )));
+            }
+        }
+
+        for (name, values) in self.replace.iter() {
+            entry.replace(name, values)?;
+        }
+        for (name, values) in self.add.iter() {
+            match &**name {
+                "category" => {
+                    for tag in strings(values) {
+                        if !entry.category.contains(&tag) {
+                            entry.category.push(tag);
+                        }
+                    }
+                }
+                "name" if entry.name.is_none() => entry.replace(name, values)?,
+                "content" if entry.content.is_none() => entry.replace(name, values)?,
+                _ => {
+                    return Err(MicropubError::invalid_request(format!(
+                        "property can only have one value: {name}"
+                    )))
+                }
+            }
+        }
+        for name in self.delete_properties.iter() {
+            match &**name {
+                "name" => entry.name = None,
+                "content" => entry.content = None,
+                "category" => entry.category.clear(),
+                _ => unreachable!("guaranteed by UPDATABLE_PROPERTIES"),
+            }
+        }
+        for (name, values) in self.delete_values.iter() {
+            let values = strings(values);
+            match &**name {
+                "name" => entry.name = entry.name.take().filter(|v| !values.contains(v)),
+                "content" => entry.content = entry.content.take().filter(|v| !values.contains(v)),
+                "category" => entry.category.retain(|tag| !values.contains(tag)),
+                _ => unreachable!("guaranteed by UPDATABLE_PROPERTIES"),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl MicropubError {
+    pub fn invalid_request(description: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::BAD_REQUEST,
+            error: "invalid_request",
+            description: description.into(),
+        }
+    }
+
+    pub fn unauthorized(description: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::UNAUTHORIZED,
+            error: "unauthorized",
+            description: description.into(),
+        }
+    }
+
+    pub fn insufficient_scope(scope: &str) -> Self {
+        Self {
+            status: StatusCode::FORBIDDEN,
+            error: "insufficient_scope",
+            description: format!("token does not have scope: {scope}"),
+        }
+    }
+
+    pub fn not_found(url: &str) -> Self {
+        Self {
+            status: StatusCode::BAD_REQUEST,
+            error: "invalid_request",
+            description: format!("no post found for url: {url}"),
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({ "error": self.error, "error_description": self.description })
+    }
+}
+
+impl From<eyre::Report> for MicropubError {
+    fn from(error: eyre::Report) -> Self {
+        Self {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            error: "server_error",
+            description: format!("{error:?}"),
+        }
+    }
+}
+
+/// writes the source of a markdown post, like the one from `/compose`.
+pub fn post_source(meta: &PostMeta, body: &str) -> eyre::Result<String> {
+    Ok(format!("{}\n{body}", meta.render()?))
+}
+
+/// splits the source of a post into its metadata elements and its body.
+pub fn split_post_source(source: &str) -> (&str, &str) {
+    let mut meta_end = 0;
+    for line in source.split_inclusive('\n') {
+        let trimmed = line.trim();
+        let is_meta = trimmed.starts_with("<link ") || trimmed.starts_with("<meta ");
+        if !is_meta && !trimmed.is_empty() {
+            break;
+        }
+        meta_end += line.len();
+    }
+    let (meta, body) = source.split_at(meta_end);
+
+    (meta, body)
+}
+
+/// finds the post that was imported from the given url with `autost import`.
+pub fn find_imported_post(url: &str) -> eyre::Result<Option<PostsPath>> {
+    let entries = match read_dir(&*PostsPath::IMPORTED) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(error) => Err(error)?,
+    };
+    for entry in entries {
+        let path = PostsPath::IMPORTED.join_dir_entry(&entry?)?;
+        if path.default_rendered_path()?.is_none() {
+            continue;
+        }
+        if TemplatedPost::load(&path)?.meta.archived.as_deref() == Some(url) {
+            return Ok(Some(path));
+        }
+    }
+
+    Ok(None)
+}
+
+/// finds the posts that have the given post in their references, which would
+/// fail to render without it.
+pub fn find_referencing_posts(path: &PostsPath) -> eyre::Result<Vec<PostsPath>> {
+    let mut result = vec![];
+    // references can be in html, toml, or yaml front matter.
+    let needle = path.references_url();
+    for other in PostsPath::list_posts()? {
+        if other == *path {
+            continue;
+        }
+        // only load posts that might refer to the post, since loading is slow.
+        if !read_to_string(&other)?.contains(&needle) {
+            continue;
+        }
+        if TemplatedPost::load(&other)?.meta.references.contains(path) {
+            result.push(other);
+        }
+    }
+
+    Ok(result)
+}
+
+/// the url that a post (like the last of a post’s references) can be found at.
+pub fn post_url(path: &PostsPath) -> eyre::Result<Option<String>> {
+    if let Some(archived) = TemplatedPost::load(path)?.meta.archived {
+        return Ok(Some(archived));
+    }
+
+    Ok(path.rendered_path()?.map(|path| path.external_url()))
+}
+
+fn first_string(values: &[Value]) -> Option<String> {
+    strings(values).into_iter().next()
+}
+
+fn strings(values: &[Value]) -> Vec<String> {
+    values
+        .iter()
+        .filter_map(|value| value.as_str())
+        .map(|value| value.to_owned())
+        .collect()
+}
+
+#[test]
+fn test_micropub_request() {
+    let pairs = |pairs: &[(&str, &str)]| {
+        pairs
+            .iter()
+            .map(|(k, v)| ((*k).to_owned(), (*v).to_owned()))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        MicropubRequest::from_form(pairs(&[
+            ("h", "entry"),
+            ("content", "hello *world*"),
+            ("category[]", "foo"),
+            ("category[]", "bar"),
+            ("photo", "https://example.com/cat.png"),
+            ("access_token", "hunter2"),
+            ("mp-slug", "hello"),
+        ]))
+        .unwrap(),
+        MicropubRequest::Create(MicropubEntry {
+            content: Some("hello *world*".to_owned()),
+            category: vec!["foo".to_owned(), "bar".to_owned()],
+            photo: vec![MicropubPhoto {
+                url: "https://example.com/cat.png".to_owned(),
+                alt: None,
+            }],
+            ..Default::default()
+        })
+    );
+    assert_eq!(
+        MicropubRequest::from_form(pairs(&[("action", "delete"), ("url", "/1.html")])).unwrap(),
+        MicropubRequest::Delete {
+            url: "/1.html".to_owned()
+        }
+    );
+    assert!(MicropubRequest::from_form(pairs(&[("h", "card")])).is_err());
+
+    let MicropubRequest::Create(entry) = MicropubRequest::from_json(json!({
+        "type": ["h-entry"],
+        "properties": {
+            "name": ["hi"],
+            "content": [{"html": "<p>hello</p>"}],
+            "in-reply-to": ["https://example.com/1.html"],
+            "photo": [{"value": "https://example.com/cat.png", "alt": "a [cat]"}],
+        },
+    }))
+    .unwrap() else {
+        panic!("not a create");
+    };
+    assert_eq!(entry.name.as_deref(), Some("hi"));
+    assert_eq!(
+        entry.in_reply_to.as_deref(),
+        Some("https://example.com/1.html")
+    );
+    assert_eq!(
+        entry.body(),
+        "<p>hello</p>\n\n![a \\[cat\\]](<https://example.com/cat.png>)"
+    );
+
+    let MicropubRequest::Update { url, update } = MicropubRequest::from_json(json!({
+        "action": "update",
+        "url": "https://example.com/1.html",
+        "replace": {"content": ["new content"]},
+        "add": {"category": ["new", "old"]},
+        "delete": {"category": ["gone"]},
+    }))
+    .unwrap() else {
+        panic!("not an update");
+    };
+    assert_eq!(url, "https://example.com/1.html");
+    let mut entry = MicropubEntry {
+        name: Some("title".to_owned()),
+        content: Some("old content".to_owned()),
+        category: vec!["old".to_owned(), "gone".to_owned()],
+        ..Default::default()
+    };
+    update.apply(&mut entry).unwrap();
+    assert_eq!(entry.content.as_deref(), Some("new content"));
+    assert_eq!(entry.category, ["old", "new"]);
+
+    let MicropubRequest::Update { update, .. } = MicropubRequest::from_json(json!({
+        "action": "update",
+        "url": "https://example.com/1.html",
+        "delete": ["name", "photo"],
+    }))
+    .unwrap() else {
+        panic!("not an update");
+    };
+    assert!(update.apply(&mut entry).is_err());
+}
+
+#[test]
+fn test_split_post_source() {
+    let meta = "<meta name=\"title\" content=\"headline\">\n<meta name=\"tags\" content=\"foo\">";
+    let (p, q) = split_post_source("\n<meta name=\"title\" content=\"headline\">\n<meta name=\"tags\" content=\"foo\">\n\npost *body*\n<meta name=\"x\">\n");
+    assert_eq!(p.trim(), meta);
+    assert_eq!(q, "post *body*\n<meta name=\"x\">\n");
+    assert_eq!(split_post_source("no meta"), ("", "no meta"));
+}
@@ -0,0 +1,236 @@
+//! http helpers shared by `autost server`, activitypub, and webmentions.
+//!
+//! [`PublicClient`] is for urls we get from other sites, like webmention sources and targets,
+//! and activitypub actors, keys, and inboxes. it refuses to connect to loopback, link-local,
+//! private, or otherwise non-global addresses, so other sites can’t make `autost server`
+//! fetch pages from inside your network.
+//!
+//! - names are resolved by [`GlobalResolver`], which drops non-global addresses
+//! - ip address literals are checked before each request and each redirect
+
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    sync::Arc,
+    time::Duration,
+};
+
+use chrono::{DateTime, Utc};
+use jane_eyre::eyre::{self, bail, OptionExt};
+use reqwest::{
+    blocking::{Client, RequestBuilder},
+    dns::{Addrs, Name, Resolve, Resolving},
+    redirect::Policy,
+};
+use url::{Host, Url};
+
+/// redirects to follow before giving up, like reqwest’s default policy.
+const MAX_REDIRECTS: usize = 10;
+
+/// a blocking http client that only connects to global addresses.
+pub struct PublicClient {
+    inner: Client,
+}
+
+/// resolves names to their global addresses only.
+struct GlobalResolver;
+
+impl PublicClient {
+    pub fn new() -> eyre::Result<Self> {
+        let inner = Client::builder()
+            .timeout(Duration::from_secs(30))
+            .dns_resolver(Arc::new(GlobalResolver))
+            .redirect(Policy::custom(|attempt| {
+                if attempt.previous().len() >= MAX_REDIRECTS {
+                    attempt.error("too many redirects")
+                } else if let Err(error) = check_url(attempt.url()) {
+                    attempt.error(error.to_string())
+                } else {
+                    attempt.follow()
+                }
+            }))
+            // a proxy would resolve names itself, so our resolver would never see them.
+            .no_proxy()
+            .build()?;
+
+        Ok(Self { inner })
+    }
+
+    pub fn get(&self, url: &str) -> eyre::Result<RequestBuilder> {
+        check_url(&Url::parse(url)?)?;
+
+        Ok(self.inner.get(url))
+    }
+
+    pub fn post(&self, url: &str) -> eyre::Result<RequestBuilder> {
+        check_url(&Url::parse(url)?)?;
+
+        Ok(self.inner.post(url))
+    }
+}
+
+impl Resolve for GlobalResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let name = name.as_str().to_owned();
+        Box::pin(async move {
+            let addrs = tokio::net::lookup_host((&*name, 0))
+                .await?
+                .filter(|addr| is_global_ip(addr.ip()))
+                .collect::<Vec<_>>();
+            if addrs.is_empty() {
+                return Err(format!("{name} has no global addresses").into());
+            }
+
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+/// checks that a url is http or https, and that its host isn’t a non-global ip address.
+/// names are checked when they are resolved.
+fn check_url(url: &Url) -> eyre::Result<()> {
+    if !matches!(url.scheme(), "http" | "https") {
+        bail!("not an http or https url: {url}");
+    }
+    let ip = match url.host().ok_or_eyre("url has no host")? {
+        Host::Domain(_) => return Ok(()),
+        Host::Ipv4(ip) => IpAddr::V4(ip),
+        Host::Ipv6(ip) => IpAddr::V6(ip),
+    };
+    if !is_global_ip(ip) {
+        bail!("refusing to connect to non-global address: {url}");
+    }
+
+    Ok(())
+}
+
+/// formats a date for headers like `Date` and `Last-Modified`.
+pub fn http_date(date: DateTime<Utc>) -> String {
+    date.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
+
+pub fn parse_http_date(date: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc2822(date)
+        .ok()
+        .map(|date| date.with_timezone(&Utc))
+}
+
+/// returns false for loopback, link-local, private, shared, documentation, and other
+/// special-purpose addresses (like the unstable [`IpAddr::is_global`]).
+pub fn is_global_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_global_ipv4(ip),
+        IpAddr::V6(ip) => is_global_ipv6(ip),
+    }
+}
+
+fn is_global_ipv4(ip: Ipv4Addr) -> bool {
+    let [a, b, c, _] = ip.octets();
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        // 0.0.0.0/8 (“this network”)
+        || a == 0
+        // 100.64.0.0/10 (shared address space)
+        || (a == 100 && b & 0xC0 == 64)
+        // 192.0.0.0/24 (ietf protocol assignments)
+        || (a == 192 && b == 0 && c == 0)
+        // 198.18.0.0/15 (benchmarking)
+        || (a == 198 && b & 0xFE == 18)
+        // 240.0.0.0/4 (reserved)
+        || a >= 240)
+}
+
+fn is_global_ipv6(ip: Ipv6Addr) -> bool {
+    // ipv4-mapped (::ffff:0:0/96) and nat64 (64:ff9b::/96) addresses reach ipv4 addresses.
+    if let Some(ip) = ip.to_ipv4_mapped() {
+        return is_global_ipv4(ip);
+    }
+    let segments = ip.segments();
+    if segments[..6] == [0x64, 0xFF9B, 0, 0, 0, 0] {
+        let [_, _, _, _, _, _, _, _, _, _, _, _, a, b, c, d] = ip.octets();
+        return is_global_ipv4(Ipv4Addr::new(a, b, c, d));
+    }
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        // ::/96 (ipv4-compatible, deprecated)
+        || segments[..6] == [0; 6]
+        // fc00::/7 (unique local)
+        || segments[0] & 0xFE00 == 0xFC00
+        // fe80::/10 (link-local) and fec0::/10 (site-local, deprecated)
+        || segments[0] & 0xFFC0 == 0xFE80
+        || segments[0] & 0xFFC0 == 0xFEC0
+        // 2001:db8::/32 (documentation)
+        || (segments[0] == 0x2001 && segments[1] == 0xDB8)
+        // 100::/64 (discard)
+        || segments[..4] == [0x100, 0, 0, 0])
+}
+
+#[test]
+fn test_is_global_ip() {
+    for ip in [
+        "0.0.0.0",
+        "0.1.2.3",
+        "10.0.0.1",
+        "100.64.0.1",
+        "127.0.0.1",
+        "169.254.169.254",
+        "172.16.0.1",
+        "192.0.0.1",
+        "192.0.2.1",
+        "192.168.1.1",
+        "198.18.0.1",
+        "224.0.0.1",
+        "240.0.0.1",
+        "255.255.255.255",
+        "::",
+        "::1",
+        "::127.0.0.1",
+        "::ffff:127.0.0.1",
+        "::ffff:192.168.1.1",
+        "64:ff9b::a00:1",
+        "100::1",
+        "2001:db8::1",
+        "fc00::1",
+        "fd12:3456::1",
+        "fe80::1",
+        "fec0::1",
+        "ff02::1",
+    ] {
+        assert!(!is_global_ip(ip.parse().unwrap()), "{ip}");
+    }
+    for ip in [
+        "1.1.1.1",
+        "93.184.215.14",
+        "100.128.0.1",
+        "172.32.0.1",
+        "::ffff:1.1.1.1",
+        "64:ff9b::101:101",
+        "2606:4700:4700::1111",
+    ] {
+        assert!(is_global_ip(ip.parse().unwrap()), "{ip}");
+    }
+}
+
+#[test]
+fn test_check_url() -> eyre::Result<()> {
+    assert!(check_url(&Url::parse("https://example.com/")?).is_ok());
+    assert!(check_url(&Url::parse("http://1.1.1.1/")?).is_ok());
+    assert!(check_url(&Url::parse("http://127.0.0.1:8420/")?).is_err());
+    assert!(check_url(&Url::parse("http://[::1]/")?).is_err());
+    assert!(check_url(&Url::parse("http://[::ffff:10.0.0.1]/")?).is_err());
+    assert!(check_url(&Url::parse("http://169.254.169.254/latest/meta-data/")?).is_err());
+    assert!(check_url(&Url::parse("file:///etc/passwd")?).is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_global_resolver() {
+    let name = "localhost".parse::<Name>().unwrap();
+    assert!(GlobalResolver.resolve(name).await.is_err());
+}
@@ -1,5 +1,12 @@
 //! output templates. these templates are wrapped in a safe interface that
 //! guarantees that path-relative urls are made path-absolute.
+//!
+//! urls are fixed once per output document (or atom feed entry), not once per
+//! nested template, so the templates that only ever get embedded in other
+//! templates (headers, authors, page meta) don’t fix urls themselves, and
+//! their render methods are private to this module.
+
+use std::collections::{BTreeMap, BTreeSet};
 
 use askama::Template;
 use jane_eyre::eyre;
@@ -11,6 +18,10 @@ use crate::{
         serialize_html_document, serialize_html_fragment, TendrilExt, Transform,
     },
     path::{parse_path_relative_scheme_less_url_string, SitePath},
+    redirect::RedirectTarget,
+    search::{SearchQuery, SearchResults},
+    tombstone::Tombstone,
+    webmention::Webmention,
     Author, PostMeta, Thread, SETTINGS,
 };
 
@@ -23,6 +34,7 @@ pub struct ThreadsPageTemplate<'template> {
     threads_content: &'template str,
     page_title: &'template str,
     feed_href: &'template Option<SitePath>,
+    static_file_urls: &'template StaticFileUrls,
 }
 
 #[derive(Clone, Debug, Template)]
@@ -30,6 +42,13 @@ pub struct ThreadsPageTemplate<'template> {
 pub struct ThreadsContentTemplate<'template> {
     thread: &'template Thread,
     simple_mode: bool,
+    tag_pages: &'template TagPages,
+}
+
+/// the set of tags that have tag pages, so we only link to tag pages that exist.
+#[derive(Clone, Debug, Default)]
+pub struct TagPages {
+    tags: BTreeSet<String>,
 }
 
 #[derive(Clone, Debug, Template)]
@@ -52,19 +71,167 @@ pub struct ThreadOrPostMetaTemplate<'template> {
     thread: &'template Thread,
 }
 
+#[derive(Clone, Debug, Template)]
+#[template(path = "author-header.html")]
+pub struct AuthorHeaderTemplate<'template> {
+    author: &'template Author,
+    thread_count: usize,
+    feed_href: &'template SitePath,
+}
+
+#[derive(Clone, Debug, Template)]
+#[template(path = "authors.html")]
+pub struct AuthorIndexTemplate<'template> {
+    links: &'template [AuthorPageLink],
+}
+
+#[derive(Clone, Debug)]
+pub struct AuthorPageLink {
+    pub author: Author,
+    pub page_href: SitePath,
+    pub thread_count: usize,
+}
+
+#[derive(Clone, Debug, Template)]
+#[template(path = "archive-header.html")]
+pub struct ArchiveHeaderTemplate<'template> {
+    title: &'template str,
+    thread_count: usize,
+    index_href: &'template SitePath,
+}
+
+#[derive(Clone, Debug, Template)]
+#[template(path = "archive-index.html")]
+pub struct ArchiveIndexTemplate<'template> {
+    month_names: [&'static str; 12],
+    years: &'template [ArchiveYearLink],
+    undated: &'template Option<ArchivePageLink>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ArchiveYearLink {
+    pub year: i32,
+    pub page_href: SitePath,
+    pub thread_count: usize,
+    /// one for each month, from january to december.
+    pub months: [Option<ArchivePageLink>; 12],
+}
+
+#[derive(Clone, Debug)]
+pub struct ArchivePageLink {
+    pub page_href: SitePath,
+    pub thread_count: usize,
+}
+
+#[derive(Clone, Debug, Template)]
+#[template(path = "tags.html")]
+pub struct TagIndexTemplate<'template> {
+    /// interesting tag groups first, then a group for all other tags.
+    groups: &'template [Vec<TagLink>],
+}
+
+#[derive(Clone, Debug)]
+pub struct TagLink {
+    pub tag: String,
+    pub page_href: Option<SitePath>,
+    pub thread_count: usize,
+    pub font_size_percent: usize,
+}
+
+#[derive(Clone, Debug, Template)]
+#[template(path = "search.html")]
+pub struct SearchTemplate<'template> {
+    /// path-relative url of the page that handles the search form.
+    action: &'template str,
+    query: &'template SearchQuery,
+    /// if none, the search is done client-side by script.js.
+    results: Option<&'template SearchResults>,
+}
+
+#[derive(Clone, Debug, Template)]
+#[template(path = "webmentions.html")]
+pub struct WebmentionsTemplate<'template> {
+    /// approved mentions only.
+    mentions: &'template [Webmention],
+}
+
+#[derive(Clone, Debug, Template)]
+#[template(path = "sitemap.xml")]
+pub struct SitemapTemplate<'template> {
+    urls: &'template [SitemapUrl],
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SitemapUrl {
+    pub loc: String,
+    pub lastmod: Option<String>,
+}
+
+#[derive(Clone, Debug, Template)]
+#[template(path = "sitemap-index.xml")]
+pub struct SitemapIndexTemplate<'template> {
+    sitemap_locs: &'template [String],
+}
+
+#[derive(Clone, Debug, Template)]
+#[template(path = "robots.txt")]
+pub struct RobotsTxtTemplate<'template> {
+    sitemap_url: &'template str,
+}
+
+#[derive(Clone, Debug, Template)]
+#[template(path = "redirect.html")]
+pub struct RedirectTemplate<'template> {
+    url: &'template str,
+    canonical_url: &'template str,
+}
+
+#[derive(Clone, Debug, Template)]
+#[template(path = "tombstone.html")]
+pub struct TombstoneTemplate<'template> {
+    tombstone: &'template Tombstone,
+}
+
 #[derive(Clone, Debug, Template)]
 #[template(path = "feed.xml")]
 pub struct AtomFeedTemplate<'template> {
     thread_refs: Vec<&'template Thread>,
+    /// deleted posts, as `at:deleted-entry` elements (rfc 6721).
+    deleted_entries: Vec<&'template Tombstone>,
+    tag_pages: &'template TagPages,
     feed_title: &'template str,
     updated: &'template str,
 }
 
+/// urls for static files like `style.css`, which are content-hashed if the render wrote
+/// hashed copies of them (`hashed_static_filenames`).
+#[derive(Clone, Debug, Default)]
+pub struct StaticFileUrls {
+    /// content-hashed filenames, keyed by their original filenames.
+    hashed_filenames: BTreeMap<String, String>,
+}
+
+impl StaticFileUrls {
+    pub fn insert(&mut self, filename: &str, hashed_filename: &str) {
+        self.hashed_filenames
+            .insert(filename.to_owned(), hashed_filename.to_owned());
+    }
+
+    /// returns the path-relative url for a static file like `style.css`.
+    pub fn url(&self, filename: &str) -> String {
+        self.hashed_filenames
+            .get(filename)
+            .cloned()
+            .unwrap_or_else(|| filename.to_owned())
+    }
+}
+
 impl ThreadsPageTemplate<'_> {
     pub fn render(
         threads_content: &str,
         page_title: &str,
         feed_href: &Option<SitePath>,
+        static_file_urls: &StaticFileUrls,
     ) -> eyre::Result<String> {
         fix_relative_urls_in_html_document(
             &ThreadsPageTemplate {
@@ -72,6 +239,7 @@ impl ThreadsPageTemplate<'_> {
                 threads_content,
                 page_title,
                 feed_href,
+                static_file_urls,
             }
             .render()?,
         )
@@ -82,6 +250,7 @@ impl ThreadsPageTemplate<'_> {
         threads_content: &str,
         page_title: &str,
         feed_href: &Option<SitePath>,
+        static_file_urls: &StaticFileUrls,
     ) -> eyre::Result<String> {
         let thread_page_meta = ThreadOrPostMetaTemplate::render(thread)?;
 
@@ -91,6 +260,7 @@ impl ThreadsPageTemplate<'_> {
                 threads_content,
                 page_title,
                 feed_href,
+                static_file_urls,
             }
             .render()?,
         )
@@ -98,70 +268,200 @@ impl ThreadsPageTemplate<'_> {
 }
 
 impl<'template> ThreadsContentTemplate<'template> {
-    pub fn render_normal(thread: &'template Thread) -> eyre::Result<String> {
+    pub fn render_normal(
+        thread: &'template Thread,
+        tag_pages: &'template TagPages,
+    ) -> eyre::Result<String> {
         fix_relative_urls_in_html_fragment(&Self::render_normal_without_fixing_relative_urls(
-            thread,
+            thread, tag_pages,
         )?)
     }
 
     pub fn render_normal_without_fixing_relative_urls(
         thread: &'template Thread,
+        tag_pages: &'template TagPages,
     ) -> eyre::Result<String> {
         Ok(Self {
             thread,
             simple_mode: false,
+            tag_pages,
         }
         .render()?)
     }
 
-    fn render_simple(thread: &'template Thread) -> eyre::Result<String> {
+    fn render_simple(
+        thread: &'template Thread,
+        tag_pages: &'template TagPages,
+    ) -> eyre::Result<String> {
         fix_relative_urls_in_html_fragment(
             &Self {
                 thread,
                 simple_mode: true,
+                tag_pages,
             }
             .render()?,
         )
     }
 }
 
+impl TagPages {
+    pub fn new(tags: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            tags: tags.into_iter().collect(),
+        }
+    }
+
+    /// for when we can’t know which tags have pages, like when previewing a post.
+    pub fn interesting() -> Self {
+        Self::new(SETTINGS.interesting_tags_iter().map(ToOwned::to_owned))
+    }
+
+    pub fn has_page(&self, tag: &str) -> bool {
+        self.tags.contains(tag)
+    }
+}
+
 impl<'template> ThreadOrPostHeaderTemplate<'template> {
-    pub fn render(
+    /// urls are not fixed, so this must only be embedded in another template.
+    fn render(
         thread: &'template Thread,
         post_meta: &'template PostMeta,
         is_thread_header: bool,
     ) -> eyre::Result<String> {
-        fix_relative_urls_in_html_fragment(
-            &Self {
-                thread,
-                post_meta,
-                is_thread_header,
-            }
-            .render()?,
-        )
+        Ok(Self {
+            thread,
+            post_meta,
+            is_thread_header,
+        }
+        .render()?)
     }
 }
 
 impl<'template> ThreadOrPostAuthorTemplate<'template> {
-    pub fn render(author: &'template Author) -> eyre::Result<String> {
-        fix_relative_urls_in_html_fragment(&Self { author }.render()?)
+    /// urls are not fixed, so this must only be embedded in another template.
+    fn render(author: &'template Author) -> eyre::Result<String> {
+        Ok(Self { author }.render()?)
     }
 }
 
 impl<'template> ThreadOrPostMetaTemplate<'template> {
-    pub fn render(thread: &'template Thread) -> eyre::Result<String> {
-        fix_relative_urls_in_html_fragment(&Self { thread }.render()?)
+    /// urls are not fixed, so this must only be embedded in another template.
+    fn render(thread: &'template Thread) -> eyre::Result<String> {
+        Ok(Self { thread }.render()?)
+    }
+}
+
+impl<'template> AuthorHeaderTemplate<'template> {
+    /// urls are not fixed, so this must only be used as `threads_content` in a
+    /// [`ThreadsPageTemplate`].
+    pub fn render_without_fixing_relative_urls(
+        author: &'template Author,
+        thread_count: usize,
+        feed_href: &'template SitePath,
+    ) -> eyre::Result<String> {
+        Ok(Self {
+            author,
+            thread_count,
+            feed_href,
+        }
+        .render()?)
+    }
+}
+
+impl<'template> AuthorIndexTemplate<'template> {
+    /// urls are not fixed, so this must only be used as `threads_content` in a
+    /// [`ThreadsPageTemplate`].
+    pub fn render_without_fixing_relative_urls(
+        links: &'template [AuthorPageLink],
+    ) -> eyre::Result<String> {
+        Ok(Self { links }.render()?)
+    }
+}
+
+impl<'template> SearchTemplate<'template> {
+    /// urls are not fixed, so this must only be used as `threads_content` in a
+    /// [`ThreadsPageTemplate`].
+    pub fn render_without_fixing_relative_urls(
+        action: &'template str,
+        query: &'template SearchQuery,
+        results: Option<&'template SearchResults>,
+    ) -> eyre::Result<String> {
+        Ok(Self {
+            action,
+            query,
+            results,
+        }
+        .render()?)
+    }
+}
+
+impl<'template> ArchiveHeaderTemplate<'template> {
+    /// urls are not fixed, so this must only be used as `threads_content` in a
+    /// [`ThreadsPageTemplate`].
+    pub fn render_without_fixing_relative_urls(
+        title: &'template str,
+        thread_count: usize,
+        index_href: &'template SitePath,
+    ) -> eyre::Result<String> {
+        Ok(Self {
+            title,
+            thread_count,
+            index_href,
+        }
+        .render()?)
+    }
+}
+
+impl<'template> ArchiveIndexTemplate<'template> {
+    /// urls are not fixed, so this must only be used as `threads_content` in a
+    /// [`ThreadsPageTemplate`].
+    pub fn render_without_fixing_relative_urls(
+        years: &'template [ArchiveYearLink],
+        undated: &'template Option<ArchivePageLink>,
+    ) -> eyre::Result<String> {
+        Ok(Self {
+            month_names: [
+                "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+            ],
+            years,
+            undated,
+        }
+        .render()?)
+    }
+}
+
+impl<'template> TagIndexTemplate<'template> {
+    /// urls are not fixed, so this must only be used as `threads_content` in a
+    /// [`ThreadsPageTemplate`].
+    pub fn render_without_fixing_relative_urls(
+        groups: &'template [Vec<TagLink>],
+    ) -> eyre::Result<String> {
+        Ok(Self { groups }.render()?)
+    }
+}
+
+impl<'template> WebmentionsTemplate<'template> {
+    /// urls are not fixed, so this must only be appended to `threads_content` in a
+    /// [`ThreadsPageTemplate`].
+    pub fn render_without_fixing_relative_urls(
+        mentions: &'template [Webmention],
+    ) -> eyre::Result<String> {
+        Ok(Self { mentions }.render()?)
     }
 }
 
 impl<'template> AtomFeedTemplate<'template> {
     pub fn render(
         thread_refs: Vec<&'template Thread>,
+        deleted_entries: Vec<&'template Tombstone>,
+        tag_pages: &'template TagPages,
         feed_title: &'template str,
         updated: &'template str,
     ) -> eyre::Result<String> {
         Ok(Self {
             thread_refs,
+            deleted_entries,
+            tag_pages,
             feed_title,
             updated,
         }
@@ -169,6 +469,44 @@ impl<'template> AtomFeedTemplate<'template> {
     }
 }
 
+impl<'template> SitemapTemplate<'template> {
+    pub fn render(urls: &'template [SitemapUrl]) -> eyre::Result<String> {
+        Ok(Self { urls }.render()?)
+    }
+}
+
+impl<'template> SitemapIndexTemplate<'template> {
+    pub fn render(sitemap_locs: &'template [String]) -> eyre::Result<String> {
+        Ok(Self { sitemap_locs }.render()?)
+    }
+}
+
+impl<'template> RobotsTxtTemplate<'template> {
+    pub fn render(sitemap_url: &'template str) -> eyre::Result<String> {
+        Ok(Self { sitemap_url }.render()?)
+    }
+}
+
+impl<'template> RedirectTemplate<'template> {
+    pub fn render(target: &'template RedirectTarget) -> eyre::Result<String> {
+        Ok(Self {
+            url: &target.url,
+            canonical_url: &target.canonical_url,
+        }
+        .render()?)
+    }
+}
+
+impl<'template> TombstoneTemplate<'template> {
+    /// urls are not fixed, so this must only be used as `threads_content` in a
+    /// [`ThreadsPageTemplate`].
+    pub fn render_without_fixing_relative_urls(
+        tombstone: &'template Tombstone,
+    ) -> eyre::Result<String> {
+        Ok(Self { tombstone }.render()?)
+    }
+}
+
 fn fix_relative_urls_in_html_document(html: &str) -> eyre::Result<String> {
     let dom = parse_html_document(html.as_bytes())?;
     let dom = fix_relative_urls(dom)?;
@@ -190,7 +528,8 @@ fn fix_relative_urls(dom: RcDom) -> eyre::Result<RcDom> {
             if let NodeData::Element { name, attrs, .. } = &kid.data {
                 if let Some(attr_names) = html_attributes_with_urls().get(name) {
                     for attr in attrs.borrow_mut().iter_mut() {
-                        if attr_names.contains(&attr.name) {
+                        // leave in-page links like footnotes alone.
+                        if attr_names.contains(&attr.name) && !attr.value.starts_with('#') {
                             if let Some(url) =
                                 parse_path_relative_scheme_less_url_string(attr.value.to_str())
                             {
@@ -207,3 +546,137 @@ fn fix_relative_urls(dom: RcDom) -> eyre::Result<RcDom> {
 
     Ok(dom)
 }
+
+#[cfg(test)]
+fn test_thread(unsafe_html: &str) -> eyre::Result<Thread> {
+    use crate::{path::PostsPath, TemplatedPost};
+
+    let path = PostsPath::ROOT.join("1.md")?;
+    let post = TemplatedPost::filter(unsafe_html, Some(path))?;
+
+    Thread::try_from(post)
+}
+
+#[test]
+fn test_threads_page_fixes_relative_urls() -> eyre::Result<()> {
+    let html = ThreadsPageTemplate::render(
+        r#"<a href="foo.html">x</a><a href="/bar.html">y</a><a href="https://example.net/">z</a>"#,
+        "title",
+        &None,
+        &StaticFileUrls::default(),
+    )?;
+    assert!(html.contains(r#"<link rel="stylesheet" href="/style.css">"#));
+    assert!(html.contains(r#"<a href="/foo.html">x</a>"#));
+    assert!(html.contains(r#"<a href="/bar.html">y</a>"#));
+    assert!(html.contains(r#"<a href="https://example.net/">z</a>"#));
+
+    let html = ThreadsPageTemplate::render(
+        r##"<a href="#user-content-fn-1">1</a>"##,
+        "",
+        &None,
+        &StaticFileUrls::default(),
+    )?;
+    assert!(html.contains(r##"<a href="#user-content-fn-1">1</a>"##));
+
+    let mut static_file_urls = StaticFileUrls::default();
+    static_file_urls.insert("style.css", "style.0123456789abcdef.css");
+    let html = ThreadsPageTemplate::render("", "", &None, &static_file_urls)?;
+    assert!(html.contains(r#"<link rel="stylesheet" href="/style.0123456789abcdef.css">"#));
+    assert!(html.contains(r#"<script src="/script.js"></script>"#));
+
+    Ok(())
+}
+
+#[test]
+fn test_single_thread_page_fixes_relative_urls_in_nested_templates() -> eyre::Result<()> {
+    let thread = test_thread(
+        r#"<link rel="author" href="authors/eggbug.html" name="eggbug"><meta name="author_display_handle" content="eggbug"><img src="attachments/x.png">"#,
+    )?;
+    let threads_content = ThreadsContentTemplate::render_normal_without_fixing_relative_urls(
+        &thread,
+        &TagPages::default(),
+    )?;
+    let html = ThreadsPageTemplate::render_single_thread(
+        &thread,
+        &threads_content,
+        "title",
+        &None,
+        &StaticFileUrls::default(),
+    )?;
+    // ThreadOrPostHeaderTemplate
+    assert!(html.contains(r#"<a class="time u-url" href="/1.html">"#));
+    // ThreadOrPostAuthorTemplate
+    assert!(html.contains(r#"<a class="p-name u-url handle" href="/authors/eggbug.html">"#));
+    // ThreadOrPostMetaTemplate
+    assert!(html.contains(r#"<meta property="og:image" content="/attachments/x.png">"#));
+    // post content
+    assert!(html.contains(r#"<img src="/attachments/x.png">"#));
+    assert!(!html.contains(r#"="1.html""#));
+    assert!(!html.contains(r#"="attachments/"#));
+
+    Ok(())
+}
+
+#[test]
+fn test_threads_content_fixes_relative_urls() -> eyre::Result<()> {
+    let thread = test_thread(
+        r#"<meta name="tags" content="a/b"><meta name="tags" content="c"><a href="foo.html">x</a>"#,
+    )?;
+    let tag_pages = TagPages::new(["a/b".to_owned()]);
+    let html = ThreadsContentTemplate::render_normal(&thread, &tag_pages)?;
+    assert!(html.contains(r#"<a class="tag" href="/tagged/a%252Fb.html">"#));
+    assert!(!html.contains(r#"href="/tagged/c.html""#));
+    assert!(html.contains(r#"href="/foo.html""#));
+    assert!(html.contains(r#"<a class="time u-url" href="/1.html">"#));
+
+    let html = AtomFeedTemplate::render(vec![&thread], vec![], &tag_pages, "title", "now")?;
+    assert!(html.contains(r#"href=&quot;/foo.html&quot;"#));
+
+    Ok(())
+}
+
+#[test]
+fn test_threads_content_with_content_warnings() -> eyre::Result<()> {
+    let thread = test_thread(
+        r#"<meta name="cws" content="eye contact"><meta name="adult_content"><p>boo</p>"#,
+    )?;
+    let html = ThreadsContentTemplate::render_normal(&thread, &TagPages::default())?;
+    assert!(html.contains(
+        r#"<details class="cw content e-content"><summary>content warning: 18+, eye contact</summary><p>boo</p></details>"#
+    ));
+    assert_eq!(
+        thread.og_description.as_deref(),
+        Some("content warning: 18+, eye contact")
+    );
+
+    let xml =
+        AtomFeedTemplate::render(vec![&thread], vec![], &TagPages::default(), "title", "now")?;
+    assert!(xml.contains("<summary>content warning: 18+, eye contact</summary>"));
+
+    let thread = test_thread("<p>boo</p>")?;
+    let html = ThreadsContentTemplate::render_normal(&thread, &TagPages::default())?;
+    assert!(html.contains(r#"<div class="content e-content"><p>boo</p></div>"#));
+
+    Ok(())
+}
+
+#[test]
+fn test_atom_feed_deleted_entries() -> eyre::Result<()> {
+    let tombstone = Tombstone {
+        page: "2024/10/foo.html".to_owned(),
+        entry_id: "2.html".to_owned(),
+        deleted: "2024-10-02T00:00:00Z".to_owned(),
+        ..Default::default()
+    };
+    let xml = AtomFeedTemplate::render(
+        vec![],
+        vec![&tombstone],
+        &TagPages::default(),
+        "title",
+        "now",
+    )?;
+    assert!(xml.contains(r#"<at:deleted-entry ref="2.html" when="2024-10-02T00:00:00Z">"#));
+    assert!(xml.contains(r#"<link rel="alternate" href="https://example.com/2024/10/foo.html"/>"#));
+
+    Ok(())
+}
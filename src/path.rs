@@ -1,6 +1,7 @@
 use std::{
-    fs::{hard_link, DirEntry},
+    fs::{hard_link, read_dir, DirEntry},
     io::ErrorKind,
+    ops::Deref,
     path::{Component, Path, PathBuf},
     sync::LazyLock,
 };
@@ -8,7 +9,10 @@ use std::{
 use jane_eyre::eyre::{self, bail, Context, OptionExt};
 use url::Url;
 
-use crate::SETTINGS;
+use crate::{
+    permalink::{permalink, read_permalink},
+    PostMeta, SETTINGS,
+};
 
 pub type PostsPath = RelativePath<PostsKind>;
 pub type SitePath = RelativePath<SiteKind>;
@@ -21,6 +25,29 @@ pub struct RelativePath<Kind: PathKind> {
     kind: Kind,
 }
 
+/// a path that is created the first time it’s used, for associated consts like
+/// [`PostsPath::ROOT`]. a `LazyLock` const would be a new `LazyLock` everywhere it’s used,
+/// so every use would create the path again.
+pub struct StaticPath<T: 'static>(fn() -> &'static T);
+
+impl<T> Deref for StaticPath<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        (self.0)()
+    }
+}
+
+/// creates a [`StaticPath`] from an expression returning `eyre::Result<$type>`.
+macro_rules! static_path {
+    ($type:ty, $path:expr) => {
+        StaticPath(|| {
+            static PATH: LazyLock<$type> = LazyLock::new(|| $path.expect("guaranteed by argument"));
+            &PATH
+        })
+    };
+}
+
 trait PathKind: Sized {
     const ROOT: &'static str;
     fn new(path: &Path) -> eyre::Result<Self>;
@@ -141,10 +168,10 @@ impl<Kind: PathKind> AsRef<Path> for RelativePath<Kind> {
 }
 
 impl PostsPath {
-    pub const ROOT: LazyLock<Self> =
-        LazyLock::new(|| Self::new(PostsKind::ROOT.into()).expect("guaranteed by argument"));
-    pub const IMPORTED: LazyLock<Self> =
-        LazyLock::new(|| Self::ROOT.join("imported").expect("guaranteed by argument"));
+    pub const ROOT: StaticPath<Self> =
+        static_path!(PostsPath, PostsPath::new(PostsKind::ROOT.into()));
+    pub const IMPORTED: StaticPath<Self> =
+        static_path!(PostsPath, PostsPath::ROOT.join("imported"));
 
     /// creates a path from `<link rel=references href>`, which is relative to
     /// the posts directory, but percent-encoded as a url.
@@ -199,7 +226,35 @@ impl PostsPath {
         )
     }
 
+    /// returns the path this post is rendered to, which is [`Self::default_rendered_path`]
+    /// unless the post has a slug or the site has a `permalink_pattern`.
+    ///
+    /// this reads the post’s front matter, so prefer [`Self::rendered_path_with_meta`] if the
+    /// post was already loaded.
     pub fn rendered_path(&self) -> eyre::Result<Option<SitePath>> {
+        match self.kind {
+            PostsKind::Post { .. } => match read_permalink(self)? {
+                Some(result) => Ok(Some(result)),
+                None => self.default_rendered_path(),
+            },
+            PostsKind::Other => Ok(None),
+        }
+    }
+
+    /// like [`Self::rendered_path`], but with the metadata of the post.
+    pub fn rendered_path_with_meta(&self, meta: &PostMeta) -> eyre::Result<Option<SitePath>> {
+        match self.kind {
+            PostsKind::Post { .. } => match permalink(self, meta)? {
+                Some(result) => Ok(Some(result)),
+                None => self.default_rendered_path(),
+            },
+            PostsKind::Other => Ok(None),
+        }
+    }
+
+    /// returns the path this post is rendered to without a slug or `permalink_pattern`,
+    /// like `site/123.html` for `posts/123.md`.
+    pub fn default_rendered_path(&self) -> eyre::Result<Option<SitePath>> {
         match self.kind {
             PostsKind::Post { .. } => {
                 let (basename, _) = self
@@ -213,6 +268,85 @@ impl PostsPath {
         }
     }
 
+    /// lists the posts in `posts`, leaving out imported posts, other files, and the
+    /// directories that cohost2autost creates for chost thread ancestors.
+    pub fn list_posts() -> eyre::Result<Vec<Self>> {
+        let entries = match read_dir(&*Self::ROOT) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            Err(error) => return Err(error.into()),
+        };
+        let mut result = vec![];
+        for entry in entries {
+            let entry = entry?;
+            if entry.metadata()?.is_dir() {
+                continue;
+            }
+            let path = Self::ROOT.join_dir_entry(&entry)?;
+            if path.default_rendered_path()?.is_some() {
+                result.push(path);
+            }
+        }
+        result.sort();
+
+        Ok(result)
+    }
+
+    /// finds the post that is rendered to the given page, where `url` is relative
+    /// to `base_url` (see [`site_base_relative_url`]).
+    pub fn find_by_rendered_url(url: &str) -> eyre::Result<Option<Self>> {
+        for path in Self::find_all_by_default_rendered_url(url)? {
+            if path
+                .rendered_path()?
+                .is_some_and(|p| p.base_relative_url() == url)
+            {
+                return Ok(Some(path));
+            }
+        }
+
+        // the post may have a slug or a `permalink_pattern`, so check the other posts too.
+        for dir in [&*Self::ROOT, &*Self::IMPORTED] {
+            let Ok(entries) = read_dir(dir) else {
+                continue;
+            };
+            for entry in entries {
+                let path = dir.join_dir_entry(&entry?)?;
+                if path
+                    .rendered_path()?
+                    .is_some_and(|p| p.base_relative_url() == url)
+                {
+                    return Ok(Some(path));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn find_all_by_default_rendered_url(url: &str) -> eyre::Result<Vec<Self>> {
+        let mut result = vec![];
+        let Some(basename) = url.strip_suffix(".html") else {
+            return Ok(result);
+        };
+        if basename.is_empty() || basename.contains(['/', '?', '#']) {
+            return Ok(result);
+        }
+        for dir in [&*Self::ROOT, &*Self::IMPORTED] {
+            for extension in ["md", "html"] {
+                let path = dir.join(&format!("{basename}.{extension}"))?;
+                if path.as_ref().exists()
+                    && path
+                        .default_rendered_path()?
+                        .is_some_and(|p| p.base_relative_url() == url)
+                {
+                    result.push(path);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
     pub fn is_markdown_post(&self) -> bool {
         matches!(
             self.kind,
@@ -223,6 +357,16 @@ impl PostsPath {
         )
     }
 
+    pub fn is_imported_post(&self) -> bool {
+        matches!(
+            self.kind,
+            PostsKind::Post {
+                in_imported_dir: true,
+                ..
+            }
+        )
+    }
+
     pub fn basename(&self) -> Option<&str> {
         if let PostsKind::Post {
             in_imported_dir: true,
@@ -241,20 +385,37 @@ impl PostsPath {
 }
 
 impl SitePath {
-    pub const ROOT: LazyLock<Self> =
-        LazyLock::new(|| Self::new(SiteKind::ROOT.into()).expect("guaranteed by argument"));
-    pub const TAGGED: LazyLock<Self> =
-        LazyLock::new(|| Self::ROOT.join("tagged").expect("guaranteed by argument"));
-    pub const ATTACHMENTS: LazyLock<Self> = LazyLock::new(|| {
-        Self::ROOT
-            .join("attachments")
-            .expect("guaranteed by argument")
-    });
-    pub const THUMBS: LazyLock<Self> = LazyLock::new(|| {
-        Self::ATTACHMENTS
-            .join("thumbs")
-            .expect("guaranteed by argument")
-    });
+    pub const ROOT: StaticPath<Self> = static_path!(SitePath, SitePath::new(SiteKind::ROOT.into()));
+    pub const TAGGED: StaticPath<Self> = static_path!(SitePath, SitePath::ROOT.join("tagged"));
+    pub const AUTHORS: StaticPath<Self> = static_path!(SitePath, SitePath::ROOT.join("authors"));
+    pub const ARCHIVE: StaticPath<Self> = static_path!(SitePath, SitePath::ROOT.join("archive"));
+    pub const SEARCH: StaticPath<Self> = static_path!(SitePath, SitePath::ROOT.join("search"));
+    pub const ATTACHMENTS: StaticPath<Self> =
+        static_path!(SitePath, SitePath::ROOT.join("attachments"));
+    pub const THUMBS: StaticPath<Self> =
+        static_path!(SitePath, SitePath::ATTACHMENTS.join("thumbs"));
+
+    pub fn tag_page_path(tag: &str) -> eyre::Result<Self> {
+        Self::TAGGED.join(&format!("{}.html", Self::tag_basename(tag)))
+    }
+
+    pub fn tag_feed_path(tag: &str) -> eyre::Result<Self> {
+        Self::TAGGED.join(&format!("{}.feed.xml", Self::tag_basename(tag)))
+    }
+
+    fn tag_basename(tag: &str) -> String {
+        // `tagged/index.html` is the tag index, so the `index` tag gets `_index`. to keep that
+        // unambiguous, tags that already start with `_` get another one.
+        if tag == "index" {
+            return "_index".to_owned();
+        }
+        let result = path_safe_filename_component(tag);
+        if tag.starts_with('_') {
+            return format!("_{result}");
+        }
+
+        result
+    }
 
     /// creates a path from an attachment url in a rendered post, which is relative to
     /// the posts directory, but percent-encoded as a url.
@@ -268,6 +429,14 @@ impl SitePath {
         Self::new(path)
     }
 
+    /// creates a path from a page url that is relative to `base_url`, like `1.html`,
+    /// but percent-encoded as a url.
+    pub fn from_base_relative_url(url: &str) -> eyre::Result<Self> {
+        let url = urlencoding::decode(url)?;
+
+        Self::ROOT.join(&url)
+    }
+
     /// use this only in post authoring contexts, like the output of importers.
     pub fn base_relative_url(&self) -> String {
         self.relative_url()
@@ -290,6 +459,12 @@ impl SitePath {
         self.relative_path()
     }
 
+    /// converts path to a path string relative to the site output directory,
+    /// with `/` separators on all platforms, for `autost deploy`.
+    pub fn deploy_path(&self) -> String {
+        self.components().collect::<Vec<_>>().join("/")
+    }
+
     pub fn attachments_path(&self) -> eyre::Result<Option<AttachmentsPath>> {
         match self.kind {
             SiteKind::Attachments => {
@@ -303,28 +478,21 @@ impl SitePath {
 }
 
 impl AttachmentsPath {
-    pub const ROOT: LazyLock<Self> =
-        LazyLock::new(|| Self::new(AttachmentsKind::ROOT.into()).expect("guaranteed by argument"));
-    pub const THUMBS: LazyLock<Self> =
-        LazyLock::new(|| Self::ROOT.join("thumbs").expect("guaranteed by argument"));
+    pub const ROOT: StaticPath<Self> = static_path!(
+        AttachmentsPath,
+        AttachmentsPath::new(AttachmentsKind::ROOT.into())
+    );
+    pub const THUMBS: StaticPath<Self> =
+        static_path!(AttachmentsPath, AttachmentsPath::ROOT.join("thumbs"));
     #[deprecated(since = "1.2.0", note = "cohost emoji are now stored in COHOST_STATIC")]
-    pub const EMOJI: LazyLock<Self> =
-        LazyLock::new(|| Self::ROOT.join("emoji").expect("guaranteed by argument"));
-    pub const COHOST_STATIC: LazyLock<Self> = LazyLock::new(|| {
-        Self::ROOT
-            .join("cohost-static")
-            .expect("guaranteed by argument")
-    });
-    pub const COHOST_AVATAR: LazyLock<Self> = LazyLock::new(|| {
-        Self::ROOT
-            .join("cohost-avatar")
-            .expect("guaranteed by argument")
-    });
-    pub const COHOST_HEADER: LazyLock<Self> = LazyLock::new(|| {
-        Self::ROOT
-            .join("cohost-header")
-            .expect("guaranteed by argument")
-    });
+    pub const EMOJI: StaticPath<Self> =
+        static_path!(AttachmentsPath, AttachmentsPath::ROOT.join("emoji"));
+    pub const COHOST_STATIC: StaticPath<Self> =
+        static_path!(AttachmentsPath, AttachmentsPath::ROOT.join("cohost-static"));
+    pub const COHOST_AVATAR: StaticPath<Self> =
+        static_path!(AttachmentsPath, AttachmentsPath::ROOT.join("cohost-avatar"));
+    pub const COHOST_HEADER: StaticPath<Self> =
+        static_path!(AttachmentsPath, AttachmentsPath::ROOT.join("cohost-header"));
 
     pub fn site_path(&self) -> eyre::Result<SitePath> {
         let mut result = SitePath::ATTACHMENTS.to_owned();
@@ -427,6 +595,85 @@ impl<Kind: PathKind> RelativePath<Kind> {
     }
 }
 
+/// makes the given string safe to use as a single filename component, by percent-encoding any
+/// characters that are path separators or otherwise not allowed in filenames on some platforms.
+///
+/// other characters are kept as is, so existing filenames like `bird photography` don’t change.
+pub fn path_safe_filename_component(name: &str) -> String {
+    let result = name
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' | '%' => {
+                format!("%{:02X}", c as u32)
+            }
+            c if c.is_control() => urlencoding::encode(&c.to_string()).into_owned(),
+            c => c.to_string(),
+        })
+        .collect::<String>();
+
+    // `.` and `..` are not normal components, and hidden files may not get deployed.
+    if result.is_empty() || result.starts_with(".") {
+        format!("_{result}")
+    } else {
+        result
+    }
+}
+
+#[test]
+fn test_tag_page_path() -> eyre::Result<()> {
+    assert_eq!(
+        SitePath::tag_page_path("bird photography")?.internal_url(),
+        "/tagged/bird%20photography.html"
+    );
+    assert_eq!(
+        SitePath::tag_page_path("a/b")?.internal_url(),
+        "/tagged/a%252Fb.html"
+    );
+    assert_eq!(
+        SitePath::tag_feed_path("..")?.internal_url(),
+        "/tagged/_...feed.xml"
+    );
+    assert_eq!(
+        SitePath::tag_page_path("index")?.internal_url(),
+        "/tagged/_index.html"
+    );
+    assert_eq!(
+        SitePath::tag_page_path("_index")?.internal_url(),
+        "/tagged/__index.html"
+    );
+    assert_eq!(
+        SitePath::tag_feed_path("_..")?.internal_url(),
+        "/tagged/__...feed.xml"
+    );
+    assert_ne!(
+        SitePath::tag_page_path("index")?,
+        SitePath::tag_page_path("%69ndex")?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_path_safe_filename_component() {
+    assert_eq!(path_safe_filename_component("foo"), "foo");
+    assert_eq!(
+        path_safe_filename_component("bird photography"),
+        "bird photography"
+    );
+    assert_eq!(path_safe_filename_component("café"), "café");
+    assert_eq!(path_safe_filename_component("foo/bar"), "foo%2Fbar");
+    assert_eq!(path_safe_filename_component(r"foo\bar"), "foo%5Cbar");
+    assert_eq!(path_safe_filename_component("50%"), "50%25");
+    assert_eq!(path_safe_filename_component("a:b?"), "a%3Ab%3F");
+    assert_eq!(path_safe_filename_component("\n"), "%0A");
+    assert_eq!(path_safe_filename_component(""), "_");
+    assert_eq!(path_safe_filename_component("."), "_.");
+    assert_eq!(path_safe_filename_component(".."), "_..");
+    assert!(SitePath::TAGGED
+        .join(&path_safe_filename_component("../foo/bar"))
+        .is_ok());
+}
+
 pub fn hard_link_if_not_exists(
     existing: impl AsRef<Path>,
     new: impl AsRef<Path>,
@@ -585,3 +832,32 @@ fn test_is_path_relative_scheme_less_url_string() {
         Some("2script.js")
     );
 }
+
+/// if `url` is on this site (or on `autost server` at `host`), returns the part after `base_url`.
+pub fn site_base_relative_url(url: &str, host: Option<&str>) -> Option<String> {
+    if let Some(result) = url.strip_prefix(&SETTINGS.external_base_url) {
+        return Some(result.to_owned());
+    }
+    let path = match Url::parse(url) {
+        Ok(url) if host.is_some_and(|host| url.authority() == host) => url.path().to_owned(),
+        Ok(_) => return None,
+        Err(_) if url.starts_with('/') => url.to_owned(),
+        Err(_) => return None,
+    };
+
+    path.strip_prefix(&SETTINGS.base_url)
+        .map(|path| path.to_owned())
+}
+
+#[test]
+fn test_site_base_relative_url() {
+    // example settings have base_url "/" and external_base_url "https://example.com/".
+    let url = |url| site_base_relative_url(url, Some("[::1]:8420"));
+    assert_eq!(url("https://example.com/1.html").as_deref(), Some("1.html"));
+    assert_eq!(url("http://[::1]:8420/1.html").as_deref(), Some("1.html"));
+    assert_eq!(
+        url("/attachments/x/y.png").as_deref(),
+        Some("attachments/x/y.png")
+    );
+    assert_eq!(url("https://example.net/1.html"), None);
+}
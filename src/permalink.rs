@@ -0,0 +1,230 @@
+use std::{fs::read_to_string, io::ErrorKind};
+
+use chrono::{DateTime, Datelike};
+use jane_eyre::eyre::{self, bail, Context};
+
+use crate::{
+    front_matter::front_matter_meta,
+    path::{path_safe_filename_component, PostsPath, SitePath},
+    PostMeta, SETTINGS,
+};
+
+const VARIABLES: &[&str] = &["year", "month", "day", "slug"];
+
+/// directories that `autost render` writes other pages into, so posts can’t be rendered there.
+const RESERVED_DIRS: &[&str] = &[
+    ".well-known",
+    "activitypub",
+    "archive",
+    "attachments",
+    "authors",
+    "search",
+    "tagged",
+];
+
+/// pages that `autost render` writes collections and other pages to.
+const RESERVED_PAGES: &[&str] = &[
+    "all.html",
+    "excluded.html",
+    "index.html",
+    "liked.html",
+    "marked_interesting.html",
+    "private.html",
+    "search.html",
+    "skipped_other.html",
+    "skipped_own.html",
+    "untagged_interesting.html",
+];
+
+/// returns the custom rendered path of the given post, if it has a slug or the site has a
+/// `permalink_pattern`, or None if the post should be rendered to its default path.
+pub fn permalink(path: &PostsPath, meta: &PostMeta) -> eyre::Result<Option<SitePath>> {
+    let (basename, _) = path
+        .filename()
+        .rsplit_once(".")
+        .expect("guaranteed by PostsKind::new");
+    let Some(permalink) = expand_permalink_pattern(
+        SETTINGS.permalink_pattern(),
+        meta.slug.as_deref().unwrap_or(basename),
+        meta.slug.is_some(),
+        meta.published.as_deref(),
+    )?
+    else {
+        return Ok(None);
+    };
+    if is_reserved_permalink(&permalink) {
+        bail!("post {path:?} would be rendered to {permalink}, which is reserved for other pages");
+    }
+
+    Ok(Some(SitePath::ROOT.join(&permalink)?))
+}
+
+/// like [`permalink`], but reads the front matter of the post first.
+pub fn read_permalink(path: &PostsPath) -> eyre::Result<Option<SitePath>> {
+    let source = match read_to_string(path) {
+        Ok(source) => source,
+        Err(error) if error.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(error) => Err(error)?,
+    };
+    let meta = front_matter_meta(path, &source)
+        .wrap_err_with(|| format!("failed to read front matter: {path:?}"))?;
+
+    permalink(path, &meta)
+}
+
+fn is_reserved_permalink(permalink: &str) -> bool {
+    match permalink.split_once('/') {
+        Some((dir, _)) => RESERVED_DIRS.contains(&dir),
+        None => RESERVED_PAGES.contains(&permalink),
+    }
+}
+
+/// expands a permalink pattern like `{year}/{month}/{slug}.html` into a path relative to the
+/// site directory, or returns None if the post should be rendered to its default path.
+///
+/// posts without a valid `published` date fall back to `{slug}.html` if they have a slug.
+pub fn expand_permalink_pattern(
+    pattern: Option<&str>,
+    slug: &str,
+    has_slug: bool,
+    published: Option<&str>,
+) -> eyre::Result<Option<String>> {
+    let slug = path_safe_filename_component(slug);
+    let Some(pattern) = pattern else {
+        return Ok(has_slug.then(|| format!("{slug}.html")));
+    };
+    let published = published.and_then(|published| DateTime::parse_from_rfc3339(published).ok());
+
+    let mut result = String::default();
+    let mut rest = pattern;
+    while let Some((before, after)) = rest.split_once('{') {
+        let Some((variable, after)) = after.split_once('}') else {
+            bail!("unterminated variable in permalink pattern: {pattern}");
+        };
+        result.push_str(before);
+        match (variable, published) {
+            ("slug", _) => result.push_str(&slug),
+            ("year", Some(published)) => result.push_str(&format!("{:04}", published.year())),
+            ("month", Some(published)) => result.push_str(&format!("{:02}", published.month())),
+            ("day", Some(published)) => result.push_str(&format!("{:02}", published.day())),
+            ("year" | "month" | "day", None) => {
+                return Ok(has_slug.then(|| format!("{slug}.html")));
+            }
+            (variable, _) => bail!("unknown variable in permalink pattern: {{{variable}}}"),
+        }
+        rest = after;
+    }
+    result.push_str(rest);
+
+    Ok(Some(result))
+}
+
+pub fn validate_permalink_pattern(pattern: &str) -> eyre::Result<()> {
+    if !pattern.ends_with(".html") {
+        bail!("permalink_pattern setting must end with .html!");
+    }
+    if !pattern.contains("{slug}") {
+        bail!("permalink_pattern setting must contain {{slug}}!");
+    }
+    if pattern.starts_with('/') {
+        bail!("permalink_pattern setting must not start with slash!");
+    }
+    if pattern
+        .split('/')
+        .any(|c| c.is_empty() || c == "." || c == "..")
+    {
+        bail!("permalink_pattern setting must not contain empty, `.`, or `..` components!");
+    }
+    if let Some((dir, _)) = pattern.split_once('/') {
+        if RESERVED_DIRS.contains(&dir) {
+            bail!("permalink_pattern setting must not start with {dir}/, which is reserved for other pages!");
+        }
+    }
+    let mut rest = pattern;
+    while let Some((_, after)) = rest.split_once('{') {
+        let Some((variable, after)) = after.split_once('}') else {
+            bail!("permalink_pattern setting has an unterminated variable!");
+        };
+        if !VARIABLES.contains(&variable) {
+            bail!("permalink_pattern setting has unknown variable {{{variable}}}; must be one of: {VARIABLES:?}");
+        }
+        rest = after;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_expand_permalink_pattern() -> eyre::Result<()> {
+    let published = Some("2024-10-01T23:00:00-04:00");
+    let pattern = Some("{year}/{month}/{day}/{slug}.html");
+
+    // without a pattern, only posts with slugs get custom paths.
+    assert_eq!(
+        expand_permalink_pattern(None, "123", false, published)?,
+        None
+    );
+    assert_eq!(
+        expand_permalink_pattern(None, "hello world", true, published)?,
+        Some("hello world.html".to_owned())
+    );
+
+    // dates are in the time zone they were written in.
+    assert_eq!(
+        expand_permalink_pattern(pattern, "123", false, published)?,
+        Some("2024/10/01/123.html".to_owned())
+    );
+    assert_eq!(
+        expand_permalink_pattern(pattern, "foo/bar", true, published)?,
+        Some("2024/10/01/foo%2Fbar.html".to_owned())
+    );
+
+    // posts without dates fall back to their slug, or their default path.
+    assert_eq!(
+        expand_permalink_pattern(pattern, "foo", true, None)?,
+        Some("foo.html".to_owned())
+    );
+    assert_eq!(expand_permalink_pattern(pattern, "123", false, None)?, None);
+    assert_eq!(
+        expand_permalink_pattern(pattern, "123", false, Some("yesterday"))?,
+        None
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_validate_permalink_pattern() {
+    assert!(validate_permalink_pattern("{year}/{month}/{slug}.html").is_ok());
+    assert!(validate_permalink_pattern("posts/{slug}.html").is_ok());
+    assert!(validate_permalink_pattern("{year}/{month}/{slug}").is_err());
+    assert!(validate_permalink_pattern("{year}/{month}.html").is_err());
+    assert!(validate_permalink_pattern("/{slug}.html").is_err());
+    assert!(validate_permalink_pattern("../{slug}.html").is_err());
+    assert!(validate_permalink_pattern("{year}//{slug}.html").is_err());
+    assert!(validate_permalink_pattern("{hour}/{slug}.html").is_err());
+    assert!(validate_permalink_pattern("{year/{slug}.html").is_err());
+    assert!(validate_permalink_pattern("tagged/{slug}.html").is_err());
+    assert!(validate_permalink_pattern("archive/{year}/{slug}.html").is_err());
+    assert!(validate_permalink_pattern("authors/{slug}.html").is_err());
+}
+
+#[test]
+fn test_is_reserved_permalink() {
+    for permalink in [
+        "index.html",
+        "all.html",
+        "tagged/foo.html",
+        "archive/2024.html",
+    ] {
+        assert!(is_reserved_permalink(permalink), "{permalink}");
+    }
+    for permalink in [
+        "foo.html",
+        "indexes.html",
+        "2024/10/index.html",
+        "posts/all.html",
+    ] {
+        assert!(!is_reserved_permalink(permalink), "{permalink}");
+    }
+}
@@ -0,0 +1,86 @@
+//! redirects from old page urls, from the `[redirects]` setting, and from the default paths of
+//! posts with custom paths (see [`crate::permalink`]). `render` writes a meta refresh page for
+//! each of them, and `autost server` answers them with a real 301.
+
+use jane_eyre::eyre::{self, bail};
+use url::Url;
+
+use crate::SETTINGS;
+
+/// a redirect target, as a url for the `Location` or meta refresh, and a canonical url.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RedirectTarget {
+    pub url: String,
+    pub canonical_url: String,
+}
+
+impl RedirectTarget {
+    /// creates a target from a `[redirects]` value, which is either relative to `base_url`,
+    /// or an absolute url.
+    pub fn new(target: &str) -> Self {
+        if Url::parse(target).is_ok() {
+            return Self {
+                url: target.to_owned(),
+                canonical_url: target.to_owned(),
+            };
+        }
+
+        Self {
+            url: format!("{}{target}", SETTINGS.base_url),
+            canonical_url: format!("{}{target}", SETTINGS.external_base_url),
+        }
+    }
+}
+
+pub fn validate_redirect(from: &str, to: &str) -> eyre::Result<()> {
+    if !from.ends_with(".html") {
+        bail!("redirects setting has bad path {from:?}: must end with .html!");
+    }
+    if from.starts_with('/') || Url::parse(from).is_ok() {
+        bail!("redirects setting has bad path {from:?}: must be relative to base_url!");
+    }
+    if from
+        .split('/')
+        .any(|c| c.is_empty() || c.starts_with('.') || c.contains(['?', '#', '\\']))
+    {
+        bail!("redirects setting has bad path {from:?}: must not contain empty, hidden, `.`, or `..` components!");
+    }
+    if to.is_empty() || (to.starts_with('/') && Url::parse(to).is_err()) {
+        bail!("redirects setting has bad target {to:?}: must be relative to base_url, or an absolute url!");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_redirect_target() {
+    // example settings have base_url "/" and external_base_url "https://example.com/".
+    assert_eq!(
+        RedirectTarget::new("2024/10/foo.html"),
+        RedirectTarget {
+            url: "/2024/10/foo.html".to_owned(),
+            canonical_url: "https://example.com/2024/10/foo.html".to_owned(),
+        }
+    );
+    assert_eq!(
+        RedirectTarget::new("https://example.net/foo"),
+        RedirectTarget {
+            url: "https://example.net/foo".to_owned(),
+            canonical_url: "https://example.net/foo".to_owned(),
+        }
+    );
+}
+
+#[test]
+fn test_validate_redirect() {
+    assert!(validate_redirect("1.html", "foo.html").is_ok());
+    assert!(validate_redirect("old/1.html", "https://example.net/").is_ok());
+    assert!(validate_redirect("1", "foo.html").is_err());
+    assert!(validate_redirect("/1.html", "foo.html").is_err());
+    assert!(validate_redirect("https://example.com/1.html", "foo.html").is_err());
+    assert!(validate_redirect("../1.html", "foo.html").is_err());
+    assert!(validate_redirect("a//1.html", "foo.html").is_err());
+    assert!(validate_redirect(".1.html", "foo.html").is_err());
+    assert!(validate_redirect("1.html", "").is_err());
+    assert!(validate_redirect("1.html", "/foo.html").is_err());
+}
@@ -0,0 +1,507 @@
+//! sanitisation policies for post html, which depend on where the post came from, and can be
+//! changed for each source with the `[sanitisation]` setting.
+//!
+//! for now, the policy controls what `style` attributes can do. other attributes and elements
+//! are sanitised the same way regardless of source (see [`crate::TemplatedPost::filter`]).
+
+use std::{borrow::Cow, mem::take};
+
+use jane_eyre::eyre::{self, bail};
+use serde::Deserialize;
+
+use crate::{
+    css::{parse_inline_style, serialise_inline_style, InlineStyleToken},
+    path::{PostsPath, SitePath},
+    PostMeta,
+};
+
+/// css properties allowed in `style` attributes by `style_properties = "default"`.
+/// each property also allows its longhands, like `border` allows `border-top-color`.
+pub const DEFAULT_STYLE_PROPERTIES: &[&str] = &[
+    "align-content",
+    "align-items",
+    "align-self",
+    "animation",
+    "aspect-ratio",
+    "background",
+    "border",
+    "box-shadow",
+    "box-sizing",
+    "clear",
+    "color",
+    "column",
+    "columns",
+    "direction",
+    "display",
+    "filter",
+    "flex",
+    "float",
+    "font",
+    "gap",
+    "grid",
+    "height",
+    "hyphens",
+    "justify-content",
+    "justify-items",
+    "justify-self",
+    "letter-spacing",
+    "line-height",
+    "list-style",
+    "margin",
+    "max-height",
+    "max-width",
+    "min-height",
+    "min-width",
+    "mix-blend-mode",
+    "object-fit",
+    "object-position",
+    "opacity",
+    "order",
+    "outline",
+    "overflow",
+    "padding",
+    "place-content",
+    "place-items",
+    "place-self",
+    "rotate",
+    "row-gap",
+    "scale",
+    "tab-size",
+    "table-layout",
+    "text",
+    "transform",
+    "transition",
+    "translate",
+    "vertical-align",
+    "visibility",
+    "white-space",
+    "width",
+    "word-break",
+    "word-spacing",
+    "writing-mode",
+];
+
+/// functions whose string arguments are urls, like `image-set("foo.png" 1x)`.
+const URL_FUNCTIONS: &[&str] = &["image-set", "-webkit-image-set", "src"];
+
+/// where a post came from, which decides its [`SanitisationPolicy`].
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd)]
+#[serde(rename_all = "snake_case")]
+pub enum PostSource {
+    /// posts without an `archived` url, which are usually posts you wrote.
+    OwnPosts,
+    /// chosts converted by `autost cohost2autost`.
+    Cohost,
+    /// posts imported by `autost import`, or archived from anywhere other than cohost.
+    Imports,
+}
+
+/// `[sanitisation.<source>]` settings, which override the defaults for that source.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SanitisationSettings {
+    style_properties: Option<StyleProperties>,
+    external_style_urls: Option<bool>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum StyleProperties {
+    Keyword(StylePropertiesKeyword),
+    List(Vec<String>),
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum StylePropertiesKeyword {
+    /// any property.
+    All,
+    /// only the [`DEFAULT_STYLE_PROPERTIES`].
+    Default,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SanitisationPolicy {
+    /// css properties allowed in `style` attributes, or None to allow any property.
+    pub style_properties: Option<Vec<String>>,
+    /// whether to keep urls in `style` attributes that are not attachments or `data:` urls.
+    pub external_style_urls: bool,
+}
+
+impl PostSource {
+    pub fn of(path: Option<&PostsPath>, meta: &PostMeta) -> Self {
+        if path.is_some_and(|path| path.is_imported_post()) {
+            return Self::Imports;
+        }
+        match meta.archived.as_deref() {
+            Some(archived) if archived.starts_with("https://cohost.org/") => Self::Cohost,
+            Some(_) => Self::Imports,
+            None => Self::OwnPosts,
+        }
+    }
+}
+
+impl SanitisationSettings {
+    pub fn validate(&self) -> eyre::Result<()> {
+        if let Some(StyleProperties::List(properties)) = &self.style_properties {
+            for property in properties {
+                if property.is_empty()
+                    || !property
+                        .chars()
+                        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+                {
+                    bail!("sanitisation setting has bad style property {property:?}: must be a lowercase css property name!");
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl SanitisationPolicy {
+    /// creates the policy for the given source, with its settings (if any) over the defaults.
+    ///
+    /// by default, own posts are not filtered, chosts can use any property but not external
+    /// urls, and imported posts can only use the [`DEFAULT_STYLE_PROPERTIES`].
+    pub fn new(source: PostSource, settings: Option<&SanitisationSettings>) -> Self {
+        let default_style_properties = match source {
+            // chosts already went through cohost’s own sanitiser, and css crimes need things
+            // like `position` and `z-index`. `article.post` in style.css has `contain: paint`,
+            // so even `position: fixed` can’t cover or escape the post.
+            PostSource::OwnPosts | PostSource::Cohost => StylePropertiesKeyword::All,
+            PostSource::Imports => StylePropertiesKeyword::Default,
+        };
+        let style_properties = settings
+            .and_then(|settings| settings.style_properties.clone())
+            .unwrap_or(StyleProperties::Keyword(default_style_properties));
+        let style_properties = match style_properties {
+            StyleProperties::Keyword(StylePropertiesKeyword::All) => None,
+            StyleProperties::Keyword(StylePropertiesKeyword::Default) => Some(
+                DEFAULT_STYLE_PROPERTIES
+                    .iter()
+                    .map(|property| (*property).to_owned())
+                    .collect(),
+            ),
+            StyleProperties::List(properties) => Some(properties),
+        };
+        let external_style_urls = settings
+            .and_then(|settings| settings.external_style_urls)
+            .unwrap_or(source == PostSource::OwnPosts);
+
+        Self {
+            style_properties,
+            external_style_urls,
+        }
+    }
+
+    pub fn allows_style_property(&self, property: &str) -> bool {
+        // custom properties do nothing unless an allowed property uses them.
+        if property.starts_with("--") {
+            return true;
+        }
+        let Some(allowed) = &self.style_properties else {
+            return true;
+        };
+        let property = ["-webkit-", "-moz-", "-ms-", "-o-"]
+            .iter()
+            .find_map(|prefix| property.strip_prefix(prefix))
+            .unwrap_or(property);
+
+        allowed.iter().any(|allowed| {
+            property
+                .strip_prefix(allowed.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('-'))
+        })
+    }
+
+    pub fn allows_style_url(&self, url: &str) -> bool {
+        self.external_style_urls
+            || url
+                .get(..5)
+                .is_some_and(|scheme| scheme.eq_ignore_ascii_case("data:"))
+            || SitePath::from_rendered_attachment_url(url).is_ok()
+    }
+
+    /// filters a `style` attribute, dropping declarations with properties that are not allowed,
+    /// and rewriting urls that are not allowed to `none`.
+    pub fn filter_inline_style<'s>(&self, style: &'s str) -> Cow<'s, str> {
+        if self.style_properties.is_none() && self.external_style_urls {
+            return Cow::Borrowed(style);
+        }
+
+        // split into declarations at semicolons that are not inside a function or block.
+        let mut declarations = vec![];
+        let mut declaration = vec![];
+        let mut depth = 0usize;
+        for token in parse_inline_style(style) {
+            if let InlineStyleToken::Other(other) = &token {
+                if depth == 0 && other == ";" {
+                    declarations.push(take(&mut declaration));
+                    continue;
+                } else if other.ends_with(['(', '[', '{']) {
+                    depth += 1;
+                } else if matches!(&**other, ")" | "]" | "}") {
+                    depth = depth.saturating_sub(1);
+                }
+            }
+            declaration.push(token);
+        }
+        declarations.push(declaration);
+
+        Cow::Owned(
+            declarations
+                .into_iter()
+                .filter_map(|declaration| self.filter_declaration(declaration))
+                .map(|declaration| serialise_inline_style(&declaration))
+                .collect::<Vec<_>>()
+                .join(";"),
+        )
+    }
+
+    fn filter_declaration(
+        &self,
+        declaration: Vec<InlineStyleToken>,
+    ) -> Option<Vec<InlineStyleToken>> {
+        let colon = declaration
+            .iter()
+            .position(|token| matches!(token, InlineStyleToken::Other(other) if other == ":"))?;
+        let mut property = String::default();
+        for token in &declaration[..colon] {
+            let InlineStyleToken::Other(other) = token else {
+                return None;
+            };
+            property.push_str(other);
+        }
+        let property = property.trim().to_ascii_lowercase();
+        if property.is_empty()
+            || !property
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            || !self.allows_style_property(&property)
+        {
+            return None;
+        }
+
+        let mut result = vec![
+            InlineStyleToken::Other(property),
+            InlineStyleToken::Other(":".to_owned()),
+        ];
+        let mut functions = vec![];
+        for token in declaration.into_iter().skip(colon + 1) {
+            result.push(match token {
+                InlineStyleToken::Url(url) if !self.allows_style_url(&url) => {
+                    InlineStyleToken::Other("none".to_owned())
+                }
+                InlineStyleToken::String(string)
+                    if functions
+                        .last()
+                        .is_some_and(|function: &String| URL_FUNCTIONS.contains(&&**function))
+                        && !self.allows_style_url(&string) =>
+                {
+                    InlineStyleToken::Other("none".to_owned())
+                }
+                InlineStyleToken::Other(other) => {
+                    if let Some(function) = other.strip_suffix('(') {
+                        functions.push(function.to_ascii_lowercase());
+                    } else if other.ends_with(['[', '{']) {
+                        functions.push(String::default());
+                    } else if matches!(&*other, ")" | "]" | "}") {
+                        functions.pop();
+                    }
+                    InlineStyleToken::Other(other)
+                }
+                other => other,
+            });
+        }
+
+        Some(result)
+    }
+}
+
+#[test]
+fn test_post_source() -> eyre::Result<()> {
+    let meta = |archived: Option<&str>| PostMeta {
+        archived: archived.map(|archived| archived.to_owned()),
+        ..Default::default()
+    };
+    let own = PostsPath::ROOT.join("10000000.md")?;
+    let chost = PostsPath::ROOT.join("123456.html")?;
+    let imported = PostsPath::imported_post_path(1);
+    assert_eq!(
+        PostSource::of(Some(&own), &meta(None)),
+        PostSource::OwnPosts
+    );
+    assert_eq!(PostSource::of(None, &meta(None)), PostSource::OwnPosts);
+    assert_eq!(
+        PostSource::of(
+            Some(&chost),
+            &meta(Some("https://cohost.org/staff/post/123456-foo"))
+        ),
+        PostSource::Cohost
+    );
+    assert_eq!(
+        PostSource::of(Some(&imported), &meta(Some("https://example.net/foo"))),
+        PostSource::Imports
+    );
+    assert_eq!(
+        PostSource::of(Some(&imported), &meta(None)),
+        PostSource::Imports
+    );
+    assert_eq!(
+        PostSource::of(Some(&own), &meta(Some("https://example.net/foo"))),
+        PostSource::Imports
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_sanitisation_policy_defaults() {
+    let own_posts = SanitisationPolicy::new(PostSource::OwnPosts, None);
+    assert_eq!(own_posts.style_properties, None);
+    assert!(own_posts.external_style_urls);
+    let cohost = SanitisationPolicy::new(PostSource::Cohost, None);
+    assert_eq!(cohost.style_properties, None);
+    assert!(!cohost.external_style_urls);
+    let imports = SanitisationPolicy::new(PostSource::Imports, None);
+    assert!(imports.style_properties.is_some());
+    assert!(!imports.external_style_urls);
+
+    let settings = SanitisationSettings {
+        style_properties: Some(StyleProperties::List(vec!["color".to_owned()])),
+        external_style_urls: None,
+    };
+    let policy = SanitisationPolicy::new(PostSource::OwnPosts, Some(&settings));
+    assert_eq!(policy.style_properties, Some(vec!["color".to_owned()]));
+    assert!(policy.external_style_urls);
+}
+
+#[test]
+fn test_allows_style_property() {
+    let policy = SanitisationPolicy::new(PostSource::Imports, None);
+    assert!(policy.allows_style_property("color"));
+    assert!(policy.allows_style_property("border-top-left-radius"));
+    assert!(policy.allows_style_property("-webkit-text-stroke"));
+    assert!(policy.allows_style_property("--color-cherry"));
+    assert!(!policy.allows_style_property("position"));
+    assert!(!policy.allows_style_property("z-index"));
+    assert!(!policy.allows_style_property("inset"));
+    assert!(!policy.allows_style_property("colorful"));
+    assert!(!policy.allows_style_property("-webkit-position"));
+}
+
+#[test]
+fn test_filter_inline_style() {
+    let own_posts = SanitisationPolicy::new(PostSource::OwnPosts, None);
+    let cohost = SanitisationPolicy::new(PostSource::Cohost, None);
+    let imports = SanitisationPolicy::new(PostSource::Imports, None);
+
+    // own posts are not filtered at all.
+    let style = "position: fixed; background: url(https://example.net/x.png)";
+    assert_eq!(own_posts.filter_inline_style(style), style);
+
+    // overlays and stacking tricks.
+    assert_eq!(
+        imports.filter_inline_style("position: fixed; inset: 0; z-index: 2147483647; color: red"),
+        "color: red"
+    );
+    assert_eq!(
+        imports.filter_inline_style("POSITION: fixed;Color:red"),
+        "color:red"
+    );
+    assert_eq!(
+        imports.filter_inline_style(r"posit\69on: fixed; color: red"),
+        "color: red"
+    );
+    assert_eq!(
+        imports.filter_inline_style("position/**/: fixed; color: red"),
+        "color: red"
+    );
+    assert_eq!(
+        imports.filter_inline_style("color: red; position"),
+        "color: red"
+    );
+    assert_eq!(
+        imports.filter_inline_style("color: var(--x, ;position: fixed)"),
+        "color: var(--x, ;position: fixed)"
+    );
+
+    // urls that would leak the reader’s ip address.
+    assert_eq!(
+        cohost.filter_inline_style("background: red url(https://example.net/x.png)"),
+        "background: red none"
+    );
+    assert_eq!(
+        cohost.filter_inline_style(r#"background-image: url("//example.net/x.png")"#),
+        "background-image: none"
+    );
+    assert_eq!(
+        cohost.filter_inline_style(r#"background-image: URL("https://example.net/x.png")"#),
+        "background-image: none"
+    );
+    assert_eq!(
+        cohost.filter_inline_style("background-image: Url('https://example.net/x.png')"),
+        "background-image: none"
+    );
+    assert_eq!(
+        cohost.filter_inline_style("background-image: URL(https://example.net/x.png)"),
+        "background-image: none"
+    );
+    assert_eq!(
+        cohost.filter_inline_style(
+            r#"background-image: image-set("https://example.net/x.png" 1x, url(https://example.net/y.png) 2x)"#
+        ),
+        "background-image: image-set(none 1x, none 2x)"
+    );
+    assert_eq!(
+        cohost.filter_inline_style(
+            r#"background-image: -WEBKIT-image-set("https://example.net/x.png" 1x)"#
+        ),
+        "background-image: -WEBKIT-image-set(none 1x)"
+    );
+    assert_eq!(
+        cohost.filter_inline_style(r#"font-family: "https://example.net/x.png""#),
+        r#"font-family: 'https://example.net/x.png'"#
+    );
+
+    // attachments and data urls are fine.
+    assert_eq!(
+        cohost.filter_inline_style(
+            "background: url(attachments/0/x.png), url(data:image/png;base64,AAAA)"
+        ),
+        "background: url('attachments/0/x.png'), url('data:image/png;base64,AAAA')"
+    );
+    assert_eq!(
+        cohost.filter_inline_style("background: url(attachments/../../x.png)"),
+        "background: none"
+    );
+}
+
+#[test]
+fn test_templated_post_filter() -> eyre::Result<()> {
+    let html = r#"<div style="position: fixed; inset: 0; background: url(https://example.net/x.png)">x</div>"#;
+    let post = crate::TemplatedPost::filter(html, Some(PostsPath::imported_post_path(1)))?;
+    assert_eq!(post.safe_html, r#"<div style="background: none">x</div>"#);
+    let post = crate::TemplatedPost::filter(html, Some(PostsPath::ROOT.join("1.md")?))?;
+    assert_eq!(post.safe_html, html);
+    let html = r#"<div style="position: fixed">x</div>"#;
+    let post = crate::TemplatedPost::filter(html, Some(PostsPath::imported_post_path(1)))?;
+    assert_eq!(post.safe_html, "<div>x</div>");
+
+    Ok(())
+}
+
+#[test]
+fn test_validate_sanitisation_settings() {
+    let settings = |properties: &[&str]| SanitisationSettings {
+        style_properties: Some(StyleProperties::List(
+            properties.iter().map(|p| (*p).to_owned()).collect(),
+        )),
+        external_style_urls: None,
+    };
+    assert!(settings(&["color", "border-radius"]).validate().is_ok());
+    assert!(settings(&[""]).validate().is_err());
+    assert!(settings(&["Color"]).validate().is_err());
+    assert!(settings(&["color;position"]).validate().is_err());
+}
@@ -0,0 +1,366 @@
+//! full-text search index. the index is written at render time as static json
+//! shards that script.js can query without a server, and the server queries
+//! the same shards for `/search?q=`.
+//!
+//! - `search/documents.json` is an array of [`SearchDocument`]
+//! - `search/terms/<hex>.json` maps each term starting with the code point
+//!   `<hex>` to its postings, an array of `[document, weight]`
+//!
+//! terms are matched by prefix, so every term that a query term can match is
+//! in the same shard. the tokenizer and query logic must be kept in sync with
+//! `search()` in script.js.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs::{create_dir_all, remove_dir_all, File},
+    io::{self, Write},
+};
+
+use jane_eyre::eyre::{self, OptionExt};
+use serde::{Deserialize, Serialize};
+
+use crate::{path::SitePath, Thread};
+
+/// maximum number of results returned by [`search`].
+pub const SEARCH_RESULT_LIMIT: usize = 100;
+
+const TITLE_WEIGHT: usize = 4;
+const TAG_WEIGHT: usize = 4;
+const AUTHOR_WEIGHT: usize = 2;
+const TEXT_WEIGHT: usize = 1;
+const SUMMARY_LENGTH: usize = 200;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct SearchDocument {
+    pub url: String,
+    pub title: Option<String>,
+    pub published: Option<String>,
+    pub author: Option<SearchAuthor>,
+    pub tags: Vec<String>,
+    pub summary: String,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct SearchAuthor {
+    pub href: String,
+    pub display_name: String,
+    pub display_handle: String,
+}
+
+/// postings for each term in a shard, as `(document, weight)`.
+pub type SearchShard = BTreeMap<String, Vec<(usize, usize)>>;
+
+#[derive(Debug, Default)]
+pub struct SearchIndex {
+    documents: Vec<SearchDocument>,
+    shards: BTreeMap<String, SearchShard>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SearchQuery {
+    pub q: String,
+    pub tag: Option<String>,
+    pub author: Option<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SearchResults {
+    /// number of matching documents, which may be more than `documents.len()`.
+    pub total: usize,
+    pub documents: Vec<SearchDocument>,
+}
+
+impl SearchIndex {
+    /// adds a thread to the index. results for equal scores are returned in the
+    /// order that threads were added.
+    pub fn add_thread(&mut self, thread: &Thread) -> eyre::Result<()> {
+        let url = thread
+            .url_for_html_permalink()?
+            .ok_or_eyre("thread has no permalink")?;
+        let document = self.documents.len();
+        let mut weights = BTreeMap::<String, usize>::default();
+        let mut add = |text: &str, weight: usize| {
+            for term in tokenize(text) {
+                *weights.entry(term).or_default() += weight;
+            }
+        };
+        for post in thread.posts.iter() {
+            if let Some(title) = &post.meta.title {
+                add(title, TITLE_WEIGHT);
+            }
+            for tag in post.meta.tags.iter() {
+                add(tag, TAG_WEIGHT);
+            }
+            if let Some(author) = &post.meta.author {
+                add(&author.display_name, AUTHOR_WEIGHT);
+                add(&author.display_handle, AUTHOR_WEIGHT);
+            }
+            add(&post.og_description, TEXT_WEIGHT);
+        }
+        for (term, weight) in weights {
+            self.shards
+                .entry(shard_key(&term))
+                .or_default()
+                .entry(term)
+                .or_default()
+                .push((document, weight));
+        }
+
+        self.documents.push(SearchDocument {
+            url,
+            title: thread.meta.title.clone().filter(|title| !title.is_empty()),
+            published: thread.meta.published.clone(),
+            author: thread.meta.author.as_ref().map(|author| SearchAuthor {
+                href: author.href.clone(),
+                display_name: author.display_name.clone(),
+                display_handle: author.display_handle.clone(),
+            }),
+            tags: thread.meta.tags.clone(),
+            summary: summary(thread.og_description.as_deref().unwrap_or_default()),
+        });
+
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.documents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+    }
+
+    /// replaces the index in `search/`, returning the paths written.
+    pub fn write(&self) -> eyre::Result<Vec<SitePath>> {
+        match remove_dir_all(&*SitePath::SEARCH) {
+            Err(error) if error.kind() != io::ErrorKind::NotFound => Err(error)?,
+            _ => {}
+        }
+        let terms_dir = SitePath::SEARCH.join("terms")?;
+        create_dir_all(&terms_dir)?;
+
+        let mut result = vec![];
+        let documents_path = SitePath::SEARCH.join("documents.json")?;
+        let mut file = File::create(&documents_path)?;
+        serde_json::to_writer(&mut file, &self.documents)?;
+        writeln!(file)?;
+        result.push(documents_path);
+        for (key, shard) in self.shards.iter() {
+            let shard_path = terms_dir.join(&format!("{key}.json"))?;
+            let mut file = File::create(&shard_path)?;
+            serde_json::to_writer(&mut file, shard)?;
+            writeln!(file)?;
+            result.push(shard_path);
+        }
+
+        Ok(result)
+    }
+
+    pub fn search(&self, query: &SearchQuery) -> eyre::Result<SearchResults> {
+        search(query, &self.documents, |key| {
+            Ok(self.shards.get(key).cloned())
+        })
+    }
+}
+
+/// loads `search/documents.json` as written by [`SearchIndex::write`].
+pub fn load_documents() -> eyre::Result<Vec<SearchDocument>> {
+    let file = File::open(SitePath::SEARCH.join("documents.json")?)?;
+
+    Ok(serde_json::from_reader(file)?)
+}
+
+/// loads a shard as written by [`SearchIndex::write`], if it exists.
+pub fn load_shard(key: &str) -> eyre::Result<Option<SearchShard>> {
+    let path = SitePath::SEARCH
+        .join("terms")?
+        .join(&format!("{key}.json"))?;
+    match File::open(path) {
+        Ok(file) => Ok(Some(serde_json::from_reader(file)?)),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error)?,
+    }
+}
+
+/// finds documents that match every term in the query and pass the tag and
+/// author filters, best match first. an empty query matches every document.
+pub fn search(
+    query: &SearchQuery,
+    documents: &[SearchDocument],
+    mut load_shard: impl FnMut(&str) -> eyre::Result<Option<SearchShard>>,
+) -> eyre::Result<SearchResults> {
+    let mut scores: Option<BTreeMap<usize, usize>> = None;
+    let mut shards = BTreeMap::default();
+    for query_term in tokenize(&query.q).collect::<BTreeSet<_>>() {
+        let key = shard_key(&query_term);
+        if !shards.contains_key(&key) {
+            shards.insert(key.clone(), load_shard(&key)?.unwrap_or_default());
+        }
+        let mut term_scores = BTreeMap::<usize, usize>::default();
+        for (_, postings) in shards[&key]
+            .range(query_term.clone()..)
+            .take_while(|(term, _)| term.starts_with(&query_term))
+        {
+            for &(document, weight) in postings {
+                *term_scores.entry(document).or_default() += weight;
+            }
+        }
+        scores = Some(match scores {
+            None => term_scores,
+            Some(scores) => scores
+                .into_iter()
+                .filter_map(|(document, score)| {
+                    Some((document, score + term_scores.get(&document)?))
+                })
+                .collect(),
+        });
+    }
+    let scores = scores.unwrap_or_else(|| (0..documents.len()).map(|i| (i, 0)).collect());
+
+    let tag = query.tag.as_deref().map(normalize_filter);
+    let author = query.author.as_deref().map(normalize_filter);
+    let mut matches = scores
+        .into_iter()
+        .filter(|&(document, _)| document < documents.len())
+        .filter(|&(document, _)| {
+            let document = &documents[document];
+            tag.as_ref().map_or(true, |tag| {
+                document.tags.iter().any(|t| normalize_filter(t) == *tag)
+            }) && author.as_ref().map_or(true, |author| {
+                document.author.as_ref().is_some_and(|a| {
+                    [&a.href, &a.display_handle, &a.display_name]
+                        .into_iter()
+                        .any(|name| normalize_filter(name) == *author)
+                })
+            })
+        })
+        .collect::<Vec<_>>();
+    matches.sort_by(|(p, p_score), (q, q_score)| q_score.cmp(p_score).then(p.cmp(q)));
+
+    Ok(SearchResults {
+        total: matches.len(),
+        documents: matches
+            .into_iter()
+            .take(SEARCH_RESULT_LIMIT)
+            .map(|(document, _)| documents[document].clone())
+            .collect(),
+    })
+}
+
+/// splits text into lowercase terms, which are runs of alphanumeric characters.
+/// must match `tokenize()` in script.js.
+pub fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(|term| term.to_lowercase())
+}
+
+/// the shard for a term is named after its first code point, in hex.
+/// must match `shardKey()` in script.js.
+fn shard_key(term: &str) -> String {
+    term.chars()
+        .next()
+        .map_or_else(String::new, |c| format!("{:x}", c as u32))
+}
+
+fn normalize_filter(value: &str) -> String {
+    value
+        .trim()
+        .trim_start_matches('#')
+        .trim_start_matches('@')
+        .to_lowercase()
+}
+
+fn summary(text: &str) -> String {
+    if text.chars().count() <= SUMMARY_LENGTH {
+        return text.to_owned();
+    }
+    let truncated = text.chars().take(SUMMARY_LENGTH).collect::<String>();
+    let truncated = truncated
+        .rsplit_once(' ')
+        .map_or(&*truncated, |(truncated, _)| truncated);
+
+    format!("{truncated}…")
+}
+
+#[test]
+fn test_tokenize() {
+    assert_eq!(
+        tokenize("Hello, World! it’s 2024—ÇA VA?").collect::<Vec<_>>(),
+        ["hello", "world", "it", "s", "2024", "ça", "va"]
+    );
+    assert_eq!(shard_key("ça"), "e7");
+    assert_eq!(shard_key("hello"), "68");
+}
+
+#[test]
+fn test_search() -> eyre::Result<()> {
+    use crate::{PostMeta, TemplatedPost};
+
+    fn thread(path: &str, title: &str, tags: &[&str], text: &str) -> eyre::Result<Thread> {
+        let post = TemplatedPost::filter(text, Some(crate::path::PostsPath::ROOT.join(path)?))?;
+        let meta = PostMeta {
+            title: Some(title.to_owned()),
+            tags: tags.iter().map(|tag| (*tag).to_owned()).collect(),
+            author: Some(crate::Author {
+                href: "https://example.com/".to_owned(),
+                name: "".to_owned(),
+                display_name: "Example".to_owned(),
+                display_handle: "example.com".to_owned(),
+            }),
+            ..post.meta.clone()
+        };
+        let post = TemplatedPost { meta, ..post };
+        Thread::try_from(post)
+    }
+
+    let mut index = SearchIndex::default();
+    index.add_thread(&thread(
+        "1.md",
+        "cats",
+        &["Pets"],
+        "<p>my cat is orange</p>",
+    )?)?;
+    index.add_thread(&thread(
+        "2.md",
+        "dogs",
+        &["pets"],
+        "<p>my dog likes cats</p>",
+    )?)?;
+    index.add_thread(&thread("3.md", "food", &[], "<p>orange juice</p>")?)?;
+    let urls = |query: SearchQuery| -> eyre::Result<Vec<String>> {
+        Ok(index
+            .search(&query)?
+            .documents
+            .into_iter()
+            .map(|document| document.url)
+            .collect())
+    };
+    let q = |q: &str| SearchQuery {
+        q: q.to_owned(),
+        ..Default::default()
+    };
+
+    // title matches rank first; prefixes match.
+    assert_eq!(urls(q("cat"))?, ["/1.html", "/2.html"]);
+    // every term must match.
+    assert_eq!(urls(q("Orange cat"))?, ["/1.html"]);
+    assert_eq!(urls(q("zebra"))?, Vec::<String>::new());
+    // filters are case-insensitive.
+    assert_eq!(
+        urls(SearchQuery {
+            tag: Some("#PETS".to_owned()),
+            ..q("orange")
+        })?,
+        ["/1.html"]
+    );
+    assert_eq!(
+        urls(SearchQuery {
+            author: Some("@Example.com".to_owned()),
+            ..q("")
+        })?,
+        ["/1.html", "/2.html", "/3.html"]
+    );
+
+    Ok(())
+}
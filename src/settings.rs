@@ -1,21 +1,43 @@
 use std::{
-    collections::{BTreeSet, HashMap},
+    collections::{BTreeMap, BTreeSet, HashMap},
+    env,
     fs::File,
     io::{BufRead, BufReader, Read},
+    net::{IpAddr, Ipv6Addr},
     path::{Path, PathBuf},
 };
 
 use jane_eyre::eyre::{self, bail};
 use serde::Deserialize;
+use syntect::highlighting::ThemeSet;
 use tracing::warn;
 
-use crate::{path::parse_path_relative_scheme_less_url_string, Author, TemplatedPost, Thread};
+use crate::{
+    check::{Lint, LintLevel},
+    path::parse_path_relative_scheme_less_url_string,
+    permalink::validate_permalink_pattern,
+    redirect::validate_redirect,
+    sanitise::{PostSource, SanitisationPolicy, SanitisationSettings},
+    Author, TemplatedPost, Thread,
+};
 
 #[derive(Deserialize)]
 pub struct Settings {
     pub base_url: String,
     pub external_base_url: String,
     pub server_port: Option<u16>,
+    server_bind_address: Option<IpAddr>,
+    server_password: Option<String>,
+    micropub_token: Option<String>,
+    micropub_endpoint: Option<String>,
+    webmention_endpoint: Option<String>,
+    webmention_send_on_publish: Option<bool>,
+    activitypub: Option<bool>,
+    activitypub_username: Option<String>,
+    activitypub_inbox: Option<bool>,
+    activitypub_deliver_on_publish: Option<bool>,
+    cohost_markdown: Option<bool>,
+    syntax_highlighting_theme: Option<String>,
     pub site_title: String,
     pub other_self_authors: Vec<String>,
     pub interesting_tags: Vec<Vec<String>>,
@@ -26,10 +48,25 @@ pub struct Settings {
     interesting_archived_threads_list: Option<Vec<String>>,
     excluded_archived_threads_list_path: Option<String>,
     excluded_archived_threads_list: Option<Vec<String>>,
+    exclude_adult_content_from_index: Option<bool>,
+    published_private_authors: Option<Vec<String>>,
     pub self_author: Option<Author>,
     pub renamed_tags: Option<HashMap<String, String>>,
+    pub emoji: Option<BTreeMap<String, String>>,
     pub implied_tags: Option<HashMap<String, Vec<String>>>,
     pub nav: Vec<NavLink>,
+    pub author_pages: Option<AuthorPages>,
+    archive_pages: Option<bool>,
+    all_tag_pages: Option<bool>,
+    all_tag_pages_min_count: Option<usize>,
+    search: Option<bool>,
+    robots_disallow: Option<Vec<String>>,
+    precompress: Option<bool>,
+    hashed_static_filenames: Option<bool>,
+    lints: Option<BTreeMap<Lint, LintLevel>>,
+    permalink_pattern: Option<String>,
+    redirects: Option<BTreeMap<String, String>>,
+    sanitisation: Option<BTreeMap<PostSource, SanitisationSettings>>,
 
     #[deprecated(since = "0.3.0", note = "use path_to_static")]
     path_to_autost: Option<String>,
@@ -42,6 +79,17 @@ pub struct TagDefinition {
     pub implies: Option<Vec<String>>,
 }
 
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthorPages {
+    /// only `self_author` and `other_self_authors`.
+    SelfAuthors,
+    /// only `other_self_authors`.
+    OtherSelfAuthors,
+    /// every author, including authors of archived or imported posts.
+    All,
+}
+
 #[derive(Deserialize)]
 pub struct NavLink {
     pub href: String,
@@ -74,6 +122,22 @@ impl Settings {
         if !result.external_base_url.ends_with("/") {
             bail!("external_base_url setting must end with slash!");
         }
+        if let Some(theme) = result.syntax_highlighting_theme.as_deref() {
+            let themes = ThemeSet::load_defaults().themes;
+            if !themes.contains_key(theme) {
+                let themes = themes.keys().map(|theme| &**theme).collect::<Vec<_>>();
+                bail!("syntax_highlighting_theme setting must be one of: {themes:?}");
+            }
+        }
+        if let Some(pattern) = result.permalink_pattern.as_deref() {
+            validate_permalink_pattern(pattern)?;
+        }
+        for (from, to) in result.redirects() {
+            validate_redirect(from, to)?;
+        }
+        for settings in result.sanitisation.iter().flat_map(|s| s.values()) {
+            settings.validate()?;
+        }
         if let Some(path) = result.archived_thread_tags_path.as_ref() {
             let entries = BufReader::new(File::open(path)?)
                 .lines()
@@ -138,6 +202,101 @@ impl Settings {
         self.server_port.unwrap_or(8420)
     }
 
+    pub fn server_bind_address(&self) -> IpAddr {
+        self.server_bind_address
+            .unwrap_or(IpAddr::V6(Ipv6Addr::LOCALHOST))
+    }
+
+    /// returns the password (or token) for `autost server`, from the `AUTOST_SERVER_PASSWORD`
+    /// environment variable or the `server_password` setting.
+    pub fn server_password(&self) -> Option<String> {
+        env::var("AUTOST_SERVER_PASSWORD")
+            .ok()
+            .or_else(|| self.server_password.clone())
+            .filter(|password| !password.is_empty())
+    }
+
+    /// returns the token for the micropub endpoint in `autost server`, from the
+    /// `AUTOST_MICROPUB_TOKEN` environment variable or the `micropub_token` setting, falling back
+    /// to [`Settings::server_password`].
+    pub fn micropub_token(&self) -> Option<String> {
+        env::var("AUTOST_MICROPUB_TOKEN")
+            .ok()
+            .or_else(|| self.micropub_token.clone())
+            .filter(|token| !token.is_empty())
+            .or_else(|| self.server_password())
+    }
+
+    /// returns the micropub endpoint to advertise in `<link rel="micropub">`, if any.
+    pub fn micropub_endpoint(&self) -> Option<&str> {
+        self.micropub_endpoint.as_deref()
+    }
+
+    /// returns the webmention endpoint to advertise in `<link rel="webmention">`, if any.
+    pub fn webmention_endpoint(&self) -> Option<&str> {
+        self.webmention_endpoint.as_deref()
+    }
+
+    pub fn webmention_send_on_publish(&self) -> bool {
+        self.webmention_send_on_publish.unwrap_or(false)
+    }
+
+    pub fn activitypub(&self) -> bool {
+        self.activitypub.unwrap_or(false)
+    }
+
+    /// returns the username in `acct:<username>@<host>`, for following the blog.
+    pub fn activitypub_username(&self) -> &str {
+        self.activitypub_username.as_deref().unwrap_or("blog")
+    }
+
+    pub fn activitypub_inbox(&self) -> bool {
+        self.activitypub() && self.activitypub_inbox.unwrap_or(false)
+    }
+
+    pub fn activitypub_deliver_on_publish(&self) -> bool {
+        self.activitypub() && self.activitypub_deliver_on_publish.unwrap_or(false)
+    }
+
+    pub fn cohost_markdown(&self) -> bool {
+        self.cohost_markdown.unwrap_or(false)
+    }
+
+    pub fn syntax_highlighting_theme(&self) -> Option<&str> {
+        self.syntax_highlighting_theme.as_deref()
+    }
+
+    pub fn lint_level(&self, lint: Lint) -> LintLevel {
+        self.lints
+            .as_ref()
+            .and_then(|lints| lints.get(&lint))
+            .copied()
+            .unwrap_or(lint.default_level())
+    }
+
+    pub fn permalink_pattern(&self) -> Option<&str> {
+        self.permalink_pattern.as_deref()
+    }
+
+    /// returns the `[redirects]`, from paths relative to `base_url`, to paths relative to
+    /// `base_url` or absolute urls.
+    pub fn redirects(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.redirects
+            .iter()
+            .flatten()
+            .map(|(from, to)| (&**from, &**to))
+    }
+
+    /// returns the sanitisation policy for posts from the given source.
+    pub fn sanitisation_policy(&self, source: PostSource) -> SanitisationPolicy {
+        let settings = self
+            .sanitisation
+            .as_ref()
+            .and_then(|sanitisation| sanitisation.get(&source));
+
+        SanitisationPolicy::new(source, settings)
+    }
+
     pub fn page_title(&self, title: Option<&str>) -> String {
         match title {
             Some(title) => format!("{} — {}", title, self.site_title),
@@ -158,12 +317,62 @@ impl Settings {
             || self.other_self_authors.iter().any(|a| *a == author.href)
     }
 
+    pub fn author_has_page(&self, author: &Author) -> bool {
+        match self.author_pages {
+            None => false,
+            Some(AuthorPages::SelfAuthors) => self.is_any_self_author(author),
+            Some(AuthorPages::OtherSelfAuthors) => {
+                self.other_self_authors.iter().any(|a| *a == author.href)
+            }
+            Some(AuthorPages::All) => true,
+        }
+    }
+
+    pub fn exclude_adult_content_from_index(&self) -> bool {
+        self.exclude_adult_content_from_index.unwrap_or(false)
+    }
+
+    pub fn archive_pages(&self) -> bool {
+        self.archive_pages.unwrap_or(false)
+    }
+
     pub fn tag_is_interesting(&self, tag: &str) -> bool {
         self.interesting_tags_iter()
             .find(|&interesting_tag| interesting_tag == tag)
             .is_some()
     }
 
+    /// returns true iff the given tag should get a tag page, given the number of interesting
+    /// threads with that tag.
+    pub fn tag_gets_page(&self, tag: &str, thread_count: usize) -> bool {
+        self.tag_is_interesting(tag)
+            || (self.all_tag_pages() && thread_count >= self.all_tag_pages_min_count.unwrap_or(1))
+    }
+
+    pub fn all_tag_pages(&self) -> bool {
+        self.all_tag_pages.unwrap_or(false)
+    }
+
+    pub fn search(&self) -> bool {
+        self.search.unwrap_or(false)
+    }
+
+    pub fn precompress(&self) -> bool {
+        self.precompress.unwrap_or(false)
+    }
+
+    pub fn hashed_static_filenames(&self) -> bool {
+        self.hashed_static_filenames.unwrap_or(false)
+    }
+
+    /// returns the `Disallow` paths for robots.txt, which are relative to `base_url`.
+    pub fn robots_disallow_paths(&self) -> impl Iterator<Item = String> + '_ {
+        self.robots_disallow
+            .iter()
+            .flatten()
+            .map(|path| format!("{}{}", self.base_url, path.trim_start_matches('/')))
+    }
+
     pub fn interesting_tags_iter(&self) -> impl Iterator<Item = &str> {
         self.interesting_tags.iter().flatten().map(|tag| &**tag)
     }
@@ -186,6 +395,20 @@ impl Settings {
             .is_some_and(|(list, archived)| list.iter().any(|x| x == archived))
     }
 
+    /// returns true iff any post in the thread is by a private or logged-in-only cohost project,
+    /// unless that author is in `published_private_authors`.
+    pub fn thread_is_private(&self, thread: &Thread) -> bool {
+        thread.posts.iter().any(|post| {
+            post.meta.is_author_private()
+                && !post.meta.author.as_ref().is_some_and(|author| {
+                    self.published_private_authors
+                        .iter()
+                        .flatten()
+                        .any(|href| *href == author.href)
+                })
+        })
+    }
+
     pub fn extra_archived_thread_tags(&self, post: &TemplatedPost) -> &[String] {
         self.archived_thread_tags
             .as_ref()
@@ -297,6 +520,86 @@ fn test_resolve_tags() -> eyre::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_author_has_page() -> eyre::Result<()> {
+    let mut settings = Settings::load_example()?;
+    let author = |href: &str| Author {
+        href: href.to_owned(),
+        name: "".to_owned(),
+        display_name: "".to_owned(),
+        display_handle: "".to_owned(),
+    };
+    let main = author("https://example.com");
+    let other = author("https://cohost.org/staff");
+    let archived = author("https://cohost.org/catball");
+
+    settings.author_pages = None;
+    assert!(!settings.author_has_page(&main));
+    assert!(!settings.author_has_page(&other));
+    assert!(!settings.author_has_page(&archived));
+
+    settings.author_pages = Some(AuthorPages::SelfAuthors);
+    assert!(settings.author_has_page(&main));
+    assert!(settings.author_has_page(&other));
+    assert!(!settings.author_has_page(&archived));
+
+    settings.author_pages = Some(AuthorPages::OtherSelfAuthors);
+    assert!(!settings.author_has_page(&main));
+    assert!(settings.author_has_page(&other));
+    assert!(!settings.author_has_page(&archived));
+
+    settings.author_pages = Some(AuthorPages::All);
+    assert!(settings.author_has_page(&main));
+    assert!(settings.author_has_page(&other));
+    assert!(settings.author_has_page(&archived));
+
+    Ok(())
+}
+
+#[test]
+fn test_tag_gets_page() -> eyre::Result<()> {
+    let mut settings = Settings::load_example()?;
+    assert!(settings.tag_gets_page("photography", 1));
+    assert!(!settings.tag_gets_page("cats", 5));
+
+    settings.all_tag_pages = Some(true);
+    assert!(settings.tag_gets_page("photography", 1));
+    assert!(settings.tag_gets_page("cats", 1));
+
+    settings.all_tag_pages_min_count = Some(3);
+    assert!(settings.tag_gets_page("photography", 1));
+    assert!(!settings.tag_gets_page("cats", 2));
+    assert!(settings.tag_gets_page("cats", 3));
+
+    Ok(())
+}
+
+#[test]
+fn test_thread_is_private() -> eyre::Result<()> {
+    let mut settings = Settings::load_example()?;
+    let thread = |meta: &str| -> eyre::Result<Thread> {
+        let author = r#"<link rel="author" href="https://cohost.org/catball" name="catball">"#;
+        Thread::try_from(TemplatedPost::filter(&format!("{author}{meta}"), None)?)
+    };
+    let public = thread(
+        r#"<meta name="author_privacy" content="public"><meta name="author_logged_out_post_visibility" content="public">"#,
+    )?;
+    let private = thread(r#"<meta name="author_privacy" content="private">"#)?;
+    let logged_in_only = thread(
+        r#"<meta name="author_privacy" content="public"><meta name="author_logged_out_post_visibility" content="none">"#,
+    )?;
+    assert!(!settings.thread_is_private(&thread("")?));
+    assert!(!settings.thread_is_private(&public));
+    assert!(settings.thread_is_private(&private));
+    assert!(settings.thread_is_private(&logged_in_only));
+
+    settings.published_private_authors = Some(vec!["https://cohost.org/catball".to_owned()]);
+    assert!(!settings.thread_is_private(&private));
+    assert!(!settings.thread_is_private(&logged_in_only));
+
+    Ok(())
+}
+
 #[test]
 fn test_base_url_path_components() -> eyre::Result<()> {
     let mut settings = Settings::load_example()?;
@@ -313,3 +616,15 @@ fn test_base_url_path_components() -> eyre::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_lint_level() -> eyre::Result<()> {
+    let mut settings = Settings::load_example()?;
+    assert_eq!(settings.lint_level(Lint::BadDate), LintLevel::Warn);
+    settings.lints = Some(toml::from_str("bad_date = \"deny\"")?);
+    assert_eq!(settings.lint_level(Lint::BadDate), LintLevel::Deny);
+    assert_eq!(settings.lint_level(Lint::MissingReference), LintLevel::Deny);
+    assert!(toml::from_str::<BTreeMap<Lint, LintLevel>>("bad_daet = \"deny\"").is_err());
+
+    Ok(())
+}
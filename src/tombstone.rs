@@ -0,0 +1,160 @@
+//! tombstones for deleted posts, so their pages say they were deleted instead of 404ing, and
+//! feed readers can drop their entries (<https://www.rfc-editor.org/rfc/rfc6721>).
+//!
+//! - `tombstones.json` has a tombstone for each deleted post, keyed by page
+//! - `site/<page>` is the tombstone page, unless another post has been rendered there since
+
+use std::{
+    collections::BTreeMap,
+    fs::{read_to_string, remove_file, File},
+    io::{ErrorKind, Write},
+    path::PathBuf,
+    sync::Mutex,
+};
+
+use chrono::{SecondsFormat, Utc};
+use jane_eyre::eyre::{self, Context};
+use serde::{Deserialize, Serialize};
+
+use crate::{path::PostsPath, PostMeta, TemplatedPost};
+
+/// guards the store, which `autost server` can write from several requests at once.
+static STORE_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Tombstone {
+    /// base-relative url of the page the post was rendered to, like `1.html`.
+    pub page: String,
+    /// atom entry id the post had in feeds.
+    pub entry_id: String,
+    /// when the post was deleted, as an rfc 3339 timestamp.
+    pub deleted: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// tags the post had, so it can be removed from their feeds too.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct TombstoneStore {
+    path: PathBuf,
+}
+
+impl Tombstone {
+    /// creates a tombstone for a post that is about to be deleted, or None if it has no page.
+    pub fn new(path: &PostsPath, meta: &PostMeta) -> eyre::Result<Option<Self>> {
+        let (Some(rendered_path), Some(default_rendered_path)) = (
+            path.rendered_path_with_meta(meta)?,
+            path.default_rendered_path()?,
+        ) else {
+            return Ok(None);
+        };
+
+        Ok(Some(Self {
+            page: rendered_path.base_relative_url(),
+            entry_id: default_rendered_path.atom_feed_entry_id(),
+            deleted: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+            title: meta.title.clone(),
+            tags: meta.tags.clone(),
+        }))
+    }
+}
+
+impl Default for TombstoneStore {
+    fn default() -> Self {
+        Self::new("tombstones.json")
+    }
+}
+
+impl TombstoneStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// returns all tombstones, keyed by page.
+    pub fn load_all(&self) -> eyre::Result<BTreeMap<String, Tombstone>> {
+        let _guard = STORE_LOCK.lock().unwrap();
+        self.load_all_unlocked()
+    }
+
+    pub fn load(&self, page: &str) -> eyre::Result<Option<Tombstone>> {
+        Ok(self.load_all()?.remove(page))
+    }
+
+    pub fn add(&self, tombstone: Tombstone) -> eyre::Result<()> {
+        let _guard = STORE_LOCK.lock().unwrap();
+        let mut tombstones = self.load_all_unlocked()?;
+        tombstones.insert(tombstone.page.clone(), tombstone);
+
+        self.save_unlocked(&tombstones)
+    }
+
+    fn load_all_unlocked(&self) -> eyre::Result<BTreeMap<String, Tombstone>> {
+        match read_to_string(&self.path) {
+            Ok(json) => serde_json::from_str(&json)
+                .wrap_err_with(|| format!("failed to parse {:?}", self.path)),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(BTreeMap::default()),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    fn save_unlocked(&self, tombstones: &BTreeMap<String, Tombstone>) -> eyre::Result<()> {
+        let mut file = File::create(&self.path)?;
+        writeln!(file, "{}", serde_json::to_string_pretty(tombstones)?)?;
+
+        Ok(())
+    }
+}
+
+/// deletes a post and its rendered page, leaving a tombstone in its place, and returns the
+/// page it was rendered to (if any), which can’t be worked out once the post is gone.
+///
+/// this does not check if other posts refer to it (see `find_referencing_posts`).
+pub fn delete_post(store: &TombstoneStore, path: &PostsPath) -> eyre::Result<Option<String>> {
+    let post = TemplatedPost::load(path)?;
+    let tombstone = Tombstone::new(path, &post.meta)?;
+    let rendered_path = post.rendered_path;
+    remove_file(path).wrap_err("failed to delete post file")?;
+    if let Some(rendered_path) = &rendered_path {
+        match remove_file(rendered_path) {
+            Err(error) if error.kind() != ErrorKind::NotFound => {
+                Err(error).wrap_err("failed to delete rendered post")?
+            }
+            _ => {}
+        }
+    }
+    if let Some(tombstone) = tombstone {
+        store.add(tombstone)?;
+    }
+
+    Ok(rendered_path.map(|path| path.base_relative_url()))
+}
+
+#[test]
+fn test_tombstone_store() -> eyre::Result<()> {
+    let path = std::env::temp_dir().join(format!(
+        "autost-test-tombstones-{}.json",
+        uuid::Uuid::new_v4()
+    ));
+    let store = TombstoneStore::new(&path);
+    let tombstone = |page: &str| Tombstone {
+        page: page.to_owned(),
+        entry_id: page.to_owned(),
+        deleted: "2024-10-01T00:00:00Z".to_owned(),
+        ..Default::default()
+    };
+
+    assert_eq!(store.load_all()?, BTreeMap::default());
+    store.add(tombstone("1.html"))?;
+    store.add(tombstone("2024/10/foo.html"))?;
+    assert_eq!(
+        store.load_all()?.keys().collect::<Vec<_>>(),
+        ["1.html", "2024/10/foo.html"]
+    );
+    assert_eq!(store.load("1.html")?, Some(tombstone("1.html")));
+    assert_eq!(store.load("2.html")?, None);
+    std::fs::remove_file(path)?;
+
+    Ok(())
+}
@@ -0,0 +1,1089 @@
+//! webmentions (<https://www.w3.org/TR/webmention/>) received by `autost server`,
+//! and sent by `autost webmention send`.
+//!
+//! - mentions are verified by fetching the source, which must link to the target
+//! - the source is parsed as an h-entry, to find its author, content, and kind
+//! - new mentions are pending until approved with `autost webmention approve`
+//! - mentions are stored in `webmentions/<page>.json`, keyed by the rendered page
+//! - mentions we sent are logged in `webmentions/sent/<page>.json`, with a hash of the
+//!   post, so that we only send them again when the post changes or gets deleted
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs::{create_dir_all, read_dir, read_to_string, remove_file, File},
+    io::{ErrorKind, Read, Write},
+    path::{Path, PathBuf},
+    rc::Rc,
+    sync::Mutex,
+};
+
+use chrono::{SecondsFormat, Utc};
+use jane_eyre::eyre::{self, bail, OptionExt};
+use markup5ever_rcdom::{Handle, NodeData};
+use serde::{Deserialize, Serialize};
+use sha2::{digest::generic_array::functional::FunctionalSequence, Digest, Sha256};
+use tracing::{info, warn};
+use url::Url;
+
+use crate::{
+    command::import::{
+        document_base_href, has_class, mf2_dt, mf2_find, mf2_find_all, mf2_p, mf2_u,
+    },
+    dom::{parse_html_document, parse_html_fragment, AttrsRefExt, BreadthTraverse, DepthTraverse},
+    net::PublicClient,
+    path::PostsPath,
+    TemplatedPost, SETTINGS,
+};
+
+/// content longer than this many characters is truncated.
+const MAX_CONTENT_CHARS: usize = 500;
+
+/// sources larger than this many bytes are not verified.
+const MAX_SOURCE_BYTES: u64 = 1 << 20;
+
+/// held while reading and writing mention files, so concurrent mentions don’t clobber each other.
+static STORE_LOCK: Mutex<()> = Mutex::new(());
+
+/// fetches webmention sources and targets, and sends webmentions. implement
+/// this to stub out the network in tests.
+pub trait WebmentionFetcher: Send + Sync {
+    fn fetch(&self, url: &str) -> eyre::Result<FetchedPage>;
+
+    /// posts a urlencoded form, returning the response status.
+    fn post_form(&self, url: &str, form: &[(&str, &str)]) -> eyre::Result<u16>;
+}
+
+/// fetches sources with a [`PublicClient`], following redirects.
+pub struct RealWebmentionFetcher;
+
+#[derive(Clone, Debug, Default)]
+pub struct FetchedPage {
+    pub status: u16,
+    /// url after redirects.
+    pub url: String,
+    /// header names are lowercase.
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WebmentionStatus {
+    #[default]
+    Pending,
+    Approved,
+    Rejected,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WebmentionKind {
+    Reply,
+    Like,
+    Repost,
+    #[default]
+    Mention,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Webmention {
+    pub source: String,
+    pub target: String,
+    pub status: WebmentionStatus,
+    pub kind: WebmentionKind,
+    /// url of the mentioning post, from its u-url, or else the source.
+    pub url: String,
+    pub author: Option<WebmentionAuthor>,
+    /// plain text, possibly truncated.
+    pub content: Option<String>,
+    pub published: Option<String>,
+    pub received: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct WebmentionAuthor {
+    pub name: Option<String>,
+    pub url: Option<String>,
+}
+
+/// the result of verifying a mention.
+#[derive(Debug, PartialEq)]
+pub enum Verified {
+    Valid(Webmention),
+    /// the source was deleted or no longer links to the target.
+    Gone,
+}
+
+/// log of the mentions we sent for one of our pages.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct SentWebmentions {
+    pub source: String,
+    /// hash of the post when we last sent mentions for it.
+    pub hash: String,
+    pub targets: BTreeSet<String>,
+}
+
+/// the links in one of our posts that we can send mentions for.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OutgoingLinks {
+    /// base-relative url of the rendered page, like `10000000.html`.
+    pub page: String,
+    pub source: String,
+    pub hash: String,
+    pub targets: BTreeSet<String>,
+}
+
+/// stores mentions as one json file per target page.
+#[derive(Clone, Debug)]
+pub struct WebmentionStore {
+    dir: PathBuf,
+}
+
+impl WebmentionFetcher for RealWebmentionFetcher {
+    fn fetch(&self, url: &str) -> eyre::Result<FetchedPage> {
+        let response = PublicClient::new()?.get(url)?.send()?;
+        let status = response.status().as_u16();
+        let url = response.url().to_string();
+        let headers = response
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_owned())))
+            .collect();
+        let mut body = vec![];
+        response.take(MAX_SOURCE_BYTES + 1).read_to_end(&mut body)?;
+        if body.len() as u64 > MAX_SOURCE_BYTES {
+            bail!("source is too large: {url}");
+        }
+
+        Ok(FetchedPage {
+            status,
+            url,
+            headers,
+            body,
+        })
+    }
+
+    fn post_form(&self, url: &str, form: &[(&str, &str)]) -> eyre::Result<u16> {
+        let response = PublicClient::new()?.post(url)?.form(form).send()?;
+
+        Ok(response.status().as_u16())
+    }
+}
+
+impl FetchedPage {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| &**value)
+    }
+
+    pub fn is_html(&self) -> bool {
+        self.header("content-type")
+            .map_or(true, |content_type| content_type.contains("html"))
+    }
+}
+
+impl WebmentionKind {
+    /// how to describe the mention in the responses section.
+    pub fn verb(&self) -> &'static str {
+        match self {
+            WebmentionKind::Reply => "replied",
+            WebmentionKind::Like => "liked this",
+            WebmentionKind::Repost => "reposted this",
+            WebmentionKind::Mention => "mentioned this",
+        }
+    }
+}
+
+impl Default for WebmentionStore {
+    fn default() -> Self {
+        Self::new("webmentions")
+    }
+}
+
+impl WebmentionStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// `page` is the base-relative url of the rendered page, like `1.html` or `2024/10/foo.html`.
+    fn path(&self, page: &str) -> eyre::Result<PathBuf> {
+        let Some(basename) = page.strip_suffix(".html") else {
+            bail!("not a page url: {page}");
+        };
+        if basename.contains('\\')
+            || basename
+                .split('/')
+                .any(|component| component.is_empty() || component.starts_with('.'))
+        {
+            bail!("not a page url: {page}");
+        }
+
+        // pages with a `permalink_pattern` can be in subdirectories, but the store is flat.
+        Ok(self
+            .dir
+            .join(format!("{}.json", basename.replace('/', "%2F"))))
+    }
+
+    pub fn load(&self, page: &str) -> eyre::Result<Vec<Webmention>> {
+        let _guard = STORE_LOCK.lock().unwrap();
+        self.load_unlocked(&self.path(page)?)
+    }
+
+    /// returns all mentions, keyed by page.
+    pub fn load_all(&self) -> eyre::Result<BTreeMap<String, Vec<Webmention>>> {
+        let _guard = STORE_LOCK.lock().unwrap();
+        self.load_all_unlocked()
+    }
+
+    fn load_all_unlocked(&self) -> eyre::Result<BTreeMap<String, Vec<Webmention>>> {
+        let mut result = BTreeMap::default();
+        let entries = match read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(result),
+            Err(error) => return Err(error.into()),
+        };
+        for entry in entries {
+            let path = entry?.path();
+            let Some(basename) = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_suffix(".json"))
+            else {
+                continue;
+            };
+            result.insert(
+                format!("{}.html", basename.replace("%2F", "/")),
+                self.load_unlocked(&path)?,
+            );
+        }
+
+        Ok(result)
+    }
+
+    /// returns the approved mentions of a page, for rendering.
+    pub fn approved(&self, page: &str) -> eyre::Result<Vec<Webmention>> {
+        let mut result = self.load(page)?;
+        result.retain(|mention| mention.status == WebmentionStatus::Approved);
+
+        Ok(result)
+    }
+
+    /// adds, updates, or removes a mention of `page` after verifying it.
+    /// updated mentions keep their moderation status.
+    pub fn receive(
+        &self,
+        page: &str,
+        source: &str,
+        target: &str,
+        verified: Verified,
+    ) -> eyre::Result<()> {
+        let _guard = STORE_LOCK.lock().unwrap();
+        let path = self.path(page)?;
+        let mut mentions = self.load_unlocked(&path)?;
+        let existing = mentions
+            .iter()
+            .position(|mention| mention.source == source && mention.target == target);
+        match (verified, existing) {
+            (Verified::Valid(mut mention), Some(index)) => {
+                info!("updating webmention of {page} from {source}");
+                mention.status = mentions[index].status;
+                mentions[index] = mention;
+            }
+            (Verified::Valid(mention), None) => {
+                info!("new webmention of {page} from {source}");
+                mentions.push(mention);
+            }
+            (Verified::Gone, Some(index)) => {
+                info!("removing webmention of {page} from {source}");
+                mentions.remove(index);
+            }
+            (Verified::Gone, None) => return Ok(()),
+        }
+
+        self.save_unlocked(&path, &mentions)
+    }
+
+    /// sets the status of mentions from `source`, optionally only those of `target`.
+    /// returns the number of mentions changed.
+    pub fn set_status(
+        &self,
+        source: &str,
+        target: Option<&str>,
+        status: WebmentionStatus,
+    ) -> eyre::Result<usize> {
+        let _guard = STORE_LOCK.lock().unwrap();
+        let mut result = 0;
+        for (page, mut mentions) in self.load_all_unlocked()? {
+            let mut changed = false;
+            for mention in mentions.iter_mut() {
+                if mention.source == source
+                    && target.map_or(true, |target| mention.target == target)
+                {
+                    mention.status = status;
+                    changed = true;
+                    result += 1;
+                }
+            }
+            if changed {
+                self.save_unlocked(&self.path(&page)?, &mentions)?;
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn load_unlocked(&self, path: &Path) -> eyre::Result<Vec<Webmention>> {
+        match read_to_string(path) {
+            Ok(json) => Ok(serde_json::from_str(&json)?),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(vec![]),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    fn sent_path(&self, page: &str) -> eyre::Result<PathBuf> {
+        let path = self.path(page)?;
+        let filename = path.file_name().ok_or_eyre("path has no filename")?;
+
+        Ok(self.dir.join("sent").join(filename))
+    }
+
+    /// returns the log of mentions we sent for `page`, if any.
+    pub fn load_sent(&self, page: &str) -> eyre::Result<Option<SentWebmentions>> {
+        let _guard = STORE_LOCK.lock().unwrap();
+        match read_to_string(self.sent_path(page)?) {
+            Ok(json) => Ok(Some(serde_json::from_str(&json)?)),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// returns the pages we have sent mentions for, including deleted pages we haven’t sent the deletion for.
+    pub fn sent_pages(&self) -> eyre::Result<Vec<String>> {
+        let mut result = vec![];
+        let entries = match read_dir(self.dir.join("sent")) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(result),
+            Err(error) => return Err(error.into()),
+        };
+        for entry in entries {
+            let path = entry?.path();
+            if let Some(basename) = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_suffix(".json"))
+            {
+                result.push(format!("{}.html", basename.replace("%2F", "/")));
+            }
+        }
+        result.sort();
+
+        Ok(result)
+    }
+
+    pub fn save_sent(&self, page: &str, sent: &SentWebmentions) -> eyre::Result<()> {
+        let _guard = STORE_LOCK.lock().unwrap();
+        let path = self.sent_path(page)?;
+        create_dir_all(path.parent().ok_or_eyre("path has no parent")?)?;
+        let mut file = File::create(path)?;
+        writeln!(file, "{}", serde_json::to_string_pretty(sent)?)?;
+
+        Ok(())
+    }
+
+    /// forgets the mentions we sent for `page`, once we have sent them for its deletion.
+    pub fn remove_sent(&self, page: &str) -> eyre::Result<()> {
+        let _guard = STORE_LOCK.lock().unwrap();
+        match remove_file(self.sent_path(page)?) {
+            Err(error) if error.kind() != ErrorKind::NotFound => Err(error.into()),
+            _ => Ok(()),
+        }
+    }
+
+    fn save_unlocked(&self, path: &Path, mentions: &[Webmention]) -> eyre::Result<()> {
+        if mentions.is_empty() {
+            return match remove_file(path) {
+                Err(error) if error.kind() != ErrorKind::NotFound => Err(error.into()),
+                _ => Ok(()),
+            };
+        }
+        create_dir_all(&self.dir)?;
+        let mut file = File::create(path)?;
+        writeln!(file, "{}", serde_json::to_string_pretty(mentions)?)?;
+
+        Ok(())
+    }
+}
+
+impl OutgoingLinks {
+    /// returns the links in one of our posts, or None if the post is not ours or has no page.
+    /// links include replies to imported posts, via the `archived` url of the referenced post.
+    pub fn load(path: &PostsPath) -> eyre::Result<Option<Self>> {
+        let post = TemplatedPost::load(path)?;
+        if !post.meta.is_main_self_author(&SETTINGS) {
+            return Ok(None);
+        }
+        let Some(rendered_path) = path.rendered_path()? else {
+            return Ok(None);
+        };
+
+        let mut targets = BTreeSet::default();
+        let dom = parse_html_fragment(post.safe_html.as_bytes())?;
+        for node in BreadthTraverse::elements(dom.document.clone()) {
+            let NodeData::Element { attrs, .. } = &node.data else {
+                unreachable!()
+            };
+            if let Some(href) = attrs.borrow().attr_str("href")? {
+                targets.extend(outgoing_target(href));
+            }
+        }
+        for reference in post.meta.references.iter() {
+            let reference = TemplatedPost::load(reference)?;
+            if let Some(archived) = reference.meta.archived.as_deref() {
+                targets.extend(outgoing_target(archived));
+            }
+        }
+        let hash = Sha256::digest(post.original_html.as_bytes())
+            .map(|o| format!("{o:02x}"))
+            .join("");
+
+        Ok(Some(Self {
+            page: rendered_path.base_relative_url(),
+            source: rendered_path.external_url(),
+            hash,
+            targets,
+        }))
+    }
+}
+
+/// returns the url without its fragment, if it can be sent a webmention.
+fn outgoing_target(url: &str) -> Option<String> {
+    let url = without_fragment(Url::parse(url).ok()?);
+    if !is_http_url(&url) || url.as_str().starts_with(&SETTINGS.external_base_url) {
+        return None;
+    }
+
+    Some(url.to_string())
+}
+
+/// sends webmentions for `page`, or for its deletion if `links` is None. targets
+/// that are no longer linked to are sent mentions too, so they can remove theirs.
+/// does nothing if the post hasn’t changed since the last send. once a deletion
+/// has been sent, the log for the page is removed. returns the number of mentions sent.
+pub fn send(
+    fetcher: &dyn WebmentionFetcher,
+    store: &WebmentionStore,
+    page: &str,
+    links: Option<&OutgoingLinks>,
+    dry_run: bool,
+) -> eyre::Result<usize> {
+    let previous = store.load_sent(page)?;
+    let (source, mut targets) = match (links, &previous) {
+        (Some(links), Some(previous)) if previous.hash == links.hash => return Ok(0),
+        (Some(links), _) => (links.source.clone(), links.targets.clone()),
+        (None, Some(previous)) => (previous.source.clone(), BTreeSet::default()),
+        (None, None) => return Ok(0),
+    };
+    if let Some(previous) = &previous {
+        targets.extend(previous.targets.iter().cloned());
+    }
+
+    let mut result = 0;
+    let mut failed = false;
+    for target in targets.iter() {
+        let endpoint = match discover_endpoint(fetcher, target) {
+            Ok(Some(endpoint)) => endpoint,
+            Ok(None) => continue,
+            Err(error) => {
+                warn!(?error, "failed to discover webmention endpoint of {target}");
+                failed = true;
+                continue;
+            }
+        };
+        if dry_run {
+            info!("would send webmention: {source} -> {target} (endpoint {endpoint})");
+            result += 1;
+            continue;
+        }
+        info!("sending webmention: {source} -> {target} (endpoint {endpoint})");
+        match fetcher.post_form(
+            endpoint.as_str(),
+            &[("source", &source), ("target", target)],
+        ) {
+            Ok(status) if (200..300).contains(&status) => result += 1,
+            Ok(status) => {
+                warn!("webmention endpoint {endpoint} returned status {status}");
+                failed = true;
+            }
+            Err(error) => {
+                warn!(?error, "failed to send webmention to {endpoint}");
+                failed = true;
+            }
+        }
+    }
+
+    // if anything failed, keep the old log, so we try again next time.
+    if !dry_run && !failed {
+        match links {
+            Some(links) => store.save_sent(
+                page,
+                &SentWebmentions {
+                    source,
+                    hash: links.hash.clone(),
+                    targets: links.targets.clone(),
+                },
+            )?,
+            None => store.remove_sent(page)?,
+        }
+    }
+
+    Ok(result)
+}
+
+/// sends webmentions for one of our posts, or for its deletion if the post no longer exists.
+pub fn send_for_post(
+    fetcher: &dyn WebmentionFetcher,
+    store: &WebmentionStore,
+    path: &PostsPath,
+    dry_run: bool,
+) -> eyre::Result<usize> {
+    if path.as_ref().exists() {
+        let Some(links) = OutgoingLinks::load(path)? else {
+            return Ok(0);
+        };
+        send(fetcher, store, &links.page, Some(&links), dry_run)
+    } else {
+        let Some(rendered_path) = path.rendered_path()? else {
+            return Ok(0);
+        };
+        send(
+            fetcher,
+            store,
+            &rendered_path.base_relative_url(),
+            None,
+            dry_run,
+        )
+    }
+}
+
+/// finds the webmention endpoint of `target`, from its `Link` header, or else
+/// the first `<link>` or `<a>` with `rel="webmention"`.
+pub fn discover_endpoint(
+    fetcher: &dyn WebmentionFetcher,
+    target: &str,
+) -> eyre::Result<Option<Url>> {
+    let page = fetcher.fetch(target)?;
+    if !(200..300).contains(&page.status) {
+        return Ok(None);
+    }
+    let url = Url::parse(&page.url)?;
+    for (name, value) in page.headers.iter() {
+        if name.eq_ignore_ascii_case("link") {
+            if let Some(href) = webmention_link_header_href(value) {
+                return Ok(Some(url.join(href)?));
+            }
+        }
+    }
+    if !page.is_html() {
+        return Ok(None);
+    }
+
+    let dom = parse_html_document(&page.body)?;
+    let base_href = document_base_href(dom.document.clone(), &url)?;
+    for node in DepthTraverse::elements(dom.document.clone()) {
+        let NodeData::Element { name, attrs, .. } = &node.data else {
+            unreachable!()
+        };
+        if !matches!(&*name.local, "link" | "a") {
+            continue;
+        }
+        let attrs = attrs.borrow();
+        let is_webmention = attrs.attr_str("rel")?.is_some_and(|rel| {
+            rel.split_ascii_whitespace()
+                .any(|rel| rel.eq_ignore_ascii_case("webmention"))
+        });
+        if let (true, Some(href)) = (is_webmention, attrs.attr_str("href")?) {
+            return Ok(Some(base_href.join(href)?));
+        }
+    }
+
+    Ok(None)
+}
+
+/// returns the url of the first link with `rel="webmention"` in a `Link` header.
+fn webmention_link_header_href(value: &str) -> Option<&str> {
+    let mut rest = value;
+    while let Some(start) = rest.find('<') {
+        let end = start + rest[start..].find('>')?;
+        let href = &rest[start + 1..end];
+        rest = &rest[end + 1..];
+        let params = &rest[..rest.find('<').unwrap_or(rest.len())];
+        for param in params.split([';', ',']) {
+            let Some((name, value)) = param.split_once('=') else {
+                continue;
+            };
+            if name.trim().eq_ignore_ascii_case("rel")
+                && value
+                    .trim()
+                    .trim_matches('"')
+                    .split_ascii_whitespace()
+                    .any(|rel| rel.eq_ignore_ascii_case("webmention"))
+            {
+                return Some(href);
+            }
+        }
+    }
+
+    None
+}
+
+/// fetches `source` and checks that it links to `target`, then parses the mention.
+pub fn verify(
+    fetcher: &dyn WebmentionFetcher,
+    source: &str,
+    target: &str,
+) -> eyre::Result<Verified> {
+    let page = fetcher.fetch(source)?;
+    if page.status == 410 {
+        return Ok(Verified::Gone);
+    }
+    if !(200..300).contains(&page.status) {
+        bail!("source returned status {}: {source}", page.status);
+    }
+    let target_url = without_fragment(Url::parse(target)?);
+    let mut mention = Webmention {
+        source: source.to_owned(),
+        target: target.to_owned(),
+        url: source.to_owned(),
+        received: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+        ..Default::default()
+    };
+
+    if !page.is_html() {
+        // plain text and other documents just need to contain the target url.
+        if !String::from_utf8_lossy(&page.body).contains(target) {
+            return Ok(Verified::Gone);
+        }
+        return Ok(Verified::Valid(mention));
+    }
+
+    let dom = parse_html_document(&page.body)?;
+    let base_href = document_base_href(dom.document.clone(), &Url::parse(&page.url)?)?;
+    if !links_to(dom.document.clone(), &base_href, &target_url)? {
+        return Ok(Verified::Gone);
+    }
+
+    if let Some(h_entry) = mf2_find(dom.document.clone(), "h-entry") {
+        for (class, kind) in [
+            ("u-in-reply-to", WebmentionKind::Reply),
+            ("u-like-of", WebmentionKind::Like),
+            ("u-repost-of", WebmentionKind::Repost),
+        ] {
+            if u_values(h_entry.clone(), class, &base_href)?
+                .into_iter()
+                .any(|url| without_fragment(url) == target_url)
+            {
+                mention.kind = kind;
+                break;
+            }
+        }
+        if let Some(url) = own_u_url(h_entry.clone(), &base_href)?.filter(is_http_url) {
+            mention.url = url.to_string();
+        }
+        mention.author = author(h_entry.clone(), &base_href)?;
+        mention.content = match mf2_p(h_entry.clone(), "e-content")? {
+            Some(content) => Some(content),
+            None => mf2_p(h_entry.clone(), "p-content")?,
+        }
+        .filter(|content| !content.is_empty())
+        .map(|content| truncate(&content));
+        mention.published = mf2_dt(h_entry.clone(), "dt-published").ok().flatten();
+    }
+
+    Ok(Verified::Valid(mention))
+}
+
+fn author(h_entry: Handle, base_href: &Url) -> eyre::Result<Option<WebmentionAuthor>> {
+    let Some(p_author) = mf2_find(h_entry, "p-author") else {
+        return Ok(None);
+    };
+    let result = if has_class(p_author.clone(), "h-card")? {
+        WebmentionAuthor {
+            name: mf2_p(p_author.clone(), "p-name")?,
+            url: own_u_url(p_author.clone(), base_href)?
+                .filter(is_http_url)
+                .map(|url| url.to_string()),
+        }
+    } else {
+        WebmentionAuthor {
+            name: mf2_p(p_author.clone(), "p-author")?,
+            url: None,
+        }
+    };
+
+    Ok(Some(result))
+}
+
+/// returns the urls of all elements with `class`, including nested h-cite u-urls.
+fn u_values(node: Handle, class: &str, base_href: &Url) -> eyre::Result<Vec<Url>> {
+    let mut result = vec![];
+    for element in mf2_find_all(node, class) {
+        if let NodeData::Element { attrs, .. } = &element.data {
+            if let Some(href) = attrs.borrow().attr_str("href")? {
+                result.extend(base_href.join(href).ok());
+                continue;
+            }
+        }
+        result.extend(mf2_u(element.clone(), "u-url", base_href).ok().flatten());
+    }
+
+    Ok(result)
+}
+
+/// returns the u-url of `root`, ignoring any in nested microformats like an h-cite.
+fn own_u_url(root: Handle, base_href: &Url) -> eyre::Result<Option<Url>> {
+    'u_url: for element in mf2_find_all(root.clone(), "u-url") {
+        // access the parent, per <markup5ever_rcdom-0.3.0/lib.rs:170>.
+        let mut node = element.clone();
+        while let Some(weak) = node.parent.take() {
+            let parent = weak.upgrade().expect("dangling weak pointer");
+            node.parent.set(Some(weak));
+            if Rc::ptr_eq(&parent, &root) {
+                break;
+            }
+            if is_microformat_root(parent.clone())? {
+                continue 'u_url;
+            }
+            node = parent;
+        }
+        let NodeData::Element { attrs, .. } = &element.data else {
+            unreachable!("guaranteed by mf2_find_all")
+        };
+        let attrs = attrs.borrow();
+        for name in ["href", "src", "value"] {
+            if let Some(url) = attrs.attr_str(name)? {
+                return Ok(base_href.join(url).ok());
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+fn is_microformat_root(node: Handle) -> eyre::Result<bool> {
+    if let NodeData::Element { attrs, .. } = &node.data {
+        if let Some(class) = attrs.borrow().attr_str("class")? {
+            return Ok(class.split_ascii_whitespace().any(|c| c.starts_with("h-")));
+        }
+    }
+
+    Ok(false)
+}
+
+/// returns true if any `[href]` or `[src]` in the document points to `target`.
+fn links_to(document: Handle, base_href: &Url, target: &Url) -> eyre::Result<bool> {
+    for node in BreadthTraverse::elements(document) {
+        let NodeData::Element { attrs, .. } = &node.data else {
+            unreachable!()
+        };
+        let attrs = attrs.borrow();
+        for name in ["href", "src"] {
+            if let Some(url) = attrs.attr_str(name)? {
+                if base_href
+                    .join(url)
+                    .is_ok_and(|url| &without_fragment(url) == target)
+                {
+                    return Ok(true);
+                }
+            }
+        }
+    }
+
+    Ok(false)
+}
+
+fn without_fragment(mut url: Url) -> Url {
+    url.set_fragment(None);
+    url
+}
+
+pub fn is_http_url(url: &Url) -> bool {
+    matches!(url.scheme(), "http" | "https")
+}
+
+fn truncate(content: &str) -> String {
+    let content = content.split_whitespace().collect::<Vec<_>>().join(" ");
+    match content.char_indices().nth(MAX_CONTENT_CHARS) {
+        Some((index, _)) => format!("{}…", &content[..index]),
+        None => content,
+    }
+}
+
+/// serves pages from memory, and records the forms posted to it.
+#[cfg(test)]
+#[derive(Default)]
+pub struct StaticWebmentionFetcher {
+    pub pages: BTreeMap<String, FetchedPage>,
+    pub posted: Mutex<Vec<(String, Vec<(String, String)>)>>,
+}
+
+#[cfg(test)]
+impl WebmentionFetcher for StaticWebmentionFetcher {
+    fn fetch(&self, url: &str) -> eyre::Result<FetchedPage> {
+        match self.pages.get(url) {
+            Some(page) => Ok(page.clone()),
+            None => Ok(FetchedPage {
+                status: 404,
+                url: url.to_owned(),
+                ..Default::default()
+            }),
+        }
+    }
+
+    fn post_form(&self, url: &str, form: &[(&str, &str)]) -> eyre::Result<u16> {
+        let form = form
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        self.posted.lock().unwrap().push((url.to_owned(), form));
+
+        Ok(202)
+    }
+}
+
+#[cfg(test)]
+impl FetchedPage {
+    pub fn html(url: &str, body: &str) -> Self {
+        Self {
+            status: 200,
+            url: url.to_owned(),
+            headers: vec![("content-type".to_owned(), "text/html".to_owned())],
+            body: body.as_bytes().to_owned(),
+        }
+    }
+}
+
+#[test]
+fn test_verify() -> eyre::Result<()> {
+    let target = "https://example.com/1.html";
+    let fetcher = StaticWebmentionFetcher {
+        pages: BTreeMap::from([
+            (
+                "https://a.example/reply".to_owned(),
+                FetchedPage::html(
+                    "https://a.example/reply",
+                    r#"<base href="/posts/"><div class="h-entry">
+                    <a class="u-in-reply-to" href="https://example.com/1.html#comments">re</a>
+                    <a class="u-url" href="reply">permalink</a>
+                    <span class="p-author h-card"><a class="p-name u-url" href="/~a">a</a></span>
+                    <div class="e-content"><p>nice  <b>post</b></p></div>
+                    <time class="dt-published" datetime="2024-01-01T00:00Z"></time>
+                </div>"#,
+                ),
+            ),
+            (
+                "https://b.example/like".to_owned(),
+                FetchedPage::html(
+                    "https://b.example/like",
+                    r#"<div class="h-entry"><div class="u-like-of h-cite"><a class="u-url" href="https://example.com/1.html">x</a></div></div>"#,
+                ),
+            ),
+            (
+                "https://c.example/nolink".to_owned(),
+                FetchedPage::html("https://c.example/nolink", "<a href=/2.html>x</a>"),
+            ),
+            (
+                "https://d.example/deleted".to_owned(),
+                FetchedPage {
+                    status: 410,
+                    ..Default::default()
+                },
+            ),
+        ]),
+        ..Default::default()
+    };
+
+    let Verified::Valid(reply) = verify(&fetcher, "https://a.example/reply", target)? else {
+        panic!("reply should be valid");
+    };
+    assert_eq!(reply.kind, WebmentionKind::Reply);
+    assert_eq!(reply.status, WebmentionStatus::Pending);
+    assert_eq!(reply.url, "https://a.example/posts/reply");
+    assert_eq!(reply.content.as_deref(), Some("nice post"));
+    assert_eq!(reply.published.as_deref(), Some("2024-01-01T00:00Z"));
+    let author = reply.author.expect("reply has author");
+    assert_eq!(author.name.as_deref(), Some("a"));
+    assert_eq!(author.url.as_deref(), Some("https://a.example/~a"));
+    let Verified::Valid(like) = verify(&fetcher, "https://b.example/like", target)? else {
+        panic!("like should be valid");
+    };
+    assert_eq!(like.kind, WebmentionKind::Like);
+    assert_eq!(like.url, "https://b.example/like");
+    assert_eq!(
+        verify(&fetcher, "https://c.example/nolink", target)?,
+        Verified::Gone
+    );
+    assert_eq!(
+        verify(&fetcher, "https://d.example/deleted", target)?,
+        Verified::Gone
+    );
+    assert!(verify(&fetcher, "https://e.example/missing", target).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_webmention_store() -> eyre::Result<()> {
+    let dir =
+        std::env::temp_dir().join(format!("autost-test-webmentions-{}", uuid::Uuid::new_v4()));
+    let store = WebmentionStore::new(&dir);
+    let (source, target) = ("https://a.example/", "https://example.com/1.html");
+    let mention = |content: &str| {
+        Verified::Valid(Webmention {
+            source: source.to_owned(),
+            target: target.to_owned(),
+            content: Some(content.to_owned()),
+            ..Default::default()
+        })
+    };
+
+    assert!(store.path("../1.html").is_err());
+    assert!(store.path("2024//1.html").is_err());
+    assert_eq!(
+        store.path("2024/10/1.html")?,
+        dir.join("2024%2F10%2F1.json")
+    );
+    store.receive("1.html", source, target, mention("one"))?;
+    assert_eq!(store.load("1.html")?.len(), 1);
+    assert_eq!(store.approved("1.html")?, vec![]);
+    assert_eq!(
+        store.set_status(source, None, WebmentionStatus::Approved)?,
+        1
+    );
+    store.receive("1.html", source, target, mention("two"))?;
+    let approved = store.approved("1.html")?;
+    assert_eq!(approved.len(), 1);
+    assert_eq!(approved[0].content.as_deref(), Some("two"));
+    assert_eq!(store.load_all()?.keys().collect::<Vec<_>>(), ["1.html"]);
+    store.receive("1.html", source, target, Verified::Gone)?;
+    assert_eq!(store.load("1.html")?, vec![]);
+    assert!(!dir.join("1.json").exists());
+    std::fs::remove_dir_all(dir)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_discover_endpoint() -> eyre::Result<()> {
+    let mut header = FetchedPage::html(
+        "https://a.example/post",
+        r#"<link rel=webmention href=/ignored>"#,
+    );
+    header.headers.push((
+        "Link".to_owned(),
+        r#"<https://a.example/other>; rel="other", </webmention?x=1,2>; rel="nofollow webmention""#
+            .to_owned(),
+    ));
+    let fetcher = StaticWebmentionFetcher {
+        pages: BTreeMap::from([
+            ("https://a.example/post".to_owned(), header),
+            (
+                "https://b.example/post".to_owned(),
+                FetchedPage::html(
+                    "https://b.example/redirected/post",
+                    r#"<a rel="me" href="/me"></a><a rel="webmention" href="wm">first</a><link rel="webmention" href="/second">"#,
+                ),
+            ),
+            (
+                "https://c.example/post".to_owned(),
+                FetchedPage::html(
+                    "https://c.example/post",
+                    r#"<link rel="webmention" href="">"#,
+                ),
+            ),
+            (
+                "https://d.example/post".to_owned(),
+                FetchedPage::html("https://d.example/post", "<p>no endpoint"),
+            ),
+        ]),
+        ..Default::default()
+    };
+    let endpoint =
+        |url| Ok::<_, eyre::Report>(discover_endpoint(&fetcher, url)?.map(|url| url.to_string()));
+
+    assert_eq!(
+        endpoint("https://a.example/post")?.as_deref(),
+        Some("https://a.example/webmention?x=1,2")
+    );
+    assert_eq!(
+        endpoint("https://b.example/post")?.as_deref(),
+        Some("https://b.example/redirected/wm")
+    );
+    assert_eq!(
+        endpoint("https://c.example/post")?.as_deref(),
+        Some("https://c.example/post")
+    );
+    assert_eq!(endpoint("https://d.example/post")?, None);
+    assert_eq!(endpoint("https://e.example/missing")?, None);
+
+    Ok(())
+}
+
+#[test]
+fn test_send() -> eyre::Result<()> {
+    let dir =
+        std::env::temp_dir().join(format!("autost-test-webmentions-{}", uuid::Uuid::new_v4()));
+    let store = WebmentionStore::new(&dir);
+    let page =
+        |url: &str| FetchedPage::html(url, r#"<link rel="webmention" href="https://wm.example/">"#);
+    let fetcher = StaticWebmentionFetcher {
+        pages: BTreeMap::from([
+            ("https://a.example/".to_owned(), page("https://a.example/")),
+            ("https://b.example/".to_owned(), page("https://b.example/")),
+        ]),
+        ..Default::default()
+    };
+    let sent_targets = || {
+        fetcher
+            .posted
+            .lock()
+            .unwrap()
+            .drain(..)
+            .map(|(_, form)| form[1].1.clone())
+            .collect::<Vec<_>>()
+    };
+    let mut links = OutgoingLinks {
+        page: "10000000.html".to_owned(),
+        source: "https://example.com/10000000.html".to_owned(),
+        hash: "1".to_owned(),
+        targets: BTreeSet::from([
+            "https://a.example/".to_owned(),
+            "https://b.example/".to_owned(),
+            "https://no-endpoint.example/".to_owned(),
+        ]),
+    };
+
+    // dry runs don’t send or log anything.
+    assert_eq!(send(&fetcher, &store, &links.page, Some(&links), true)?, 2);
+    assert_eq!(sent_targets(), Vec::<String>::new());
+    assert_eq!(send(&fetcher, &store, &links.page, Some(&links), false)?, 2);
+    assert_eq!(sent_targets(), ["https://a.example/", "https://b.example/"]);
+    assert_eq!(send(&fetcher, &store, &links.page, Some(&links), false)?, 0);
+
+    // removed links are sent one last time.
+    links.hash = "2".to_owned();
+    links.targets.remove("https://b.example/");
+    assert_eq!(send(&fetcher, &store, &links.page, Some(&links), false)?, 2);
+    assert_eq!(sent_targets(), ["https://a.example/", "https://b.example/"]);
+    assert_eq!(send(&fetcher, &store, &links.page, Some(&links), false)?, 0);
+
+    // deletions are sent once, then the log is removed.
+    assert_eq!(send(&fetcher, &store, &links.page, None, false)?, 1);
+    assert_eq!(sent_targets(), ["https://a.example/"]);
+    assert_eq!(send(&fetcher, &store, &links.page, None, false)?, 0);
+    assert_eq!(store.sent_pages()?, Vec::<String>::new());
+    std::fs::remove_dir_all(dir)?;
+
+    Ok(())
+}
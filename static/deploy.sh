@@ -13,5 +13,6 @@ upload() {
 # `/./` means `--relative` only includes the part to the right, so the `site`
 # part still gets flattened on the destination. we do this instead of `cd site`
 # because the `$interesting_output_filenames_list_path` may be relative.
-upload "$@" site/./attachments site/./*.css site/./*.js site/./*.woff2 site/./*.pdf
+# `*.css*` and `*.js*` include any precompressed `.gz` and `.br` variants.
+upload "$@" site/./attachments site/./*.css* site/./*.js* site/./*.woff2 site/./*.pdf
 upload "$@" --files-from="$interesting_output_filenames_list_path" site/./
@@ -71,6 +71,135 @@ if (compose) {
     });
 }
 
+const searchForm = document.querySelector("form.search[data-client-side]");
+if (searchForm) {
+    const summary = document.querySelector("p.search-summary");
+    const results = document.querySelector("ol.search-results");
+    const indexUrl = `${document.body.dataset.baseUrl}search/`;
+    const shards = new Map;
+    let documents = null;
+    const fetchJson = async url => {
+        const response = await fetch(url);
+        if (response.status == 404) return null;
+        if (!response.ok) throw new Error(`GET ${url}: ${response.status}`);
+        return await response.json();
+    };
+    const loadShard = key => {
+        if (!shards.has(key)) {
+            shards.set(key, fetchJson(`${indexUrl}terms/${key}.json`).then(shard => shard ?? {}));
+        }
+        return shards.get(key);
+    };
+    const renderResults = (total, matches) => {
+        summary.textContent = `${total} ${total == 1 ? "result" : "results"}`;
+        if (total > matches.length) {
+            summary.textContent += `, showing the first ${matches.length}`;
+        }
+        results.innerHTML = "";
+        for (const doc of matches) {
+            const li = document.createElement("li");
+            const a = document.createElement("a");
+            a.href = doc.url;
+            a.textContent = doc.title ?? "untitled post";
+            const small = document.createElement("small");
+            if (doc.published) {
+                const time = document.createElement("time");
+                time.dateTime = doc.published;
+                time.textContent = doc.published;
+                small.append(time);
+            }
+            if (doc.author) {
+                small.append(` by ${doc.author.display_name || doc.author.display_handle}`);
+            }
+            const p = document.createElement("p");
+            p.textContent = doc.summary;
+            li.append(a, "\n", small, "\n", p);
+            results.append(li);
+        }
+    };
+    const runSearch = async () => {
+        const params = new URLSearchParams(location.search);
+        for (const name of ["q", "tag", "author"]) {
+            searchForm.elements[name].value = params.get(name) ?? "";
+        }
+        if (![...params.values()].some(value => value != "")) return;
+        try {
+            documents ??= await fetchJson(`${indexUrl}documents.json`);
+            const { total, matches } = await search(documents, loadShard, {
+                q: params.get("q") ?? "",
+                tag: params.get("tag") || null,
+                author: params.get("author") || null,
+            });
+            renderResults(total, matches);
+        } catch (e) {
+            summary.textContent = `search failed: ${e}`;
+        }
+    };
+    searchForm.addEventListener("submit", event => {
+        event.preventDefault();
+        const params = new URLSearchParams(new FormData(searchForm));
+        history.pushState(null, "", `?${params}`);
+        runSearch();
+    });
+    addEventListener("popstate", event => {
+        runSearch();
+    });
+    runSearch();
+}
+
+// must be kept in sync with src/search.rs.
+const SEARCH_RESULT_LIMIT = 100;
+function tokenize(text) {
+    return (text.match(/[\p{Alphabetic}\p{N}]+/gu) ?? []).map(term => term.toLowerCase());
+}
+function shardKey(term) {
+    return term.codePointAt(0).toString(16);
+}
+function normalizeFilter(value) {
+    return value.trim().replace(/^#*/, "").replace(/^@*/, "").toLowerCase();
+}
+async function search(documents, loadShard, { q, tag, author }) {
+    let scores = null;
+    for (const queryTerm of new Set(tokenize(q))) {
+        const shard = await loadShard(shardKey(queryTerm));
+        const termScores = new Map;
+        for (const [term, postings] of Object.entries(shard)) {
+            if (!term.startsWith(queryTerm)) continue;
+            for (const [doc, weight] of postings) {
+                termScores.set(doc, (termScores.get(doc) ?? 0) + weight);
+            }
+        }
+        if (scores == null) {
+            scores = termScores;
+        } else {
+            for (const [doc, score] of scores) {
+                if (termScores.has(doc)) {
+                    scores.set(doc, score + termScores.get(doc));
+                } else {
+                    scores.delete(doc);
+                }
+            }
+        }
+    }
+    scores ??= new Map(documents.map((_, i) => [i, 0]));
+
+    tag = tag == null ? null : normalizeFilter(tag);
+    author = author == null ? null : normalizeFilter(author);
+    const matches = [...scores]
+        .filter(([doc]) => doc < documents.length)
+        .filter(([doc]) => {
+            const { tags, author: a } = documents[doc];
+            return (tag == null || tags.some(t => normalizeFilter(t) == tag))
+                && (author == null || a != null && [a.href, a.display_handle, a.display_name]
+                    .some(name => normalizeFilter(name) == author));
+        });
+    matches.sort(([p, pScore], [q, qScore]) => qScore - pScore || p - q);
+    return {
+        total: matches.length,
+        matches: matches.slice(0, SEARCH_RESULT_LIMIT).map(([doc]) => documents[doc]),
+    };
+}
+
 checkAutostServer();
 
 async function checkAutostServer() {
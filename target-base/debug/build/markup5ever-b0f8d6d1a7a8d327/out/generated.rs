@@ -0,0 +1,3468 @@
+pub type LocalName = :: string_cache :: Atom < LocalNameStaticSet > ;
+# [derive (PartialEq , Eq , PartialOrd , Ord)] pub struct LocalNameStaticSet ;
+impl :: string_cache :: StaticAtomSet for LocalNameStaticSet { fn get () -> & 'static :: string_cache :: PhfStrSet { static SET : :: string_cache :: PhfStrSet = :: string_cache :: PhfStrSet { key : 12913932095322966823u64 , disps : & [(0u32 , 163u32) , (0u32 , 82u32) , (0u32 , 296u32) , (0u32 , 79u32) , (0u32 , 3u32) , (0u32 , 47u32) , (0u32 , 3u32) , (0u32 , 7u32) , (0u32 , 227u32) , (0u32 , 24u32) , (0u32 , 49u32) , (0u32 , 550u32) , (0u32 , 2u32) , (0u32 , 773u32) , (0u32 , 0u32) , (0u32 , 507u32) , (0u32 , 155u32) , (0u32 , 242u32) , (0u32 , 261u32) , (0u32 , 0u32) , (0u32 , 0u32) , (0u32 , 83u32) , (0u32 , 96u32) , (0u32 , 0u32) , (0u32 , 44u32) , (0u32 , 971u32) , (0u32 , 0u32) , (0u32 , 236u32) , (0u32 , 0u32) , (0u32 , 0u32) , (0u32 , 117u32) , (0u32 , 40u32) , (0u32 , 2u32) , (0u32 , 4u32) , (0u32 , 79u32) , (0u32 , 189u32) , (0u32 , 0u32) , (0u32 , 249u32) , (0u32 , 377u32) , (0u32 , 17u32) , (0u32 , 22u32) , (0u32 , 574u32) , (0u32 , 577u32) , (0u32 , 352u32) , (0u32 , 74u32) , (0u32 , 43u32) , (0u32 , 30u32) , (1u32 , 634u32) , (0u32 , 4u32) , (1u32 , 3u32) , (0u32 , 325u32) , (0u32 , 52u32) , (0u32 , 1u32) , (0u32 , 251u32) , (0u32 , 11u32) , (0u32 , 130u32) , (0u32 , 273u32) , (0u32 , 544u32) , (0u32 , 341u32) , (0u32 , 234u32) , (0u32 , 384u32) , (0u32 , 380u32) , (0u32 , 444u32) , (0u32 , 30u32) , (0u32 , 163u32) , (0u32 , 1u32) , (0u32 , 238u32) , (0u32 , 193u32) , (0u32 , 263u32) , (0u32 , 254u32) , (0u32 , 1u32) , (0u32 , 4u32) , (0u32 , 201u32) , (0u32 , 605u32) , (0u32 , 7u32) , (0u32 , 1u32) , (0u32 , 140u32) , (0u32 , 0u32) , (0u32 , 86u32) , (0u32 , 5u32) , (0u32 , 5u32) , (0u32 , 2u32) , (0u32 , 8u32) , (0u32 , 95u32) , (0u32 , 10u32) , (0u32 , 140u32) , (0u32 , 296u32) , (0u32 , 516u32) , (0u32 , 939u32) , (0u32 , 194u32) , (0u32 , 58u32) , (0u32 , 202u32) , (0u32 , 31u32) , (0u32 , 249u32) , (0u32 , 119u32) , (0u32 , 169u32) , (0u32 , 9u32) , (0u32 , 784u32) , (0u32 , 19u32) , (0u32 , 1016u32) , (0u32 , 15u32) , (0u32 , 14u32) , (4u32 , 220u32) , (0u32 , 2u32) , (0u32 , 0u32) , (0u32 , 446u32) , (0u32 , 520u32) , (0u32 , 102u32) , (0u32 , 190u32) , (0u32 , 802u32) , (0u32 , 143u32) , (0u32 , 3u32) , (0u32 , 0u32) , (0u32 , 0u32) , (0u32 , 2u32) , (0u32 , 27u32) , (0u32 , 62u32) , (0u32 , 74u32) , (0u32 , 28u32) , (0u32 , 28u32) , (0u32 , 1114u32) , (0u32 , 896u32) , (0u32 , 1u32) , (0u32 , 39u32) , (0u32 , 469u32) , (0u32 , 38u32) , (0u32 , 102u32) , (0u32 , 687u32) , (1u32 , 859u32) , (0u32 , 0u32) , (0u32 , 75u32) , (0u32 , 533u32) , (0u32 , 3u32) , (0u32 , 732u32) , (0u32 , 15u32) , (0u32 , 48u32) , (0u32 , 0u32) , (0u32 , 4u32) , (0u32 , 87u32) , (1u32 , 162u32) , (0u32 , 15u32) , (0u32 , 976u32) , (0u32 , 148u32) , (0u32 , 10u32) , (1u32 , 72u32) , (0u32 , 526u32) , (0u32 , 8u32) , (0u32 , 59u32) , (0u32 , 9u32) , (0u32 , 18u32) , (0u32 , 748u32) , (0u32 , 30u32) , (0u32 , 780u32) , (0u32 , 980u32) , (0u32 , 70u32) , (0u32 , 410u32) , (0u32 , 44u32) , (0u32 , 13u32) , (3u32 , 849u32) , (0u32 , 100u32) , (0u32 , 58u32) , (1u32 , 654u32) , (0u32 , 23u32) , (0u32 , 276u32) , (0u32 , 134u32) , (0u32 , 24u32) , (2u32 , 3u32) , (1u32 , 5u32) , (0u32 , 47u32) , (0u32 , 74u32) , (0u32 , 188u32) , (0u32 , 21u32) , (1u32 , 142u32) , (0u32 , 2u32) , (0u32 , 59u32) , (1u32 , 1106u32) , (0u32 , 559u32) , (1u32 , 300u32) , (0u32 , 580u32) , (1u32 , 642u32) , (0u32 , 76u32) , (3u32 , 744u32) , (8u32 , 769u32) , (0u32 , 1u32) , (7u32 , 180u32) , (0u32 , 990u32) , (0u32 , 461u32) , (0u32 , 64u32) , (0u32 , 59u32) , (0u32 , 2u32) , (0u32 , 67u32) , (0u32 , 13u32) , (0u32 , 21u32) , (1u32 , 326u32) , (0u32 , 13u32) , (1u32 , 924u32) , (0u32 , 0u32) , (0u32 , 0u32) , (0u32 , 35u32) , (0u32 , 23u32) , (0u32 , 298u32) , (4u32 , 72u32) , (4u32 , 1057u32) , (0u32 , 156u32) , (5u32 , 307u32) , (5u32 , 404u32) , (0u32 , 13u32) , (2u32 , 826u32) , (0u32 , 9u32) , (0u32 , 248u32) , (0u32 , 431u32) , (2u32 , 55u32) , (3u32 , 238u32) , (0u32 , 97u32) , (0u32 , 310u32) , (0u32 , 145u32) , (0u32 , 12u32) , (1u32 , 425u32) , (0u32 , 213u32) , (0u32 , 741u32) , (0u32 , 698u32) , (32u32 , 914u32) , (0u32 , 8u32) , (0u32 , 366u32) , (4u32 , 89u32) , (0u32 , 371u32) , (0u32 , 7u32)] , atoms : & ["onhelp",
+"button",
+"notanumber",
+"onlanguagechange",
+"tfoot",
+"dl",
+"power",
+"factorof",
+"annotation-xml",
+"onbeforeeditfocus",
+"clipPathUnits",
+"overline-thickness",
+"clippath",
+"units-per-em",
+"aside",
+"minus",
+"thinmathspace",
+"embed",
+"source",
+"horiz-adv-x",
+"script",
+"diffuseConstant",
+"td",
+"stroke-opacity",
+"value",
+"nobr",
+"animate",
+"onfilterchange",
+"stroke",
+"actiontype",
+"string",
+"xlink:type",
+"maskunits",
+"onbeforeprint",
+"xlink:title",
+"gradientTransform",
+"kernelunitlength",
+"symbol",
+"direction",
+"option",
+"divisor",
+"a",
+"mediummathspace",
+"stop-opacity",
+"mglyph",
+"tablevalues",
+"kernelmatrix",
+"targetY",
+"columnwidth",
+"crossorigin",
+"th",
+"onmovestart",
+"speed",
+"onbeforecopy",
+"scriptlevel",
+"colspan",
+"declare",
+"onpaste",
+"aria-relevant",
+"diffuseconstant",
+"aria-sort",
+"arg",
+"repeat-max",
+"marker-start",
+"exp",
+"xml:base",
+"hidden",
+"definition-src",
+"xlink:actuate",
+"momentabout",
+"replace",
+"fontsize",
+"mspace",
+"pointsaty",
+"statechange",
+"checked",
+"itemscope",
+"sub",
+"feDisplacementMap",
+"aria-required",
+"limitingconeangle",
+"xlink:show",
+"fetch",
+"fefunca",
+"mathsize",
+"startOffset",
+"aria-valuenow",
+"colgroup",
+"datetime",
+"parse",
+"text-decoration",
+"patternUnits",
+"primes",
+"alt",
+"offset",
+"orientation",
+"feSpecularLighting",
+"center",
+"numOctaves",
+"keysplines",
+"title",
+"itemtype",
+"markerunits",
+"outerproduct",
+"flood-color",
+"accentunder",
+"altglyph",
+"onmouseout",
+"maction",
+"aria-colindex",
+"systemlanguage",
+"word-spacing",
+"radiogroup",
+"radialgradient",
+"srcset",
+"underline-thickness",
+"maxlength",
+"aria-setsize",
+"u2",
+"strikethrough-position",
+"feSpotLight",
+"matrixrow",
+"data",
+"fecomposite",
+"k4",
+"listener",
+"unicode-range",
+"aria-atomic",
+"small",
+"font-face-uri",
+"enctype",
+"points",
+"veryverythinmathspace",
+"bevelled",
+"scoped",
+"onmouseover",
+"feOffset",
+"active",
+"amplitude",
+"textpath",
+"u",
+"fediffuselighting",
+"marginwidth",
+"panose-1",
+"variance",
+"disabled",
+"onstart",
+"actuate",
+"veryverythickmathspace",
+"font-size",
+"gradientUnits",
+"mask",
+"operator",
+"y1",
+"arccsc",
+"onkeyup",
+"aria-flowto",
+"unicode-bidi",
+"samp",
+"onscroll",
+"font-face-format",
+"int",
+"missing-glyph",
+"cols",
+"big",
+"intercept",
+"svg",
+"scalarproduct",
+"name",
+"textlength",
+"image",
+"tt",
+"arabic-form",
+"aria-braillelabel",
+"fedisplacementmap",
+"onchange",
+"aria-orientation",
+"lengthAdjust",
+"aria-selected",
+"spellcheck",
+"gradienttransform",
+"tanh",
+"by",
+"when",
+"lengthadjust",
+"accept",
+"event",
+"lspace",
+"aria-autocomplete",
+"ismap",
+"face",
+"sizes",
+"systemLanguage",
+"fetile",
+"moment",
+"d",
+"mprescripts",
+"attributename",
+"ondragend",
+"oncut",
+"polyline",
+"groupalign",
+"ondragover",
+"clear",
+"defer",
+"aria-labelledby",
+"noembed",
+"rowspan",
+"diff",
+"conjugate",
+"contextmenu",
+"xlink",
+"codomain",
+"kernelMatrix",
+"aria-valuetext",
+"rx",
+"feDropShadow",
+"gcd",
+"rowalign",
+"mark",
+"seed",
+"stitchtiles",
+"edgemode",
+"oncontextmenu",
+"alttext",
+"preserveAspectRatio",
+"fill",
+"closure",
+"loop",
+"pattern",
+"line",
+"aria-description",
+"s",
+"feoffset",
+"rect",
+"otherwise",
+"repeat",
+"polygon",
+"cos",
+"plus",
+"mtr",
+"feColorMatrix",
+"dominant-baseline",
+"aria-busy",
+"nextid",
+"noshade",
+"feComponentTransfer",
+"patternContentUnits",
+"fill-rule",
+"occurrence",
+"radialGradient",
+"csch",
+"mean",
+"mstyle",
+"calcmode",
+"arctan",
+"bvar",
+"partialdiff",
+"download",
+"step",
+"ononline",
+"oncopy",
+"feMergeNode",
+"longdesc",
+"feFuncR",
+"neq",
+"factorial",
+"area",
+"csymbol",
+"symmetric",
+"color-interpolation",
+"shape",
+"edge",
+"plaintext",
+"listing",
+"altGlyphItem",
+"tr",
+"markerwidth",
+"foreignObject",
+"feconvolvematrix",
+"http-equiv",
+"span",
+"codebase",
+"values",
+"aria-colindextext",
+"animateTransform",
+"text",
+"stemv",
+"prefetch",
+"vector",
+"accesskey",
+"applet",
+"ident",
+"cosh",
+"fedropshadow",
+"baseline",
+"separators",
+"cap-height",
+"noresize",
+"onmouseup",
+"true",
+"fx",
+"aria-multiselectable",
+"formnovalidate",
+"h3",
+"repeatcount",
+"nowrap",
+"show",
+"coth",
+"datatemplate",
+"valuetype",
+"result",
+"uplimit",
+"slot",
+"solidcolor",
+"mathbackground",
+"color",
+"rule",
+"xref",
+"preserveaspectratio",
+"onlosecapture",
+"stddeviation",
+"glyph",
+"dx",
+"feimage",
+"target",
+"contentScriptType",
+"feFlood",
+"onformchange",
+"onmousedown",
+"size",
+"onsubmit",
+"targetX",
+"superscriptshift",
+"g1",
+"clip",
+"surfaceScale",
+"interval",
+"onrepeat",
+"widths",
+"mode",
+"hanging",
+"rowspacing",
+"refy",
+"requiredExtensions",
+"columnspacing",
+"transpose",
+"stroke-linecap",
+"switch",
+"imaginary",
+"onfocusout",
+"font-family",
+"maskContentUnits",
+"mphantom",
+"keypoints",
+"onmouseleave",
+"style",
+"dialog",
+"arcsech",
+"basefrequency",
+"selector",
+"bbox",
+"onselectstart",
+"caption",
+"noscript",
+"xmlns:xlink",
+"aria-multiline",
+"k2",
+"mtd",
+"sdev",
+"attributeType",
+"cot",
+"preservealpha",
+"low",
+"fy",
+"feturbulence",
+"noframes",
+"onpagehide",
+"rationals",
+"icon",
+"columnspan",
+"selection",
+"externalresourcesrequired",
+"onbeforecut",
+"origin",
+"nargs",
+"sec",
+"viewBox",
+"minlength",
+"semantics",
+"arcsin",
+"or",
+"ci",
+"metadata",
+"sandbox",
+"start",
+"xmp",
+"ondataavailable",
+"markerheight",
+"xml:space",
+"aria-current",
+"linethickness",
+"space",
+"bgcolor",
+"linebreak",
+"inverse",
+"autoplay",
+"compact",
+"onmove",
+"aria-expanded",
+"logbase",
+"pathLength",
+"canvas",
+"maligngroup",
+"font-weight",
+"spacer",
+"divide",
+"k1",
+"tan",
+"x",
+"oninput",
+"ln",
+"onactivate",
+"malignmark",
+"b",
+"repeat-template",
+"height",
+"aria-datatype",
+"exponent",
+"stop-color",
+"filterRes",
+"targety",
+"floor",
+"aria-brailleroledescription",
+"repeatdur",
+"hgroup",
+"head",
+"alignmentscope",
+"manifest",
+"feDistantLight",
+"ondragstart",
+"approx",
+"aria-checked",
+"defs",
+"prompt",
+"tabindex",
+"feTile",
+"maskUnits",
+"optgroup",
+"label",
+"transform",
+"ms",
+"contenteditable",
+"patterncontentunits",
+"not",
+"onbeforeupdate",
+"onpopstate",
+"v-alphabetic",
+"piece",
+"keygen",
+"arctanh",
+"hkern",
+"figcaption",
+"prsubset",
+"hspace",
+"sep",
+"onbounce",
+"from",
+"stroke-dashoffset",
+"preload",
+"equalrows",
+"stitchTiles",
+"gt",
+"sech",
+"em",
+"contentstyletype",
+"aria-live",
+"readonly",
+"aria-pressed",
+"to",
+"fontstyle",
+"selected",
+"scheme",
+"animateColor",
+"azimuth",
+"requiredfeatures",
+"aria-label",
+"oncellchange",
+"rp",
+"lineargradient",
+"primitiveunits",
+"mathematical",
+"onrowsinserted",
+"content",
+"datalist",
+"altglyphdef",
+"intersect",
+"stroke-linejoin",
+"femergenode",
+"datafld",
+"rev",
+"onkeydown",
+"template",
+"display",
+"class",
+"eulergamma",
+"tableValues",
+"aria-disabled",
+"ideographic",
+"fefuncb",
+"scriptsizemultiplier",
+"emptyset",
+"inputmode",
+"formtarget",
+"ychannelselector",
+"feImage",
+"alink",
+"yChannelSelector",
+"ondeactivate",
+"footer",
+"aria-hidden",
+"zoomAndPan",
+"repeatCount",
+"irrelevant",
+"munder",
+"border",
+"local",
+"charoff",
+"aria-grab",
+"img",
+"restart",
+"x2",
+"hidefocus",
+"median",
+"optimum",
+"vectorproduct",
+"frameborder",
+"dfn",
+"formenctype",
+"mtable",
+"refX",
+"isindex",
+"onrowexit",
+"glyphref",
+"cellspacing",
+"search",
+"xlink:role",
+"depth",
+"aria-level",
+"clip-rule",
+"alphabetic",
+"body",
+"font-variant",
+"vert-adv-y",
+"li",
+"param",
+"fence",
+"unselectable",
+"onselect",
+"glyph-name",
+"referrerpolicy",
+"equivalent",
+"glyph-orientation-horizontal",
+"card",
+"onblur",
+"x1",
+"mroot",
+"startoffset",
+"underline-position",
+"macros",
+"reversed",
+"ry",
+"other",
+"spacing",
+"mathvariant",
+"onbeforeunload",
+"alignment-baseline",
+"set",
+"integrity",
+"acronym",
+"specularconstant",
+"nav",
+"nomodule",
+"patternTransform",
+"refx",
+"thickmathspace",
+"laplacian",
+"animatetransform",
+"notin",
+"select",
+"onclick",
+"horiz-origin-x",
+"link",
+"reals",
+"in",
+"bdo",
+"onbefordeactivate",
+"requiredextensions",
+"ping",
+"u1",
+"use",
+"for",
+"format",
+"keyTimes",
+"feFuncB",
+"specularConstant",
+"stdDeviation",
+"rquote",
+"ul",
+"animatecolor",
+"v-ideographic",
+"letter-spacing",
+"fontweight",
+"char",
+"wrap",
+"id",
+"feMorphology",
+"iframe",
+"degree",
+"h2",
+"kind",
+"type",
+"k",
+"lighting-color",
+"minsize",
+"onfocusin",
+"stemh",
+"decoding",
+"curl",
+"naturalnumbers",
+"targetx",
+"contentStyleType",
+"property",
+"fetchpriority",
+"aria-dropeffect",
+"y",
+"align",
+"del",
+"rb",
+"reln",
+"onerror",
+"calcMode",
+"clippathunits",
+"msqrt",
+"baseprofile",
+"begin",
+"nonce",
+"repeat-start",
+"msub",
+"time",
+"xmlns",
+"kerning",
+"viewtarget",
+"formaction",
+"view",
+"index",
+"codetype",
+"xor",
+"method",
+"legend",
+"figure",
+"feblend",
+"h5",
+"oninvalid",
+"lowsrc",
+"basefont",
+"msubsup",
+"picture",
+"feflood",
+"implies",
+"stroke-width",
+"address",
+"font-face",
+"sin",
+"ascent",
+"imaginaryi",
+"pointsatz",
+"limitingConeAngle",
+"surfacescale",
+"aria-rowspan",
+"domain",
+"textarea",
+"marquee",
+"aria-rowindex",
+"cite",
+"fepointlight",
+"accumulate",
+"ondblclick",
+"pi",
+"requiredFeatures",
+"divergence",
+"attributeName",
+"div",
+"apply",
+"columnalign",
+"map",
+"altimg",
+"desc",
+"h6",
+"autocomplete",
+"g",
+"high",
+"marker",
+"spreadmethod",
+"specularexponent",
+"onzoom",
+"onstop",
+"onpropertychange",
+"animatemotion",
+"audio",
+"col",
+"onfinish",
+"ins",
+"aria-valuemin",
+"feDiffuseLighting",
+"writing-mode",
+"archive",
+"dd",
+"*",
+"rel",
+"tspan",
+"v-hanging",
+"onstorage",
+"h4",
+"strong",
+"r",
+"controls",
+"rotate",
+"viewbox",
+"mathcolor",
+"annotation",
+"glyphRef",
+"onbegin",
+"encoding",
+"accept-charset",
+"role",
+"animateMotion",
+"discard",
+"cn",
+"blink",
+"exponentiale",
+"shape-rendering",
+"abbr",
+"aria-rowindextext",
+"aria-secret",
+"close",
+"fontfamily",
+"aria-errormessage",
+"onabort",
+"coords",
+"cartesianproduct",
+"ol",
+"subscriptshift",
+"translate",
+"scrolldelay",
+"color-rendering",
+"keytimes",
+"handler",
+"action",
+"xlink:href",
+"font-face-name",
+"aria-modal",
+"var",
+"list",
+"enable-background",
+"real",
+"cursor",
+"required",
+"font-style",
+"rt",
+"menu",
+"rendering-intent",
+"maxsize",
+"textLength",
+"onrowenter",
+"aria-channel",
+"feConvolveMatrix",
+"aria-colspan",
+"menuitem",
+"dirname",
+"fn",
+"aria-details",
+"feTurbulence",
+"ondatasetcomplete",
+"xChannelSelector",
+"clipPath",
+"max",
+"onafterprint",
+"lcm",
+"merror",
+"fefuncr",
+"gradientunits",
+"font-stretch",
+"largeop",
+"srcdoc",
+"separator",
+"spreadMethod",
+"viewTarget",
+"accent",
+"open",
+"feComposite",
+"scope",
+"elevation",
+"mi",
+"exists",
+"aria-activedescendant",
+"fieldset",
+"fedistantlight",
+"color-profile",
+"geq",
+"onmessage",
+"onpageshow",
+"",
+"lowlimit",
+"mtext",
+"cy",
+"specification",
+"visibility",
+"condition",
+"integers",
+"stroke-dasharray",
+"y2",
+"subset",
+"loading",
+"g2",
+"rtc",
+"mrow",
+"novalidate",
+"clip-path",
+"muted",
+"ellipse",
+"autosubmit",
+"aria-posinset",
+"formmethod",
+"summary",
+"union",
+"abs",
+"fePointLight",
+"onresize",
+"accent-height",
+"input",
+"onbeforepaste",
+"markerHeight",
+"onreadystatechange",
+"pointsAtZ",
+"fecolormatrix",
+"animation",
+"progress",
+"rspace",
+"onmoveend",
+"cellpadding",
+"femerge",
+"eq",
+"color-interpolation-filters",
+"movablelimits",
+"filterunits",
+"unicode",
+"x-height",
+"onreset",
+"onkeypress",
+"notsubset",
+"blockquote",
+"munderover",
+"vert-origin-y",
+"xchannelselector",
+"aria-colcount",
+"math",
+"html",
+"verythickmathspace",
+"foreignobject",
+"edgeMode",
+"onbeforeactivate",
+"fegaussianblur",
+"href",
+"mo",
+"arcsinh",
+"version",
+"onmousemove",
+"linearGradient",
+"filterres",
+"stop",
+"compose",
+"autofocus",
+"textPath",
+"arcrole",
+"article",
+"dy",
+"filter",
+"tbody",
+"definitionurl",
+"baseProfile",
+"line-height",
+"pointsatx",
+"primitiveUnits",
+"sup",
+"marker-mid",
+"media",
+"piecewise",
+"refY",
+"dt",
+"hreflang",
+"onoffline",
+"axis",
+"forall",
+"matrix",
+"repeatDur",
+"onend",
+"tbreak",
+"mpadded",
+"datasrc",
+"slope",
+"leq",
+"language",
+"equalcolumns",
+"poster",
+"main",
+"k3",
+"width",
+"sum",
+"aria-keyshortcuts",
+"async",
+"strike",
+"circle",
+"infinity",
+"oncontrolselect",
+"table",
+"mlabeledtr",
+"baseline-shift",
+"charset",
+"seamless",
+"mmultiscripts",
+"opacity",
+"mpath",
+"notation",
+"onerrorupdate",
+"min",
+"descent",
+"domainofapplication",
+"none",
+"determinant",
+"stroke-miterlimit",
+"dir",
+"grad",
+"pointsAtY",
+"vkern",
+"msup",
+"attributetype",
+"fespotlight",
+"dur",
+"cx",
+"fecomponenttransfer",
+"keyPoints",
+"marker-end",
+"bgsound",
+"font",
+"scriptminsize",
+"kernelUnitLength",
+"form",
+"classid",
+"overline-position",
+"output",
+"orient",
+"lt",
+"mn",
+"nest",
+"setdiff",
+"ruby",
+"mfrac",
+"markerUnits",
+"wbr",
+"menclose",
+"thead",
+"bias",
+"standby",
+"and",
+"zoomandpan",
+"additive",
+"arccos",
+"limit",
+"order",
+"femorphology",
+"feGaussianBlur",
+"mover",
+"root",
+"v-mathematical",
+"arccsch",
+"aria-owns",
+"in2",
+"p",
+"onunload",
+"placeholder",
+"csc",
+"text-anchor",
+"displaystyle",
+"specularExponent",
+"object",
+"numoctaves",
+"fefuncg",
+"dataformatas",
+"arccosh",
+"ondatasetchanged",
+"pre",
+"externalResourcesRequired",
+"columnlines",
+"onhashchange",
+"baseFrequency",
+"stretchy",
+"usemap",
+"tendsto",
+"notprsubset",
+"false",
+"onfocus",
+"ondrag",
+"scale",
+"aria-rowcount",
+"altGlyph",
+"maskcontentunits",
+"frame",
+"controllerchange",
+"frameset",
+"headers",
+"ondrop",
+"vlink",
+"markerWidth",
+"rem",
+"feMerge",
+"allowfullscreen",
+"toggle",
+"br",
+"track",
+"meter",
+"xml:lang",
+"q",
+"itemref",
+"video",
+"verythinmathspace",
+"meta",
+"profile",
+"onforminput",
+"aria-controls",
+"onload",
+"flood-opacity",
+"src",
+"aria-roledescription",
+"aria-haspopup",
+"quotient",
+"repeat-min",
+"strikethrough-thickness",
+"nohref",
+"mfenced",
+"patterntransform",
+"vert-origin-x",
+"altglyphitem",
+"tref",
+"details",
+"rows",
+"overflow",
+"horiz-origin-y",
+"lang",
+"section",
+"patternunits",
+"ondragenter",
+"marginheight",
+"ondragleave",
+"background",
+"text-rendering",
+"bdi",
+"times",
+"preserveAlpha",
+"itemid",
+"onmouseenter",
+"h1",
+"image-rendering",
+"sinh",
+"onmousewheel",
+"ondragdrop",
+"kbd",
+"feFuncA",
+"i",
+"draggable",
+"z",
+"rowlines",
+"fill-opacity",
+"rules",
+"definitionURL",
+"aria-invalid",
+"aria-valuemax",
+"glyph-orientation-vertical",
+"lambda",
+"onrowsdelete",
+"font-face-src",
+"feFuncG",
+"aria-readonly",
+"onafterupdate",
+"itemprop",
+"arccoth",
+"keySplines",
+"xlink:arcrole",
+"arcsec",
+"code",
+"log",
+"aria-describedby",
+"altGlyphDef",
+"lquote",
+"radius",
+"scrolling",
+"hr",
+"fespecularlighting",
+"multicol",
+"pathlength",
+"complexes",
+"pointer-events",
+"multiple",
+"mozbrowser",
+"aria-placeholder",
+"header",
+"path",
+"framespacing",
+"end",
+"arccot",
+"aria-templateid",
+"ceiling",
+"filterUnits",
+"font-size-adjust",
+"contentscripttype",
+"pointsAtX",
+"product",
+"srclang",
+"vspace",
+"valign",
+"base",
+"feBlend",
+"default"] , hashes : & [3274242198u32 , 1310663047u32 , 3471797576u32 , 625288246u32 , 3038441148u32 , 4105594630u32 , 1256699373u32 , 340120193u32 , 1040004347u32 , 977519882u32 , 193163341u32 , 1764594812u32 , 1365633322u32 , 2673802232u32 , 185884973u32 , 4284346275u32 , 3172500845u32 , 1702873090u32 , 1860706754u32 , 3480491085u32 , 2392965895u32 , 898067958u32 , 970872435u32 , 2007550408u32 , 1928649000u32 , 2459092703u32 , 1804017132u32 , 990264918u32 , 970213824u32 , 3674106722u32 , 3293186083u32 , 3629415669u32 , 1008080924u32 , 1853897548u32 , 115637463u32 , 1313537745u32 , 3434111473u32 , 349046089u32 , 316404133u32 , 150823702u32 , 835434193u32 , 2696521111u32 , 1867791235u32 , 2377326793u32 , 3666749829u32 , 2974337319u32 , 1511898050u32 , 3455867731u32 , 3931194778u32 , 1099196523u32 , 3030471804u32 , 1271998022u32 , 3707244343u32 , 3855161187u32 , 261387879u32 , 1371589245u32 , 3712274519u32 , 3896904842u32 , 3895686923u32 , 2909783456u32 , 2172529172u32 , 157988637u32 , 742460070u32 , 1704381425u32 , 1184515296u32 , 3107512368u32 , 1634534214u32 , 1425318046u32 , 497920229u32 , 3467076559u32 , 3667422931u32 , 2140727092u32 , 484169377u32 , 2145215358u32 , 2075053822u32 , 3969247273u32 , 1437637129u32 , 3611920660u32 , 2091062413u32 , 303986147u32 , 4086049772u32 , 731340681u32 , 3615584812u32 , 1409620084u32 , 191876287u32 , 33840900u32 , 3377397637u32 , 482099679u32 , 2832170387u32 , 2659411739u32 , 857129565u32 , 718491279u32 , 2225179655u32 , 3219296642u32 , 54234400u32 , 1545869555u32 , 2875176293u32 , 4267760977u32 , 2190494129u32 , 1980252733u32 , 3664934712u32 , 876893636u32 , 3173477931u32 , 4151148662u32 , 2068298677u32 , 1039356818u32 , 3473820876u32 , 1563912927u32 , 4199680039u32 , 699250933u32 , 6175338u32 , 225950890u32 , 906623274u32 , 2038498193u32 , 771578779u32 , 657335448u32 , 4155473355u32 , 2440702496u32 , 603056529u32 , 2055579803u32 , 2701977759u32 , 1980444132u32 , 2297035459u32 , 2660037162u32 , 1954813431u32 , 1289272887u32 , 3054316836u32 , 3441525639u32 , 3167429639u32 , 3038336232u32 , 1963517842u32 , 2079547615u32 , 4125580644u32 , 42180808u32 , 4224609035u32 , 2062376914u32 , 1406178112u32 , 816344801u32 , 1004995609u32 , 1290517656u32 , 1248144311u32 , 582074557u32 , 517970045u32 , 798432117u32 , 540156035u32 , 2253042667u32 , 3710863668u32 , 627157391u32 , 1964478094u32 , 938548574u32 , 1089255496u32 , 2219898174u32 , 671572742u32 , 4039765020u32 , 1882551413u32 , 2248823500u32 , 1462083347u32 , 4260186941u32 , 4119024989u32 , 3263344947u32 , 2351838155u32 , 705165595u32 , 4214847380u32 , 3933235587u32 , 1733808494u32 , 2250640510u32 , 3902001267u32 , 742853127u32 , 3545802469u32 , 1704100245u32 , 361055874u32 , 2990700622u32 , 3199297686u32 , 3099170635u32 , 2324745345u32 , 671909442u32 , 1855625123u32 , 3766194097u32 , 940673285u32 , 3345816294u32 , 2468381339u32 , 2279738660u32 , 795111050u32 , 1851109935u32 , 322837783u32 , 763259218u32 , 2457901430u32 , 788771646u32 , 1695163510u32 , 3426316285u32 , 2606069879u32 , 1307082681u32 , 2994067376u32 , 1530204897u32 , 1782822870u32 , 3559237039u32 , 4279947214u32 , 3359083184u32 , 4019205149u32 , 4263436362u32 , 2537656704u32 , 3978774502u32 , 2620153124u32 , 1589547421u32 , 2250182719u32 , 1696233065u32 , 42384297u32 , 3278678123u32 , 18699357u32 , 357019207u32 , 2389793136u32 , 2646948180u32 , 3848540887u32 , 1727774507u32 , 3336988988u32 , 2998184538u32 , 3543883658u32 , 2068578658u32 , 11175636u32 , 1313257404u32 , 2108171772u32 , 1578024761u32 , 3522778558u32 , 1031843807u32 , 3666761738u32 , 4080036470u32 , 1808534403u32 , 3271273745u32 , 2069591134u32 , 3184408810u32 , 1020198921u32 , 2550357363u32 , 1904744880u32 , 1281759160u32 , 3119168777u32 , 909665113u32 , 1095105424u32 , 15493316u32 , 3919937075u32 , 1030377645u32 , 2822084181u32 , 3819629636u32 , 3173400481u32 , 1443202521u32 , 2461483617u32 , 54579969u32 , 1003700867u32 , 624093324u32 , 183626286u32 , 4123782853u32 , 1642132156u32 , 630747693u32 , 3984346281u32 , 2378024993u32 , 1924991364u32 , 1638532578u32 , 4089167428u32 , 3140418151u32 , 3224307212u32 , 1844361094u32 , 1545307082u32 , 2211055779u32 , 11851321u32 , 3838527384u32 , 4203201272u32 , 1356591456u32 , 3182531199u32 , 368096184u32 , 2397015458u32 , 3391938715u32 , 2938551886u32 , 179509946u32 , 1630221347u32 , 498024801u32 , 1678664026u32 , 1660726878u32 , 3055049187u32 , 1961077096u32 , 917510687u32 , 1038372645u32 , 1465547969u32 , 4145642027u32 , 1918948260u32 , 1834101858u32 , 1721701609u32 , 1122986055u32 , 1813694309u32 , 910599529u32 , 2884343567u32 , 1788099404u32 , 1340705914u32 , 3590914330u32 , 1855317180u32 , 67912785u32 , 1776997671u32 , 2047753258u32 , 2509936390u32 , 1209573904u32 , 3709793219u32 , 3337178u32 , 479697793u32 , 2785209304u32 , 882636649u32 , 3572217425u32 , 3906128438u32 , 4144887102u32 , 3439885968u32 , 3965997140u32 , 1827361888u32 , 1013909549u32 , 3006277213u32 , 447376357u32 , 2783092694u32 , 355519382u32 , 3200099719u32 , 41264406u32 , 3374296737u32 , 1754115804u32 , 3068760032u32 , 13967138u32 , 3937191824u32 , 2791371120u32 , 696243149u32 , 159552655u32 , 3347513700u32 , 3293521275u32 , 4019313672u32 , 278885378u32 , 2407704912u32 , 1494292933u32 , 1960251696u32 , 2591735095u32 , 849788126u32 , 4197022858u32 , 1114660064u32 , 169227419u32 , 3311581079u32 , 3813953811u32 , 1328456326u32 , 2246839197u32 , 2789342562u32 , 359587721u32 , 1152797465u32 , 882588460u32 , 297767543u32 , 3072954000u32 , 2231254690u32 , 859546789u32 , 3868980231u32 , 155801033u32 , 4266430317u32 , 4121432320u32 , 2761967137u32 , 2213967518u32 , 1882820659u32 , 1746599759u32 , 3983412185u32 , 938891037u32 , 3550926143u32 , 4219469015u32 , 1151125310u32 , 445054362u32 , 3044528111u32 , 3125694201u32 , 3496513502u32 , 3949169397u32 , 688563368u32 , 2907519379u32 , 3614904285u32 , 1199486522u32 , 4001891308u32 , 3677307242u32 , 328232673u32 , 1648013096u32 , 1687848216u32 , 2817038524u32 , 1622799733u32 , 3730423347u32 , 1659529969u32 , 44291915u32 , 1113648994u32 , 1529410224u32 , 1068196084u32 , 2363338602u32 , 3054955216u32 , 3855238303u32 , 741572628u32 , 3504125432u32 , 1114315443u32 , 189988860u32 , 209075522u32 , 2282986093u32 , 4250499820u32 , 1638551108u32 , 1872549968u32 , 4234761996u32 , 2271345682u32 , 2986159298u32 , 3367662520u32 , 544785510u32 , 121548787u32 , 611308476u32 , 1224089u32 , 1437651958u32 , 1751032114u32 , 3434645925u32 , 2113798551u32 , 869956525u32 , 3307009014u32 , 2813597207u32 , 3871587251u32 , 2152634883u32 , 1013528205u32 , 4287406983u32 , 2754786514u32 , 511592759u32 , 2776856061u32 , 1123183688u32 , 431905749u32 , 2756908117u32 , 271329001u32 , 4079840542u32 , 2720850668u32 , 4197990453u32 , 1886467117u32 , 1363498200u32 , 1744675009u32 , 3084283302u32 , 1700718142u32 , 1531081973u32 , 23332298u32 , 4131332030u32 , 846747598u32 , 832628307u32 , 3173625849u32 , 3383742429u32 , 2196119817u32 , 428550904u32 , 2927217335u32 , 3022346510u32 , 2331857769u32 , 4133028771u32 , 2710653095u32 , 1038736949u32 , 1147032844u32 , 441619653u32 , 2095934344u32 , 585557574u32 , 3251283326u32 , 1048711711u32 , 2990288183u32 , 1025781044u32 , 1026931307u32 , 2965230298u32 , 2613470253u32 , 1064789251u32 , 507264248u32 , 2192643058u32 , 4061826754u32 , 2191740635u32 , 1947003845u32 , 1227300306u32 , 2771320901u32 , 1932517039u32 , 2250472488u32 , 551624971u32 , 236464811u32 , 3155395509u32 , 1493588833u32 , 2577957964u32 , 610893786u32 , 1906307066u32 , 1553568401u32 , 1351348774u32 , 824143574u32 , 787973706u32 , 26369323u32 , 290068694u32 , 962337312u32 , 883707780u32 , 89533980u32 , 1326112048u32 , 2135316053u32 , 2274933261u32 , 1853245385u32 , 3368766693u32 , 581625092u32 , 3561280171u32 , 83113090u32 , 797499465u32 , 660920321u32 , 631162154u32 , 697370994u32 , 176981824u32 , 2481108222u32 , 2282081120u32 , 1270424231u32 , 388019751u32 , 2229477384u32 , 3587911337u32 , 1312324510u32 , 3792741489u32 , 3192182081u32 , 34937617u32 , 2070420020u32 , 2798901808u32 , 836037603u32 , 520649620u32 , 3468989663u32 , 2812859730u32 , 1106010321u32 , 3608092222u32 , 1634030783u32 , 1265818470u32 , 1079146945u32 , 2454674208u32 , 739780104u32 , 898289440u32 , 1622957280u32 , 2377615277u32 , 4016453314u32 , 251844155u32 , 4004244404u32 , 2998850704u32 , 4252201132u32 , 1154571981u32 , 1543956842u32 , 1697385883u32 , 1733779952u32 , 3844535459u32 , 1449911196u32 , 1711900506u32 , 1421968030u32 , 1498440529u32 , 782184238u32 , 2271863710u32 , 1670804630u32 , 3281157013u32 , 510569370u32 , 1381343165u32 , 849449597u32 , 2797369290u32 , 130627326u32 , 53892155u32 , 3087731897u32 , 2952660456u32 , 2705478669u32 , 1835393626u32 , 948133874u32 , 4179141959u32 , 635163395u32 , 662200295u32 , 3735993810u32 , 3857554377u32 , 3457414096u32 , 460370253u32 , 1996441005u32 , 1459652027u32 , 1904259492u32 , 3953335784u32 , 326494834u32 , 487396000u32 , 2143535654u32 , 273258113u32 , 2467431560u32 , 2690168509u32 , 620177523u32 , 3023844475u32 , 3161172129u32 , 2322716179u32 , 3776643770u32 , 1292033197u32 , 415004145u32 , 2040992428u32 , 1585700207u32 , 1327970202u32 , 3727352922u32 , 1386122418u32 , 3290302249u32 , 3261773436u32 , 4048787516u32 , 3421247560u32 , 2731266052u32 , 2555432291u32 , 1507707020u32 , 2488889144u32 , 3564373280u32 , 4088548386u32 , 2979666456u32 , 1325939503u32 , 1488329004u32 , 960352296u32 , 1102757337u32 , 173634036u32 , 533400559u32 , 422796352u32 , 3022377460u32 , 2117758015u32 , 3012156523u32 , 946506250u32 , 1273749580u32 , 2835436835u32 , 593929698u32 , 1135843586u32 , 2467217256u32 , 1503507106u32 , 577943792u32 , 894428246u32 , 2218757200u32 , 3862168567u32 , 143478574u32 , 4104071589u32 , 1436742140u32 , 3802817635u32 , 1000698605u32 , 937717349u32 , 1969026116u32 , 2570653005u32 , 8379005u32 , 3039008911u32 , 2353997387u32 , 2340151391u32 , 3478361443u32 , 3799256240u32 , 123800926u32 , 3349181976u32 , 1460139621u32 , 4208387215u32 , 2548120553u32 , 3315223323u32 , 3087218890u32 , 1496007974u32 , 551601771u32 , 3637562920u32 , 1402964693u32 , 842732693u32 , 3285832223u32 , 3808849337u32 , 3727156162u32 , 374628926u32 , 1493772713u32 , 1713731671u32 , 2648768565u32 , 3423054706u32 , 2146706716u32 , 3342514579u32 , 3275662599u32 , 3247137570u32 , 4064148976u32 , 2965140528u32 , 1208964997u32 , 1527443701u32 , 1754753923u32 , 3859396703u32 , 1048777406u32 , 2983961815u32 , 1715378710u32 , 904682406u32 , 1523977509u32 , 171222834u32 , 396757642u32 , 748030312u32 , 2125303890u32 , 849353939u32 , 414121004u32 , 3805722218u32 , 4081904869u32 , 2548012788u32 , 79799947u32 , 3847257422u32 , 2961964106u32 , 1760739777u32 , 59658625u32 , 1232676803u32 , 3835553204u32 , 3225919750u32 , 2980412961u32 , 175932733u32 , 4193230156u32 , 4118614427u32 , 2381415889u32 , 471362903u32 , 1805514067u32 , 998270762u32 , 2695151648u32 , 3031015162u32 , 3278498399u32 , 3318082606u32 , 4015812680u32 , 1784113404u32 , 3590479039u32 , 3148690945u32 , 1633961095u32 , 3864273530u32 , 95564789u32 , 1259852793u32 , 1968334064u32 , 491405495u32 , 3377140045u32 , 139625561u32 , 4121872099u32 , 3860308009u32 , 3682934193u32 , 2338483475u32 , 2458868732u32 , 3336636850u32 , 59137076u32 , 1268358839u32 , 1846373592u32 , 590134515u32 , 1946543310u32 , 1500515759u32 , 1288088242u32 , 1737183206u32 , 3017840974u32 , 3812424145u32 , 3897195105u32 , 636181447u32 , 1100105663u32 , 3841841410u32 , 308719458u32 , 3574495545u32 , 1081021937u32 , 2530726984u32 , 2107411667u32 , 775230342u32 , 623782337u32 , 2801309061u32 , 2870941502u32 , 1159398089u32 , 1960989138u32 , 3756296120u32 , 13939510u32 , 1857928766u32 , 3668239605u32 , 2816759430u32 , 3708044859u32 , 3011660448u32 , 1064147050u32 , 57662688u32 , 527925402u32 , 207807063u32 , 3181810604u32 , 844290399u32 , 3391050331u32 , 3111674091u32 , 4133853804u32 , 516688877u32 , 2624297068u32 , 513392110u32 , 3650491062u32 , 3376838313u32 , 1324932700u32 , 1647114263u32 , 3588373335u32 , 3517724257u32 , 1343823872u32 , 4204390246u32 , 785460432u32 , 2985998690u32 , 951933631u32 , 413668059u32 , 787991425u32 , 2778544105u32 , 839481326u32 , 2274905264u32 , 270981811u32 , 1014874794u32 , 1109713652u32 , 3430839532u32 , 3495408325u32 , 986461641u32 , 1621808796u32 , 3015830598u32 , 1285071230u32 , 4257931290u32 , 3296146244u32 , 3483791417u32 , 3273539984u32 , 1146457377u32 , 2086755244u32 , 4213661298u32 , 2410855252u32 , 297387744u32 , 738404731u32 , 4110733451u32 , 2840815696u32 , 3088760233u32 , 4049759821u32 , 3101068841u32 , 2531535454u32 , 2770211272u32 , 1060911337u32 , 1956171144u32 , 1265017544u32 , 259654618u32 , 150393700u32 , 3081310440u32 , 604024844u32 , 3414928998u32 , 149474300u32 , 3711536507u32 , 3074935477u32 , 2637290003u32 , 3742507012u32 , 3356497412u32 , 167018041u32 , 597485693u32 , 3123322936u32 , 3138542682u32 , 2526332624u32 , 1784393862u32 , 4082073077u32 , 2766318564u32 , 777563392u32 , 1697590725u32 , 2052860768u32 , 680267915u32 , 1492357159u32 , 1359418558u32 , 2806594463u32 , 927236544u32 , 1984356571u32 , 3267395277u32 , 434586848u32 , 4105776530u32 , 281302065u32 , 1694998203u32 , 3745411061u32 , 3361694260u32 , 1824216206u32 , 3174660173u32 , 1086403303u32 , 2901051737u32 , 1925546115u32 , 418618809u32 , 2778411632u32 , 2112894915u32 , 1397943031u32 , 2749033157u32 , 3790724572u32 , 835417400u32 , 3420533286u32 , 1081225797u32 , 2162760400u32 , 2627228424u32 , 1344436999u32 , 3164895664u32 , 2877167735u32 , 722849851u32 , 3303937590u32 , 1850141989u32 , 4027115771u32 , 840604679u32 , 1490652026u32 , 3520680811u32 , 3902548497u32 , 67060975u32 , 485064890u32 , 491646294u32 , 1123905875u32 , 1289880550u32 , 1108361085u32 , 1137297938u32 , 4064199297u32 , 2762337148u32 , 342910504u32 , 3754959190u32 , 4274065971u32 , 904157188u32 , 427162403u32 , 1833541319u32 , 2606928828u32 , 4069948231u32 , 3395089108u32 , 145077647u32 , 67570456u32 , 3598404451u32 , 1956396338u32 , 314533796u32 , 1119904607u32 , 2042178864u32 , 2488083091u32 , 2005060454u32 , 2933611753u32 , 2922547937u32 , 3555644606u32 , 1494323141u32 , 1495418526u32 , 790236809u32 , 268294220u32 , 1029483847u32 , 2196595662u32 , 235814134u32 , 752636074u32 , 3503166008u32 , 718703276u32 , 1340310784u32 , 2049953055u32 , 1175213659u32 , 2619524090u32 , 496466373u32 , 3040373773u32 , 1306006527u32 , 2052503918u32 , 642136761u32 , 3924127257u32 , 4194776985u32 , 1899012687u32 , 2509793760u32 , 1181865717u32 , 2295989784u32 , 3213189697u32 , 3371793166u32 , 534663353u32 , 4262303727u32 , 2790534997u32 , 2418868736u32 , 1170121292u32 , 431655942u32 , 4061972332u32 , 3433960873u32 , 822105573u32 , 3064390904u32 , 1085252765u32 , 3250191289u32 , 167340041u32 , 1372447217u32 , 3810706127u32 , 1967061597u32 , 3595420161u32 , 1816790706u32 , 1682171771u32 , 1196494198u32 , 2176790048u32 , 683509362u32 , 3682806967u32 , 1000927536u32 , 4215833524u32 , 4114830095u32 , 1597870884u32 , 1966251997u32 , 1948197221u32 , 3717621328u32 , 4041034977u32 , 207735385u32 , 3447324541u32 , 1469020628u32 , 2234510680u32 , 3963094042u32 , 1390052554u32 , 3937177209u32 , 1639866691u32 , 654777154u32 , 3880329584u32 , 776053845u32 , 3394829011u32 , 2024968194u32 , 2832092832u32 , 24676607u32 , 4242245856u32 , 2442913746u32 , 3865554921u32 , 4003256955u32 , 315569262u32 , 3350461016u32 , 1333435944u32 , 2210310308u32 , 3390498297u32 , 2362637345u32 , 2825708013u32 , 2752679045u32 , 3257443589u32 , 2344768153u32 , 1150843912u32 , 3653391143u32 , 2271299428u32 , 311085664u32 , 4015803665u32 , 290158395u32 , 1031924441u32 , 127349794u32 , 3094279332u32 , 4041222665u32 , 4032437431u32 , 2003234786u32 , 1528320406u32 , 1587898242u32 , 3868900413u32 , 1270941540u32 , 4227008938u32 , 4103141842u32 , 1747256964u32 , 1693167880u32 , 3353385752u32 , 4036843480u32 , 3505831613u32 , 712744979u32 , 3538034810u32 , 1687463409u32 , 4067843623u32 , 4263383224u32 , 4114207458u32 , 3139000449u32 , 3316294029u32 , 214212608u32 , 4157184116u32 , 2229560705u32 , 2897211203u32 , 3646787135u32 , 3205621890u32 , 3364636024u32 , 2166163946u32 , 1677013279u32 , 1025361169u32 , 3482160649u32 , 3648607726u32 , 942939809u32 , 3888122413u32 , 2822081788u32 , 51506945u32 , 2603585436u32 , 3230490522u32 , 3535003966u32 , 3929006940u32 , 3958599450u32 , 3553740950u32 , 2634614319u32 , 4014958776u32 , 655080190u32 , 258670217u32 , 2577707114u32 , 494432567u32 , 3182856880u32 , 1843828572u32 , 868058960u32 , 3332067991u32 , 2812786495u32 , 872051764u32 , 269731020u32 , 3658418485u32 , 1048020461u32 , 3428477807u32 , 1070642373u32 , 2893479988u32 , 890471348u32 , 3231125223u32 , 3978549325u32 , 3757867551u32 , 3021310310u32 , 3469436088u32 , 2286720981u32 , 1160497299u32 , 1282825161u32 , 274055517u32 , 1479082945u32 , 4010765371u32 , 1582153498u32 , 1297307566u32 , 768955282u32 , 3221036252u32 , 1346406727u32 , 1341662051u32 , 1356155463u32 , 540984354u32 , 1466152561u32 , 1368901689u32 , 2491696158u32 , 1054325476u32 , 3435481251u32 , 745064063u32 , 1058837628u32 , 3725445512u32 , 1048380744u32 , 825000363u32 , 4174481087u32 , 4232762703u32 , 544309206u32 , 503445220u32 , 1213081654u32 , 1849672485u32 , 1943821868u32 , 2519983718u32 , 2185800901u32 , 1411183032u32 , 3472575083u32 , 2627759492u32 , 3720179864u32 , 1563370450u32 , 3436454788u32 , 3569972244u32 , 4199665453u32 , 2753608321u32 , 1463711312u32 , 3364582022u32 , 300579694u32 , 2607187618u32 , 1674115585u32 , 2679162678u32 , 262645782u32 , 2330201181u32 , 2730644329u32 , 631093780u32 , 1689950037u32 , 3413889554u32 , 372536167u32 , 3528056260u32 , 968834697u32 , 284653812u32 , 3041455211u32 , 3761485389u32 , 570359024u32 , 2907088801u32 , 2352150961u32 , 369465691u32 , 829316774u32 , 1380239101u32 , 2384049441u32 , 908933404u32 , 2751698208u32 , 2869380463u32 , 903602157u32 , 717168679u32 , 909579565u32 , 4018656289u32 , 1713409972u32 , 447734275u32 , 3222412735u32 , 3794671005u32 , 613227492u32 , 601046546u32 , 1536632361u32 , 2994561025u32] } ;
+& SET } fn empty_string_index () -> u32 { 812u32 } } pub const ATOM_LOCALNAME__6F_6E_68_65_6C_70 : LocalName = LocalName :: pack_static (0u32) ;
+pub const ATOM_LOCALNAME__62_75_74_74_6F_6E : LocalName = LocalName :: pack_static (1u32) ;
+pub const ATOM_LOCALNAME__6E_6F_74_61_6E_75_6D_62_65_72 : LocalName = LocalName :: pack_static (2u32) ;
+pub const ATOM_LOCALNAME__6F_6E_6C_61_6E_67_75_61_67_65_63_68_61_6E_67_65 : LocalName = LocalName :: pack_static (3u32) ;
+pub const ATOM_LOCALNAME__74_66_6F_6F_74 : LocalName = LocalName :: pack_static (4u32) ;
+pub const ATOM_LOCALNAME__64_6C : LocalName = LocalName :: pack_static (5u32) ;
+pub const ATOM_LOCALNAME__70_6F_77_65_72 : LocalName = LocalName :: pack_static (6u32) ;
+pub const ATOM_LOCALNAME__66_61_63_74_6F_72_6F_66 : LocalName = LocalName :: pack_static (7u32) ;
+pub const ATOM_LOCALNAME__61_6E_6E_6F_74_61_74_69_6F_6E_2D_78_6D_6C : LocalName = LocalName :: pack_static (8u32) ;
+pub const ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_65_64_69_74_66_6F_63_75_73 : LocalName = LocalName :: pack_static (9u32) ;
+pub const ATOM_LOCALNAME__63_6C_69_70_50_61_74_68_55_6E_69_74_73 : LocalName = LocalName :: pack_static (10u32) ;
+pub const ATOM_LOCALNAME__6F_76_65_72_6C_69_6E_65_2D_74_68_69_63_6B_6E_65_73_73 : LocalName = LocalName :: pack_static (11u32) ;
+pub const ATOM_LOCALNAME__63_6C_69_70_70_61_74_68 : LocalName = LocalName :: pack_static (12u32) ;
+pub const ATOM_LOCALNAME__75_6E_69_74_73_2D_70_65_72_2D_65_6D : LocalName = LocalName :: pack_static (13u32) ;
+pub const ATOM_LOCALNAME__61_73_69_64_65 : LocalName = LocalName :: pack_static (14u32) ;
+pub const ATOM_LOCALNAME__6D_69_6E_75_73 : LocalName = LocalName :: pack_static (15u32) ;
+pub const ATOM_LOCALNAME__74_68_69_6E_6D_61_74_68_73_70_61_63_65 : LocalName = LocalName :: pack_static (16u32) ;
+pub const ATOM_LOCALNAME__65_6D_62_65_64 : LocalName = LocalName :: pack_static (17u32) ;
+pub const ATOM_LOCALNAME__73_6F_75_72_63_65 : LocalName = LocalName :: pack_static (18u32) ;
+pub const ATOM_LOCALNAME__68_6F_72_69_7A_2D_61_64_76_2D_78 : LocalName = LocalName :: pack_static (19u32) ;
+pub const ATOM_LOCALNAME__73_63_72_69_70_74 : LocalName = LocalName :: pack_static (20u32) ;
+pub const ATOM_LOCALNAME__64_69_66_66_75_73_65_43_6F_6E_73_74_61_6E_74 : LocalName = LocalName :: pack_static (21u32) ;
+pub const ATOM_LOCALNAME__74_64 : LocalName = LocalName :: pack_static (22u32) ;
+pub const ATOM_LOCALNAME__73_74_72_6F_6B_65_2D_6F_70_61_63_69_74_79 : LocalName = LocalName :: pack_static (23u32) ;
+pub const ATOM_LOCALNAME__76_61_6C_75_65 : LocalName = LocalName :: pack_static (24u32) ;
+pub const ATOM_LOCALNAME__6E_6F_62_72 : LocalName = LocalName :: pack_static (25u32) ;
+pub const ATOM_LOCALNAME__61_6E_69_6D_61_74_65 : LocalName = LocalName :: pack_static (26u32) ;
+pub const ATOM_LOCALNAME__6F_6E_66_69_6C_74_65_72_63_68_61_6E_67_65 : LocalName = LocalName :: pack_static (27u32) ;
+pub const ATOM_LOCALNAME__73_74_72_6F_6B_65 : LocalName = LocalName :: pack_static (28u32) ;
+pub const ATOM_LOCALNAME__61_63_74_69_6F_6E_74_79_70_65 : LocalName = LocalName :: pack_static (29u32) ;
+pub const ATOM_LOCALNAME__73_74_72_69_6E_67 : LocalName = LocalName :: pack_static (30u32) ;
+pub const ATOM_LOCALNAME__78_6C_69_6E_6B_3A_74_79_70_65 : LocalName = LocalName :: pack_static (31u32) ;
+pub const ATOM_LOCALNAME__6D_61_73_6B_75_6E_69_74_73 : LocalName = LocalName :: pack_static (32u32) ;
+pub const ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_70_72_69_6E_74 : LocalName = LocalName :: pack_static (33u32) ;
+pub const ATOM_LOCALNAME__78_6C_69_6E_6B_3A_74_69_74_6C_65 : LocalName = LocalName :: pack_static (34u32) ;
+pub const ATOM_LOCALNAME__67_72_61_64_69_65_6E_74_54_72_61_6E_73_66_6F_72_6D : LocalName = LocalName :: pack_static (35u32) ;
+pub const ATOM_LOCALNAME__6B_65_72_6E_65_6C_75_6E_69_74_6C_65_6E_67_74_68 : LocalName = LocalName :: pack_static (36u32) ;
+pub const ATOM_LOCALNAME__73_79_6D_62_6F_6C : LocalName = LocalName :: pack_static (37u32) ;
+pub const ATOM_LOCALNAME__64_69_72_65_63_74_69_6F_6E : LocalName = LocalName :: pack_static (38u32) ;
+pub const ATOM_LOCALNAME__6F_70_74_69_6F_6E : LocalName = LocalName :: pack_static (39u32) ;
+pub const ATOM_LOCALNAME__64_69_76_69_73_6F_72 : LocalName = LocalName :: pack_static (40u32) ;
+pub const ATOM_LOCALNAME__61 : LocalName = LocalName :: pack_static (41u32) ;
+pub const ATOM_LOCALNAME__6D_65_64_69_75_6D_6D_61_74_68_73_70_61_63_65 : LocalName = LocalName :: pack_static (42u32) ;
+pub const ATOM_LOCALNAME__73_74_6F_70_2D_6F_70_61_63_69_74_79 : LocalName = LocalName :: pack_static (43u32) ;
+pub const ATOM_LOCALNAME__6D_67_6C_79_70_68 : LocalName = LocalName :: pack_static (44u32) ;
+pub const ATOM_LOCALNAME__74_61_62_6C_65_76_61_6C_75_65_73 : LocalName = LocalName :: pack_static (45u32) ;
+pub const ATOM_LOCALNAME__6B_65_72_6E_65_6C_6D_61_74_72_69_78 : LocalName = LocalName :: pack_static (46u32) ;
+pub const ATOM_LOCALNAME__74_61_72_67_65_74_59 : LocalName = LocalName :: pack_static (47u32) ;
+pub const ATOM_LOCALNAME__63_6F_6C_75_6D_6E_77_69_64_74_68 : LocalName = LocalName :: pack_static (48u32) ;
+pub const ATOM_LOCALNAME__63_72_6F_73_73_6F_72_69_67_69_6E : LocalName = LocalName :: pack_static (49u32) ;
+pub const ATOM_LOCALNAME__74_68 : LocalName = LocalName :: pack_static (50u32) ;
+pub const ATOM_LOCALNAME__6F_6E_6D_6F_76_65_73_74_61_72_74 : LocalName = LocalName :: pack_static (51u32) ;
+pub const ATOM_LOCALNAME__73_70_65_65_64 : LocalName = LocalName :: pack_static (52u32) ;
+pub const ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_63_6F_70_79 : LocalName = LocalName :: pack_static (53u32) ;
+pub const ATOM_LOCALNAME__73_63_72_69_70_74_6C_65_76_65_6C : LocalName = LocalName :: pack_static (54u32) ;
+pub const ATOM_LOCALNAME__63_6F_6C_73_70_61_6E : LocalName = LocalName :: pack_static (55u32) ;
+pub const ATOM_LOCALNAME__64_65_63_6C_61_72_65 : LocalName = LocalName :: pack_static (56u32) ;
+pub const ATOM_LOCALNAME__6F_6E_70_61_73_74_65 : LocalName = LocalName :: pack_static (57u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_72_65_6C_65_76_61_6E_74 : LocalName = LocalName :: pack_static (58u32) ;
+pub const ATOM_LOCALNAME__64_69_66_66_75_73_65_63_6F_6E_73_74_61_6E_74 : LocalName = LocalName :: pack_static (59u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_73_6F_72_74 : LocalName = LocalName :: pack_static (60u32) ;
+pub const ATOM_LOCALNAME__61_72_67 : LocalName = LocalName :: pack_static (61u32) ;
+pub const ATOM_LOCALNAME__72_65_70_65_61_74_2D_6D_61_78 : LocalName = LocalName :: pack_static (62u32) ;
+pub const ATOM_LOCALNAME__6D_61_72_6B_65_72_2D_73_74_61_72_74 : LocalName = LocalName :: pack_static (63u32) ;
+pub const ATOM_LOCALNAME__65_78_70 : LocalName = LocalName :: pack_static (64u32) ;
+pub const ATOM_LOCALNAME__78_6D_6C_3A_62_61_73_65 : LocalName = LocalName :: pack_static (65u32) ;
+pub const ATOM_LOCALNAME__68_69_64_64_65_6E : LocalName = LocalName :: pack_static (66u32) ;
+pub const ATOM_LOCALNAME__64_65_66_69_6E_69_74_69_6F_6E_2D_73_72_63 : LocalName = LocalName :: pack_static (67u32) ;
+pub const ATOM_LOCALNAME__78_6C_69_6E_6B_3A_61_63_74_75_61_74_65 : LocalName = LocalName :: pack_static (68u32) ;
+pub const ATOM_LOCALNAME__6D_6F_6D_65_6E_74_61_62_6F_75_74 : LocalName = LocalName :: pack_static (69u32) ;
+pub const ATOM_LOCALNAME__72_65_70_6C_61_63_65 : LocalName = LocalName :: pack_static (70u32) ;
+pub const ATOM_LOCALNAME__66_6F_6E_74_73_69_7A_65 : LocalName = LocalName :: pack_static (71u32) ;
+pub const ATOM_LOCALNAME__6D_73_70_61_63_65 : LocalName = LocalName :: pack_static (72u32) ;
+pub const ATOM_LOCALNAME__70_6F_69_6E_74_73_61_74_79 : LocalName = LocalName :: pack_static (73u32) ;
+pub const ATOM_LOCALNAME__73_74_61_74_65_63_68_61_6E_67_65 : LocalName = LocalName :: pack_static (74u32) ;
+pub const ATOM_LOCALNAME__63_68_65_63_6B_65_64 : LocalName = LocalName :: pack_static (75u32) ;
+pub const ATOM_LOCALNAME__69_74_65_6D_73_63_6F_70_65 : LocalName = LocalName :: pack_static (76u32) ;
+pub const ATOM_LOCALNAME__73_75_62 : LocalName = LocalName :: pack_static (77u32) ;
+pub const ATOM_LOCALNAME__66_65_44_69_73_70_6C_61_63_65_6D_65_6E_74_4D_61_70 : LocalName = LocalName :: pack_static (78u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_72_65_71_75_69_72_65_64 : LocalName = LocalName :: pack_static (79u32) ;
+pub const ATOM_LOCALNAME__6C_69_6D_69_74_69_6E_67_63_6F_6E_65_61_6E_67_6C_65 : LocalName = LocalName :: pack_static (80u32) ;
+pub const ATOM_LOCALNAME__78_6C_69_6E_6B_3A_73_68_6F_77 : LocalName = LocalName :: pack_static (81u32) ;
+pub const ATOM_LOCALNAME__66_65_74_63_68 : LocalName = LocalName :: pack_static (82u32) ;
+pub const ATOM_LOCALNAME__66_65_66_75_6E_63_61 : LocalName = LocalName :: pack_static (83u32) ;
+pub const ATOM_LOCALNAME__6D_61_74_68_73_69_7A_65 : LocalName = LocalName :: pack_static (84u32) ;
+pub const ATOM_LOCALNAME__73_74_61_72_74_4F_66_66_73_65_74 : LocalName = LocalName :: pack_static (85u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_76_61_6C_75_65_6E_6F_77 : LocalName = LocalName :: pack_static (86u32) ;
+pub const ATOM_LOCALNAME__63_6F_6C_67_72_6F_75_70 : LocalName = LocalName :: pack_static (87u32) ;
+pub const ATOM_LOCALNAME__64_61_74_65_74_69_6D_65 : LocalName = LocalName :: pack_static (88u32) ;
+pub const ATOM_LOCALNAME__70_61_72_73_65 : LocalName = LocalName :: pack_static (89u32) ;
+pub const ATOM_LOCALNAME__74_65_78_74_2D_64_65_63_6F_72_61_74_69_6F_6E : LocalName = LocalName :: pack_static (90u32) ;
+pub const ATOM_LOCALNAME__70_61_74_74_65_72_6E_55_6E_69_74_73 : LocalName = LocalName :: pack_static (91u32) ;
+pub const ATOM_LOCALNAME__70_72_69_6D_65_73 : LocalName = LocalName :: pack_static (92u32) ;
+pub const ATOM_LOCALNAME__61_6C_74 : LocalName = LocalName :: pack_static (93u32) ;
+pub const ATOM_LOCALNAME__6F_66_66_73_65_74 : LocalName = LocalName :: pack_static (94u32) ;
+pub const ATOM_LOCALNAME__6F_72_69_65_6E_74_61_74_69_6F_6E : LocalName = LocalName :: pack_static (95u32) ;
+pub const ATOM_LOCALNAME__66_65_53_70_65_63_75_6C_61_72_4C_69_67_68_74_69_6E_67 : LocalName = LocalName :: pack_static (96u32) ;
+pub const ATOM_LOCALNAME__63_65_6E_74_65_72 : LocalName = LocalName :: pack_static (97u32) ;
+pub const ATOM_LOCALNAME__6E_75_6D_4F_63_74_61_76_65_73 : LocalName = LocalName :: pack_static (98u32) ;
+pub const ATOM_LOCALNAME__6B_65_79_73_70_6C_69_6E_65_73 : LocalName = LocalName :: pack_static (99u32) ;
+pub const ATOM_LOCALNAME__74_69_74_6C_65 : LocalName = LocalName :: pack_static (100u32) ;
+pub const ATOM_LOCALNAME__69_74_65_6D_74_79_70_65 : LocalName = LocalName :: pack_static (101u32) ;
+pub const ATOM_LOCALNAME__6D_61_72_6B_65_72_75_6E_69_74_73 : LocalName = LocalName :: pack_static (102u32) ;
+pub const ATOM_LOCALNAME__6F_75_74_65_72_70_72_6F_64_75_63_74 : LocalName = LocalName :: pack_static (103u32) ;
+pub const ATOM_LOCALNAME__66_6C_6F_6F_64_2D_63_6F_6C_6F_72 : LocalName = LocalName :: pack_static (104u32) ;
+pub const ATOM_LOCALNAME__61_63_63_65_6E_74_75_6E_64_65_72 : LocalName = LocalName :: pack_static (105u32) ;
+pub const ATOM_LOCALNAME__61_6C_74_67_6C_79_70_68 : LocalName = LocalName :: pack_static (106u32) ;
+pub const ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_6F_75_74 : LocalName = LocalName :: pack_static (107u32) ;
+pub const ATOM_LOCALNAME__6D_61_63_74_69_6F_6E : LocalName = LocalName :: pack_static (108u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_63_6F_6C_69_6E_64_65_78 : LocalName = LocalName :: pack_static (109u32) ;
+pub const ATOM_LOCALNAME__73_79_73_74_65_6D_6C_61_6E_67_75_61_67_65 : LocalName = LocalName :: pack_static (110u32) ;
+pub const ATOM_LOCALNAME__77_6F_72_64_2D_73_70_61_63_69_6E_67 : LocalName = LocalName :: pack_static (111u32) ;
+pub const ATOM_LOCALNAME__72_61_64_69_6F_67_72_6F_75_70 : LocalName = LocalName :: pack_static (112u32) ;
+pub const ATOM_LOCALNAME__72_61_64_69_61_6C_67_72_61_64_69_65_6E_74 : LocalName = LocalName :: pack_static (113u32) ;
+pub const ATOM_LOCALNAME__73_72_63_73_65_74 : LocalName = LocalName :: pack_static (114u32) ;
+pub const ATOM_LOCALNAME__75_6E_64_65_72_6C_69_6E_65_2D_74_68_69_63_6B_6E_65_73_73 : LocalName = LocalName :: pack_static (115u32) ;
+pub const ATOM_LOCALNAME__6D_61_78_6C_65_6E_67_74_68 : LocalName = LocalName :: pack_static (116u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_73_65_74_73_69_7A_65 : LocalName = LocalName :: pack_static (117u32) ;
+pub const ATOM_LOCALNAME__75_32 : LocalName = LocalName :: pack_static (118u32) ;
+pub const ATOM_LOCALNAME__73_74_72_69_6B_65_74_68_72_6F_75_67_68_2D_70_6F_73_69_74_69_6F_6E : LocalName = LocalName :: pack_static (119u32) ;
+pub const ATOM_LOCALNAME__66_65_53_70_6F_74_4C_69_67_68_74 : LocalName = LocalName :: pack_static (120u32) ;
+pub const ATOM_LOCALNAME__6D_61_74_72_69_78_72_6F_77 : LocalName = LocalName :: pack_static (121u32) ;
+pub const ATOM_LOCALNAME__64_61_74_61 : LocalName = LocalName :: pack_static (122u32) ;
+pub const ATOM_LOCALNAME__66_65_63_6F_6D_70_6F_73_69_74_65 : LocalName = LocalName :: pack_static (123u32) ;
+pub const ATOM_LOCALNAME__6B_34 : LocalName = LocalName :: pack_static (124u32) ;
+pub const ATOM_LOCALNAME__6C_69_73_74_65_6E_65_72 : LocalName = LocalName :: pack_static (125u32) ;
+pub const ATOM_LOCALNAME__75_6E_69_63_6F_64_65_2D_72_61_6E_67_65 : LocalName = LocalName :: pack_static (126u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_61_74_6F_6D_69_63 : LocalName = LocalName :: pack_static (127u32) ;
+pub const ATOM_LOCALNAME__73_6D_61_6C_6C : LocalName = LocalName :: pack_static (128u32) ;
+pub const ATOM_LOCALNAME__66_6F_6E_74_2D_66_61_63_65_2D_75_72_69 : LocalName = LocalName :: pack_static (129u32) ;
+pub const ATOM_LOCALNAME__65_6E_63_74_79_70_65 : LocalName = LocalName :: pack_static (130u32) ;
+pub const ATOM_LOCALNAME__70_6F_69_6E_74_73 : LocalName = LocalName :: pack_static (131u32) ;
+pub const ATOM_LOCALNAME__76_65_72_79_76_65_72_79_74_68_69_6E_6D_61_74_68_73_70_61_63_65 : LocalName = LocalName :: pack_static (132u32) ;
+pub const ATOM_LOCALNAME__62_65_76_65_6C_6C_65_64 : LocalName = LocalName :: pack_static (133u32) ;
+pub const ATOM_LOCALNAME__73_63_6F_70_65_64 : LocalName = LocalName :: pack_static (134u32) ;
+pub const ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_6F_76_65_72 : LocalName = LocalName :: pack_static (135u32) ;
+pub const ATOM_LOCALNAME__66_65_4F_66_66_73_65_74 : LocalName = LocalName :: pack_static (136u32) ;
+pub const ATOM_LOCALNAME__61_63_74_69_76_65 : LocalName = LocalName :: pack_static (137u32) ;
+pub const ATOM_LOCALNAME__61_6D_70_6C_69_74_75_64_65 : LocalName = LocalName :: pack_static (138u32) ;
+pub const ATOM_LOCALNAME__74_65_78_74_70_61_74_68 : LocalName = LocalName :: pack_static (139u32) ;
+pub const ATOM_LOCALNAME__75 : LocalName = LocalName :: pack_static (140u32) ;
+pub const ATOM_LOCALNAME__66_65_64_69_66_66_75_73_65_6C_69_67_68_74_69_6E_67 : LocalName = LocalName :: pack_static (141u32) ;
+pub const ATOM_LOCALNAME__6D_61_72_67_69_6E_77_69_64_74_68 : LocalName = LocalName :: pack_static (142u32) ;
+pub const ATOM_LOCALNAME__70_61_6E_6F_73_65_2D_31 : LocalName = LocalName :: pack_static (143u32) ;
+pub const ATOM_LOCALNAME__76_61_72_69_61_6E_63_65 : LocalName = LocalName :: pack_static (144u32) ;
+pub const ATOM_LOCALNAME__64_69_73_61_62_6C_65_64 : LocalName = LocalName :: pack_static (145u32) ;
+pub const ATOM_LOCALNAME__6F_6E_73_74_61_72_74 : LocalName = LocalName :: pack_static (146u32) ;
+pub const ATOM_LOCALNAME__61_63_74_75_61_74_65 : LocalName = LocalName :: pack_static (147u32) ;
+pub const ATOM_LOCALNAME__76_65_72_79_76_65_72_79_74_68_69_63_6B_6D_61_74_68_73_70_61_63_65 : LocalName = LocalName :: pack_static (148u32) ;
+pub const ATOM_LOCALNAME__66_6F_6E_74_2D_73_69_7A_65 : LocalName = LocalName :: pack_static (149u32) ;
+pub const ATOM_LOCALNAME__67_72_61_64_69_65_6E_74_55_6E_69_74_73 : LocalName = LocalName :: pack_static (150u32) ;
+pub const ATOM_LOCALNAME__6D_61_73_6B : LocalName = LocalName :: pack_static (151u32) ;
+pub const ATOM_LOCALNAME__6F_70_65_72_61_74_6F_72 : LocalName = LocalName :: pack_static (152u32) ;
+pub const ATOM_LOCALNAME__79_31 : LocalName = LocalName :: pack_static (153u32) ;
+pub const ATOM_LOCALNAME__61_72_63_63_73_63 : LocalName = LocalName :: pack_static (154u32) ;
+pub const ATOM_LOCALNAME__6F_6E_6B_65_79_75_70 : LocalName = LocalName :: pack_static (155u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_66_6C_6F_77_74_6F : LocalName = LocalName :: pack_static (156u32) ;
+pub const ATOM_LOCALNAME__75_6E_69_63_6F_64_65_2D_62_69_64_69 : LocalName = LocalName :: pack_static (157u32) ;
+pub const ATOM_LOCALNAME__73_61_6D_70 : LocalName = LocalName :: pack_static (158u32) ;
+pub const ATOM_LOCALNAME__6F_6E_73_63_72_6F_6C_6C : LocalName = LocalName :: pack_static (159u32) ;
+pub const ATOM_LOCALNAME__66_6F_6E_74_2D_66_61_63_65_2D_66_6F_72_6D_61_74 : LocalName = LocalName :: pack_static (160u32) ;
+pub const ATOM_LOCALNAME__69_6E_74 : LocalName = LocalName :: pack_static (161u32) ;
+pub const ATOM_LOCALNAME__6D_69_73_73_69_6E_67_2D_67_6C_79_70_68 : LocalName = LocalName :: pack_static (162u32) ;
+pub const ATOM_LOCALNAME__63_6F_6C_73 : LocalName = LocalName :: pack_static (163u32) ;
+pub const ATOM_LOCALNAME__62_69_67 : LocalName = LocalName :: pack_static (164u32) ;
+pub const ATOM_LOCALNAME__69_6E_74_65_72_63_65_70_74 : LocalName = LocalName :: pack_static (165u32) ;
+pub const ATOM_LOCALNAME__73_76_67 : LocalName = LocalName :: pack_static (166u32) ;
+pub const ATOM_LOCALNAME__73_63_61_6C_61_72_70_72_6F_64_75_63_74 : LocalName = LocalName :: pack_static (167u32) ;
+pub const ATOM_LOCALNAME__6E_61_6D_65 : LocalName = LocalName :: pack_static (168u32) ;
+pub const ATOM_LOCALNAME__74_65_78_74_6C_65_6E_67_74_68 : LocalName = LocalName :: pack_static (169u32) ;
+pub const ATOM_LOCALNAME__69_6D_61_67_65 : LocalName = LocalName :: pack_static (170u32) ;
+pub const ATOM_LOCALNAME__74_74 : LocalName = LocalName :: pack_static (171u32) ;
+pub const ATOM_LOCALNAME__61_72_61_62_69_63_2D_66_6F_72_6D : LocalName = LocalName :: pack_static (172u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_62_72_61_69_6C_6C_65_6C_61_62_65_6C : LocalName = LocalName :: pack_static (173u32) ;
+pub const ATOM_LOCALNAME__66_65_64_69_73_70_6C_61_63_65_6D_65_6E_74_6D_61_70 : LocalName = LocalName :: pack_static (174u32) ;
+pub const ATOM_LOCALNAME__6F_6E_63_68_61_6E_67_65 : LocalName = LocalName :: pack_static (175u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_6F_72_69_65_6E_74_61_74_69_6F_6E : LocalName = LocalName :: pack_static (176u32) ;
+pub const ATOM_LOCALNAME__6C_65_6E_67_74_68_41_64_6A_75_73_74 : LocalName = LocalName :: pack_static (177u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_73_65_6C_65_63_74_65_64 : LocalName = LocalName :: pack_static (178u32) ;
+pub const ATOM_LOCALNAME__73_70_65_6C_6C_63_68_65_63_6B : LocalName = LocalName :: pack_static (179u32) ;
+pub const ATOM_LOCALNAME__67_72_61_64_69_65_6E_74_74_72_61_6E_73_66_6F_72_6D : LocalName = LocalName :: pack_static (180u32) ;
+pub const ATOM_LOCALNAME__74_61_6E_68 : LocalName = LocalName :: pack_static (181u32) ;
+pub const ATOM_LOCALNAME__62_79 : LocalName = LocalName :: pack_static (182u32) ;
+pub const ATOM_LOCALNAME__77_68_65_6E : LocalName = LocalName :: pack_static (183u32) ;
+pub const ATOM_LOCALNAME__6C_65_6E_67_74_68_61_64_6A_75_73_74 : LocalName = LocalName :: pack_static (184u32) ;
+pub const ATOM_LOCALNAME__61_63_63_65_70_74 : LocalName = LocalName :: pack_static (185u32) ;
+pub const ATOM_LOCALNAME__65_76_65_6E_74 : LocalName = LocalName :: pack_static (186u32) ;
+pub const ATOM_LOCALNAME__6C_73_70_61_63_65 : LocalName = LocalName :: pack_static (187u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_61_75_74_6F_63_6F_6D_70_6C_65_74_65 : LocalName = LocalName :: pack_static (188u32) ;
+pub const ATOM_LOCALNAME__69_73_6D_61_70 : LocalName = LocalName :: pack_static (189u32) ;
+pub const ATOM_LOCALNAME__66_61_63_65 : LocalName = LocalName :: pack_static (190u32) ;
+pub const ATOM_LOCALNAME__73_69_7A_65_73 : LocalName = LocalName :: pack_static (191u32) ;
+pub const ATOM_LOCALNAME__73_79_73_74_65_6D_4C_61_6E_67_75_61_67_65 : LocalName = LocalName :: pack_static (192u32) ;
+pub const ATOM_LOCALNAME__66_65_74_69_6C_65 : LocalName = LocalName :: pack_static (193u32) ;
+pub const ATOM_LOCALNAME__6D_6F_6D_65_6E_74 : LocalName = LocalName :: pack_static (194u32) ;
+pub const ATOM_LOCALNAME__64 : LocalName = LocalName :: pack_static (195u32) ;
+pub const ATOM_LOCALNAME__6D_70_72_65_73_63_72_69_70_74_73 : LocalName = LocalName :: pack_static (196u32) ;
+pub const ATOM_LOCALNAME__61_74_74_72_69_62_75_74_65_6E_61_6D_65 : LocalName = LocalName :: pack_static (197u32) ;
+pub const ATOM_LOCALNAME__6F_6E_64_72_61_67_65_6E_64 : LocalName = LocalName :: pack_static (198u32) ;
+pub const ATOM_LOCALNAME__6F_6E_63_75_74 : LocalName = LocalName :: pack_static (199u32) ;
+pub const ATOM_LOCALNAME__70_6F_6C_79_6C_69_6E_65 : LocalName = LocalName :: pack_static (200u32) ;
+pub const ATOM_LOCALNAME__67_72_6F_75_70_61_6C_69_67_6E : LocalName = LocalName :: pack_static (201u32) ;
+pub const ATOM_LOCALNAME__6F_6E_64_72_61_67_6F_76_65_72 : LocalName = LocalName :: pack_static (202u32) ;
+pub const ATOM_LOCALNAME__63_6C_65_61_72 : LocalName = LocalName :: pack_static (203u32) ;
+pub const ATOM_LOCALNAME__64_65_66_65_72 : LocalName = LocalName :: pack_static (204u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_6C_61_62_65_6C_6C_65_64_62_79 : LocalName = LocalName :: pack_static (205u32) ;
+pub const ATOM_LOCALNAME__6E_6F_65_6D_62_65_64 : LocalName = LocalName :: pack_static (206u32) ;
+pub const ATOM_LOCALNAME__72_6F_77_73_70_61_6E : LocalName = LocalName :: pack_static (207u32) ;
+pub const ATOM_LOCALNAME__64_69_66_66 : LocalName = LocalName :: pack_static (208u32) ;
+pub const ATOM_LOCALNAME__63_6F_6E_6A_75_67_61_74_65 : LocalName = LocalName :: pack_static (209u32) ;
+pub const ATOM_LOCALNAME__63_6F_6E_74_65_78_74_6D_65_6E_75 : LocalName = LocalName :: pack_static (210u32) ;
+pub const ATOM_LOCALNAME__78_6C_69_6E_6B : LocalName = LocalName :: pack_static (211u32) ;
+pub const ATOM_LOCALNAME__63_6F_64_6F_6D_61_69_6E : LocalName = LocalName :: pack_static (212u32) ;
+pub const ATOM_LOCALNAME__6B_65_72_6E_65_6C_4D_61_74_72_69_78 : LocalName = LocalName :: pack_static (213u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_76_61_6C_75_65_74_65_78_74 : LocalName = LocalName :: pack_static (214u32) ;
+pub const ATOM_LOCALNAME__72_78 : LocalName = LocalName :: pack_static (215u32) ;
+pub const ATOM_LOCALNAME__66_65_44_72_6F_70_53_68_61_64_6F_77 : LocalName = LocalName :: pack_static (216u32) ;
+pub const ATOM_LOCALNAME__67_63_64 : LocalName = LocalName :: pack_static (217u32) ;
+pub const ATOM_LOCALNAME__72_6F_77_61_6C_69_67_6E : LocalName = LocalName :: pack_static (218u32) ;
+pub const ATOM_LOCALNAME__6D_61_72_6B : LocalName = LocalName :: pack_static (219u32) ;
+pub const ATOM_LOCALNAME__73_65_65_64 : LocalName = LocalName :: pack_static (220u32) ;
+pub const ATOM_LOCALNAME__73_74_69_74_63_68_74_69_6C_65_73 : LocalName = LocalName :: pack_static (221u32) ;
+pub const ATOM_LOCALNAME__65_64_67_65_6D_6F_64_65 : LocalName = LocalName :: pack_static (222u32) ;
+pub const ATOM_LOCALNAME__6F_6E_63_6F_6E_74_65_78_74_6D_65_6E_75 : LocalName = LocalName :: pack_static (223u32) ;
+pub const ATOM_LOCALNAME__61_6C_74_74_65_78_74 : LocalName = LocalName :: pack_static (224u32) ;
+pub const ATOM_LOCALNAME__70_72_65_73_65_72_76_65_41_73_70_65_63_74_52_61_74_69_6F : LocalName = LocalName :: pack_static (225u32) ;
+pub const ATOM_LOCALNAME__66_69_6C_6C : LocalName = LocalName :: pack_static (226u32) ;
+pub const ATOM_LOCALNAME__63_6C_6F_73_75_72_65 : LocalName = LocalName :: pack_static (227u32) ;
+pub const ATOM_LOCALNAME__6C_6F_6F_70 : LocalName = LocalName :: pack_static (228u32) ;
+pub const ATOM_LOCALNAME__70_61_74_74_65_72_6E : LocalName = LocalName :: pack_static (229u32) ;
+pub const ATOM_LOCALNAME__6C_69_6E_65 : LocalName = LocalName :: pack_static (230u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_64_65_73_63_72_69_70_74_69_6F_6E : LocalName = LocalName :: pack_static (231u32) ;
+pub const ATOM_LOCALNAME__73 : LocalName = LocalName :: pack_static (232u32) ;
+pub const ATOM_LOCALNAME__66_65_6F_66_66_73_65_74 : LocalName = LocalName :: pack_static (233u32) ;
+pub const ATOM_LOCALNAME__72_65_63_74 : LocalName = LocalName :: pack_static (234u32) ;
+pub const ATOM_LOCALNAME__6F_74_68_65_72_77_69_73_65 : LocalName = LocalName :: pack_static (235u32) ;
+pub const ATOM_LOCALNAME__72_65_70_65_61_74 : LocalName = LocalName :: pack_static (236u32) ;
+pub const ATOM_LOCALNAME__70_6F_6C_79_67_6F_6E : LocalName = LocalName :: pack_static (237u32) ;
+pub const ATOM_LOCALNAME__63_6F_73 : LocalName = LocalName :: pack_static (238u32) ;
+pub const ATOM_LOCALNAME__70_6C_75_73 : LocalName = LocalName :: pack_static (239u32) ;
+pub const ATOM_LOCALNAME__6D_74_72 : LocalName = LocalName :: pack_static (240u32) ;
+pub const ATOM_LOCALNAME__66_65_43_6F_6C_6F_72_4D_61_74_72_69_78 : LocalName = LocalName :: pack_static (241u32) ;
+pub const ATOM_LOCALNAME__64_6F_6D_69_6E_61_6E_74_2D_62_61_73_65_6C_69_6E_65 : LocalName = LocalName :: pack_static (242u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_62_75_73_79 : LocalName = LocalName :: pack_static (243u32) ;
+pub const ATOM_LOCALNAME__6E_65_78_74_69_64 : LocalName = LocalName :: pack_static (244u32) ;
+pub const ATOM_LOCALNAME__6E_6F_73_68_61_64_65 : LocalName = LocalName :: pack_static (245u32) ;
+pub const ATOM_LOCALNAME__66_65_43_6F_6D_70_6F_6E_65_6E_74_54_72_61_6E_73_66_65_72 : LocalName = LocalName :: pack_static (246u32) ;
+pub const ATOM_LOCALNAME__70_61_74_74_65_72_6E_43_6F_6E_74_65_6E_74_55_6E_69_74_73 : LocalName = LocalName :: pack_static (247u32) ;
+pub const ATOM_LOCALNAME__66_69_6C_6C_2D_72_75_6C_65 : LocalName = LocalName :: pack_static (248u32) ;
+pub const ATOM_LOCALNAME__6F_63_63_75_72_72_65_6E_63_65 : LocalName = LocalName :: pack_static (249u32) ;
+pub const ATOM_LOCALNAME__72_61_64_69_61_6C_47_72_61_64_69_65_6E_74 : LocalName = LocalName :: pack_static (250u32) ;
+pub const ATOM_LOCALNAME__63_73_63_68 : LocalName = LocalName :: pack_static (251u32) ;
+pub const ATOM_LOCALNAME__6D_65_61_6E : LocalName = LocalName :: pack_static (252u32) ;
+pub const ATOM_LOCALNAME__6D_73_74_79_6C_65 : LocalName = LocalName :: pack_static (253u32) ;
+pub const ATOM_LOCALNAME__63_61_6C_63_6D_6F_64_65 : LocalName = LocalName :: pack_static (254u32) ;
+pub const ATOM_LOCALNAME__61_72_63_74_61_6E : LocalName = LocalName :: pack_static (255u32) ;
+pub const ATOM_LOCALNAME__62_76_61_72 : LocalName = LocalName :: pack_static (256u32) ;
+pub const ATOM_LOCALNAME__70_61_72_74_69_61_6C_64_69_66_66 : LocalName = LocalName :: pack_static (257u32) ;
+pub const ATOM_LOCALNAME__64_6F_77_6E_6C_6F_61_64 : LocalName = LocalName :: pack_static (258u32) ;
+pub const ATOM_LOCALNAME__73_74_65_70 : LocalName = LocalName :: pack_static (259u32) ;
+pub const ATOM_LOCALNAME__6F_6E_6F_6E_6C_69_6E_65 : LocalName = LocalName :: pack_static (260u32) ;
+pub const ATOM_LOCALNAME__6F_6E_63_6F_70_79 : LocalName = LocalName :: pack_static (261u32) ;
+pub const ATOM_LOCALNAME__66_65_4D_65_72_67_65_4E_6F_64_65 : LocalName = LocalName :: pack_static (262u32) ;
+pub const ATOM_LOCALNAME__6C_6F_6E_67_64_65_73_63 : LocalName = LocalName :: pack_static (263u32) ;
+pub const ATOM_LOCALNAME__66_65_46_75_6E_63_52 : LocalName = LocalName :: pack_static (264u32) ;
+pub const ATOM_LOCALNAME__6E_65_71 : LocalName = LocalName :: pack_static (265u32) ;
+pub const ATOM_LOCALNAME__66_61_63_74_6F_72_69_61_6C : LocalName = LocalName :: pack_static (266u32) ;
+pub const ATOM_LOCALNAME__61_72_65_61 : LocalName = LocalName :: pack_static (267u32) ;
+pub const ATOM_LOCALNAME__63_73_79_6D_62_6F_6C : LocalName = LocalName :: pack_static (268u32) ;
+pub const ATOM_LOCALNAME__73_79_6D_6D_65_74_72_69_63 : LocalName = LocalName :: pack_static (269u32) ;
+pub const ATOM_LOCALNAME__63_6F_6C_6F_72_2D_69_6E_74_65_72_70_6F_6C_61_74_69_6F_6E : LocalName = LocalName :: pack_static (270u32) ;
+pub const ATOM_LOCALNAME__73_68_61_70_65 : LocalName = LocalName :: pack_static (271u32) ;
+pub const ATOM_LOCALNAME__65_64_67_65 : LocalName = LocalName :: pack_static (272u32) ;
+pub const ATOM_LOCALNAME__70_6C_61_69_6E_74_65_78_74 : LocalName = LocalName :: pack_static (273u32) ;
+pub const ATOM_LOCALNAME__6C_69_73_74_69_6E_67 : LocalName = LocalName :: pack_static (274u32) ;
+pub const ATOM_LOCALNAME__61_6C_74_47_6C_79_70_68_49_74_65_6D : LocalName = LocalName :: pack_static (275u32) ;
+pub const ATOM_LOCALNAME__74_72 : LocalName = LocalName :: pack_static (276u32) ;
+pub const ATOM_LOCALNAME__6D_61_72_6B_65_72_77_69_64_74_68 : LocalName = LocalName :: pack_static (277u32) ;
+pub const ATOM_LOCALNAME__66_6F_72_65_69_67_6E_4F_62_6A_65_63_74 : LocalName = LocalName :: pack_static (278u32) ;
+pub const ATOM_LOCALNAME__66_65_63_6F_6E_76_6F_6C_76_65_6D_61_74_72_69_78 : LocalName = LocalName :: pack_static (279u32) ;
+pub const ATOM_LOCALNAME__68_74_74_70_2D_65_71_75_69_76 : LocalName = LocalName :: pack_static (280u32) ;
+pub const ATOM_LOCALNAME__73_70_61_6E : LocalName = LocalName :: pack_static (281u32) ;
+pub const ATOM_LOCALNAME__63_6F_64_65_62_61_73_65 : LocalName = LocalName :: pack_static (282u32) ;
+pub const ATOM_LOCALNAME__76_61_6C_75_65_73 : LocalName = LocalName :: pack_static (283u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_63_6F_6C_69_6E_64_65_78_74_65_78_74 : LocalName = LocalName :: pack_static (284u32) ;
+pub const ATOM_LOCALNAME__61_6E_69_6D_61_74_65_54_72_61_6E_73_66_6F_72_6D : LocalName = LocalName :: pack_static (285u32) ;
+pub const ATOM_LOCALNAME__74_65_78_74 : LocalName = LocalName :: pack_static (286u32) ;
+pub const ATOM_LOCALNAME__73_74_65_6D_76 : LocalName = LocalName :: pack_static (287u32) ;
+pub const ATOM_LOCALNAME__70_72_65_66_65_74_63_68 : LocalName = LocalName :: pack_static (288u32) ;
+pub const ATOM_LOCALNAME__76_65_63_74_6F_72 : LocalName = LocalName :: pack_static (289u32) ;
+pub const ATOM_LOCALNAME__61_63_63_65_73_73_6B_65_79 : LocalName = LocalName :: pack_static (290u32) ;
+pub const ATOM_LOCALNAME__61_70_70_6C_65_74 : LocalName = LocalName :: pack_static (291u32) ;
+pub const ATOM_LOCALNAME__69_64_65_6E_74 : LocalName = LocalName :: pack_static (292u32) ;
+pub const ATOM_LOCALNAME__63_6F_73_68 : LocalName = LocalName :: pack_static (293u32) ;
+pub const ATOM_LOCALNAME__66_65_64_72_6F_70_73_68_61_64_6F_77 : LocalName = LocalName :: pack_static (294u32) ;
+pub const ATOM_LOCALNAME__62_61_73_65_6C_69_6E_65 : LocalName = LocalName :: pack_static (295u32) ;
+pub const ATOM_LOCALNAME__73_65_70_61_72_61_74_6F_72_73 : LocalName = LocalName :: pack_static (296u32) ;
+pub const ATOM_LOCALNAME__63_61_70_2D_68_65_69_67_68_74 : LocalName = LocalName :: pack_static (297u32) ;
+pub const ATOM_LOCALNAME__6E_6F_72_65_73_69_7A_65 : LocalName = LocalName :: pack_static (298u32) ;
+pub const ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_75_70 : LocalName = LocalName :: pack_static (299u32) ;
+pub const ATOM_LOCALNAME__74_72_75_65 : LocalName = LocalName :: pack_static (300u32) ;
+pub const ATOM_LOCALNAME__66_78 : LocalName = LocalName :: pack_static (301u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_6D_75_6C_74_69_73_65_6C_65_63_74_61_62_6C_65 : LocalName = LocalName :: pack_static (302u32) ;
+pub const ATOM_LOCALNAME__66_6F_72_6D_6E_6F_76_61_6C_69_64_61_74_65 : LocalName = LocalName :: pack_static (303u32) ;
+pub const ATOM_LOCALNAME__68_33 : LocalName = LocalName :: pack_static (304u32) ;
+pub const ATOM_LOCALNAME__72_65_70_65_61_74_63_6F_75_6E_74 : LocalName = LocalName :: pack_static (305u32) ;
+pub const ATOM_LOCALNAME__6E_6F_77_72_61_70 : LocalName = LocalName :: pack_static (306u32) ;
+pub const ATOM_LOCALNAME__73_68_6F_77 : LocalName = LocalName :: pack_static (307u32) ;
+pub const ATOM_LOCALNAME__63_6F_74_68 : LocalName = LocalName :: pack_static (308u32) ;
+pub const ATOM_LOCALNAME__64_61_74_61_74_65_6D_70_6C_61_74_65 : LocalName = LocalName :: pack_static (309u32) ;
+pub const ATOM_LOCALNAME__76_61_6C_75_65_74_79_70_65 : LocalName = LocalName :: pack_static (310u32) ;
+pub const ATOM_LOCALNAME__72_65_73_75_6C_74 : LocalName = LocalName :: pack_static (311u32) ;
+pub const ATOM_LOCALNAME__75_70_6C_69_6D_69_74 : LocalName = LocalName :: pack_static (312u32) ;
+pub const ATOM_LOCALNAME__73_6C_6F_74 : LocalName = LocalName :: pack_static (313u32) ;
+pub const ATOM_LOCALNAME__73_6F_6C_69_64_63_6F_6C_6F_72 : LocalName = LocalName :: pack_static (314u32) ;
+pub const ATOM_LOCALNAME__6D_61_74_68_62_61_63_6B_67_72_6F_75_6E_64 : LocalName = LocalName :: pack_static (315u32) ;
+pub const ATOM_LOCALNAME__63_6F_6C_6F_72 : LocalName = LocalName :: pack_static (316u32) ;
+pub const ATOM_LOCALNAME__72_75_6C_65 : LocalName = LocalName :: pack_static (317u32) ;
+pub const ATOM_LOCALNAME__78_72_65_66 : LocalName = LocalName :: pack_static (318u32) ;
+pub const ATOM_LOCALNAME__70_72_65_73_65_72_76_65_61_73_70_65_63_74_72_61_74_69_6F : LocalName = LocalName :: pack_static (319u32) ;
+pub const ATOM_LOCALNAME__6F_6E_6C_6F_73_65_63_61_70_74_75_72_65 : LocalName = LocalName :: pack_static (320u32) ;
+pub const ATOM_LOCALNAME__73_74_64_64_65_76_69_61_74_69_6F_6E : LocalName = LocalName :: pack_static (321u32) ;
+pub const ATOM_LOCALNAME__67_6C_79_70_68 : LocalName = LocalName :: pack_static (322u32) ;
+pub const ATOM_LOCALNAME__64_78 : LocalName = LocalName :: pack_static (323u32) ;
+pub const ATOM_LOCALNAME__66_65_69_6D_61_67_65 : LocalName = LocalName :: pack_static (324u32) ;
+pub const ATOM_LOCALNAME__74_61_72_67_65_74 : LocalName = LocalName :: pack_static (325u32) ;
+pub const ATOM_LOCALNAME__63_6F_6E_74_65_6E_74_53_63_72_69_70_74_54_79_70_65 : LocalName = LocalName :: pack_static (326u32) ;
+pub const ATOM_LOCALNAME__66_65_46_6C_6F_6F_64 : LocalName = LocalName :: pack_static (327u32) ;
+pub const ATOM_LOCALNAME__6F_6E_66_6F_72_6D_63_68_61_6E_67_65 : LocalName = LocalName :: pack_static (328u32) ;
+pub const ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_64_6F_77_6E : LocalName = LocalName :: pack_static (329u32) ;
+pub const ATOM_LOCALNAME__73_69_7A_65 : LocalName = LocalName :: pack_static (330u32) ;
+pub const ATOM_LOCALNAME__6F_6E_73_75_62_6D_69_74 : LocalName = LocalName :: pack_static (331u32) ;
+pub const ATOM_LOCALNAME__74_61_72_67_65_74_58 : LocalName = LocalName :: pack_static (332u32) ;
+pub const ATOM_LOCALNAME__73_75_70_65_72_73_63_72_69_70_74_73_68_69_66_74 : LocalName = LocalName :: pack_static (333u32) ;
+pub const ATOM_LOCALNAME__67_31 : LocalName = LocalName :: pack_static (334u32) ;
+pub const ATOM_LOCALNAME__63_6C_69_70 : LocalName = LocalName :: pack_static (335u32) ;
+pub const ATOM_LOCALNAME__73_75_72_66_61_63_65_53_63_61_6C_65 : LocalName = LocalName :: pack_static (336u32) ;
+pub const ATOM_LOCALNAME__69_6E_74_65_72_76_61_6C : LocalName = LocalName :: pack_static (337u32) ;
+pub const ATOM_LOCALNAME__6F_6E_72_65_70_65_61_74 : LocalName = LocalName :: pack_static (338u32) ;
+pub const ATOM_LOCALNAME__77_69_64_74_68_73 : LocalName = LocalName :: pack_static (339u32) ;
+pub const ATOM_LOCALNAME__6D_6F_64_65 : LocalName = LocalName :: pack_static (340u32) ;
+pub const ATOM_LOCALNAME__68_61_6E_67_69_6E_67 : LocalName = LocalName :: pack_static (341u32) ;
+pub const ATOM_LOCALNAME__72_6F_77_73_70_61_63_69_6E_67 : LocalName = LocalName :: pack_static (342u32) ;
+pub const ATOM_LOCALNAME__72_65_66_79 : LocalName = LocalName :: pack_static (343u32) ;
+pub const ATOM_LOCALNAME__72_65_71_75_69_72_65_64_45_78_74_65_6E_73_69_6F_6E_73 : LocalName = LocalName :: pack_static (344u32) ;
+pub const ATOM_LOCALNAME__63_6F_6C_75_6D_6E_73_70_61_63_69_6E_67 : LocalName = LocalName :: pack_static (345u32) ;
+pub const ATOM_LOCALNAME__74_72_61_6E_73_70_6F_73_65 : LocalName = LocalName :: pack_static (346u32) ;
+pub const ATOM_LOCALNAME__73_74_72_6F_6B_65_2D_6C_69_6E_65_63_61_70 : LocalName = LocalName :: pack_static (347u32) ;
+pub const ATOM_LOCALNAME__73_77_69_74_63_68 : LocalName = LocalName :: pack_static (348u32) ;
+pub const ATOM_LOCALNAME__69_6D_61_67_69_6E_61_72_79 : LocalName = LocalName :: pack_static (349u32) ;
+pub const ATOM_LOCALNAME__6F_6E_66_6F_63_75_73_6F_75_74 : LocalName = LocalName :: pack_static (350u32) ;
+pub const ATOM_LOCALNAME__66_6F_6E_74_2D_66_61_6D_69_6C_79 : LocalName = LocalName :: pack_static (351u32) ;
+pub const ATOM_LOCALNAME__6D_61_73_6B_43_6F_6E_74_65_6E_74_55_6E_69_74_73 : LocalName = LocalName :: pack_static (352u32) ;
+pub const ATOM_LOCALNAME__6D_70_68_61_6E_74_6F_6D : LocalName = LocalName :: pack_static (353u32) ;
+pub const ATOM_LOCALNAME__6B_65_79_70_6F_69_6E_74_73 : LocalName = LocalName :: pack_static (354u32) ;
+pub const ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_6C_65_61_76_65 : LocalName = LocalName :: pack_static (355u32) ;
+pub const ATOM_LOCALNAME__73_74_79_6C_65 : LocalName = LocalName :: pack_static (356u32) ;
+pub const ATOM_LOCALNAME__64_69_61_6C_6F_67 : LocalName = LocalName :: pack_static (357u32) ;
+pub const ATOM_LOCALNAME__61_72_63_73_65_63_68 : LocalName = LocalName :: pack_static (358u32) ;
+pub const ATOM_LOCALNAME__62_61_73_65_66_72_65_71_75_65_6E_63_79 : LocalName = LocalName :: pack_static (359u32) ;
+pub const ATOM_LOCALNAME__73_65_6C_65_63_74_6F_72 : LocalName = LocalName :: pack_static (360u32) ;
+pub const ATOM_LOCALNAME__62_62_6F_78 : LocalName = LocalName :: pack_static (361u32) ;
+pub const ATOM_LOCALNAME__6F_6E_73_65_6C_65_63_74_73_74_61_72_74 : LocalName = LocalName :: pack_static (362u32) ;
+pub const ATOM_LOCALNAME__63_61_70_74_69_6F_6E : LocalName = LocalName :: pack_static (363u32) ;
+pub const ATOM_LOCALNAME__6E_6F_73_63_72_69_70_74 : LocalName = LocalName :: pack_static (364u32) ;
+pub const ATOM_LOCALNAME__78_6D_6C_6E_73_3A_78_6C_69_6E_6B : LocalName = LocalName :: pack_static (365u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_6D_75_6C_74_69_6C_69_6E_65 : LocalName = LocalName :: pack_static (366u32) ;
+pub const ATOM_LOCALNAME__6B_32 : LocalName = LocalName :: pack_static (367u32) ;
+pub const ATOM_LOCALNAME__6D_74_64 : LocalName = LocalName :: pack_static (368u32) ;
+pub const ATOM_LOCALNAME__73_64_65_76 : LocalName = LocalName :: pack_static (369u32) ;
+pub const ATOM_LOCALNAME__61_74_74_72_69_62_75_74_65_54_79_70_65 : LocalName = LocalName :: pack_static (370u32) ;
+pub const ATOM_LOCALNAME__63_6F_74 : LocalName = LocalName :: pack_static (371u32) ;
+pub const ATOM_LOCALNAME__70_72_65_73_65_72_76_65_61_6C_70_68_61 : LocalName = LocalName :: pack_static (372u32) ;
+pub const ATOM_LOCALNAME__6C_6F_77 : LocalName = LocalName :: pack_static (373u32) ;
+pub const ATOM_LOCALNAME__66_79 : LocalName = LocalName :: pack_static (374u32) ;
+pub const ATOM_LOCALNAME__66_65_74_75_72_62_75_6C_65_6E_63_65 : LocalName = LocalName :: pack_static (375u32) ;
+pub const ATOM_LOCALNAME__6E_6F_66_72_61_6D_65_73 : LocalName = LocalName :: pack_static (376u32) ;
+pub const ATOM_LOCALNAME__6F_6E_70_61_67_65_68_69_64_65 : LocalName = LocalName :: pack_static (377u32) ;
+pub const ATOM_LOCALNAME__72_61_74_69_6F_6E_61_6C_73 : LocalName = LocalName :: pack_static (378u32) ;
+pub const ATOM_LOCALNAME__69_63_6F_6E : LocalName = LocalName :: pack_static (379u32) ;
+pub const ATOM_LOCALNAME__63_6F_6C_75_6D_6E_73_70_61_6E : LocalName = LocalName :: pack_static (380u32) ;
+pub const ATOM_LOCALNAME__73_65_6C_65_63_74_69_6F_6E : LocalName = LocalName :: pack_static (381u32) ;
+pub const ATOM_LOCALNAME__65_78_74_65_72_6E_61_6C_72_65_73_6F_75_72_63_65_73_72_65_71_75_69_72_65_64 : LocalName = LocalName :: pack_static (382u32) ;
+pub const ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_63_75_74 : LocalName = LocalName :: pack_static (383u32) ;
+pub const ATOM_LOCALNAME__6F_72_69_67_69_6E : LocalName = LocalName :: pack_static (384u32) ;
+pub const ATOM_LOCALNAME__6E_61_72_67_73 : LocalName = LocalName :: pack_static (385u32) ;
+pub const ATOM_LOCALNAME__73_65_63 : LocalName = LocalName :: pack_static (386u32) ;
+pub const ATOM_LOCALNAME__76_69_65_77_42_6F_78 : LocalName = LocalName :: pack_static (387u32) ;
+pub const ATOM_LOCALNAME__6D_69_6E_6C_65_6E_67_74_68 : LocalName = LocalName :: pack_static (388u32) ;
+pub const ATOM_LOCALNAME__73_65_6D_61_6E_74_69_63_73 : LocalName = LocalName :: pack_static (389u32) ;
+pub const ATOM_LOCALNAME__61_72_63_73_69_6E : LocalName = LocalName :: pack_static (390u32) ;
+pub const ATOM_LOCALNAME__6F_72 : LocalName = LocalName :: pack_static (391u32) ;
+pub const ATOM_LOCALNAME__63_69 : LocalName = LocalName :: pack_static (392u32) ;
+pub const ATOM_LOCALNAME__6D_65_74_61_64_61_74_61 : LocalName = LocalName :: pack_static (393u32) ;
+pub const ATOM_LOCALNAME__73_61_6E_64_62_6F_78 : LocalName = LocalName :: pack_static (394u32) ;
+pub const ATOM_LOCALNAME__73_74_61_72_74 : LocalName = LocalName :: pack_static (395u32) ;
+pub const ATOM_LOCALNAME__78_6D_70 : LocalName = LocalName :: pack_static (396u32) ;
+pub const ATOM_LOCALNAME__6F_6E_64_61_74_61_61_76_61_69_6C_61_62_6C_65 : LocalName = LocalName :: pack_static (397u32) ;
+pub const ATOM_LOCALNAME__6D_61_72_6B_65_72_68_65_69_67_68_74 : LocalName = LocalName :: pack_static (398u32) ;
+pub const ATOM_LOCALNAME__78_6D_6C_3A_73_70_61_63_65 : LocalName = LocalName :: pack_static (399u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_63_75_72_72_65_6E_74 : LocalName = LocalName :: pack_static (400u32) ;
+pub const ATOM_LOCALNAME__6C_69_6E_65_74_68_69_63_6B_6E_65_73_73 : LocalName = LocalName :: pack_static (401u32) ;
+pub const ATOM_LOCALNAME__73_70_61_63_65 : LocalName = LocalName :: pack_static (402u32) ;
+pub const ATOM_LOCALNAME__62_67_63_6F_6C_6F_72 : LocalName = LocalName :: pack_static (403u32) ;
+pub const ATOM_LOCALNAME__6C_69_6E_65_62_72_65_61_6B : LocalName = LocalName :: pack_static (404u32) ;
+pub const ATOM_LOCALNAME__69_6E_76_65_72_73_65 : LocalName = LocalName :: pack_static (405u32) ;
+pub const ATOM_LOCALNAME__61_75_74_6F_70_6C_61_79 : LocalName = LocalName :: pack_static (406u32) ;
+pub const ATOM_LOCALNAME__63_6F_6D_70_61_63_74 : LocalName = LocalName :: pack_static (407u32) ;
+pub const ATOM_LOCALNAME__6F_6E_6D_6F_76_65 : LocalName = LocalName :: pack_static (408u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_65_78_70_61_6E_64_65_64 : LocalName = LocalName :: pack_static (409u32) ;
+pub const ATOM_LOCALNAME__6C_6F_67_62_61_73_65 : LocalName = LocalName :: pack_static (410u32) ;
+pub const ATOM_LOCALNAME__70_61_74_68_4C_65_6E_67_74_68 : LocalName = LocalName :: pack_static (411u32) ;
+pub const ATOM_LOCALNAME__63_61_6E_76_61_73 : LocalName = LocalName :: pack_static (412u32) ;
+pub const ATOM_LOCALNAME__6D_61_6C_69_67_6E_67_72_6F_75_70 : LocalName = LocalName :: pack_static (413u32) ;
+pub const ATOM_LOCALNAME__66_6F_6E_74_2D_77_65_69_67_68_74 : LocalName = LocalName :: pack_static (414u32) ;
+pub const ATOM_LOCALNAME__73_70_61_63_65_72 : LocalName = LocalName :: pack_static (415u32) ;
+pub const ATOM_LOCALNAME__64_69_76_69_64_65 : LocalName = LocalName :: pack_static (416u32) ;
+pub const ATOM_LOCALNAME__6B_31 : LocalName = LocalName :: pack_static (417u32) ;
+pub const ATOM_LOCALNAME__74_61_6E : LocalName = LocalName :: pack_static (418u32) ;
+pub const ATOM_LOCALNAME__78 : LocalName = LocalName :: pack_static (419u32) ;
+pub const ATOM_LOCALNAME__6F_6E_69_6E_70_75_74 : LocalName = LocalName :: pack_static (420u32) ;
+pub const ATOM_LOCALNAME__6C_6E : LocalName = LocalName :: pack_static (421u32) ;
+pub const ATOM_LOCALNAME__6F_6E_61_63_74_69_76_61_74_65 : LocalName = LocalName :: pack_static (422u32) ;
+pub const ATOM_LOCALNAME__6D_61_6C_69_67_6E_6D_61_72_6B : LocalName = LocalName :: pack_static (423u32) ;
+pub const ATOM_LOCALNAME__62 : LocalName = LocalName :: pack_static (424u32) ;
+pub const ATOM_LOCALNAME__72_65_70_65_61_74_2D_74_65_6D_70_6C_61_74_65 : LocalName = LocalName :: pack_static (425u32) ;
+pub const ATOM_LOCALNAME__68_65_69_67_68_74 : LocalName = LocalName :: pack_static (426u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_64_61_74_61_74_79_70_65 : LocalName = LocalName :: pack_static (427u32) ;
+pub const ATOM_LOCALNAME__65_78_70_6F_6E_65_6E_74 : LocalName = LocalName :: pack_static (428u32) ;
+pub const ATOM_LOCALNAME__73_74_6F_70_2D_63_6F_6C_6F_72 : LocalName = LocalName :: pack_static (429u32) ;
+pub const ATOM_LOCALNAME__66_69_6C_74_65_72_52_65_73 : LocalName = LocalName :: pack_static (430u32) ;
+pub const ATOM_LOCALNAME__74_61_72_67_65_74_79 : LocalName = LocalName :: pack_static (431u32) ;
+pub const ATOM_LOCALNAME__66_6C_6F_6F_72 : LocalName = LocalName :: pack_static (432u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_62_72_61_69_6C_6C_65_72_6F_6C_65_64_65_73_63_72_69_70_74_69_6F_6E : LocalName = LocalName :: pack_static (433u32) ;
+pub const ATOM_LOCALNAME__72_65_70_65_61_74_64_75_72 : LocalName = LocalName :: pack_static (434u32) ;
+pub const ATOM_LOCALNAME__68_67_72_6F_75_70 : LocalName = LocalName :: pack_static (435u32) ;
+pub const ATOM_LOCALNAME__68_65_61_64 : LocalName = LocalName :: pack_static (436u32) ;
+pub const ATOM_LOCALNAME__61_6C_69_67_6E_6D_65_6E_74_73_63_6F_70_65 : LocalName = LocalName :: pack_static (437u32) ;
+pub const ATOM_LOCALNAME__6D_61_6E_69_66_65_73_74 : LocalName = LocalName :: pack_static (438u32) ;
+pub const ATOM_LOCALNAME__66_65_44_69_73_74_61_6E_74_4C_69_67_68_74 : LocalName = LocalName :: pack_static (439u32) ;
+pub const ATOM_LOCALNAME__6F_6E_64_72_61_67_73_74_61_72_74 : LocalName = LocalName :: pack_static (440u32) ;
+pub const ATOM_LOCALNAME__61_70_70_72_6F_78 : LocalName = LocalName :: pack_static (441u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_63_68_65_63_6B_65_64 : LocalName = LocalName :: pack_static (442u32) ;
+pub const ATOM_LOCALNAME__64_65_66_73 : LocalName = LocalName :: pack_static (443u32) ;
+pub const ATOM_LOCALNAME__70_72_6F_6D_70_74 : LocalName = LocalName :: pack_static (444u32) ;
+pub const ATOM_LOCALNAME__74_61_62_69_6E_64_65_78 : LocalName = LocalName :: pack_static (445u32) ;
+pub const ATOM_LOCALNAME__66_65_54_69_6C_65 : LocalName = LocalName :: pack_static (446u32) ;
+pub const ATOM_LOCALNAME__6D_61_73_6B_55_6E_69_74_73 : LocalName = LocalName :: pack_static (447u32) ;
+pub const ATOM_LOCALNAME__6F_70_74_67_72_6F_75_70 : LocalName = LocalName :: pack_static (448u32) ;
+pub const ATOM_LOCALNAME__6C_61_62_65_6C : LocalName = LocalName :: pack_static (449u32) ;
+pub const ATOM_LOCALNAME__74_72_61_6E_73_66_6F_72_6D : LocalName = LocalName :: pack_static (450u32) ;
+pub const ATOM_LOCALNAME__6D_73 : LocalName = LocalName :: pack_static (451u32) ;
+pub const ATOM_LOCALNAME__63_6F_6E_74_65_6E_74_65_64_69_74_61_62_6C_65 : LocalName = LocalName :: pack_static (452u32) ;
+pub const ATOM_LOCALNAME__70_61_74_74_65_72_6E_63_6F_6E_74_65_6E_74_75_6E_69_74_73 : LocalName = LocalName :: pack_static (453u32) ;
+pub const ATOM_LOCALNAME__6E_6F_74 : LocalName = LocalName :: pack_static (454u32) ;
+pub const ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_75_70_64_61_74_65 : LocalName = LocalName :: pack_static (455u32) ;
+pub const ATOM_LOCALNAME__6F_6E_70_6F_70_73_74_61_74_65 : LocalName = LocalName :: pack_static (456u32) ;
+pub const ATOM_LOCALNAME__76_2D_61_6C_70_68_61_62_65_74_69_63 : LocalName = LocalName :: pack_static (457u32) ;
+pub const ATOM_LOCALNAME__70_69_65_63_65 : LocalName = LocalName :: pack_static (458u32) ;
+pub const ATOM_LOCALNAME__6B_65_79_67_65_6E : LocalName = LocalName :: pack_static (459u32) ;
+pub const ATOM_LOCALNAME__61_72_63_74_61_6E_68 : LocalName = LocalName :: pack_static (460u32) ;
+pub const ATOM_LOCALNAME__68_6B_65_72_6E : LocalName = LocalName :: pack_static (461u32) ;
+pub const ATOM_LOCALNAME__66_69_67_63_61_70_74_69_6F_6E : LocalName = LocalName :: pack_static (462u32) ;
+pub const ATOM_LOCALNAME__70_72_73_75_62_73_65_74 : LocalName = LocalName :: pack_static (463u32) ;
+pub const ATOM_LOCALNAME__68_73_70_61_63_65 : LocalName = LocalName :: pack_static (464u32) ;
+pub const ATOM_LOCALNAME__73_65_70 : LocalName = LocalName :: pack_static (465u32) ;
+pub const ATOM_LOCALNAME__6F_6E_62_6F_75_6E_63_65 : LocalName = LocalName :: pack_static (466u32) ;
+pub const ATOM_LOCALNAME__66_72_6F_6D : LocalName = LocalName :: pack_static (467u32) ;
+pub const ATOM_LOCALNAME__73_74_72_6F_6B_65_2D_64_61_73_68_6F_66_66_73_65_74 : LocalName = LocalName :: pack_static (468u32) ;
+pub const ATOM_LOCALNAME__70_72_65_6C_6F_61_64 : LocalName = LocalName :: pack_static (469u32) ;
+pub const ATOM_LOCALNAME__65_71_75_61_6C_72_6F_77_73 : LocalName = LocalName :: pack_static (470u32) ;
+pub const ATOM_LOCALNAME__73_74_69_74_63_68_54_69_6C_65_73 : LocalName = LocalName :: pack_static (471u32) ;
+pub const ATOM_LOCALNAME__67_74 : LocalName = LocalName :: pack_static (472u32) ;
+pub const ATOM_LOCALNAME__73_65_63_68 : LocalName = LocalName :: pack_static (473u32) ;
+pub const ATOM_LOCALNAME__65_6D : LocalName = LocalName :: pack_static (474u32) ;
+pub const ATOM_LOCALNAME__63_6F_6E_74_65_6E_74_73_74_79_6C_65_74_79_70_65 : LocalName = LocalName :: pack_static (475u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_6C_69_76_65 : LocalName = LocalName :: pack_static (476u32) ;
+pub const ATOM_LOCALNAME__72_65_61_64_6F_6E_6C_79 : LocalName = LocalName :: pack_static (477u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_70_72_65_73_73_65_64 : LocalName = LocalName :: pack_static (478u32) ;
+pub const ATOM_LOCALNAME__74_6F : LocalName = LocalName :: pack_static (479u32) ;
+pub const ATOM_LOCALNAME__66_6F_6E_74_73_74_79_6C_65 : LocalName = LocalName :: pack_static (480u32) ;
+pub const ATOM_LOCALNAME__73_65_6C_65_63_74_65_64 : LocalName = LocalName :: pack_static (481u32) ;
+pub const ATOM_LOCALNAME__73_63_68_65_6D_65 : LocalName = LocalName :: pack_static (482u32) ;
+pub const ATOM_LOCALNAME__61_6E_69_6D_61_74_65_43_6F_6C_6F_72 : LocalName = LocalName :: pack_static (483u32) ;
+pub const ATOM_LOCALNAME__61_7A_69_6D_75_74_68 : LocalName = LocalName :: pack_static (484u32) ;
+pub const ATOM_LOCALNAME__72_65_71_75_69_72_65_64_66_65_61_74_75_72_65_73 : LocalName = LocalName :: pack_static (485u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_6C_61_62_65_6C : LocalName = LocalName :: pack_static (486u32) ;
+pub const ATOM_LOCALNAME__6F_6E_63_65_6C_6C_63_68_61_6E_67_65 : LocalName = LocalName :: pack_static (487u32) ;
+pub const ATOM_LOCALNAME__72_70 : LocalName = LocalName :: pack_static (488u32) ;
+pub const ATOM_LOCALNAME__6C_69_6E_65_61_72_67_72_61_64_69_65_6E_74 : LocalName = LocalName :: pack_static (489u32) ;
+pub const ATOM_LOCALNAME__70_72_69_6D_69_74_69_76_65_75_6E_69_74_73 : LocalName = LocalName :: pack_static (490u32) ;
+pub const ATOM_LOCALNAME__6D_61_74_68_65_6D_61_74_69_63_61_6C : LocalName = LocalName :: pack_static (491u32) ;
+pub const ATOM_LOCALNAME__6F_6E_72_6F_77_73_69_6E_73_65_72_74_65_64 : LocalName = LocalName :: pack_static (492u32) ;
+pub const ATOM_LOCALNAME__63_6F_6E_74_65_6E_74 : LocalName = LocalName :: pack_static (493u32) ;
+pub const ATOM_LOCALNAME__64_61_74_61_6C_69_73_74 : LocalName = LocalName :: pack_static (494u32) ;
+pub const ATOM_LOCALNAME__61_6C_74_67_6C_79_70_68_64_65_66 : LocalName = LocalName :: pack_static (495u32) ;
+pub const ATOM_LOCALNAME__69_6E_74_65_72_73_65_63_74 : LocalName = LocalName :: pack_static (496u32) ;
+pub const ATOM_LOCALNAME__73_74_72_6F_6B_65_2D_6C_69_6E_65_6A_6F_69_6E : LocalName = LocalName :: pack_static (497u32) ;
+pub const ATOM_LOCALNAME__66_65_6D_65_72_67_65_6E_6F_64_65 : LocalName = LocalName :: pack_static (498u32) ;
+pub const ATOM_LOCALNAME__64_61_74_61_66_6C_64 : LocalName = LocalName :: pack_static (499u32) ;
+pub const ATOM_LOCALNAME__72_65_76 : LocalName = LocalName :: pack_static (500u32) ;
+pub const ATOM_LOCALNAME__6F_6E_6B_65_79_64_6F_77_6E : LocalName = LocalName :: pack_static (501u32) ;
+pub const ATOM_LOCALNAME__74_65_6D_70_6C_61_74_65 : LocalName = LocalName :: pack_static (502u32) ;
+pub const ATOM_LOCALNAME__64_69_73_70_6C_61_79 : LocalName = LocalName :: pack_static (503u32) ;
+pub const ATOM_LOCALNAME__63_6C_61_73_73 : LocalName = LocalName :: pack_static (504u32) ;
+pub const ATOM_LOCALNAME__65_75_6C_65_72_67_61_6D_6D_61 : LocalName = LocalName :: pack_static (505u32) ;
+pub const ATOM_LOCALNAME__74_61_62_6C_65_56_61_6C_75_65_73 : LocalName = LocalName :: pack_static (506u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_64_69_73_61_62_6C_65_64 : LocalName = LocalName :: pack_static (507u32) ;
+pub const ATOM_LOCALNAME__69_64_65_6F_67_72_61_70_68_69_63 : LocalName = LocalName :: pack_static (508u32) ;
+pub const ATOM_LOCALNAME__66_65_66_75_6E_63_62 : LocalName = LocalName :: pack_static (509u32) ;
+pub const ATOM_LOCALNAME__73_63_72_69_70_74_73_69_7A_65_6D_75_6C_74_69_70_6C_69_65_72 : LocalName = LocalName :: pack_static (510u32) ;
+pub const ATOM_LOCALNAME__65_6D_70_74_79_73_65_74 : LocalName = LocalName :: pack_static (511u32) ;
+pub const ATOM_LOCALNAME__69_6E_70_75_74_6D_6F_64_65 : LocalName = LocalName :: pack_static (512u32) ;
+pub const ATOM_LOCALNAME__66_6F_72_6D_74_61_72_67_65_74 : LocalName = LocalName :: pack_static (513u32) ;
+pub const ATOM_LOCALNAME__79_63_68_61_6E_6E_65_6C_73_65_6C_65_63_74_6F_72 : LocalName = LocalName :: pack_static (514u32) ;
+pub const ATOM_LOCALNAME__66_65_49_6D_61_67_65 : LocalName = LocalName :: pack_static (515u32) ;
+pub const ATOM_LOCALNAME__61_6C_69_6E_6B : LocalName = LocalName :: pack_static (516u32) ;
+pub const ATOM_LOCALNAME__79_43_68_61_6E_6E_65_6C_53_65_6C_65_63_74_6F_72 : LocalName = LocalName :: pack_static (517u32) ;
+pub const ATOM_LOCALNAME__6F_6E_64_65_61_63_74_69_76_61_74_65 : LocalName = LocalName :: pack_static (518u32) ;
+pub const ATOM_LOCALNAME__66_6F_6F_74_65_72 : LocalName = LocalName :: pack_static (519u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_68_69_64_64_65_6E : LocalName = LocalName :: pack_static (520u32) ;
+pub const ATOM_LOCALNAME__7A_6F_6F_6D_41_6E_64_50_61_6E : LocalName = LocalName :: pack_static (521u32) ;
+pub const ATOM_LOCALNAME__72_65_70_65_61_74_43_6F_75_6E_74 : LocalName = LocalName :: pack_static (522u32) ;
+pub const ATOM_LOCALNAME__69_72_72_65_6C_65_76_61_6E_74 : LocalName = LocalName :: pack_static (523u32) ;
+pub const ATOM_LOCALNAME__6D_75_6E_64_65_72 : LocalName = LocalName :: pack_static (524u32) ;
+pub const ATOM_LOCALNAME__62_6F_72_64_65_72 : LocalName = LocalName :: pack_static (525u32) ;
+pub const ATOM_LOCALNAME__6C_6F_63_61_6C : LocalName = LocalName :: pack_static (526u32) ;
+pub const ATOM_LOCALNAME__63_68_61_72_6F_66_66 : LocalName = LocalName :: pack_static (527u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_67_72_61_62 : LocalName = LocalName :: pack_static (528u32) ;
+pub const ATOM_LOCALNAME__69_6D_67 : LocalName = LocalName :: pack_static (529u32) ;
+pub const ATOM_LOCALNAME__72_65_73_74_61_72_74 : LocalName = LocalName :: pack_static (530u32) ;
+pub const ATOM_LOCALNAME__78_32 : LocalName = LocalName :: pack_static (531u32) ;
+pub const ATOM_LOCALNAME__68_69_64_65_66_6F_63_75_73 : LocalName = LocalName :: pack_static (532u32) ;
+pub const ATOM_LOCALNAME__6D_65_64_69_61_6E : LocalName = LocalName :: pack_static (533u32) ;
+pub const ATOM_LOCALNAME__6F_70_74_69_6D_75_6D : LocalName = LocalName :: pack_static (534u32) ;
+pub const ATOM_LOCALNAME__76_65_63_74_6F_72_70_72_6F_64_75_63_74 : LocalName = LocalName :: pack_static (535u32) ;
+pub const ATOM_LOCALNAME__66_72_61_6D_65_62_6F_72_64_65_72 : LocalName = LocalName :: pack_static (536u32) ;
+pub const ATOM_LOCALNAME__64_66_6E : LocalName = LocalName :: pack_static (537u32) ;
+pub const ATOM_LOCALNAME__66_6F_72_6D_65_6E_63_74_79_70_65 : LocalName = LocalName :: pack_static (538u32) ;
+pub const ATOM_LOCALNAME__6D_74_61_62_6C_65 : LocalName = LocalName :: pack_static (539u32) ;
+pub const ATOM_LOCALNAME__72_65_66_58 : LocalName = LocalName :: pack_static (540u32) ;
+pub const ATOM_LOCALNAME__69_73_69_6E_64_65_78 : LocalName = LocalName :: pack_static (541u32) ;
+pub const ATOM_LOCALNAME__6F_6E_72_6F_77_65_78_69_74 : LocalName = LocalName :: pack_static (542u32) ;
+pub const ATOM_LOCALNAME__67_6C_79_70_68_72_65_66 : LocalName = LocalName :: pack_static (543u32) ;
+pub const ATOM_LOCALNAME__63_65_6C_6C_73_70_61_63_69_6E_67 : LocalName = LocalName :: pack_static (544u32) ;
+pub const ATOM_LOCALNAME__73_65_61_72_63_68 : LocalName = LocalName :: pack_static (545u32) ;
+pub const ATOM_LOCALNAME__78_6C_69_6E_6B_3A_72_6F_6C_65 : LocalName = LocalName :: pack_static (546u32) ;
+pub const ATOM_LOCALNAME__64_65_70_74_68 : LocalName = LocalName :: pack_static (547u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_6C_65_76_65_6C : LocalName = LocalName :: pack_static (548u32) ;
+pub const ATOM_LOCALNAME__63_6C_69_70_2D_72_75_6C_65 : LocalName = LocalName :: pack_static (549u32) ;
+pub const ATOM_LOCALNAME__61_6C_70_68_61_62_65_74_69_63 : LocalName = LocalName :: pack_static (550u32) ;
+pub const ATOM_LOCALNAME__62_6F_64_79 : LocalName = LocalName :: pack_static (551u32) ;
+pub const ATOM_LOCALNAME__66_6F_6E_74_2D_76_61_72_69_61_6E_74 : LocalName = LocalName :: pack_static (552u32) ;
+pub const ATOM_LOCALNAME__76_65_72_74_2D_61_64_76_2D_79 : LocalName = LocalName :: pack_static (553u32) ;
+pub const ATOM_LOCALNAME__6C_69 : LocalName = LocalName :: pack_static (554u32) ;
+pub const ATOM_LOCALNAME__70_61_72_61_6D : LocalName = LocalName :: pack_static (555u32) ;
+pub const ATOM_LOCALNAME__66_65_6E_63_65 : LocalName = LocalName :: pack_static (556u32) ;
+pub const ATOM_LOCALNAME__75_6E_73_65_6C_65_63_74_61_62_6C_65 : LocalName = LocalName :: pack_static (557u32) ;
+pub const ATOM_LOCALNAME__6F_6E_73_65_6C_65_63_74 : LocalName = LocalName :: pack_static (558u32) ;
+pub const ATOM_LOCALNAME__67_6C_79_70_68_2D_6E_61_6D_65 : LocalName = LocalName :: pack_static (559u32) ;
+pub const ATOM_LOCALNAME__72_65_66_65_72_72_65_72_70_6F_6C_69_63_79 : LocalName = LocalName :: pack_static (560u32) ;
+pub const ATOM_LOCALNAME__65_71_75_69_76_61_6C_65_6E_74 : LocalName = LocalName :: pack_static (561u32) ;
+pub const ATOM_LOCALNAME__67_6C_79_70_68_2D_6F_72_69_65_6E_74_61_74_69_6F_6E_2D_68_6F_72_69_7A_6F_6E_74_61_6C : LocalName = LocalName :: pack_static (562u32) ;
+pub const ATOM_LOCALNAME__63_61_72_64 : LocalName = LocalName :: pack_static (563u32) ;
+pub const ATOM_LOCALNAME__6F_6E_62_6C_75_72 : LocalName = LocalName :: pack_static (564u32) ;
+pub const ATOM_LOCALNAME__78_31 : LocalName = LocalName :: pack_static (565u32) ;
+pub const ATOM_LOCALNAME__6D_72_6F_6F_74 : LocalName = LocalName :: pack_static (566u32) ;
+pub const ATOM_LOCALNAME__73_74_61_72_74_6F_66_66_73_65_74 : LocalName = LocalName :: pack_static (567u32) ;
+pub const ATOM_LOCALNAME__75_6E_64_65_72_6C_69_6E_65_2D_70_6F_73_69_74_69_6F_6E : LocalName = LocalName :: pack_static (568u32) ;
+pub const ATOM_LOCALNAME__6D_61_63_72_6F_73 : LocalName = LocalName :: pack_static (569u32) ;
+pub const ATOM_LOCALNAME__72_65_76_65_72_73_65_64 : LocalName = LocalName :: pack_static (570u32) ;
+pub const ATOM_LOCALNAME__72_79 : LocalName = LocalName :: pack_static (571u32) ;
+pub const ATOM_LOCALNAME__6F_74_68_65_72 : LocalName = LocalName :: pack_static (572u32) ;
+pub const ATOM_LOCALNAME__73_70_61_63_69_6E_67 : LocalName = LocalName :: pack_static (573u32) ;
+pub const ATOM_LOCALNAME__6D_61_74_68_76_61_72_69_61_6E_74 : LocalName = LocalName :: pack_static (574u32) ;
+pub const ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_75_6E_6C_6F_61_64 : LocalName = LocalName :: pack_static (575u32) ;
+pub const ATOM_LOCALNAME__61_6C_69_67_6E_6D_65_6E_74_2D_62_61_73_65_6C_69_6E_65 : LocalName = LocalName :: pack_static (576u32) ;
+pub const ATOM_LOCALNAME__73_65_74 : LocalName = LocalName :: pack_static (577u32) ;
+pub const ATOM_LOCALNAME__69_6E_74_65_67_72_69_74_79 : LocalName = LocalName :: pack_static (578u32) ;
+pub const ATOM_LOCALNAME__61_63_72_6F_6E_79_6D : LocalName = LocalName :: pack_static (579u32) ;
+pub const ATOM_LOCALNAME__73_70_65_63_75_6C_61_72_63_6F_6E_73_74_61_6E_74 : LocalName = LocalName :: pack_static (580u32) ;
+pub const ATOM_LOCALNAME__6E_61_76 : LocalName = LocalName :: pack_static (581u32) ;
+pub const ATOM_LOCALNAME__6E_6F_6D_6F_64_75_6C_65 : LocalName = LocalName :: pack_static (582u32) ;
+pub const ATOM_LOCALNAME__70_61_74_74_65_72_6E_54_72_61_6E_73_66_6F_72_6D : LocalName = LocalName :: pack_static (583u32) ;
+pub const ATOM_LOCALNAME__72_65_66_78 : LocalName = LocalName :: pack_static (584u32) ;
+pub const ATOM_LOCALNAME__74_68_69_63_6B_6D_61_74_68_73_70_61_63_65 : LocalName = LocalName :: pack_static (585u32) ;
+pub const ATOM_LOCALNAME__6C_61_70_6C_61_63_69_61_6E : LocalName = LocalName :: pack_static (586u32) ;
+pub const ATOM_LOCALNAME__61_6E_69_6D_61_74_65_74_72_61_6E_73_66_6F_72_6D : LocalName = LocalName :: pack_static (587u32) ;
+pub const ATOM_LOCALNAME__6E_6F_74_69_6E : LocalName = LocalName :: pack_static (588u32) ;
+pub const ATOM_LOCALNAME__73_65_6C_65_63_74 : LocalName = LocalName :: pack_static (589u32) ;
+pub const ATOM_LOCALNAME__6F_6E_63_6C_69_63_6B : LocalName = LocalName :: pack_static (590u32) ;
+pub const ATOM_LOCALNAME__68_6F_72_69_7A_2D_6F_72_69_67_69_6E_2D_78 : LocalName = LocalName :: pack_static (591u32) ;
+pub const ATOM_LOCALNAME__6C_69_6E_6B : LocalName = LocalName :: pack_static (592u32) ;
+pub const ATOM_LOCALNAME__72_65_61_6C_73 : LocalName = LocalName :: pack_static (593u32) ;
+pub const ATOM_LOCALNAME__69_6E : LocalName = LocalName :: pack_static (594u32) ;
+pub const ATOM_LOCALNAME__62_64_6F : LocalName = LocalName :: pack_static (595u32) ;
+pub const ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_64_65_61_63_74_69_76_61_74_65 : LocalName = LocalName :: pack_static (596u32) ;
+pub const ATOM_LOCALNAME__72_65_71_75_69_72_65_64_65_78_74_65_6E_73_69_6F_6E_73 : LocalName = LocalName :: pack_static (597u32) ;
+pub const ATOM_LOCALNAME__70_69_6E_67 : LocalName = LocalName :: pack_static (598u32) ;
+pub const ATOM_LOCALNAME__75_31 : LocalName = LocalName :: pack_static (599u32) ;
+pub const ATOM_LOCALNAME__75_73_65 : LocalName = LocalName :: pack_static (600u32) ;
+pub const ATOM_LOCALNAME__66_6F_72 : LocalName = LocalName :: pack_static (601u32) ;
+pub const ATOM_LOCALNAME__66_6F_72_6D_61_74 : LocalName = LocalName :: pack_static (602u32) ;
+pub const ATOM_LOCALNAME__6B_65_79_54_69_6D_65_73 : LocalName = LocalName :: pack_static (603u32) ;
+pub const ATOM_LOCALNAME__66_65_46_75_6E_63_42 : LocalName = LocalName :: pack_static (604u32) ;
+pub const ATOM_LOCALNAME__73_70_65_63_75_6C_61_72_43_6F_6E_73_74_61_6E_74 : LocalName = LocalName :: pack_static (605u32) ;
+pub const ATOM_LOCALNAME__73_74_64_44_65_76_69_61_74_69_6F_6E : LocalName = LocalName :: pack_static (606u32) ;
+pub const ATOM_LOCALNAME__72_71_75_6F_74_65 : LocalName = LocalName :: pack_static (607u32) ;
+pub const ATOM_LOCALNAME__75_6C : LocalName = LocalName :: pack_static (608u32) ;
+pub const ATOM_LOCALNAME__61_6E_69_6D_61_74_65_63_6F_6C_6F_72 : LocalName = LocalName :: pack_static (609u32) ;
+pub const ATOM_LOCALNAME__76_2D_69_64_65_6F_67_72_61_70_68_69_63 : LocalName = LocalName :: pack_static (610u32) ;
+pub const ATOM_LOCALNAME__6C_65_74_74_65_72_2D_73_70_61_63_69_6E_67 : LocalName = LocalName :: pack_static (611u32) ;
+pub const ATOM_LOCALNAME__66_6F_6E_74_77_65_69_67_68_74 : LocalName = LocalName :: pack_static (612u32) ;
+pub const ATOM_LOCALNAME__63_68_61_72 : LocalName = LocalName :: pack_static (613u32) ;
+pub const ATOM_LOCALNAME__77_72_61_70 : LocalName = LocalName :: pack_static (614u32) ;
+pub const ATOM_LOCALNAME__69_64 : LocalName = LocalName :: pack_static (615u32) ;
+pub const ATOM_LOCALNAME__66_65_4D_6F_72_70_68_6F_6C_6F_67_79 : LocalName = LocalName :: pack_static (616u32) ;
+pub const ATOM_LOCALNAME__69_66_72_61_6D_65 : LocalName = LocalName :: pack_static (617u32) ;
+pub const ATOM_LOCALNAME__64_65_67_72_65_65 : LocalName = LocalName :: pack_static (618u32) ;
+pub const ATOM_LOCALNAME__68_32 : LocalName = LocalName :: pack_static (619u32) ;
+pub const ATOM_LOCALNAME__6B_69_6E_64 : LocalName = LocalName :: pack_static (620u32) ;
+pub const ATOM_LOCALNAME__74_79_70_65 : LocalName = LocalName :: pack_static (621u32) ;
+pub const ATOM_LOCALNAME__6B : LocalName = LocalName :: pack_static (622u32) ;
+pub const ATOM_LOCALNAME__6C_69_67_68_74_69_6E_67_2D_63_6F_6C_6F_72 : LocalName = LocalName :: pack_static (623u32) ;
+pub const ATOM_LOCALNAME__6D_69_6E_73_69_7A_65 : LocalName = LocalName :: pack_static (624u32) ;
+pub const ATOM_LOCALNAME__6F_6E_66_6F_63_75_73_69_6E : LocalName = LocalName :: pack_static (625u32) ;
+pub const ATOM_LOCALNAME__73_74_65_6D_68 : LocalName = LocalName :: pack_static (626u32) ;
+pub const ATOM_LOCALNAME__64_65_63_6F_64_69_6E_67 : LocalName = LocalName :: pack_static (627u32) ;
+pub const ATOM_LOCALNAME__63_75_72_6C : LocalName = LocalName :: pack_static (628u32) ;
+pub const ATOM_LOCALNAME__6E_61_74_75_72_61_6C_6E_75_6D_62_65_72_73 : LocalName = LocalName :: pack_static (629u32) ;
+pub const ATOM_LOCALNAME__74_61_72_67_65_74_78 : LocalName = LocalName :: pack_static (630u32) ;
+pub const ATOM_LOCALNAME__63_6F_6E_74_65_6E_74_53_74_79_6C_65_54_79_70_65 : LocalName = LocalName :: pack_static (631u32) ;
+pub const ATOM_LOCALNAME__70_72_6F_70_65_72_74_79 : LocalName = LocalName :: pack_static (632u32) ;
+pub const ATOM_LOCALNAME__66_65_74_63_68_70_72_69_6F_72_69_74_79 : LocalName = LocalName :: pack_static (633u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_64_72_6F_70_65_66_66_65_63_74 : LocalName = LocalName :: pack_static (634u32) ;
+pub const ATOM_LOCALNAME__79 : LocalName = LocalName :: pack_static (635u32) ;
+pub const ATOM_LOCALNAME__61_6C_69_67_6E : LocalName = LocalName :: pack_static (636u32) ;
+pub const ATOM_LOCALNAME__64_65_6C : LocalName = LocalName :: pack_static (637u32) ;
+pub const ATOM_LOCALNAME__72_62 : LocalName = LocalName :: pack_static (638u32) ;
+pub const ATOM_LOCALNAME__72_65_6C_6E : LocalName = LocalName :: pack_static (639u32) ;
+pub const ATOM_LOCALNAME__6F_6E_65_72_72_6F_72 : LocalName = LocalName :: pack_static (640u32) ;
+pub const ATOM_LOCALNAME__63_61_6C_63_4D_6F_64_65 : LocalName = LocalName :: pack_static (641u32) ;
+pub const ATOM_LOCALNAME__63_6C_69_70_70_61_74_68_75_6E_69_74_73 : LocalName = LocalName :: pack_static (642u32) ;
+pub const ATOM_LOCALNAME__6D_73_71_72_74 : LocalName = LocalName :: pack_static (643u32) ;
+pub const ATOM_LOCALNAME__62_61_73_65_70_72_6F_66_69_6C_65 : LocalName = LocalName :: pack_static (644u32) ;
+pub const ATOM_LOCALNAME__62_65_67_69_6E : LocalName = LocalName :: pack_static (645u32) ;
+pub const ATOM_LOCALNAME__6E_6F_6E_63_65 : LocalName = LocalName :: pack_static (646u32) ;
+pub const ATOM_LOCALNAME__72_65_70_65_61_74_2D_73_74_61_72_74 : LocalName = LocalName :: pack_static (647u32) ;
+pub const ATOM_LOCALNAME__6D_73_75_62 : LocalName = LocalName :: pack_static (648u32) ;
+pub const ATOM_LOCALNAME__74_69_6D_65 : LocalName = LocalName :: pack_static (649u32) ;
+pub const ATOM_LOCALNAME__78_6D_6C_6E_73 : LocalName = LocalName :: pack_static (650u32) ;
+pub const ATOM_LOCALNAME__6B_65_72_6E_69_6E_67 : LocalName = LocalName :: pack_static (651u32) ;
+pub const ATOM_LOCALNAME__76_69_65_77_74_61_72_67_65_74 : LocalName = LocalName :: pack_static (652u32) ;
+pub const ATOM_LOCALNAME__66_6F_72_6D_61_63_74_69_6F_6E : LocalName = LocalName :: pack_static (653u32) ;
+pub const ATOM_LOCALNAME__76_69_65_77 : LocalName = LocalName :: pack_static (654u32) ;
+pub const ATOM_LOCALNAME__69_6E_64_65_78 : LocalName = LocalName :: pack_static (655u32) ;
+pub const ATOM_LOCALNAME__63_6F_64_65_74_79_70_65 : LocalName = LocalName :: pack_static (656u32) ;
+pub const ATOM_LOCALNAME__78_6F_72 : LocalName = LocalName :: pack_static (657u32) ;
+pub const ATOM_LOCALNAME__6D_65_74_68_6F_64 : LocalName = LocalName :: pack_static (658u32) ;
+pub const ATOM_LOCALNAME__6C_65_67_65_6E_64 : LocalName = LocalName :: pack_static (659u32) ;
+pub const ATOM_LOCALNAME__66_69_67_75_72_65 : LocalName = LocalName :: pack_static (660u32) ;
+pub const ATOM_LOCALNAME__66_65_62_6C_65_6E_64 : LocalName = LocalName :: pack_static (661u32) ;
+pub const ATOM_LOCALNAME__68_35 : LocalName = LocalName :: pack_static (662u32) ;
+pub const ATOM_LOCALNAME__6F_6E_69_6E_76_61_6C_69_64 : LocalName = LocalName :: pack_static (663u32) ;
+pub const ATOM_LOCALNAME__6C_6F_77_73_72_63 : LocalName = LocalName :: pack_static (664u32) ;
+pub const ATOM_LOCALNAME__62_61_73_65_66_6F_6E_74 : LocalName = LocalName :: pack_static (665u32) ;
+pub const ATOM_LOCALNAME__6D_73_75_62_73_75_70 : LocalName = LocalName :: pack_static (666u32) ;
+pub const ATOM_LOCALNAME__70_69_63_74_75_72_65 : LocalName = LocalName :: pack_static (667u32) ;
+pub const ATOM_LOCALNAME__66_65_66_6C_6F_6F_64 : LocalName = LocalName :: pack_static (668u32) ;
+pub const ATOM_LOCALNAME__69_6D_70_6C_69_65_73 : LocalName = LocalName :: pack_static (669u32) ;
+pub const ATOM_LOCALNAME__73_74_72_6F_6B_65_2D_77_69_64_74_68 : LocalName = LocalName :: pack_static (670u32) ;
+pub const ATOM_LOCALNAME__61_64_64_72_65_73_73 : LocalName = LocalName :: pack_static (671u32) ;
+pub const ATOM_LOCALNAME__66_6F_6E_74_2D_66_61_63_65 : LocalName = LocalName :: pack_static (672u32) ;
+pub const ATOM_LOCALNAME__73_69_6E : LocalName = LocalName :: pack_static (673u32) ;
+pub const ATOM_LOCALNAME__61_73_63_65_6E_74 : LocalName = LocalName :: pack_static (674u32) ;
+pub const ATOM_LOCALNAME__69_6D_61_67_69_6E_61_72_79_69 : LocalName = LocalName :: pack_static (675u32) ;
+pub const ATOM_LOCALNAME__70_6F_69_6E_74_73_61_74_7A : LocalName = LocalName :: pack_static (676u32) ;
+pub const ATOM_LOCALNAME__6C_69_6D_69_74_69_6E_67_43_6F_6E_65_41_6E_67_6C_65 : LocalName = LocalName :: pack_static (677u32) ;
+pub const ATOM_LOCALNAME__73_75_72_66_61_63_65_73_63_61_6C_65 : LocalName = LocalName :: pack_static (678u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_72_6F_77_73_70_61_6E : LocalName = LocalName :: pack_static (679u32) ;
+pub const ATOM_LOCALNAME__64_6F_6D_61_69_6E : LocalName = LocalName :: pack_static (680u32) ;
+pub const ATOM_LOCALNAME__74_65_78_74_61_72_65_61 : LocalName = LocalName :: pack_static (681u32) ;
+pub const ATOM_LOCALNAME__6D_61_72_71_75_65_65 : LocalName = LocalName :: pack_static (682u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_72_6F_77_69_6E_64_65_78 : LocalName = LocalName :: pack_static (683u32) ;
+pub const ATOM_LOCALNAME__63_69_74_65 : LocalName = LocalName :: pack_static (684u32) ;
+pub const ATOM_LOCALNAME__66_65_70_6F_69_6E_74_6C_69_67_68_74 : LocalName = LocalName :: pack_static (685u32) ;
+pub const ATOM_LOCALNAME__61_63_63_75_6D_75_6C_61_74_65 : LocalName = LocalName :: pack_static (686u32) ;
+pub const ATOM_LOCALNAME__6F_6E_64_62_6C_63_6C_69_63_6B : LocalName = LocalName :: pack_static (687u32) ;
+pub const ATOM_LOCALNAME__70_69 : LocalName = LocalName :: pack_static (688u32) ;
+pub const ATOM_LOCALNAME__72_65_71_75_69_72_65_64_46_65_61_74_75_72_65_73 : LocalName = LocalName :: pack_static (689u32) ;
+pub const ATOM_LOCALNAME__64_69_76_65_72_67_65_6E_63_65 : LocalName = LocalName :: pack_static (690u32) ;
+pub const ATOM_LOCALNAME__61_74_74_72_69_62_75_74_65_4E_61_6D_65 : LocalName = LocalName :: pack_static (691u32) ;
+pub const ATOM_LOCALNAME__64_69_76 : LocalName = LocalName :: pack_static (692u32) ;
+pub const ATOM_LOCALNAME__61_70_70_6C_79 : LocalName = LocalName :: pack_static (693u32) ;
+pub const ATOM_LOCALNAME__63_6F_6C_75_6D_6E_61_6C_69_67_6E : LocalName = LocalName :: pack_static (694u32) ;
+pub const ATOM_LOCALNAME__6D_61_70 : LocalName = LocalName :: pack_static (695u32) ;
+pub const ATOM_LOCALNAME__61_6C_74_69_6D_67 : LocalName = LocalName :: pack_static (696u32) ;
+pub const ATOM_LOCALNAME__64_65_73_63 : LocalName = LocalName :: pack_static (697u32) ;
+pub const ATOM_LOCALNAME__68_36 : LocalName = LocalName :: pack_static (698u32) ;
+pub const ATOM_LOCALNAME__61_75_74_6F_63_6F_6D_70_6C_65_74_65 : LocalName = LocalName :: pack_static (699u32) ;
+pub const ATOM_LOCALNAME__67 : LocalName = LocalName :: pack_static (700u32) ;
+pub const ATOM_LOCALNAME__68_69_67_68 : LocalName = LocalName :: pack_static (701u32) ;
+pub const ATOM_LOCALNAME__6D_61_72_6B_65_72 : LocalName = LocalName :: pack_static (702u32) ;
+pub const ATOM_LOCALNAME__73_70_72_65_61_64_6D_65_74_68_6F_64 : LocalName = LocalName :: pack_static (703u32) ;
+pub const ATOM_LOCALNAME__73_70_65_63_75_6C_61_72_65_78_70_6F_6E_65_6E_74 : LocalName = LocalName :: pack_static (704u32) ;
+pub const ATOM_LOCALNAME__6F_6E_7A_6F_6F_6D : LocalName = LocalName :: pack_static (705u32) ;
+pub const ATOM_LOCALNAME__6F_6E_73_74_6F_70 : LocalName = LocalName :: pack_static (706u32) ;
+pub const ATOM_LOCALNAME__6F_6E_70_72_6F_70_65_72_74_79_63_68_61_6E_67_65 : LocalName = LocalName :: pack_static (707u32) ;
+pub const ATOM_LOCALNAME__61_6E_69_6D_61_74_65_6D_6F_74_69_6F_6E : LocalName = LocalName :: pack_static (708u32) ;
+pub const ATOM_LOCALNAME__61_75_64_69_6F : LocalName = LocalName :: pack_static (709u32) ;
+pub const ATOM_LOCALNAME__63_6F_6C : LocalName = LocalName :: pack_static (710u32) ;
+pub const ATOM_LOCALNAME__6F_6E_66_69_6E_69_73_68 : LocalName = LocalName :: pack_static (711u32) ;
+pub const ATOM_LOCALNAME__69_6E_73 : LocalName = LocalName :: pack_static (712u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_76_61_6C_75_65_6D_69_6E : LocalName = LocalName :: pack_static (713u32) ;
+pub const ATOM_LOCALNAME__66_65_44_69_66_66_75_73_65_4C_69_67_68_74_69_6E_67 : LocalName = LocalName :: pack_static (714u32) ;
+pub const ATOM_LOCALNAME__77_72_69_74_69_6E_67_2D_6D_6F_64_65 : LocalName = LocalName :: pack_static (715u32) ;
+pub const ATOM_LOCALNAME__61_72_63_68_69_76_65 : LocalName = LocalName :: pack_static (716u32) ;
+pub const ATOM_LOCALNAME__64_64 : LocalName = LocalName :: pack_static (717u32) ;
+pub const ATOM_LOCALNAME__2A : LocalName = LocalName :: pack_static (718u32) ;
+pub const ATOM_LOCALNAME__72_65_6C : LocalName = LocalName :: pack_static (719u32) ;
+pub const ATOM_LOCALNAME__74_73_70_61_6E : LocalName = LocalName :: pack_static (720u32) ;
+pub const ATOM_LOCALNAME__76_2D_68_61_6E_67_69_6E_67 : LocalName = LocalName :: pack_static (721u32) ;
+pub const ATOM_LOCALNAME__6F_6E_73_74_6F_72_61_67_65 : LocalName = LocalName :: pack_static (722u32) ;
+pub const ATOM_LOCALNAME__68_34 : LocalName = LocalName :: pack_static (723u32) ;
+pub const ATOM_LOCALNAME__73_74_72_6F_6E_67 : LocalName = LocalName :: pack_static (724u32) ;
+pub const ATOM_LOCALNAME__72 : LocalName = LocalName :: pack_static (725u32) ;
+pub const ATOM_LOCALNAME__63_6F_6E_74_72_6F_6C_73 : LocalName = LocalName :: pack_static (726u32) ;
+pub const ATOM_LOCALNAME__72_6F_74_61_74_65 : LocalName = LocalName :: pack_static (727u32) ;
+pub const ATOM_LOCALNAME__76_69_65_77_62_6F_78 : LocalName = LocalName :: pack_static (728u32) ;
+pub const ATOM_LOCALNAME__6D_61_74_68_63_6F_6C_6F_72 : LocalName = LocalName :: pack_static (729u32) ;
+pub const ATOM_LOCALNAME__61_6E_6E_6F_74_61_74_69_6F_6E : LocalName = LocalName :: pack_static (730u32) ;
+pub const ATOM_LOCALNAME__67_6C_79_70_68_52_65_66 : LocalName = LocalName :: pack_static (731u32) ;
+pub const ATOM_LOCALNAME__6F_6E_62_65_67_69_6E : LocalName = LocalName :: pack_static (732u32) ;
+pub const ATOM_LOCALNAME__65_6E_63_6F_64_69_6E_67 : LocalName = LocalName :: pack_static (733u32) ;
+pub const ATOM_LOCALNAME__61_63_63_65_70_74_2D_63_68_61_72_73_65_74 : LocalName = LocalName :: pack_static (734u32) ;
+pub const ATOM_LOCALNAME__72_6F_6C_65 : LocalName = LocalName :: pack_static (735u32) ;
+pub const ATOM_LOCALNAME__61_6E_69_6D_61_74_65_4D_6F_74_69_6F_6E : LocalName = LocalName :: pack_static (736u32) ;
+pub const ATOM_LOCALNAME__64_69_73_63_61_72_64 : LocalName = LocalName :: pack_static (737u32) ;
+pub const ATOM_LOCALNAME__63_6E : LocalName = LocalName :: pack_static (738u32) ;
+pub const ATOM_LOCALNAME__62_6C_69_6E_6B : LocalName = LocalName :: pack_static (739u32) ;
+pub const ATOM_LOCALNAME__65_78_70_6F_6E_65_6E_74_69_61_6C_65 : LocalName = LocalName :: pack_static (740u32) ;
+pub const ATOM_LOCALNAME__73_68_61_70_65_2D_72_65_6E_64_65_72_69_6E_67 : LocalName = LocalName :: pack_static (741u32) ;
+pub const ATOM_LOCALNAME__61_62_62_72 : LocalName = LocalName :: pack_static (742u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_72_6F_77_69_6E_64_65_78_74_65_78_74 : LocalName = LocalName :: pack_static (743u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_73_65_63_72_65_74 : LocalName = LocalName :: pack_static (744u32) ;
+pub const ATOM_LOCALNAME__63_6C_6F_73_65 : LocalName = LocalName :: pack_static (745u32) ;
+pub const ATOM_LOCALNAME__66_6F_6E_74_66_61_6D_69_6C_79 : LocalName = LocalName :: pack_static (746u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_65_72_72_6F_72_6D_65_73_73_61_67_65 : LocalName = LocalName :: pack_static (747u32) ;
+pub const ATOM_LOCALNAME__6F_6E_61_62_6F_72_74 : LocalName = LocalName :: pack_static (748u32) ;
+pub const ATOM_LOCALNAME__63_6F_6F_72_64_73 : LocalName = LocalName :: pack_static (749u32) ;
+pub const ATOM_LOCALNAME__63_61_72_74_65_73_69_61_6E_70_72_6F_64_75_63_74 : LocalName = LocalName :: pack_static (750u32) ;
+pub const ATOM_LOCALNAME__6F_6C : LocalName = LocalName :: pack_static (751u32) ;
+pub const ATOM_LOCALNAME__73_75_62_73_63_72_69_70_74_73_68_69_66_74 : LocalName = LocalName :: pack_static (752u32) ;
+pub const ATOM_LOCALNAME__74_72_61_6E_73_6C_61_74_65 : LocalName = LocalName :: pack_static (753u32) ;
+pub const ATOM_LOCALNAME__73_63_72_6F_6C_6C_64_65_6C_61_79 : LocalName = LocalName :: pack_static (754u32) ;
+pub const ATOM_LOCALNAME__63_6F_6C_6F_72_2D_72_65_6E_64_65_72_69_6E_67 : LocalName = LocalName :: pack_static (755u32) ;
+pub const ATOM_LOCALNAME__6B_65_79_74_69_6D_65_73 : LocalName = LocalName :: pack_static (756u32) ;
+pub const ATOM_LOCALNAME__68_61_6E_64_6C_65_72 : LocalName = LocalName :: pack_static (757u32) ;
+pub const ATOM_LOCALNAME__61_63_74_69_6F_6E : LocalName = LocalName :: pack_static (758u32) ;
+pub const ATOM_LOCALNAME__78_6C_69_6E_6B_3A_68_72_65_66 : LocalName = LocalName :: pack_static (759u32) ;
+pub const ATOM_LOCALNAME__66_6F_6E_74_2D_66_61_63_65_2D_6E_61_6D_65 : LocalName = LocalName :: pack_static (760u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_6D_6F_64_61_6C : LocalName = LocalName :: pack_static (761u32) ;
+pub const ATOM_LOCALNAME__76_61_72 : LocalName = LocalName :: pack_static (762u32) ;
+pub const ATOM_LOCALNAME__6C_69_73_74 : LocalName = LocalName :: pack_static (763u32) ;
+pub const ATOM_LOCALNAME__65_6E_61_62_6C_65_2D_62_61_63_6B_67_72_6F_75_6E_64 : LocalName = LocalName :: pack_static (764u32) ;
+pub const ATOM_LOCALNAME__72_65_61_6C : LocalName = LocalName :: pack_static (765u32) ;
+pub const ATOM_LOCALNAME__63_75_72_73_6F_72 : LocalName = LocalName :: pack_static (766u32) ;
+pub const ATOM_LOCALNAME__72_65_71_75_69_72_65_64 : LocalName = LocalName :: pack_static (767u32) ;
+pub const ATOM_LOCALNAME__66_6F_6E_74_2D_73_74_79_6C_65 : LocalName = LocalName :: pack_static (768u32) ;
+pub const ATOM_LOCALNAME__72_74 : LocalName = LocalName :: pack_static (769u32) ;
+pub const ATOM_LOCALNAME__6D_65_6E_75 : LocalName = LocalName :: pack_static (770u32) ;
+pub const ATOM_LOCALNAME__72_65_6E_64_65_72_69_6E_67_2D_69_6E_74_65_6E_74 : LocalName = LocalName :: pack_static (771u32) ;
+pub const ATOM_LOCALNAME__6D_61_78_73_69_7A_65 : LocalName = LocalName :: pack_static (772u32) ;
+pub const ATOM_LOCALNAME__74_65_78_74_4C_65_6E_67_74_68 : LocalName = LocalName :: pack_static (773u32) ;
+pub const ATOM_LOCALNAME__6F_6E_72_6F_77_65_6E_74_65_72 : LocalName = LocalName :: pack_static (774u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_63_68_61_6E_6E_65_6C : LocalName = LocalName :: pack_static (775u32) ;
+pub const ATOM_LOCALNAME__66_65_43_6F_6E_76_6F_6C_76_65_4D_61_74_72_69_78 : LocalName = LocalName :: pack_static (776u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_63_6F_6C_73_70_61_6E : LocalName = LocalName :: pack_static (777u32) ;
+pub const ATOM_LOCALNAME__6D_65_6E_75_69_74_65_6D : LocalName = LocalName :: pack_static (778u32) ;
+pub const ATOM_LOCALNAME__64_69_72_6E_61_6D_65 : LocalName = LocalName :: pack_static (779u32) ;
+pub const ATOM_LOCALNAME__66_6E : LocalName = LocalName :: pack_static (780u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_64_65_74_61_69_6C_73 : LocalName = LocalName :: pack_static (781u32) ;
+pub const ATOM_LOCALNAME__66_65_54_75_72_62_75_6C_65_6E_63_65 : LocalName = LocalName :: pack_static (782u32) ;
+pub const ATOM_LOCALNAME__6F_6E_64_61_74_61_73_65_74_63_6F_6D_70_6C_65_74_65 : LocalName = LocalName :: pack_static (783u32) ;
+pub const ATOM_LOCALNAME__78_43_68_61_6E_6E_65_6C_53_65_6C_65_63_74_6F_72 : LocalName = LocalName :: pack_static (784u32) ;
+pub const ATOM_LOCALNAME__63_6C_69_70_50_61_74_68 : LocalName = LocalName :: pack_static (785u32) ;
+pub const ATOM_LOCALNAME__6D_61_78 : LocalName = LocalName :: pack_static (786u32) ;
+pub const ATOM_LOCALNAME__6F_6E_61_66_74_65_72_70_72_69_6E_74 : LocalName = LocalName :: pack_static (787u32) ;
+pub const ATOM_LOCALNAME__6C_63_6D : LocalName = LocalName :: pack_static (788u32) ;
+pub const ATOM_LOCALNAME__6D_65_72_72_6F_72 : LocalName = LocalName :: pack_static (789u32) ;
+pub const ATOM_LOCALNAME__66_65_66_75_6E_63_72 : LocalName = LocalName :: pack_static (790u32) ;
+pub const ATOM_LOCALNAME__67_72_61_64_69_65_6E_74_75_6E_69_74_73 : LocalName = LocalName :: pack_static (791u32) ;
+pub const ATOM_LOCALNAME__66_6F_6E_74_2D_73_74_72_65_74_63_68 : LocalName = LocalName :: pack_static (792u32) ;
+pub const ATOM_LOCALNAME__6C_61_72_67_65_6F_70 : LocalName = LocalName :: pack_static (793u32) ;
+pub const ATOM_LOCALNAME__73_72_63_64_6F_63 : LocalName = LocalName :: pack_static (794u32) ;
+pub const ATOM_LOCALNAME__73_65_70_61_72_61_74_6F_72 : LocalName = LocalName :: pack_static (795u32) ;
+pub const ATOM_LOCALNAME__73_70_72_65_61_64_4D_65_74_68_6F_64 : LocalName = LocalName :: pack_static (796u32) ;
+pub const ATOM_LOCALNAME__76_69_65_77_54_61_72_67_65_74 : LocalName = LocalName :: pack_static (797u32) ;
+pub const ATOM_LOCALNAME__61_63_63_65_6E_74 : LocalName = LocalName :: pack_static (798u32) ;
+pub const ATOM_LOCALNAME__6F_70_65_6E : LocalName = LocalName :: pack_static (799u32) ;
+pub const ATOM_LOCALNAME__66_65_43_6F_6D_70_6F_73_69_74_65 : LocalName = LocalName :: pack_static (800u32) ;
+pub const ATOM_LOCALNAME__73_63_6F_70_65 : LocalName = LocalName :: pack_static (801u32) ;
+pub const ATOM_LOCALNAME__65_6C_65_76_61_74_69_6F_6E : LocalName = LocalName :: pack_static (802u32) ;
+pub const ATOM_LOCALNAME__6D_69 : LocalName = LocalName :: pack_static (803u32) ;
+pub const ATOM_LOCALNAME__65_78_69_73_74_73 : LocalName = LocalName :: pack_static (804u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_61_63_74_69_76_65_64_65_73_63_65_6E_64_61_6E_74 : LocalName = LocalName :: pack_static (805u32) ;
+pub const ATOM_LOCALNAME__66_69_65_6C_64_73_65_74 : LocalName = LocalName :: pack_static (806u32) ;
+pub const ATOM_LOCALNAME__66_65_64_69_73_74_61_6E_74_6C_69_67_68_74 : LocalName = LocalName :: pack_static (807u32) ;
+pub const ATOM_LOCALNAME__63_6F_6C_6F_72_2D_70_72_6F_66_69_6C_65 : LocalName = LocalName :: pack_static (808u32) ;
+pub const ATOM_LOCALNAME__67_65_71 : LocalName = LocalName :: pack_static (809u32) ;
+pub const ATOM_LOCALNAME__6F_6E_6D_65_73_73_61_67_65 : LocalName = LocalName :: pack_static (810u32) ;
+pub const ATOM_LOCALNAME__6F_6E_70_61_67_65_73_68_6F_77 : LocalName = LocalName :: pack_static (811u32) ;
+pub const ATOM_LOCALNAME_ : LocalName = LocalName :: pack_static (812u32) ;
+pub const ATOM_LOCALNAME__6C_6F_77_6C_69_6D_69_74 : LocalName = LocalName :: pack_static (813u32) ;
+pub const ATOM_LOCALNAME__6D_74_65_78_74 : LocalName = LocalName :: pack_static (814u32) ;
+pub const ATOM_LOCALNAME__63_79 : LocalName = LocalName :: pack_static (815u32) ;
+pub const ATOM_LOCALNAME__73_70_65_63_69_66_69_63_61_74_69_6F_6E : LocalName = LocalName :: pack_static (816u32) ;
+pub const ATOM_LOCALNAME__76_69_73_69_62_69_6C_69_74_79 : LocalName = LocalName :: pack_static (817u32) ;
+pub const ATOM_LOCALNAME__63_6F_6E_64_69_74_69_6F_6E : LocalName = LocalName :: pack_static (818u32) ;
+pub const ATOM_LOCALNAME__69_6E_74_65_67_65_72_73 : LocalName = LocalName :: pack_static (819u32) ;
+pub const ATOM_LOCALNAME__73_74_72_6F_6B_65_2D_64_61_73_68_61_72_72_61_79 : LocalName = LocalName :: pack_static (820u32) ;
+pub const ATOM_LOCALNAME__79_32 : LocalName = LocalName :: pack_static (821u32) ;
+pub const ATOM_LOCALNAME__73_75_62_73_65_74 : LocalName = LocalName :: pack_static (822u32) ;
+pub const ATOM_LOCALNAME__6C_6F_61_64_69_6E_67 : LocalName = LocalName :: pack_static (823u32) ;
+pub const ATOM_LOCALNAME__67_32 : LocalName = LocalName :: pack_static (824u32) ;
+pub const ATOM_LOCALNAME__72_74_63 : LocalName = LocalName :: pack_static (825u32) ;
+pub const ATOM_LOCALNAME__6D_72_6F_77 : LocalName = LocalName :: pack_static (826u32) ;
+pub const ATOM_LOCALNAME__6E_6F_76_61_6C_69_64_61_74_65 : LocalName = LocalName :: pack_static (827u32) ;
+pub const ATOM_LOCALNAME__63_6C_69_70_2D_70_61_74_68 : LocalName = LocalName :: pack_static (828u32) ;
+pub const ATOM_LOCALNAME__6D_75_74_65_64 : LocalName = LocalName :: pack_static (829u32) ;
+pub const ATOM_LOCALNAME__65_6C_6C_69_70_73_65 : LocalName = LocalName :: pack_static (830u32) ;
+pub const ATOM_LOCALNAME__61_75_74_6F_73_75_62_6D_69_74 : LocalName = LocalName :: pack_static (831u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_70_6F_73_69_6E_73_65_74 : LocalName = LocalName :: pack_static (832u32) ;
+pub const ATOM_LOCALNAME__66_6F_72_6D_6D_65_74_68_6F_64 : LocalName = LocalName :: pack_static (833u32) ;
+pub const ATOM_LOCALNAME__73_75_6D_6D_61_72_79 : LocalName = LocalName :: pack_static (834u32) ;
+pub const ATOM_LOCALNAME__75_6E_69_6F_6E : LocalName = LocalName :: pack_static (835u32) ;
+pub const ATOM_LOCALNAME__61_62_73 : LocalName = LocalName :: pack_static (836u32) ;
+pub const ATOM_LOCALNAME__66_65_50_6F_69_6E_74_4C_69_67_68_74 : LocalName = LocalName :: pack_static (837u32) ;
+pub const ATOM_LOCALNAME__6F_6E_72_65_73_69_7A_65 : LocalName = LocalName :: pack_static (838u32) ;
+pub const ATOM_LOCALNAME__61_63_63_65_6E_74_2D_68_65_69_67_68_74 : LocalName = LocalName :: pack_static (839u32) ;
+pub const ATOM_LOCALNAME__69_6E_70_75_74 : LocalName = LocalName :: pack_static (840u32) ;
+pub const ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_70_61_73_74_65 : LocalName = LocalName :: pack_static (841u32) ;
+pub const ATOM_LOCALNAME__6D_61_72_6B_65_72_48_65_69_67_68_74 : LocalName = LocalName :: pack_static (842u32) ;
+pub const ATOM_LOCALNAME__6F_6E_72_65_61_64_79_73_74_61_74_65_63_68_61_6E_67_65 : LocalName = LocalName :: pack_static (843u32) ;
+pub const ATOM_LOCALNAME__70_6F_69_6E_74_73_41_74_5A : LocalName = LocalName :: pack_static (844u32) ;
+pub const ATOM_LOCALNAME__66_65_63_6F_6C_6F_72_6D_61_74_72_69_78 : LocalName = LocalName :: pack_static (845u32) ;
+pub const ATOM_LOCALNAME__61_6E_69_6D_61_74_69_6F_6E : LocalName = LocalName :: pack_static (846u32) ;
+pub const ATOM_LOCALNAME__70_72_6F_67_72_65_73_73 : LocalName = LocalName :: pack_static (847u32) ;
+pub const ATOM_LOCALNAME__72_73_70_61_63_65 : LocalName = LocalName :: pack_static (848u32) ;
+pub const ATOM_LOCALNAME__6F_6E_6D_6F_76_65_65_6E_64 : LocalName = LocalName :: pack_static (849u32) ;
+pub const ATOM_LOCALNAME__63_65_6C_6C_70_61_64_64_69_6E_67 : LocalName = LocalName :: pack_static (850u32) ;
+pub const ATOM_LOCALNAME__66_65_6D_65_72_67_65 : LocalName = LocalName :: pack_static (851u32) ;
+pub const ATOM_LOCALNAME__65_71 : LocalName = LocalName :: pack_static (852u32) ;
+pub const ATOM_LOCALNAME__63_6F_6C_6F_72_2D_69_6E_74_65_72_70_6F_6C_61_74_69_6F_6E_2D_66_69_6C_74_65_72_73 : LocalName = LocalName :: pack_static (853u32) ;
+pub const ATOM_LOCALNAME__6D_6F_76_61_62_6C_65_6C_69_6D_69_74_73 : LocalName = LocalName :: pack_static (854u32) ;
+pub const ATOM_LOCALNAME__66_69_6C_74_65_72_75_6E_69_74_73 : LocalName = LocalName :: pack_static (855u32) ;
+pub const ATOM_LOCALNAME__75_6E_69_63_6F_64_65 : LocalName = LocalName :: pack_static (856u32) ;
+pub const ATOM_LOCALNAME__78_2D_68_65_69_67_68_74 : LocalName = LocalName :: pack_static (857u32) ;
+pub const ATOM_LOCALNAME__6F_6E_72_65_73_65_74 : LocalName = LocalName :: pack_static (858u32) ;
+pub const ATOM_LOCALNAME__6F_6E_6B_65_79_70_72_65_73_73 : LocalName = LocalName :: pack_static (859u32) ;
+pub const ATOM_LOCALNAME__6E_6F_74_73_75_62_73_65_74 : LocalName = LocalName :: pack_static (860u32) ;
+pub const ATOM_LOCALNAME__62_6C_6F_63_6B_71_75_6F_74_65 : LocalName = LocalName :: pack_static (861u32) ;
+pub const ATOM_LOCALNAME__6D_75_6E_64_65_72_6F_76_65_72 : LocalName = LocalName :: pack_static (862u32) ;
+pub const ATOM_LOCALNAME__76_65_72_74_2D_6F_72_69_67_69_6E_2D_79 : LocalName = LocalName :: pack_static (863u32) ;
+pub const ATOM_LOCALNAME__78_63_68_61_6E_6E_65_6C_73_65_6C_65_63_74_6F_72 : LocalName = LocalName :: pack_static (864u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_63_6F_6C_63_6F_75_6E_74 : LocalName = LocalName :: pack_static (865u32) ;
+pub const ATOM_LOCALNAME__6D_61_74_68 : LocalName = LocalName :: pack_static (866u32) ;
+pub const ATOM_LOCALNAME__68_74_6D_6C : LocalName = LocalName :: pack_static (867u32) ;
+pub const ATOM_LOCALNAME__76_65_72_79_74_68_69_63_6B_6D_61_74_68_73_70_61_63_65 : LocalName = LocalName :: pack_static (868u32) ;
+pub const ATOM_LOCALNAME__66_6F_72_65_69_67_6E_6F_62_6A_65_63_74 : LocalName = LocalName :: pack_static (869u32) ;
+pub const ATOM_LOCALNAME__65_64_67_65_4D_6F_64_65 : LocalName = LocalName :: pack_static (870u32) ;
+pub const ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_61_63_74_69_76_61_74_65 : LocalName = LocalName :: pack_static (871u32) ;
+pub const ATOM_LOCALNAME__66_65_67_61_75_73_73_69_61_6E_62_6C_75_72 : LocalName = LocalName :: pack_static (872u32) ;
+pub const ATOM_LOCALNAME__68_72_65_66 : LocalName = LocalName :: pack_static (873u32) ;
+pub const ATOM_LOCALNAME__6D_6F : LocalName = LocalName :: pack_static (874u32) ;
+pub const ATOM_LOCALNAME__61_72_63_73_69_6E_68 : LocalName = LocalName :: pack_static (875u32) ;
+pub const ATOM_LOCALNAME__76_65_72_73_69_6F_6E : LocalName = LocalName :: pack_static (876u32) ;
+pub const ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_6D_6F_76_65 : LocalName = LocalName :: pack_static (877u32) ;
+pub const ATOM_LOCALNAME__6C_69_6E_65_61_72_47_72_61_64_69_65_6E_74 : LocalName = LocalName :: pack_static (878u32) ;
+pub const ATOM_LOCALNAME__66_69_6C_74_65_72_72_65_73 : LocalName = LocalName :: pack_static (879u32) ;
+pub const ATOM_LOCALNAME__73_74_6F_70 : LocalName = LocalName :: pack_static (880u32) ;
+pub const ATOM_LOCALNAME__63_6F_6D_70_6F_73_65 : LocalName = LocalName :: pack_static (881u32) ;
+pub const ATOM_LOCALNAME__61_75_74_6F_66_6F_63_75_73 : LocalName = LocalName :: pack_static (882u32) ;
+pub const ATOM_LOCALNAME__74_65_78_74_50_61_74_68 : LocalName = LocalName :: pack_static (883u32) ;
+pub const ATOM_LOCALNAME__61_72_63_72_6F_6C_65 : LocalName = LocalName :: pack_static (884u32) ;
+pub const ATOM_LOCALNAME__61_72_74_69_63_6C_65 : LocalName = LocalName :: pack_static (885u32) ;
+pub const ATOM_LOCALNAME__64_79 : LocalName = LocalName :: pack_static (886u32) ;
+pub const ATOM_LOCALNAME__66_69_6C_74_65_72 : LocalName = LocalName :: pack_static (887u32) ;
+pub const ATOM_LOCALNAME__74_62_6F_64_79 : LocalName = LocalName :: pack_static (888u32) ;
+pub const ATOM_LOCALNAME__64_65_66_69_6E_69_74_69_6F_6E_75_72_6C : LocalName = LocalName :: pack_static (889u32) ;
+pub const ATOM_LOCALNAME__62_61_73_65_50_72_6F_66_69_6C_65 : LocalName = LocalName :: pack_static (890u32) ;
+pub const ATOM_LOCALNAME__6C_69_6E_65_2D_68_65_69_67_68_74 : LocalName = LocalName :: pack_static (891u32) ;
+pub const ATOM_LOCALNAME__70_6F_69_6E_74_73_61_74_78 : LocalName = LocalName :: pack_static (892u32) ;
+pub const ATOM_LOCALNAME__70_72_69_6D_69_74_69_76_65_55_6E_69_74_73 : LocalName = LocalName :: pack_static (893u32) ;
+pub const ATOM_LOCALNAME__73_75_70 : LocalName = LocalName :: pack_static (894u32) ;
+pub const ATOM_LOCALNAME__6D_61_72_6B_65_72_2D_6D_69_64 : LocalName = LocalName :: pack_static (895u32) ;
+pub const ATOM_LOCALNAME__6D_65_64_69_61 : LocalName = LocalName :: pack_static (896u32) ;
+pub const ATOM_LOCALNAME__70_69_65_63_65_77_69_73_65 : LocalName = LocalName :: pack_static (897u32) ;
+pub const ATOM_LOCALNAME__72_65_66_59 : LocalName = LocalName :: pack_static (898u32) ;
+pub const ATOM_LOCALNAME__64_74 : LocalName = LocalName :: pack_static (899u32) ;
+pub const ATOM_LOCALNAME__68_72_65_66_6C_61_6E_67 : LocalName = LocalName :: pack_static (900u32) ;
+pub const ATOM_LOCALNAME__6F_6E_6F_66_66_6C_69_6E_65 : LocalName = LocalName :: pack_static (901u32) ;
+pub const ATOM_LOCALNAME__61_78_69_73 : LocalName = LocalName :: pack_static (902u32) ;
+pub const ATOM_LOCALNAME__66_6F_72_61_6C_6C : LocalName = LocalName :: pack_static (903u32) ;
+pub const ATOM_LOCALNAME__6D_61_74_72_69_78 : LocalName = LocalName :: pack_static (904u32) ;
+pub const ATOM_LOCALNAME__72_65_70_65_61_74_44_75_72 : LocalName = LocalName :: pack_static (905u32) ;
+pub const ATOM_LOCALNAME__6F_6E_65_6E_64 : LocalName = LocalName :: pack_static (906u32) ;
+pub const ATOM_LOCALNAME__74_62_72_65_61_6B : LocalName = LocalName :: pack_static (907u32) ;
+pub const ATOM_LOCALNAME__6D_70_61_64_64_65_64 : LocalName = LocalName :: pack_static (908u32) ;
+pub const ATOM_LOCALNAME__64_61_74_61_73_72_63 : LocalName = LocalName :: pack_static (909u32) ;
+pub const ATOM_LOCALNAME__73_6C_6F_70_65 : LocalName = LocalName :: pack_static (910u32) ;
+pub const ATOM_LOCALNAME__6C_65_71 : LocalName = LocalName :: pack_static (911u32) ;
+pub const ATOM_LOCALNAME__6C_61_6E_67_75_61_67_65 : LocalName = LocalName :: pack_static (912u32) ;
+pub const ATOM_LOCALNAME__65_71_75_61_6C_63_6F_6C_75_6D_6E_73 : LocalName = LocalName :: pack_static (913u32) ;
+pub const ATOM_LOCALNAME__70_6F_73_74_65_72 : LocalName = LocalName :: pack_static (914u32) ;
+pub const ATOM_LOCALNAME__6D_61_69_6E : LocalName = LocalName :: pack_static (915u32) ;
+pub const ATOM_LOCALNAME__6B_33 : LocalName = LocalName :: pack_static (916u32) ;
+pub const ATOM_LOCALNAME__77_69_64_74_68 : LocalName = LocalName :: pack_static (917u32) ;
+pub const ATOM_LOCALNAME__73_75_6D : LocalName = LocalName :: pack_static (918u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_6B_65_79_73_68_6F_72_74_63_75_74_73 : LocalName = LocalName :: pack_static (919u32) ;
+pub const ATOM_LOCALNAME__61_73_79_6E_63 : LocalName = LocalName :: pack_static (920u32) ;
+pub const ATOM_LOCALNAME__73_74_72_69_6B_65 : LocalName = LocalName :: pack_static (921u32) ;
+pub const ATOM_LOCALNAME__63_69_72_63_6C_65 : LocalName = LocalName :: pack_static (922u32) ;
+pub const ATOM_LOCALNAME__69_6E_66_69_6E_69_74_79 : LocalName = LocalName :: pack_static (923u32) ;
+pub const ATOM_LOCALNAME__6F_6E_63_6F_6E_74_72_6F_6C_73_65_6C_65_63_74 : LocalName = LocalName :: pack_static (924u32) ;
+pub const ATOM_LOCALNAME__74_61_62_6C_65 : LocalName = LocalName :: pack_static (925u32) ;
+pub const ATOM_LOCALNAME__6D_6C_61_62_65_6C_65_64_74_72 : LocalName = LocalName :: pack_static (926u32) ;
+pub const ATOM_LOCALNAME__62_61_73_65_6C_69_6E_65_2D_73_68_69_66_74 : LocalName = LocalName :: pack_static (927u32) ;
+pub const ATOM_LOCALNAME__63_68_61_72_73_65_74 : LocalName = LocalName :: pack_static (928u32) ;
+pub const ATOM_LOCALNAME__73_65_61_6D_6C_65_73_73 : LocalName = LocalName :: pack_static (929u32) ;
+pub const ATOM_LOCALNAME__6D_6D_75_6C_74_69_73_63_72_69_70_74_73 : LocalName = LocalName :: pack_static (930u32) ;
+pub const ATOM_LOCALNAME__6F_70_61_63_69_74_79 : LocalName = LocalName :: pack_static (931u32) ;
+pub const ATOM_LOCALNAME__6D_70_61_74_68 : LocalName = LocalName :: pack_static (932u32) ;
+pub const ATOM_LOCALNAME__6E_6F_74_61_74_69_6F_6E : LocalName = LocalName :: pack_static (933u32) ;
+pub const ATOM_LOCALNAME__6F_6E_65_72_72_6F_72_75_70_64_61_74_65 : LocalName = LocalName :: pack_static (934u32) ;
+pub const ATOM_LOCALNAME__6D_69_6E : LocalName = LocalName :: pack_static (935u32) ;
+pub const ATOM_LOCALNAME__64_65_73_63_65_6E_74 : LocalName = LocalName :: pack_static (936u32) ;
+pub const ATOM_LOCALNAME__64_6F_6D_61_69_6E_6F_66_61_70_70_6C_69_63_61_74_69_6F_6E : LocalName = LocalName :: pack_static (937u32) ;
+pub const ATOM_LOCALNAME__6E_6F_6E_65 : LocalName = LocalName :: pack_static (938u32) ;
+pub const ATOM_LOCALNAME__64_65_74_65_72_6D_69_6E_61_6E_74 : LocalName = LocalName :: pack_static (939u32) ;
+pub const ATOM_LOCALNAME__73_74_72_6F_6B_65_2D_6D_69_74_65_72_6C_69_6D_69_74 : LocalName = LocalName :: pack_static (940u32) ;
+pub const ATOM_LOCALNAME__64_69_72 : LocalName = LocalName :: pack_static (941u32) ;
+pub const ATOM_LOCALNAME__67_72_61_64 : LocalName = LocalName :: pack_static (942u32) ;
+pub const ATOM_LOCALNAME__70_6F_69_6E_74_73_41_74_59 : LocalName = LocalName :: pack_static (943u32) ;
+pub const ATOM_LOCALNAME__76_6B_65_72_6E : LocalName = LocalName :: pack_static (944u32) ;
+pub const ATOM_LOCALNAME__6D_73_75_70 : LocalName = LocalName :: pack_static (945u32) ;
+pub const ATOM_LOCALNAME__61_74_74_72_69_62_75_74_65_74_79_70_65 : LocalName = LocalName :: pack_static (946u32) ;
+pub const ATOM_LOCALNAME__66_65_73_70_6F_74_6C_69_67_68_74 : LocalName = LocalName :: pack_static (947u32) ;
+pub const ATOM_LOCALNAME__64_75_72 : LocalName = LocalName :: pack_static (948u32) ;
+pub const ATOM_LOCALNAME__63_78 : LocalName = LocalName :: pack_static (949u32) ;
+pub const ATOM_LOCALNAME__66_65_63_6F_6D_70_6F_6E_65_6E_74_74_72_61_6E_73_66_65_72 : LocalName = LocalName :: pack_static (950u32) ;
+pub const ATOM_LOCALNAME__6B_65_79_50_6F_69_6E_74_73 : LocalName = LocalName :: pack_static (951u32) ;
+pub const ATOM_LOCALNAME__6D_61_72_6B_65_72_2D_65_6E_64 : LocalName = LocalName :: pack_static (952u32) ;
+pub const ATOM_LOCALNAME__62_67_73_6F_75_6E_64 : LocalName = LocalName :: pack_static (953u32) ;
+pub const ATOM_LOCALNAME__66_6F_6E_74 : LocalName = LocalName :: pack_static (954u32) ;
+pub const ATOM_LOCALNAME__73_63_72_69_70_74_6D_69_6E_73_69_7A_65 : LocalName = LocalName :: pack_static (955u32) ;
+pub const ATOM_LOCALNAME__6B_65_72_6E_65_6C_55_6E_69_74_4C_65_6E_67_74_68 : LocalName = LocalName :: pack_static (956u32) ;
+pub const ATOM_LOCALNAME__66_6F_72_6D : LocalName = LocalName :: pack_static (957u32) ;
+pub const ATOM_LOCALNAME__63_6C_61_73_73_69_64 : LocalName = LocalName :: pack_static (958u32) ;
+pub const ATOM_LOCALNAME__6F_76_65_72_6C_69_6E_65_2D_70_6F_73_69_74_69_6F_6E : LocalName = LocalName :: pack_static (959u32) ;
+pub const ATOM_LOCALNAME__6F_75_74_70_75_74 : LocalName = LocalName :: pack_static (960u32) ;
+pub const ATOM_LOCALNAME__6F_72_69_65_6E_74 : LocalName = LocalName :: pack_static (961u32) ;
+pub const ATOM_LOCALNAME__6C_74 : LocalName = LocalName :: pack_static (962u32) ;
+pub const ATOM_LOCALNAME__6D_6E : LocalName = LocalName :: pack_static (963u32) ;
+pub const ATOM_LOCALNAME__6E_65_73_74 : LocalName = LocalName :: pack_static (964u32) ;
+pub const ATOM_LOCALNAME__73_65_74_64_69_66_66 : LocalName = LocalName :: pack_static (965u32) ;
+pub const ATOM_LOCALNAME__72_75_62_79 : LocalName = LocalName :: pack_static (966u32) ;
+pub const ATOM_LOCALNAME__6D_66_72_61_63 : LocalName = LocalName :: pack_static (967u32) ;
+pub const ATOM_LOCALNAME__6D_61_72_6B_65_72_55_6E_69_74_73 : LocalName = LocalName :: pack_static (968u32) ;
+pub const ATOM_LOCALNAME__77_62_72 : LocalName = LocalName :: pack_static (969u32) ;
+pub const ATOM_LOCALNAME__6D_65_6E_63_6C_6F_73_65 : LocalName = LocalName :: pack_static (970u32) ;
+pub const ATOM_LOCALNAME__74_68_65_61_64 : LocalName = LocalName :: pack_static (971u32) ;
+pub const ATOM_LOCALNAME__62_69_61_73 : LocalName = LocalName :: pack_static (972u32) ;
+pub const ATOM_LOCALNAME__73_74_61_6E_64_62_79 : LocalName = LocalName :: pack_static (973u32) ;
+pub const ATOM_LOCALNAME__61_6E_64 : LocalName = LocalName :: pack_static (974u32) ;
+pub const ATOM_LOCALNAME__7A_6F_6F_6D_61_6E_64_70_61_6E : LocalName = LocalName :: pack_static (975u32) ;
+pub const ATOM_LOCALNAME__61_64_64_69_74_69_76_65 : LocalName = LocalName :: pack_static (976u32) ;
+pub const ATOM_LOCALNAME__61_72_63_63_6F_73 : LocalName = LocalName :: pack_static (977u32) ;
+pub const ATOM_LOCALNAME__6C_69_6D_69_74 : LocalName = LocalName :: pack_static (978u32) ;
+pub const ATOM_LOCALNAME__6F_72_64_65_72 : LocalName = LocalName :: pack_static (979u32) ;
+pub const ATOM_LOCALNAME__66_65_6D_6F_72_70_68_6F_6C_6F_67_79 : LocalName = LocalName :: pack_static (980u32) ;
+pub const ATOM_LOCALNAME__66_65_47_61_75_73_73_69_61_6E_42_6C_75_72 : LocalName = LocalName :: pack_static (981u32) ;
+pub const ATOM_LOCALNAME__6D_6F_76_65_72 : LocalName = LocalName :: pack_static (982u32) ;
+pub const ATOM_LOCALNAME__72_6F_6F_74 : LocalName = LocalName :: pack_static (983u32) ;
+pub const ATOM_LOCALNAME__76_2D_6D_61_74_68_65_6D_61_74_69_63_61_6C : LocalName = LocalName :: pack_static (984u32) ;
+pub const ATOM_LOCALNAME__61_72_63_63_73_63_68 : LocalName = LocalName :: pack_static (985u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_6F_77_6E_73 : LocalName = LocalName :: pack_static (986u32) ;
+pub const ATOM_LOCALNAME__69_6E_32 : LocalName = LocalName :: pack_static (987u32) ;
+pub const ATOM_LOCALNAME__70 : LocalName = LocalName :: pack_static (988u32) ;
+pub const ATOM_LOCALNAME__6F_6E_75_6E_6C_6F_61_64 : LocalName = LocalName :: pack_static (989u32) ;
+pub const ATOM_LOCALNAME__70_6C_61_63_65_68_6F_6C_64_65_72 : LocalName = LocalName :: pack_static (990u32) ;
+pub const ATOM_LOCALNAME__63_73_63 : LocalName = LocalName :: pack_static (991u32) ;
+pub const ATOM_LOCALNAME__74_65_78_74_2D_61_6E_63_68_6F_72 : LocalName = LocalName :: pack_static (992u32) ;
+pub const ATOM_LOCALNAME__64_69_73_70_6C_61_79_73_74_79_6C_65 : LocalName = LocalName :: pack_static (993u32) ;
+pub const ATOM_LOCALNAME__73_70_65_63_75_6C_61_72_45_78_70_6F_6E_65_6E_74 : LocalName = LocalName :: pack_static (994u32) ;
+pub const ATOM_LOCALNAME__6F_62_6A_65_63_74 : LocalName = LocalName :: pack_static (995u32) ;
+pub const ATOM_LOCALNAME__6E_75_6D_6F_63_74_61_76_65_73 : LocalName = LocalName :: pack_static (996u32) ;
+pub const ATOM_LOCALNAME__66_65_66_75_6E_63_67 : LocalName = LocalName :: pack_static (997u32) ;
+pub const ATOM_LOCALNAME__64_61_74_61_66_6F_72_6D_61_74_61_73 : LocalName = LocalName :: pack_static (998u32) ;
+pub const ATOM_LOCALNAME__61_72_63_63_6F_73_68 : LocalName = LocalName :: pack_static (999u32) ;
+pub const ATOM_LOCALNAME__6F_6E_64_61_74_61_73_65_74_63_68_61_6E_67_65_64 : LocalName = LocalName :: pack_static (1000u32) ;
+pub const ATOM_LOCALNAME__70_72_65 : LocalName = LocalName :: pack_static (1001u32) ;
+pub const ATOM_LOCALNAME__65_78_74_65_72_6E_61_6C_52_65_73_6F_75_72_63_65_73_52_65_71_75_69_72_65_64 : LocalName = LocalName :: pack_static (1002u32) ;
+pub const ATOM_LOCALNAME__63_6F_6C_75_6D_6E_6C_69_6E_65_73 : LocalName = LocalName :: pack_static (1003u32) ;
+pub const ATOM_LOCALNAME__6F_6E_68_61_73_68_63_68_61_6E_67_65 : LocalName = LocalName :: pack_static (1004u32) ;
+pub const ATOM_LOCALNAME__62_61_73_65_46_72_65_71_75_65_6E_63_79 : LocalName = LocalName :: pack_static (1005u32) ;
+pub const ATOM_LOCALNAME__73_74_72_65_74_63_68_79 : LocalName = LocalName :: pack_static (1006u32) ;
+pub const ATOM_LOCALNAME__75_73_65_6D_61_70 : LocalName = LocalName :: pack_static (1007u32) ;
+pub const ATOM_LOCALNAME__74_65_6E_64_73_74_6F : LocalName = LocalName :: pack_static (1008u32) ;
+pub const ATOM_LOCALNAME__6E_6F_74_70_72_73_75_62_73_65_74 : LocalName = LocalName :: pack_static (1009u32) ;
+pub const ATOM_LOCALNAME__66_61_6C_73_65 : LocalName = LocalName :: pack_static (1010u32) ;
+pub const ATOM_LOCALNAME__6F_6E_66_6F_63_75_73 : LocalName = LocalName :: pack_static (1011u32) ;
+pub const ATOM_LOCALNAME__6F_6E_64_72_61_67 : LocalName = LocalName :: pack_static (1012u32) ;
+pub const ATOM_LOCALNAME__73_63_61_6C_65 : LocalName = LocalName :: pack_static (1013u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_72_6F_77_63_6F_75_6E_74 : LocalName = LocalName :: pack_static (1014u32) ;
+pub const ATOM_LOCALNAME__61_6C_74_47_6C_79_70_68 : LocalName = LocalName :: pack_static (1015u32) ;
+pub const ATOM_LOCALNAME__6D_61_73_6B_63_6F_6E_74_65_6E_74_75_6E_69_74_73 : LocalName = LocalName :: pack_static (1016u32) ;
+pub const ATOM_LOCALNAME__66_72_61_6D_65 : LocalName = LocalName :: pack_static (1017u32) ;
+pub const ATOM_LOCALNAME__63_6F_6E_74_72_6F_6C_6C_65_72_63_68_61_6E_67_65 : LocalName = LocalName :: pack_static (1018u32) ;
+pub const ATOM_LOCALNAME__66_72_61_6D_65_73_65_74 : LocalName = LocalName :: pack_static (1019u32) ;
+pub const ATOM_LOCALNAME__68_65_61_64_65_72_73 : LocalName = LocalName :: pack_static (1020u32) ;
+pub const ATOM_LOCALNAME__6F_6E_64_72_6F_70 : LocalName = LocalName :: pack_static (1021u32) ;
+pub const ATOM_LOCALNAME__76_6C_69_6E_6B : LocalName = LocalName :: pack_static (1022u32) ;
+pub const ATOM_LOCALNAME__6D_61_72_6B_65_72_57_69_64_74_68 : LocalName = LocalName :: pack_static (1023u32) ;
+pub const ATOM_LOCALNAME__72_65_6D : LocalName = LocalName :: pack_static (1024u32) ;
+pub const ATOM_LOCALNAME__66_65_4D_65_72_67_65 : LocalName = LocalName :: pack_static (1025u32) ;
+pub const ATOM_LOCALNAME__61_6C_6C_6F_77_66_75_6C_6C_73_63_72_65_65_6E : LocalName = LocalName :: pack_static (1026u32) ;
+pub const ATOM_LOCALNAME__74_6F_67_67_6C_65 : LocalName = LocalName :: pack_static (1027u32) ;
+pub const ATOM_LOCALNAME__62_72 : LocalName = LocalName :: pack_static (1028u32) ;
+pub const ATOM_LOCALNAME__74_72_61_63_6B : LocalName = LocalName :: pack_static (1029u32) ;
+pub const ATOM_LOCALNAME__6D_65_74_65_72 : LocalName = LocalName :: pack_static (1030u32) ;
+pub const ATOM_LOCALNAME__78_6D_6C_3A_6C_61_6E_67 : LocalName = LocalName :: pack_static (1031u32) ;
+pub const ATOM_LOCALNAME__71 : LocalName = LocalName :: pack_static (1032u32) ;
+pub const ATOM_LOCALNAME__69_74_65_6D_72_65_66 : LocalName = LocalName :: pack_static (1033u32) ;
+pub const ATOM_LOCALNAME__76_69_64_65_6F : LocalName = LocalName :: pack_static (1034u32) ;
+pub const ATOM_LOCALNAME__76_65_72_79_74_68_69_6E_6D_61_74_68_73_70_61_63_65 : LocalName = LocalName :: pack_static (1035u32) ;
+pub const ATOM_LOCALNAME__6D_65_74_61 : LocalName = LocalName :: pack_static (1036u32) ;
+pub const ATOM_LOCALNAME__70_72_6F_66_69_6C_65 : LocalName = LocalName :: pack_static (1037u32) ;
+pub const ATOM_LOCALNAME__6F_6E_66_6F_72_6D_69_6E_70_75_74 : LocalName = LocalName :: pack_static (1038u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_63_6F_6E_74_72_6F_6C_73 : LocalName = LocalName :: pack_static (1039u32) ;
+pub const ATOM_LOCALNAME__6F_6E_6C_6F_61_64 : LocalName = LocalName :: pack_static (1040u32) ;
+pub const ATOM_LOCALNAME__66_6C_6F_6F_64_2D_6F_70_61_63_69_74_79 : LocalName = LocalName :: pack_static (1041u32) ;
+pub const ATOM_LOCALNAME__73_72_63 : LocalName = LocalName :: pack_static (1042u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_72_6F_6C_65_64_65_73_63_72_69_70_74_69_6F_6E : LocalName = LocalName :: pack_static (1043u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_68_61_73_70_6F_70_75_70 : LocalName = LocalName :: pack_static (1044u32) ;
+pub const ATOM_LOCALNAME__71_75_6F_74_69_65_6E_74 : LocalName = LocalName :: pack_static (1045u32) ;
+pub const ATOM_LOCALNAME__72_65_70_65_61_74_2D_6D_69_6E : LocalName = LocalName :: pack_static (1046u32) ;
+pub const ATOM_LOCALNAME__73_74_72_69_6B_65_74_68_72_6F_75_67_68_2D_74_68_69_63_6B_6E_65_73_73 : LocalName = LocalName :: pack_static (1047u32) ;
+pub const ATOM_LOCALNAME__6E_6F_68_72_65_66 : LocalName = LocalName :: pack_static (1048u32) ;
+pub const ATOM_LOCALNAME__6D_66_65_6E_63_65_64 : LocalName = LocalName :: pack_static (1049u32) ;
+pub const ATOM_LOCALNAME__70_61_74_74_65_72_6E_74_72_61_6E_73_66_6F_72_6D : LocalName = LocalName :: pack_static (1050u32) ;
+pub const ATOM_LOCALNAME__76_65_72_74_2D_6F_72_69_67_69_6E_2D_78 : LocalName = LocalName :: pack_static (1051u32) ;
+pub const ATOM_LOCALNAME__61_6C_74_67_6C_79_70_68_69_74_65_6D : LocalName = LocalName :: pack_static (1052u32) ;
+pub const ATOM_LOCALNAME__74_72_65_66 : LocalName = LocalName :: pack_static (1053u32) ;
+pub const ATOM_LOCALNAME__64_65_74_61_69_6C_73 : LocalName = LocalName :: pack_static (1054u32) ;
+pub const ATOM_LOCALNAME__72_6F_77_73 : LocalName = LocalName :: pack_static (1055u32) ;
+pub const ATOM_LOCALNAME__6F_76_65_72_66_6C_6F_77 : LocalName = LocalName :: pack_static (1056u32) ;
+pub const ATOM_LOCALNAME__68_6F_72_69_7A_2D_6F_72_69_67_69_6E_2D_79 : LocalName = LocalName :: pack_static (1057u32) ;
+pub const ATOM_LOCALNAME__6C_61_6E_67 : LocalName = LocalName :: pack_static (1058u32) ;
+pub const ATOM_LOCALNAME__73_65_63_74_69_6F_6E : LocalName = LocalName :: pack_static (1059u32) ;
+pub const ATOM_LOCALNAME__70_61_74_74_65_72_6E_75_6E_69_74_73 : LocalName = LocalName :: pack_static (1060u32) ;
+pub const ATOM_LOCALNAME__6F_6E_64_72_61_67_65_6E_74_65_72 : LocalName = LocalName :: pack_static (1061u32) ;
+pub const ATOM_LOCALNAME__6D_61_72_67_69_6E_68_65_69_67_68_74 : LocalName = LocalName :: pack_static (1062u32) ;
+pub const ATOM_LOCALNAME__6F_6E_64_72_61_67_6C_65_61_76_65 : LocalName = LocalName :: pack_static (1063u32) ;
+pub const ATOM_LOCALNAME__62_61_63_6B_67_72_6F_75_6E_64 : LocalName = LocalName :: pack_static (1064u32) ;
+pub const ATOM_LOCALNAME__74_65_78_74_2D_72_65_6E_64_65_72_69_6E_67 : LocalName = LocalName :: pack_static (1065u32) ;
+pub const ATOM_LOCALNAME__62_64_69 : LocalName = LocalName :: pack_static (1066u32) ;
+pub const ATOM_LOCALNAME__74_69_6D_65_73 : LocalName = LocalName :: pack_static (1067u32) ;
+pub const ATOM_LOCALNAME__70_72_65_73_65_72_76_65_41_6C_70_68_61 : LocalName = LocalName :: pack_static (1068u32) ;
+pub const ATOM_LOCALNAME__69_74_65_6D_69_64 : LocalName = LocalName :: pack_static (1069u32) ;
+pub const ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_65_6E_74_65_72 : LocalName = LocalName :: pack_static (1070u32) ;
+pub const ATOM_LOCALNAME__68_31 : LocalName = LocalName :: pack_static (1071u32) ;
+pub const ATOM_LOCALNAME__69_6D_61_67_65_2D_72_65_6E_64_65_72_69_6E_67 : LocalName = LocalName :: pack_static (1072u32) ;
+pub const ATOM_LOCALNAME__73_69_6E_68 : LocalName = LocalName :: pack_static (1073u32) ;
+pub const ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_77_68_65_65_6C : LocalName = LocalName :: pack_static (1074u32) ;
+pub const ATOM_LOCALNAME__6F_6E_64_72_61_67_64_72_6F_70 : LocalName = LocalName :: pack_static (1075u32) ;
+pub const ATOM_LOCALNAME__6B_62_64 : LocalName = LocalName :: pack_static (1076u32) ;
+pub const ATOM_LOCALNAME__66_65_46_75_6E_63_41 : LocalName = LocalName :: pack_static (1077u32) ;
+pub const ATOM_LOCALNAME__69 : LocalName = LocalName :: pack_static (1078u32) ;
+pub const ATOM_LOCALNAME__64_72_61_67_67_61_62_6C_65 : LocalName = LocalName :: pack_static (1079u32) ;
+pub const ATOM_LOCALNAME__7A : LocalName = LocalName :: pack_static (1080u32) ;
+pub const ATOM_LOCALNAME__72_6F_77_6C_69_6E_65_73 : LocalName = LocalName :: pack_static (1081u32) ;
+pub const ATOM_LOCALNAME__66_69_6C_6C_2D_6F_70_61_63_69_74_79 : LocalName = LocalName :: pack_static (1082u32) ;
+pub const ATOM_LOCALNAME__72_75_6C_65_73 : LocalName = LocalName :: pack_static (1083u32) ;
+pub const ATOM_LOCALNAME__64_65_66_69_6E_69_74_69_6F_6E_55_52_4C : LocalName = LocalName :: pack_static (1084u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_69_6E_76_61_6C_69_64 : LocalName = LocalName :: pack_static (1085u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_76_61_6C_75_65_6D_61_78 : LocalName = LocalName :: pack_static (1086u32) ;
+pub const ATOM_LOCALNAME__67_6C_79_70_68_2D_6F_72_69_65_6E_74_61_74_69_6F_6E_2D_76_65_72_74_69_63_61_6C : LocalName = LocalName :: pack_static (1087u32) ;
+pub const ATOM_LOCALNAME__6C_61_6D_62_64_61 : LocalName = LocalName :: pack_static (1088u32) ;
+pub const ATOM_LOCALNAME__6F_6E_72_6F_77_73_64_65_6C_65_74_65 : LocalName = LocalName :: pack_static (1089u32) ;
+pub const ATOM_LOCALNAME__66_6F_6E_74_2D_66_61_63_65_2D_73_72_63 : LocalName = LocalName :: pack_static (1090u32) ;
+pub const ATOM_LOCALNAME__66_65_46_75_6E_63_47 : LocalName = LocalName :: pack_static (1091u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_72_65_61_64_6F_6E_6C_79 : LocalName = LocalName :: pack_static (1092u32) ;
+pub const ATOM_LOCALNAME__6F_6E_61_66_74_65_72_75_70_64_61_74_65 : LocalName = LocalName :: pack_static (1093u32) ;
+pub const ATOM_LOCALNAME__69_74_65_6D_70_72_6F_70 : LocalName = LocalName :: pack_static (1094u32) ;
+pub const ATOM_LOCALNAME__61_72_63_63_6F_74_68 : LocalName = LocalName :: pack_static (1095u32) ;
+pub const ATOM_LOCALNAME__6B_65_79_53_70_6C_69_6E_65_73 : LocalName = LocalName :: pack_static (1096u32) ;
+pub const ATOM_LOCALNAME__78_6C_69_6E_6B_3A_61_72_63_72_6F_6C_65 : LocalName = LocalName :: pack_static (1097u32) ;
+pub const ATOM_LOCALNAME__61_72_63_73_65_63 : LocalName = LocalName :: pack_static (1098u32) ;
+pub const ATOM_LOCALNAME__63_6F_64_65 : LocalName = LocalName :: pack_static (1099u32) ;
+pub const ATOM_LOCALNAME__6C_6F_67 : LocalName = LocalName :: pack_static (1100u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_64_65_73_63_72_69_62_65_64_62_79 : LocalName = LocalName :: pack_static (1101u32) ;
+pub const ATOM_LOCALNAME__61_6C_74_47_6C_79_70_68_44_65_66 : LocalName = LocalName :: pack_static (1102u32) ;
+pub const ATOM_LOCALNAME__6C_71_75_6F_74_65 : LocalName = LocalName :: pack_static (1103u32) ;
+pub const ATOM_LOCALNAME__72_61_64_69_75_73 : LocalName = LocalName :: pack_static (1104u32) ;
+pub const ATOM_LOCALNAME__73_63_72_6F_6C_6C_69_6E_67 : LocalName = LocalName :: pack_static (1105u32) ;
+pub const ATOM_LOCALNAME__68_72 : LocalName = LocalName :: pack_static (1106u32) ;
+pub const ATOM_LOCALNAME__66_65_73_70_65_63_75_6C_61_72_6C_69_67_68_74_69_6E_67 : LocalName = LocalName :: pack_static (1107u32) ;
+pub const ATOM_LOCALNAME__6D_75_6C_74_69_63_6F_6C : LocalName = LocalName :: pack_static (1108u32) ;
+pub const ATOM_LOCALNAME__70_61_74_68_6C_65_6E_67_74_68 : LocalName = LocalName :: pack_static (1109u32) ;
+pub const ATOM_LOCALNAME__63_6F_6D_70_6C_65_78_65_73 : LocalName = LocalName :: pack_static (1110u32) ;
+pub const ATOM_LOCALNAME__70_6F_69_6E_74_65_72_2D_65_76_65_6E_74_73 : LocalName = LocalName :: pack_static (1111u32) ;
+pub const ATOM_LOCALNAME__6D_75_6C_74_69_70_6C_65 : LocalName = LocalName :: pack_static (1112u32) ;
+pub const ATOM_LOCALNAME__6D_6F_7A_62_72_6F_77_73_65_72 : LocalName = LocalName :: pack_static (1113u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_70_6C_61_63_65_68_6F_6C_64_65_72 : LocalName = LocalName :: pack_static (1114u32) ;
+pub const ATOM_LOCALNAME__68_65_61_64_65_72 : LocalName = LocalName :: pack_static (1115u32) ;
+pub const ATOM_LOCALNAME__70_61_74_68 : LocalName = LocalName :: pack_static (1116u32) ;
+pub const ATOM_LOCALNAME__66_72_61_6D_65_73_70_61_63_69_6E_67 : LocalName = LocalName :: pack_static (1117u32) ;
+pub const ATOM_LOCALNAME__65_6E_64 : LocalName = LocalName :: pack_static (1118u32) ;
+pub const ATOM_LOCALNAME__61_72_63_63_6F_74 : LocalName = LocalName :: pack_static (1119u32) ;
+pub const ATOM_LOCALNAME__61_72_69_61_2D_74_65_6D_70_6C_61_74_65_69_64 : LocalName = LocalName :: pack_static (1120u32) ;
+pub const ATOM_LOCALNAME__63_65_69_6C_69_6E_67 : LocalName = LocalName :: pack_static (1121u32) ;
+pub const ATOM_LOCALNAME__66_69_6C_74_65_72_55_6E_69_74_73 : LocalName = LocalName :: pack_static (1122u32) ;
+pub const ATOM_LOCALNAME__66_6F_6E_74_2D_73_69_7A_65_2D_61_64_6A_75_73_74 : LocalName = LocalName :: pack_static (1123u32) ;
+pub const ATOM_LOCALNAME__63_6F_6E_74_65_6E_74_73_63_72_69_70_74_74_79_70_65 : LocalName = LocalName :: pack_static (1124u32) ;
+pub const ATOM_LOCALNAME__70_6F_69_6E_74_73_41_74_58 : LocalName = LocalName :: pack_static (1125u32) ;
+pub const ATOM_LOCALNAME__70_72_6F_64_75_63_74 : LocalName = LocalName :: pack_static (1126u32) ;
+pub const ATOM_LOCALNAME__73_72_63_6C_61_6E_67 : LocalName = LocalName :: pack_static (1127u32) ;
+pub const ATOM_LOCALNAME__76_73_70_61_63_65 : LocalName = LocalName :: pack_static (1128u32) ;
+pub const ATOM_LOCALNAME__76_61_6C_69_67_6E : LocalName = LocalName :: pack_static (1129u32) ;
+pub const ATOM_LOCALNAME__62_61_73_65 : LocalName = LocalName :: pack_static (1130u32) ;
+pub const ATOM_LOCALNAME__66_65_42_6C_65_6E_64 : LocalName = LocalName :: pack_static (1131u32) ;
+pub const ATOM_LOCALNAME__64_65_66_61_75_6C_74 : LocalName = LocalName :: pack_static (1132u32) ;
+# [doc = "Takes a local name as a string and returns its key in the string cache."] # [macro_export] macro_rules ! local_name { ("onhelp") => { $ crate :: ATOM_LOCALNAME__6F_6E_68_65_6C_70 } ;
+("button") => { $ crate :: ATOM_LOCALNAME__62_75_74_74_6F_6E } ;
+("notanumber") => { $ crate :: ATOM_LOCALNAME__6E_6F_74_61_6E_75_6D_62_65_72 } ;
+("onlanguagechange") => { $ crate :: ATOM_LOCALNAME__6F_6E_6C_61_6E_67_75_61_67_65_63_68_61_6E_67_65 } ;
+("tfoot") => { $ crate :: ATOM_LOCALNAME__74_66_6F_6F_74 } ;
+("dl") => { $ crate :: ATOM_LOCALNAME__64_6C } ;
+("power") => { $ crate :: ATOM_LOCALNAME__70_6F_77_65_72 } ;
+("factorof") => { $ crate :: ATOM_LOCALNAME__66_61_63_74_6F_72_6F_66 } ;
+("annotation-xml") => { $ crate :: ATOM_LOCALNAME__61_6E_6E_6F_74_61_74_69_6F_6E_2D_78_6D_6C } ;
+("onbeforeeditfocus") => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_65_64_69_74_66_6F_63_75_73 } ;
+("clipPathUnits") => { $ crate :: ATOM_LOCALNAME__63_6C_69_70_50_61_74_68_55_6E_69_74_73 } ;
+("overline-thickness") => { $ crate :: ATOM_LOCALNAME__6F_76_65_72_6C_69_6E_65_2D_74_68_69_63_6B_6E_65_73_73 } ;
+("clippath") => { $ crate :: ATOM_LOCALNAME__63_6C_69_70_70_61_74_68 } ;
+("units-per-em") => { $ crate :: ATOM_LOCALNAME__75_6E_69_74_73_2D_70_65_72_2D_65_6D } ;
+("aside") => { $ crate :: ATOM_LOCALNAME__61_73_69_64_65 } ;
+("minus") => { $ crate :: ATOM_LOCALNAME__6D_69_6E_75_73 } ;
+("thinmathspace") => { $ crate :: ATOM_LOCALNAME__74_68_69_6E_6D_61_74_68_73_70_61_63_65 } ;
+("embed") => { $ crate :: ATOM_LOCALNAME__65_6D_62_65_64 } ;
+("source") => { $ crate :: ATOM_LOCALNAME__73_6F_75_72_63_65 } ;
+("horiz-adv-x") => { $ crate :: ATOM_LOCALNAME__68_6F_72_69_7A_2D_61_64_76_2D_78 } ;
+("script") => { $ crate :: ATOM_LOCALNAME__73_63_72_69_70_74 } ;
+("diffuseConstant") => { $ crate :: ATOM_LOCALNAME__64_69_66_66_75_73_65_43_6F_6E_73_74_61_6E_74 } ;
+("td") => { $ crate :: ATOM_LOCALNAME__74_64 } ;
+("stroke-opacity") => { $ crate :: ATOM_LOCALNAME__73_74_72_6F_6B_65_2D_6F_70_61_63_69_74_79 } ;
+("value") => { $ crate :: ATOM_LOCALNAME__76_61_6C_75_65 } ;
+("nobr") => { $ crate :: ATOM_LOCALNAME__6E_6F_62_72 } ;
+("animate") => { $ crate :: ATOM_LOCALNAME__61_6E_69_6D_61_74_65 } ;
+("onfilterchange") => { $ crate :: ATOM_LOCALNAME__6F_6E_66_69_6C_74_65_72_63_68_61_6E_67_65 } ;
+("stroke") => { $ crate :: ATOM_LOCALNAME__73_74_72_6F_6B_65 } ;
+("actiontype") => { $ crate :: ATOM_LOCALNAME__61_63_74_69_6F_6E_74_79_70_65 } ;
+("string") => { $ crate :: ATOM_LOCALNAME__73_74_72_69_6E_67 } ;
+("xlink:type") => { $ crate :: ATOM_LOCALNAME__78_6C_69_6E_6B_3A_74_79_70_65 } ;
+("maskunits") => { $ crate :: ATOM_LOCALNAME__6D_61_73_6B_75_6E_69_74_73 } ;
+("onbeforeprint") => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_70_72_69_6E_74 } ;
+("xlink:title") => { $ crate :: ATOM_LOCALNAME__78_6C_69_6E_6B_3A_74_69_74_6C_65 } ;
+("gradientTransform") => { $ crate :: ATOM_LOCALNAME__67_72_61_64_69_65_6E_74_54_72_61_6E_73_66_6F_72_6D } ;
+("kernelunitlength") => { $ crate :: ATOM_LOCALNAME__6B_65_72_6E_65_6C_75_6E_69_74_6C_65_6E_67_74_68 } ;
+("symbol") => { $ crate :: ATOM_LOCALNAME__73_79_6D_62_6F_6C } ;
+("direction") => { $ crate :: ATOM_LOCALNAME__64_69_72_65_63_74_69_6F_6E } ;
+("option") => { $ crate :: ATOM_LOCALNAME__6F_70_74_69_6F_6E } ;
+("divisor") => { $ crate :: ATOM_LOCALNAME__64_69_76_69_73_6F_72 } ;
+("a") => { $ crate :: ATOM_LOCALNAME__61 } ;
+("mediummathspace") => { $ crate :: ATOM_LOCALNAME__6D_65_64_69_75_6D_6D_61_74_68_73_70_61_63_65 } ;
+("stop-opacity") => { $ crate :: ATOM_LOCALNAME__73_74_6F_70_2D_6F_70_61_63_69_74_79 } ;
+("mglyph") => { $ crate :: ATOM_LOCALNAME__6D_67_6C_79_70_68 } ;
+("tablevalues") => { $ crate :: ATOM_LOCALNAME__74_61_62_6C_65_76_61_6C_75_65_73 } ;
+("kernelmatrix") => { $ crate :: ATOM_LOCALNAME__6B_65_72_6E_65_6C_6D_61_74_72_69_78 } ;
+("targetY") => { $ crate :: ATOM_LOCALNAME__74_61_72_67_65_74_59 } ;
+("columnwidth") => { $ crate :: ATOM_LOCALNAME__63_6F_6C_75_6D_6E_77_69_64_74_68 } ;
+("crossorigin") => { $ crate :: ATOM_LOCALNAME__63_72_6F_73_73_6F_72_69_67_69_6E } ;
+("th") => { $ crate :: ATOM_LOCALNAME__74_68 } ;
+("onmovestart") => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_76_65_73_74_61_72_74 } ;
+("speed") => { $ crate :: ATOM_LOCALNAME__73_70_65_65_64 } ;
+("onbeforecopy") => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_63_6F_70_79 } ;
+("scriptlevel") => { $ crate :: ATOM_LOCALNAME__73_63_72_69_70_74_6C_65_76_65_6C } ;
+("colspan") => { $ crate :: ATOM_LOCALNAME__63_6F_6C_73_70_61_6E } ;
+("declare") => { $ crate :: ATOM_LOCALNAME__64_65_63_6C_61_72_65 } ;
+("onpaste") => { $ crate :: ATOM_LOCALNAME__6F_6E_70_61_73_74_65 } ;
+("aria-relevant") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_72_65_6C_65_76_61_6E_74 } ;
+("diffuseconstant") => { $ crate :: ATOM_LOCALNAME__64_69_66_66_75_73_65_63_6F_6E_73_74_61_6E_74 } ;
+("aria-sort") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_73_6F_72_74 } ;
+("arg") => { $ crate :: ATOM_LOCALNAME__61_72_67 } ;
+("repeat-max") => { $ crate :: ATOM_LOCALNAME__72_65_70_65_61_74_2D_6D_61_78 } ;
+("marker-start") => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B_65_72_2D_73_74_61_72_74 } ;
+("exp") => { $ crate :: ATOM_LOCALNAME__65_78_70 } ;
+("xml:base") => { $ crate :: ATOM_LOCALNAME__78_6D_6C_3A_62_61_73_65 } ;
+("hidden") => { $ crate :: ATOM_LOCALNAME__68_69_64_64_65_6E } ;
+("definition-src") => { $ crate :: ATOM_LOCALNAME__64_65_66_69_6E_69_74_69_6F_6E_2D_73_72_63 } ;
+("xlink:actuate") => { $ crate :: ATOM_LOCALNAME__78_6C_69_6E_6B_3A_61_63_74_75_61_74_65 } ;
+("momentabout") => { $ crate :: ATOM_LOCALNAME__6D_6F_6D_65_6E_74_61_62_6F_75_74 } ;
+("replace") => { $ crate :: ATOM_LOCALNAME__72_65_70_6C_61_63_65 } ;
+("fontsize") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_73_69_7A_65 } ;
+("mspace") => { $ crate :: ATOM_LOCALNAME__6D_73_70_61_63_65 } ;
+("pointsaty") => { $ crate :: ATOM_LOCALNAME__70_6F_69_6E_74_73_61_74_79 } ;
+("statechange") => { $ crate :: ATOM_LOCALNAME__73_74_61_74_65_63_68_61_6E_67_65 } ;
+("checked") => { $ crate :: ATOM_LOCALNAME__63_68_65_63_6B_65_64 } ;
+("itemscope") => { $ crate :: ATOM_LOCALNAME__69_74_65_6D_73_63_6F_70_65 } ;
+("sub") => { $ crate :: ATOM_LOCALNAME__73_75_62 } ;
+("feDisplacementMap") => { $ crate :: ATOM_LOCALNAME__66_65_44_69_73_70_6C_61_63_65_6D_65_6E_74_4D_61_70 } ;
+("aria-required") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_72_65_71_75_69_72_65_64 } ;
+("limitingconeangle") => { $ crate :: ATOM_LOCALNAME__6C_69_6D_69_74_69_6E_67_63_6F_6E_65_61_6E_67_6C_65 } ;
+("xlink:show") => { $ crate :: ATOM_LOCALNAME__78_6C_69_6E_6B_3A_73_68_6F_77 } ;
+("fetch") => { $ crate :: ATOM_LOCALNAME__66_65_74_63_68 } ;
+("fefunca") => { $ crate :: ATOM_LOCALNAME__66_65_66_75_6E_63_61 } ;
+("mathsize") => { $ crate :: ATOM_LOCALNAME__6D_61_74_68_73_69_7A_65 } ;
+("startOffset") => { $ crate :: ATOM_LOCALNAME__73_74_61_72_74_4F_66_66_73_65_74 } ;
+("aria-valuenow") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_76_61_6C_75_65_6E_6F_77 } ;
+("colgroup") => { $ crate :: ATOM_LOCALNAME__63_6F_6C_67_72_6F_75_70 } ;
+("datetime") => { $ crate :: ATOM_LOCALNAME__64_61_74_65_74_69_6D_65 } ;
+("parse") => { $ crate :: ATOM_LOCALNAME__70_61_72_73_65 } ;
+("text-decoration") => { $ crate :: ATOM_LOCALNAME__74_65_78_74_2D_64_65_63_6F_72_61_74_69_6F_6E } ;
+("patternUnits") => { $ crate :: ATOM_LOCALNAME__70_61_74_74_65_72_6E_55_6E_69_74_73 } ;
+("primes") => { $ crate :: ATOM_LOCALNAME__70_72_69_6D_65_73 } ;
+("alt") => { $ crate :: ATOM_LOCALNAME__61_6C_74 } ;
+("offset") => { $ crate :: ATOM_LOCALNAME__6F_66_66_73_65_74 } ;
+("orientation") => { $ crate :: ATOM_LOCALNAME__6F_72_69_65_6E_74_61_74_69_6F_6E } ;
+("feSpecularLighting") => { $ crate :: ATOM_LOCALNAME__66_65_53_70_65_63_75_6C_61_72_4C_69_67_68_74_69_6E_67 } ;
+("center") => { $ crate :: ATOM_LOCALNAME__63_65_6E_74_65_72 } ;
+("numOctaves") => { $ crate :: ATOM_LOCALNAME__6E_75_6D_4F_63_74_61_76_65_73 } ;
+("keysplines") => { $ crate :: ATOM_LOCALNAME__6B_65_79_73_70_6C_69_6E_65_73 } ;
+("title") => { $ crate :: ATOM_LOCALNAME__74_69_74_6C_65 } ;
+("itemtype") => { $ crate :: ATOM_LOCALNAME__69_74_65_6D_74_79_70_65 } ;
+("markerunits") => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B_65_72_75_6E_69_74_73 } ;
+("outerproduct") => { $ crate :: ATOM_LOCALNAME__6F_75_74_65_72_70_72_6F_64_75_63_74 } ;
+("flood-color") => { $ crate :: ATOM_LOCALNAME__66_6C_6F_6F_64_2D_63_6F_6C_6F_72 } ;
+("accentunder") => { $ crate :: ATOM_LOCALNAME__61_63_63_65_6E_74_75_6E_64_65_72 } ;
+("altglyph") => { $ crate :: ATOM_LOCALNAME__61_6C_74_67_6C_79_70_68 } ;
+("onmouseout") => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_6F_75_74 } ;
+("maction") => { $ crate :: ATOM_LOCALNAME__6D_61_63_74_69_6F_6E } ;
+("aria-colindex") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_63_6F_6C_69_6E_64_65_78 } ;
+("systemlanguage") => { $ crate :: ATOM_LOCALNAME__73_79_73_74_65_6D_6C_61_6E_67_75_61_67_65 } ;
+("word-spacing") => { $ crate :: ATOM_LOCALNAME__77_6F_72_64_2D_73_70_61_63_69_6E_67 } ;
+("radiogroup") => { $ crate :: ATOM_LOCALNAME__72_61_64_69_6F_67_72_6F_75_70 } ;
+("radialgradient") => { $ crate :: ATOM_LOCALNAME__72_61_64_69_61_6C_67_72_61_64_69_65_6E_74 } ;
+("srcset") => { $ crate :: ATOM_LOCALNAME__73_72_63_73_65_74 } ;
+("underline-thickness") => { $ crate :: ATOM_LOCALNAME__75_6E_64_65_72_6C_69_6E_65_2D_74_68_69_63_6B_6E_65_73_73 } ;
+("maxlength") => { $ crate :: ATOM_LOCALNAME__6D_61_78_6C_65_6E_67_74_68 } ;
+("aria-setsize") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_73_65_74_73_69_7A_65 } ;
+("u2") => { $ crate :: ATOM_LOCALNAME__75_32 } ;
+("strikethrough-position") => { $ crate :: ATOM_LOCALNAME__73_74_72_69_6B_65_74_68_72_6F_75_67_68_2D_70_6F_73_69_74_69_6F_6E } ;
+("feSpotLight") => { $ crate :: ATOM_LOCALNAME__66_65_53_70_6F_74_4C_69_67_68_74 } ;
+("matrixrow") => { $ crate :: ATOM_LOCALNAME__6D_61_74_72_69_78_72_6F_77 } ;
+("data") => { $ crate :: ATOM_LOCALNAME__64_61_74_61 } ;
+("fecomposite") => { $ crate :: ATOM_LOCALNAME__66_65_63_6F_6D_70_6F_73_69_74_65 } ;
+("k4") => { $ crate :: ATOM_LOCALNAME__6B_34 } ;
+("listener") => { $ crate :: ATOM_LOCALNAME__6C_69_73_74_65_6E_65_72 } ;
+("unicode-range") => { $ crate :: ATOM_LOCALNAME__75_6E_69_63_6F_64_65_2D_72_61_6E_67_65 } ;
+("aria-atomic") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_61_74_6F_6D_69_63 } ;
+("small") => { $ crate :: ATOM_LOCALNAME__73_6D_61_6C_6C } ;
+("font-face-uri") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_2D_66_61_63_65_2D_75_72_69 } ;
+("enctype") => { $ crate :: ATOM_LOCALNAME__65_6E_63_74_79_70_65 } ;
+("points") => { $ crate :: ATOM_LOCALNAME__70_6F_69_6E_74_73 } ;
+("veryverythinmathspace") => { $ crate :: ATOM_LOCALNAME__76_65_72_79_76_65_72_79_74_68_69_6E_6D_61_74_68_73_70_61_63_65 } ;
+("bevelled") => { $ crate :: ATOM_LOCALNAME__62_65_76_65_6C_6C_65_64 } ;
+("scoped") => { $ crate :: ATOM_LOCALNAME__73_63_6F_70_65_64 } ;
+("onmouseover") => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_6F_76_65_72 } ;
+("feOffset") => { $ crate :: ATOM_LOCALNAME__66_65_4F_66_66_73_65_74 } ;
+("active") => { $ crate :: ATOM_LOCALNAME__61_63_74_69_76_65 } ;
+("amplitude") => { $ crate :: ATOM_LOCALNAME__61_6D_70_6C_69_74_75_64_65 } ;
+("textpath") => { $ crate :: ATOM_LOCALNAME__74_65_78_74_70_61_74_68 } ;
+("u") => { $ crate :: ATOM_LOCALNAME__75 } ;
+("fediffuselighting") => { $ crate :: ATOM_LOCALNAME__66_65_64_69_66_66_75_73_65_6C_69_67_68_74_69_6E_67 } ;
+("marginwidth") => { $ crate :: ATOM_LOCALNAME__6D_61_72_67_69_6E_77_69_64_74_68 } ;
+("panose-1") => { $ crate :: ATOM_LOCALNAME__70_61_6E_6F_73_65_2D_31 } ;
+("variance") => { $ crate :: ATOM_LOCALNAME__76_61_72_69_61_6E_63_65 } ;
+("disabled") => { $ crate :: ATOM_LOCALNAME__64_69_73_61_62_6C_65_64 } ;
+("onstart") => { $ crate :: ATOM_LOCALNAME__6F_6E_73_74_61_72_74 } ;
+("actuate") => { $ crate :: ATOM_LOCALNAME__61_63_74_75_61_74_65 } ;
+("veryverythickmathspace") => { $ crate :: ATOM_LOCALNAME__76_65_72_79_76_65_72_79_74_68_69_63_6B_6D_61_74_68_73_70_61_63_65 } ;
+("font-size") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_2D_73_69_7A_65 } ;
+("gradientUnits") => { $ crate :: ATOM_LOCALNAME__67_72_61_64_69_65_6E_74_55_6E_69_74_73 } ;
+("mask") => { $ crate :: ATOM_LOCALNAME__6D_61_73_6B } ;
+("operator") => { $ crate :: ATOM_LOCALNAME__6F_70_65_72_61_74_6F_72 } ;
+("y1") => { $ crate :: ATOM_LOCALNAME__79_31 } ;
+("arccsc") => { $ crate :: ATOM_LOCALNAME__61_72_63_63_73_63 } ;
+("onkeyup") => { $ crate :: ATOM_LOCALNAME__6F_6E_6B_65_79_75_70 } ;
+("aria-flowto") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_66_6C_6F_77_74_6F } ;
+("unicode-bidi") => { $ crate :: ATOM_LOCALNAME__75_6E_69_63_6F_64_65_2D_62_69_64_69 } ;
+("samp") => { $ crate :: ATOM_LOCALNAME__73_61_6D_70 } ;
+("onscroll") => { $ crate :: ATOM_LOCALNAME__6F_6E_73_63_72_6F_6C_6C } ;
+("font-face-format") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_2D_66_61_63_65_2D_66_6F_72_6D_61_74 } ;
+("int") => { $ crate :: ATOM_LOCALNAME__69_6E_74 } ;
+("missing-glyph") => { $ crate :: ATOM_LOCALNAME__6D_69_73_73_69_6E_67_2D_67_6C_79_70_68 } ;
+("cols") => { $ crate :: ATOM_LOCALNAME__63_6F_6C_73 } ;
+("big") => { $ crate :: ATOM_LOCALNAME__62_69_67 } ;
+("intercept") => { $ crate :: ATOM_LOCALNAME__69_6E_74_65_72_63_65_70_74 } ;
+("svg") => { $ crate :: ATOM_LOCALNAME__73_76_67 } ;
+("scalarproduct") => { $ crate :: ATOM_LOCALNAME__73_63_61_6C_61_72_70_72_6F_64_75_63_74 } ;
+("name") => { $ crate :: ATOM_LOCALNAME__6E_61_6D_65 } ;
+("textlength") => { $ crate :: ATOM_LOCALNAME__74_65_78_74_6C_65_6E_67_74_68 } ;
+("image") => { $ crate :: ATOM_LOCALNAME__69_6D_61_67_65 } ;
+("tt") => { $ crate :: ATOM_LOCALNAME__74_74 } ;
+("arabic-form") => { $ crate :: ATOM_LOCALNAME__61_72_61_62_69_63_2D_66_6F_72_6D } ;
+("aria-braillelabel") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_62_72_61_69_6C_6C_65_6C_61_62_65_6C } ;
+("fedisplacementmap") => { $ crate :: ATOM_LOCALNAME__66_65_64_69_73_70_6C_61_63_65_6D_65_6E_74_6D_61_70 } ;
+("onchange") => { $ crate :: ATOM_LOCALNAME__6F_6E_63_68_61_6E_67_65 } ;
+("aria-orientation") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_6F_72_69_65_6E_74_61_74_69_6F_6E } ;
+("lengthAdjust") => { $ crate :: ATOM_LOCALNAME__6C_65_6E_67_74_68_41_64_6A_75_73_74 } ;
+("aria-selected") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_73_65_6C_65_63_74_65_64 } ;
+("spellcheck") => { $ crate :: ATOM_LOCALNAME__73_70_65_6C_6C_63_68_65_63_6B } ;
+("gradienttransform") => { $ crate :: ATOM_LOCALNAME__67_72_61_64_69_65_6E_74_74_72_61_6E_73_66_6F_72_6D } ;
+("tanh") => { $ crate :: ATOM_LOCALNAME__74_61_6E_68 } ;
+("by") => { $ crate :: ATOM_LOCALNAME__62_79 } ;
+("when") => { $ crate :: ATOM_LOCALNAME__77_68_65_6E } ;
+("lengthadjust") => { $ crate :: ATOM_LOCALNAME__6C_65_6E_67_74_68_61_64_6A_75_73_74 } ;
+("accept") => { $ crate :: ATOM_LOCALNAME__61_63_63_65_70_74 } ;
+("event") => { $ crate :: ATOM_LOCALNAME__65_76_65_6E_74 } ;
+("lspace") => { $ crate :: ATOM_LOCALNAME__6C_73_70_61_63_65 } ;
+("aria-autocomplete") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_61_75_74_6F_63_6F_6D_70_6C_65_74_65 } ;
+("ismap") => { $ crate :: ATOM_LOCALNAME__69_73_6D_61_70 } ;
+("face") => { $ crate :: ATOM_LOCALNAME__66_61_63_65 } ;
+("sizes") => { $ crate :: ATOM_LOCALNAME__73_69_7A_65_73 } ;
+("systemLanguage") => { $ crate :: ATOM_LOCALNAME__73_79_73_74_65_6D_4C_61_6E_67_75_61_67_65 } ;
+("fetile") => { $ crate :: ATOM_LOCALNAME__66_65_74_69_6C_65 } ;
+("moment") => { $ crate :: ATOM_LOCALNAME__6D_6F_6D_65_6E_74 } ;
+("d") => { $ crate :: ATOM_LOCALNAME__64 } ;
+("mprescripts") => { $ crate :: ATOM_LOCALNAME__6D_70_72_65_73_63_72_69_70_74_73 } ;
+("attributename") => { $ crate :: ATOM_LOCALNAME__61_74_74_72_69_62_75_74_65_6E_61_6D_65 } ;
+("ondragend") => { $ crate :: ATOM_LOCALNAME__6F_6E_64_72_61_67_65_6E_64 } ;
+("oncut") => { $ crate :: ATOM_LOCALNAME__6F_6E_63_75_74 } ;
+("polyline") => { $ crate :: ATOM_LOCALNAME__70_6F_6C_79_6C_69_6E_65 } ;
+("groupalign") => { $ crate :: ATOM_LOCALNAME__67_72_6F_75_70_61_6C_69_67_6E } ;
+("ondragover") => { $ crate :: ATOM_LOCALNAME__6F_6E_64_72_61_67_6F_76_65_72 } ;
+("clear") => { $ crate :: ATOM_LOCALNAME__63_6C_65_61_72 } ;
+("defer") => { $ crate :: ATOM_LOCALNAME__64_65_66_65_72 } ;
+("aria-labelledby") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_6C_61_62_65_6C_6C_65_64_62_79 } ;
+("noembed") => { $ crate :: ATOM_LOCALNAME__6E_6F_65_6D_62_65_64 } ;
+("rowspan") => { $ crate :: ATOM_LOCALNAME__72_6F_77_73_70_61_6E } ;
+("diff") => { $ crate :: ATOM_LOCALNAME__64_69_66_66 } ;
+("conjugate") => { $ crate :: ATOM_LOCALNAME__63_6F_6E_6A_75_67_61_74_65 } ;
+("contextmenu") => { $ crate :: ATOM_LOCALNAME__63_6F_6E_74_65_78_74_6D_65_6E_75 } ;
+("xlink") => { $ crate :: ATOM_LOCALNAME__78_6C_69_6E_6B } ;
+("codomain") => { $ crate :: ATOM_LOCALNAME__63_6F_64_6F_6D_61_69_6E } ;
+("kernelMatrix") => { $ crate :: ATOM_LOCALNAME__6B_65_72_6E_65_6C_4D_61_74_72_69_78 } ;
+("aria-valuetext") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_76_61_6C_75_65_74_65_78_74 } ;
+("rx") => { $ crate :: ATOM_LOCALNAME__72_78 } ;
+("feDropShadow") => { $ crate :: ATOM_LOCALNAME__66_65_44_72_6F_70_53_68_61_64_6F_77 } ;
+("gcd") => { $ crate :: ATOM_LOCALNAME__67_63_64 } ;
+("rowalign") => { $ crate :: ATOM_LOCALNAME__72_6F_77_61_6C_69_67_6E } ;
+("mark") => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B } ;
+("seed") => { $ crate :: ATOM_LOCALNAME__73_65_65_64 } ;
+("stitchtiles") => { $ crate :: ATOM_LOCALNAME__73_74_69_74_63_68_74_69_6C_65_73 } ;
+("edgemode") => { $ crate :: ATOM_LOCALNAME__65_64_67_65_6D_6F_64_65 } ;
+("oncontextmenu") => { $ crate :: ATOM_LOCALNAME__6F_6E_63_6F_6E_74_65_78_74_6D_65_6E_75 } ;
+("alttext") => { $ crate :: ATOM_LOCALNAME__61_6C_74_74_65_78_74 } ;
+("preserveAspectRatio") => { $ crate :: ATOM_LOCALNAME__70_72_65_73_65_72_76_65_41_73_70_65_63_74_52_61_74_69_6F } ;
+("fill") => { $ crate :: ATOM_LOCALNAME__66_69_6C_6C } ;
+("closure") => { $ crate :: ATOM_LOCALNAME__63_6C_6F_73_75_72_65 } ;
+("loop") => { $ crate :: ATOM_LOCALNAME__6C_6F_6F_70 } ;
+("pattern") => { $ crate :: ATOM_LOCALNAME__70_61_74_74_65_72_6E } ;
+("line") => { $ crate :: ATOM_LOCALNAME__6C_69_6E_65 } ;
+("aria-description") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_64_65_73_63_72_69_70_74_69_6F_6E } ;
+("s") => { $ crate :: ATOM_LOCALNAME__73 } ;
+("feoffset") => { $ crate :: ATOM_LOCALNAME__66_65_6F_66_66_73_65_74 } ;
+("rect") => { $ crate :: ATOM_LOCALNAME__72_65_63_74 } ;
+("otherwise") => { $ crate :: ATOM_LOCALNAME__6F_74_68_65_72_77_69_73_65 } ;
+("repeat") => { $ crate :: ATOM_LOCALNAME__72_65_70_65_61_74 } ;
+("polygon") => { $ crate :: ATOM_LOCALNAME__70_6F_6C_79_67_6F_6E } ;
+("cos") => { $ crate :: ATOM_LOCALNAME__63_6F_73 } ;
+("plus") => { $ crate :: ATOM_LOCALNAME__70_6C_75_73 } ;
+("mtr") => { $ crate :: ATOM_LOCALNAME__6D_74_72 } ;
+("feColorMatrix") => { $ crate :: ATOM_LOCALNAME__66_65_43_6F_6C_6F_72_4D_61_74_72_69_78 } ;
+("dominant-baseline") => { $ crate :: ATOM_LOCALNAME__64_6F_6D_69_6E_61_6E_74_2D_62_61_73_65_6C_69_6E_65 } ;
+("aria-busy") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_62_75_73_79 } ;
+("nextid") => { $ crate :: ATOM_LOCALNAME__6E_65_78_74_69_64 } ;
+("noshade") => { $ crate :: ATOM_LOCALNAME__6E_6F_73_68_61_64_65 } ;
+("feComponentTransfer") => { $ crate :: ATOM_LOCALNAME__66_65_43_6F_6D_70_6F_6E_65_6E_74_54_72_61_6E_73_66_65_72 } ;
+("patternContentUnits") => { $ crate :: ATOM_LOCALNAME__70_61_74_74_65_72_6E_43_6F_6E_74_65_6E_74_55_6E_69_74_73 } ;
+("fill-rule") => { $ crate :: ATOM_LOCALNAME__66_69_6C_6C_2D_72_75_6C_65 } ;
+("occurrence") => { $ crate :: ATOM_LOCALNAME__6F_63_63_75_72_72_65_6E_63_65 } ;
+("radialGradient") => { $ crate :: ATOM_LOCALNAME__72_61_64_69_61_6C_47_72_61_64_69_65_6E_74 } ;
+("csch") => { $ crate :: ATOM_LOCALNAME__63_73_63_68 } ;
+("mean") => { $ crate :: ATOM_LOCALNAME__6D_65_61_6E } ;
+("mstyle") => { $ crate :: ATOM_LOCALNAME__6D_73_74_79_6C_65 } ;
+("calcmode") => { $ crate :: ATOM_LOCALNAME__63_61_6C_63_6D_6F_64_65 } ;
+("arctan") => { $ crate :: ATOM_LOCALNAME__61_72_63_74_61_6E } ;
+("bvar") => { $ crate :: ATOM_LOCALNAME__62_76_61_72 } ;
+("partialdiff") => { $ crate :: ATOM_LOCALNAME__70_61_72_74_69_61_6C_64_69_66_66 } ;
+("download") => { $ crate :: ATOM_LOCALNAME__64_6F_77_6E_6C_6F_61_64 } ;
+("step") => { $ crate :: ATOM_LOCALNAME__73_74_65_70 } ;
+("ononline") => { $ crate :: ATOM_LOCALNAME__6F_6E_6F_6E_6C_69_6E_65 } ;
+("oncopy") => { $ crate :: ATOM_LOCALNAME__6F_6E_63_6F_70_79 } ;
+("feMergeNode") => { $ crate :: ATOM_LOCALNAME__66_65_4D_65_72_67_65_4E_6F_64_65 } ;
+("longdesc") => { $ crate :: ATOM_LOCALNAME__6C_6F_6E_67_64_65_73_63 } ;
+("feFuncR") => { $ crate :: ATOM_LOCALNAME__66_65_46_75_6E_63_52 } ;
+("neq") => { $ crate :: ATOM_LOCALNAME__6E_65_71 } ;
+("factorial") => { $ crate :: ATOM_LOCALNAME__66_61_63_74_6F_72_69_61_6C } ;
+("area") => { $ crate :: ATOM_LOCALNAME__61_72_65_61 } ;
+("csymbol") => { $ crate :: ATOM_LOCALNAME__63_73_79_6D_62_6F_6C } ;
+("symmetric") => { $ crate :: ATOM_LOCALNAME__73_79_6D_6D_65_74_72_69_63 } ;
+("color-interpolation") => { $ crate :: ATOM_LOCALNAME__63_6F_6C_6F_72_2D_69_6E_74_65_72_70_6F_6C_61_74_69_6F_6E } ;
+("shape") => { $ crate :: ATOM_LOCALNAME__73_68_61_70_65 } ;
+("edge") => { $ crate :: ATOM_LOCALNAME__65_64_67_65 } ;
+("plaintext") => { $ crate :: ATOM_LOCALNAME__70_6C_61_69_6E_74_65_78_74 } ;
+("listing") => { $ crate :: ATOM_LOCALNAME__6C_69_73_74_69_6E_67 } ;
+("altGlyphItem") => { $ crate :: ATOM_LOCALNAME__61_6C_74_47_6C_79_70_68_49_74_65_6D } ;
+("tr") => { $ crate :: ATOM_LOCALNAME__74_72 } ;
+("markerwidth") => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B_65_72_77_69_64_74_68 } ;
+("foreignObject") => { $ crate :: ATOM_LOCALNAME__66_6F_72_65_69_67_6E_4F_62_6A_65_63_74 } ;
+("feconvolvematrix") => { $ crate :: ATOM_LOCALNAME__66_65_63_6F_6E_76_6F_6C_76_65_6D_61_74_72_69_78 } ;
+("http-equiv") => { $ crate :: ATOM_LOCALNAME__68_74_74_70_2D_65_71_75_69_76 } ;
+("span") => { $ crate :: ATOM_LOCALNAME__73_70_61_6E } ;
+("codebase") => { $ crate :: ATOM_LOCALNAME__63_6F_64_65_62_61_73_65 } ;
+("values") => { $ crate :: ATOM_LOCALNAME__76_61_6C_75_65_73 } ;
+("aria-colindextext") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_63_6F_6C_69_6E_64_65_78_74_65_78_74 } ;
+("animateTransform") => { $ crate :: ATOM_LOCALNAME__61_6E_69_6D_61_74_65_54_72_61_6E_73_66_6F_72_6D } ;
+("text") => { $ crate :: ATOM_LOCALNAME__74_65_78_74 } ;
+("stemv") => { $ crate :: ATOM_LOCALNAME__73_74_65_6D_76 } ;
+("prefetch") => { $ crate :: ATOM_LOCALNAME__70_72_65_66_65_74_63_68 } ;
+("vector") => { $ crate :: ATOM_LOCALNAME__76_65_63_74_6F_72 } ;
+("accesskey") => { $ crate :: ATOM_LOCALNAME__61_63_63_65_73_73_6B_65_79 } ;
+("applet") => { $ crate :: ATOM_LOCALNAME__61_70_70_6C_65_74 } ;
+("ident") => { $ crate :: ATOM_LOCALNAME__69_64_65_6E_74 } ;
+("cosh") => { $ crate :: ATOM_LOCALNAME__63_6F_73_68 } ;
+("fedropshadow") => { $ crate :: ATOM_LOCALNAME__66_65_64_72_6F_70_73_68_61_64_6F_77 } ;
+("baseline") => { $ crate :: ATOM_LOCALNAME__62_61_73_65_6C_69_6E_65 } ;
+("separators") => { $ crate :: ATOM_LOCALNAME__73_65_70_61_72_61_74_6F_72_73 } ;
+("cap-height") => { $ crate :: ATOM_LOCALNAME__63_61_70_2D_68_65_69_67_68_74 } ;
+("noresize") => { $ crate :: ATOM_LOCALNAME__6E_6F_72_65_73_69_7A_65 } ;
+("onmouseup") => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_75_70 } ;
+("true") => { $ crate :: ATOM_LOCALNAME__74_72_75_65 } ;
+("fx") => { $ crate :: ATOM_LOCALNAME__66_78 } ;
+("aria-multiselectable") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_6D_75_6C_74_69_73_65_6C_65_63_74_61_62_6C_65 } ;
+("formnovalidate") => { $ crate :: ATOM_LOCALNAME__66_6F_72_6D_6E_6F_76_61_6C_69_64_61_74_65 } ;
+("h3") => { $ crate :: ATOM_LOCALNAME__68_33 } ;
+("repeatcount") => { $ crate :: ATOM_LOCALNAME__72_65_70_65_61_74_63_6F_75_6E_74 } ;
+("nowrap") => { $ crate :: ATOM_LOCALNAME__6E_6F_77_72_61_70 } ;
+("show") => { $ crate :: ATOM_LOCALNAME__73_68_6F_77 } ;
+("coth") => { $ crate :: ATOM_LOCALNAME__63_6F_74_68 } ;
+("datatemplate") => { $ crate :: ATOM_LOCALNAME__64_61_74_61_74_65_6D_70_6C_61_74_65 } ;
+("valuetype") => { $ crate :: ATOM_LOCALNAME__76_61_6C_75_65_74_79_70_65 } ;
+("result") => { $ crate :: ATOM_LOCALNAME__72_65_73_75_6C_74 } ;
+("uplimit") => { $ crate :: ATOM_LOCALNAME__75_70_6C_69_6D_69_74 } ;
+("slot") => { $ crate :: ATOM_LOCALNAME__73_6C_6F_74 } ;
+("solidcolor") => { $ crate :: ATOM_LOCALNAME__73_6F_6C_69_64_63_6F_6C_6F_72 } ;
+("mathbackground") => { $ crate :: ATOM_LOCALNAME__6D_61_74_68_62_61_63_6B_67_72_6F_75_6E_64 } ;
+("color") => { $ crate :: ATOM_LOCALNAME__63_6F_6C_6F_72 } ;
+("rule") => { $ crate :: ATOM_LOCALNAME__72_75_6C_65 } ;
+("xref") => { $ crate :: ATOM_LOCALNAME__78_72_65_66 } ;
+("preserveaspectratio") => { $ crate :: ATOM_LOCALNAME__70_72_65_73_65_72_76_65_61_73_70_65_63_74_72_61_74_69_6F } ;
+("onlosecapture") => { $ crate :: ATOM_LOCALNAME__6F_6E_6C_6F_73_65_63_61_70_74_75_72_65 } ;
+("stddeviation") => { $ crate :: ATOM_LOCALNAME__73_74_64_64_65_76_69_61_74_69_6F_6E } ;
+("glyph") => { $ crate :: ATOM_LOCALNAME__67_6C_79_70_68 } ;
+("dx") => { $ crate :: ATOM_LOCALNAME__64_78 } ;
+("feimage") => { $ crate :: ATOM_LOCALNAME__66_65_69_6D_61_67_65 } ;
+("target") => { $ crate :: ATOM_LOCALNAME__74_61_72_67_65_74 } ;
+("contentScriptType") => { $ crate :: ATOM_LOCALNAME__63_6F_6E_74_65_6E_74_53_63_72_69_70_74_54_79_70_65 } ;
+("feFlood") => { $ crate :: ATOM_LOCALNAME__66_65_46_6C_6F_6F_64 } ;
+("onformchange") => { $ crate :: ATOM_LOCALNAME__6F_6E_66_6F_72_6D_63_68_61_6E_67_65 } ;
+("onmousedown") => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_64_6F_77_6E } ;
+("size") => { $ crate :: ATOM_LOCALNAME__73_69_7A_65 } ;
+("onsubmit") => { $ crate :: ATOM_LOCALNAME__6F_6E_73_75_62_6D_69_74 } ;
+("targetX") => { $ crate :: ATOM_LOCALNAME__74_61_72_67_65_74_58 } ;
+("superscriptshift") => { $ crate :: ATOM_LOCALNAME__73_75_70_65_72_73_63_72_69_70_74_73_68_69_66_74 } ;
+("g1") => { $ crate :: ATOM_LOCALNAME__67_31 } ;
+("clip") => { $ crate :: ATOM_LOCALNAME__63_6C_69_70 } ;
+("surfaceScale") => { $ crate :: ATOM_LOCALNAME__73_75_72_66_61_63_65_53_63_61_6C_65 } ;
+("interval") => { $ crate :: ATOM_LOCALNAME__69_6E_74_65_72_76_61_6C } ;
+("onrepeat") => { $ crate :: ATOM_LOCALNAME__6F_6E_72_65_70_65_61_74 } ;
+("widths") => { $ crate :: ATOM_LOCALNAME__77_69_64_74_68_73 } ;
+("mode") => { $ crate :: ATOM_LOCALNAME__6D_6F_64_65 } ;
+("hanging") => { $ crate :: ATOM_LOCALNAME__68_61_6E_67_69_6E_67 } ;
+("rowspacing") => { $ crate :: ATOM_LOCALNAME__72_6F_77_73_70_61_63_69_6E_67 } ;
+("refy") => { $ crate :: ATOM_LOCALNAME__72_65_66_79 } ;
+("requiredExtensions") => { $ crate :: ATOM_LOCALNAME__72_65_71_75_69_72_65_64_45_78_74_65_6E_73_69_6F_6E_73 } ;
+("columnspacing") => { $ crate :: ATOM_LOCALNAME__63_6F_6C_75_6D_6E_73_70_61_63_69_6E_67 } ;
+("transpose") => { $ crate :: ATOM_LOCALNAME__74_72_61_6E_73_70_6F_73_65 } ;
+("stroke-linecap") => { $ crate :: ATOM_LOCALNAME__73_74_72_6F_6B_65_2D_6C_69_6E_65_63_61_70 } ;
+("switch") => { $ crate :: ATOM_LOCALNAME__73_77_69_74_63_68 } ;
+("imaginary") => { $ crate :: ATOM_LOCALNAME__69_6D_61_67_69_6E_61_72_79 } ;
+("onfocusout") => { $ crate :: ATOM_LOCALNAME__6F_6E_66_6F_63_75_73_6F_75_74 } ;
+("font-family") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_2D_66_61_6D_69_6C_79 } ;
+("maskContentUnits") => { $ crate :: ATOM_LOCALNAME__6D_61_73_6B_43_6F_6E_74_65_6E_74_55_6E_69_74_73 } ;
+("mphantom") => { $ crate :: ATOM_LOCALNAME__6D_70_68_61_6E_74_6F_6D } ;
+("keypoints") => { $ crate :: ATOM_LOCALNAME__6B_65_79_70_6F_69_6E_74_73 } ;
+("onmouseleave") => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_6C_65_61_76_65 } ;
+("style") => { $ crate :: ATOM_LOCALNAME__73_74_79_6C_65 } ;
+("dialog") => { $ crate :: ATOM_LOCALNAME__64_69_61_6C_6F_67 } ;
+("arcsech") => { $ crate :: ATOM_LOCALNAME__61_72_63_73_65_63_68 } ;
+("basefrequency") => { $ crate :: ATOM_LOCALNAME__62_61_73_65_66_72_65_71_75_65_6E_63_79 } ;
+("selector") => { $ crate :: ATOM_LOCALNAME__73_65_6C_65_63_74_6F_72 } ;
+("bbox") => { $ crate :: ATOM_LOCALNAME__62_62_6F_78 } ;
+("onselectstart") => { $ crate :: ATOM_LOCALNAME__6F_6E_73_65_6C_65_63_74_73_74_61_72_74 } ;
+("caption") => { $ crate :: ATOM_LOCALNAME__63_61_70_74_69_6F_6E } ;
+("noscript") => { $ crate :: ATOM_LOCALNAME__6E_6F_73_63_72_69_70_74 } ;
+("xmlns:xlink") => { $ crate :: ATOM_LOCALNAME__78_6D_6C_6E_73_3A_78_6C_69_6E_6B } ;
+("aria-multiline") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_6D_75_6C_74_69_6C_69_6E_65 } ;
+("k2") => { $ crate :: ATOM_LOCALNAME__6B_32 } ;
+("mtd") => { $ crate :: ATOM_LOCALNAME__6D_74_64 } ;
+("sdev") => { $ crate :: ATOM_LOCALNAME__73_64_65_76 } ;
+("attributeType") => { $ crate :: ATOM_LOCALNAME__61_74_74_72_69_62_75_74_65_54_79_70_65 } ;
+("cot") => { $ crate :: ATOM_LOCALNAME__63_6F_74 } ;
+("preservealpha") => { $ crate :: ATOM_LOCALNAME__70_72_65_73_65_72_76_65_61_6C_70_68_61 } ;
+("low") => { $ crate :: ATOM_LOCALNAME__6C_6F_77 } ;
+("fy") => { $ crate :: ATOM_LOCALNAME__66_79 } ;
+("feturbulence") => { $ crate :: ATOM_LOCALNAME__66_65_74_75_72_62_75_6C_65_6E_63_65 } ;
+("noframes") => { $ crate :: ATOM_LOCALNAME__6E_6F_66_72_61_6D_65_73 } ;
+("onpagehide") => { $ crate :: ATOM_LOCALNAME__6F_6E_70_61_67_65_68_69_64_65 } ;
+("rationals") => { $ crate :: ATOM_LOCALNAME__72_61_74_69_6F_6E_61_6C_73 } ;
+("icon") => { $ crate :: ATOM_LOCALNAME__69_63_6F_6E } ;
+("columnspan") => { $ crate :: ATOM_LOCALNAME__63_6F_6C_75_6D_6E_73_70_61_6E } ;
+("selection") => { $ crate :: ATOM_LOCALNAME__73_65_6C_65_63_74_69_6F_6E } ;
+("externalresourcesrequired") => { $ crate :: ATOM_LOCALNAME__65_78_74_65_72_6E_61_6C_72_65_73_6F_75_72_63_65_73_72_65_71_75_69_72_65_64 } ;
+("onbeforecut") => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_63_75_74 } ;
+("origin") => { $ crate :: ATOM_LOCALNAME__6F_72_69_67_69_6E } ;
+("nargs") => { $ crate :: ATOM_LOCALNAME__6E_61_72_67_73 } ;
+("sec") => { $ crate :: ATOM_LOCALNAME__73_65_63 } ;
+("viewBox") => { $ crate :: ATOM_LOCALNAME__76_69_65_77_42_6F_78 } ;
+("minlength") => { $ crate :: ATOM_LOCALNAME__6D_69_6E_6C_65_6E_67_74_68 } ;
+("semantics") => { $ crate :: ATOM_LOCALNAME__73_65_6D_61_6E_74_69_63_73 } ;
+("arcsin") => { $ crate :: ATOM_LOCALNAME__61_72_63_73_69_6E } ;
+("or") => { $ crate :: ATOM_LOCALNAME__6F_72 } ;
+("ci") => { $ crate :: ATOM_LOCALNAME__63_69 } ;
+("metadata") => { $ crate :: ATOM_LOCALNAME__6D_65_74_61_64_61_74_61 } ;
+("sandbox") => { $ crate :: ATOM_LOCALNAME__73_61_6E_64_62_6F_78 } ;
+("start") => { $ crate :: ATOM_LOCALNAME__73_74_61_72_74 } ;
+("xmp") => { $ crate :: ATOM_LOCALNAME__78_6D_70 } ;
+("ondataavailable") => { $ crate :: ATOM_LOCALNAME__6F_6E_64_61_74_61_61_76_61_69_6C_61_62_6C_65 } ;
+("markerheight") => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B_65_72_68_65_69_67_68_74 } ;
+("xml:space") => { $ crate :: ATOM_LOCALNAME__78_6D_6C_3A_73_70_61_63_65 } ;
+("aria-current") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_63_75_72_72_65_6E_74 } ;
+("linethickness") => { $ crate :: ATOM_LOCALNAME__6C_69_6E_65_74_68_69_63_6B_6E_65_73_73 } ;
+("space") => { $ crate :: ATOM_LOCALNAME__73_70_61_63_65 } ;
+("bgcolor") => { $ crate :: ATOM_LOCALNAME__62_67_63_6F_6C_6F_72 } ;
+("linebreak") => { $ crate :: ATOM_LOCALNAME__6C_69_6E_65_62_72_65_61_6B } ;
+("inverse") => { $ crate :: ATOM_LOCALNAME__69_6E_76_65_72_73_65 } ;
+("autoplay") => { $ crate :: ATOM_LOCALNAME__61_75_74_6F_70_6C_61_79 } ;
+("compact") => { $ crate :: ATOM_LOCALNAME__63_6F_6D_70_61_63_74 } ;
+("onmove") => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_76_65 } ;
+("aria-expanded") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_65_78_70_61_6E_64_65_64 } ;
+("logbase") => { $ crate :: ATOM_LOCALNAME__6C_6F_67_62_61_73_65 } ;
+("pathLength") => { $ crate :: ATOM_LOCALNAME__70_61_74_68_4C_65_6E_67_74_68 } ;
+("canvas") => { $ crate :: ATOM_LOCALNAME__63_61_6E_76_61_73 } ;
+("maligngroup") => { $ crate :: ATOM_LOCALNAME__6D_61_6C_69_67_6E_67_72_6F_75_70 } ;
+("font-weight") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_2D_77_65_69_67_68_74 } ;
+("spacer") => { $ crate :: ATOM_LOCALNAME__73_70_61_63_65_72 } ;
+("divide") => { $ crate :: ATOM_LOCALNAME__64_69_76_69_64_65 } ;
+("k1") => { $ crate :: ATOM_LOCALNAME__6B_31 } ;
+("tan") => { $ crate :: ATOM_LOCALNAME__74_61_6E } ;
+("x") => { $ crate :: ATOM_LOCALNAME__78 } ;
+("oninput") => { $ crate :: ATOM_LOCALNAME__6F_6E_69_6E_70_75_74 } ;
+("ln") => { $ crate :: ATOM_LOCALNAME__6C_6E } ;
+("onactivate") => { $ crate :: ATOM_LOCALNAME__6F_6E_61_63_74_69_76_61_74_65 } ;
+("malignmark") => { $ crate :: ATOM_LOCALNAME__6D_61_6C_69_67_6E_6D_61_72_6B } ;
+("b") => { $ crate :: ATOM_LOCALNAME__62 } ;
+("repeat-template") => { $ crate :: ATOM_LOCALNAME__72_65_70_65_61_74_2D_74_65_6D_70_6C_61_74_65 } ;
+("height") => { $ crate :: ATOM_LOCALNAME__68_65_69_67_68_74 } ;
+("aria-datatype") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_64_61_74_61_74_79_70_65 } ;
+("exponent") => { $ crate :: ATOM_LOCALNAME__65_78_70_6F_6E_65_6E_74 } ;
+("stop-color") => { $ crate :: ATOM_LOCALNAME__73_74_6F_70_2D_63_6F_6C_6F_72 } ;
+("filterRes") => { $ crate :: ATOM_LOCALNAME__66_69_6C_74_65_72_52_65_73 } ;
+("targety") => { $ crate :: ATOM_LOCALNAME__74_61_72_67_65_74_79 } ;
+("floor") => { $ crate :: ATOM_LOCALNAME__66_6C_6F_6F_72 } ;
+("aria-brailleroledescription") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_62_72_61_69_6C_6C_65_72_6F_6C_65_64_65_73_63_72_69_70_74_69_6F_6E } ;
+("repeatdur") => { $ crate :: ATOM_LOCALNAME__72_65_70_65_61_74_64_75_72 } ;
+("hgroup") => { $ crate :: ATOM_LOCALNAME__68_67_72_6F_75_70 } ;
+("head") => { $ crate :: ATOM_LOCALNAME__68_65_61_64 } ;
+("alignmentscope") => { $ crate :: ATOM_LOCALNAME__61_6C_69_67_6E_6D_65_6E_74_73_63_6F_70_65 } ;
+("manifest") => { $ crate :: ATOM_LOCALNAME__6D_61_6E_69_66_65_73_74 } ;
+("feDistantLight") => { $ crate :: ATOM_LOCALNAME__66_65_44_69_73_74_61_6E_74_4C_69_67_68_74 } ;
+("ondragstart") => { $ crate :: ATOM_LOCALNAME__6F_6E_64_72_61_67_73_74_61_72_74 } ;
+("approx") => { $ crate :: ATOM_LOCALNAME__61_70_70_72_6F_78 } ;
+("aria-checked") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_63_68_65_63_6B_65_64 } ;
+("defs") => { $ crate :: ATOM_LOCALNAME__64_65_66_73 } ;
+("prompt") => { $ crate :: ATOM_LOCALNAME__70_72_6F_6D_70_74 } ;
+("tabindex") => { $ crate :: ATOM_LOCALNAME__74_61_62_69_6E_64_65_78 } ;
+("feTile") => { $ crate :: ATOM_LOCALNAME__66_65_54_69_6C_65 } ;
+("maskUnits") => { $ crate :: ATOM_LOCALNAME__6D_61_73_6B_55_6E_69_74_73 } ;
+("optgroup") => { $ crate :: ATOM_LOCALNAME__6F_70_74_67_72_6F_75_70 } ;
+("label") => { $ crate :: ATOM_LOCALNAME__6C_61_62_65_6C } ;
+("transform") => { $ crate :: ATOM_LOCALNAME__74_72_61_6E_73_66_6F_72_6D } ;
+("ms") => { $ crate :: ATOM_LOCALNAME__6D_73 } ;
+("contenteditable") => { $ crate :: ATOM_LOCALNAME__63_6F_6E_74_65_6E_74_65_64_69_74_61_62_6C_65 } ;
+("patterncontentunits") => { $ crate :: ATOM_LOCALNAME__70_61_74_74_65_72_6E_63_6F_6E_74_65_6E_74_75_6E_69_74_73 } ;
+("not") => { $ crate :: ATOM_LOCALNAME__6E_6F_74 } ;
+("onbeforeupdate") => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_75_70_64_61_74_65 } ;
+("onpopstate") => { $ crate :: ATOM_LOCALNAME__6F_6E_70_6F_70_73_74_61_74_65 } ;
+("v-alphabetic") => { $ crate :: ATOM_LOCALNAME__76_2D_61_6C_70_68_61_62_65_74_69_63 } ;
+("piece") => { $ crate :: ATOM_LOCALNAME__70_69_65_63_65 } ;
+("keygen") => { $ crate :: ATOM_LOCALNAME__6B_65_79_67_65_6E } ;
+("arctanh") => { $ crate :: ATOM_LOCALNAME__61_72_63_74_61_6E_68 } ;
+("hkern") => { $ crate :: ATOM_LOCALNAME__68_6B_65_72_6E } ;
+("figcaption") => { $ crate :: ATOM_LOCALNAME__66_69_67_63_61_70_74_69_6F_6E } ;
+("prsubset") => { $ crate :: ATOM_LOCALNAME__70_72_73_75_62_73_65_74 } ;
+("hspace") => { $ crate :: ATOM_LOCALNAME__68_73_70_61_63_65 } ;
+("sep") => { $ crate :: ATOM_LOCALNAME__73_65_70 } ;
+("onbounce") => { $ crate :: ATOM_LOCALNAME__6F_6E_62_6F_75_6E_63_65 } ;
+("from") => { $ crate :: ATOM_LOCALNAME__66_72_6F_6D } ;
+("stroke-dashoffset") => { $ crate :: ATOM_LOCALNAME__73_74_72_6F_6B_65_2D_64_61_73_68_6F_66_66_73_65_74 } ;
+("preload") => { $ crate :: ATOM_LOCALNAME__70_72_65_6C_6F_61_64 } ;
+("equalrows") => { $ crate :: ATOM_LOCALNAME__65_71_75_61_6C_72_6F_77_73 } ;
+("stitchTiles") => { $ crate :: ATOM_LOCALNAME__73_74_69_74_63_68_54_69_6C_65_73 } ;
+("gt") => { $ crate :: ATOM_LOCALNAME__67_74 } ;
+("sech") => { $ crate :: ATOM_LOCALNAME__73_65_63_68 } ;
+("em") => { $ crate :: ATOM_LOCALNAME__65_6D } ;
+("contentstyletype") => { $ crate :: ATOM_LOCALNAME__63_6F_6E_74_65_6E_74_73_74_79_6C_65_74_79_70_65 } ;
+("aria-live") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_6C_69_76_65 } ;
+("readonly") => { $ crate :: ATOM_LOCALNAME__72_65_61_64_6F_6E_6C_79 } ;
+("aria-pressed") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_70_72_65_73_73_65_64 } ;
+("to") => { $ crate :: ATOM_LOCALNAME__74_6F } ;
+("fontstyle") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_73_74_79_6C_65 } ;
+("selected") => { $ crate :: ATOM_LOCALNAME__73_65_6C_65_63_74_65_64 } ;
+("scheme") => { $ crate :: ATOM_LOCALNAME__73_63_68_65_6D_65 } ;
+("animateColor") => { $ crate :: ATOM_LOCALNAME__61_6E_69_6D_61_74_65_43_6F_6C_6F_72 } ;
+("azimuth") => { $ crate :: ATOM_LOCALNAME__61_7A_69_6D_75_74_68 } ;
+("requiredfeatures") => { $ crate :: ATOM_LOCALNAME__72_65_71_75_69_72_65_64_66_65_61_74_75_72_65_73 } ;
+("aria-label") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_6C_61_62_65_6C } ;
+("oncellchange") => { $ crate :: ATOM_LOCALNAME__6F_6E_63_65_6C_6C_63_68_61_6E_67_65 } ;
+("rp") => { $ crate :: ATOM_LOCALNAME__72_70 } ;
+("lineargradient") => { $ crate :: ATOM_LOCALNAME__6C_69_6E_65_61_72_67_72_61_64_69_65_6E_74 } ;
+("primitiveunits") => { $ crate :: ATOM_LOCALNAME__70_72_69_6D_69_74_69_76_65_75_6E_69_74_73 } ;
+("mathematical") => { $ crate :: ATOM_LOCALNAME__6D_61_74_68_65_6D_61_74_69_63_61_6C } ;
+("onrowsinserted") => { $ crate :: ATOM_LOCALNAME__6F_6E_72_6F_77_73_69_6E_73_65_72_74_65_64 } ;
+("content") => { $ crate :: ATOM_LOCALNAME__63_6F_6E_74_65_6E_74 } ;
+("datalist") => { $ crate :: ATOM_LOCALNAME__64_61_74_61_6C_69_73_74 } ;
+("altglyphdef") => { $ crate :: ATOM_LOCALNAME__61_6C_74_67_6C_79_70_68_64_65_66 } ;
+("intersect") => { $ crate :: ATOM_LOCALNAME__69_6E_74_65_72_73_65_63_74 } ;
+("stroke-linejoin") => { $ crate :: ATOM_LOCALNAME__73_74_72_6F_6B_65_2D_6C_69_6E_65_6A_6F_69_6E } ;
+("femergenode") => { $ crate :: ATOM_LOCALNAME__66_65_6D_65_72_67_65_6E_6F_64_65 } ;
+("datafld") => { $ crate :: ATOM_LOCALNAME__64_61_74_61_66_6C_64 } ;
+("rev") => { $ crate :: ATOM_LOCALNAME__72_65_76 } ;
+("onkeydown") => { $ crate :: ATOM_LOCALNAME__6F_6E_6B_65_79_64_6F_77_6E } ;
+("template") => { $ crate :: ATOM_LOCALNAME__74_65_6D_70_6C_61_74_65 } ;
+("display") => { $ crate :: ATOM_LOCALNAME__64_69_73_70_6C_61_79 } ;
+("class") => { $ crate :: ATOM_LOCALNAME__63_6C_61_73_73 } ;
+("eulergamma") => { $ crate :: ATOM_LOCALNAME__65_75_6C_65_72_67_61_6D_6D_61 } ;
+("tableValues") => { $ crate :: ATOM_LOCALNAME__74_61_62_6C_65_56_61_6C_75_65_73 } ;
+("aria-disabled") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_64_69_73_61_62_6C_65_64 } ;
+("ideographic") => { $ crate :: ATOM_LOCALNAME__69_64_65_6F_67_72_61_70_68_69_63 } ;
+("fefuncb") => { $ crate :: ATOM_LOCALNAME__66_65_66_75_6E_63_62 } ;
+("scriptsizemultiplier") => { $ crate :: ATOM_LOCALNAME__73_63_72_69_70_74_73_69_7A_65_6D_75_6C_74_69_70_6C_69_65_72 } ;
+("emptyset") => { $ crate :: ATOM_LOCALNAME__65_6D_70_74_79_73_65_74 } ;
+("inputmode") => { $ crate :: ATOM_LOCALNAME__69_6E_70_75_74_6D_6F_64_65 } ;
+("formtarget") => { $ crate :: ATOM_LOCALNAME__66_6F_72_6D_74_61_72_67_65_74 } ;
+("ychannelselector") => { $ crate :: ATOM_LOCALNAME__79_63_68_61_6E_6E_65_6C_73_65_6C_65_63_74_6F_72 } ;
+("feImage") => { $ crate :: ATOM_LOCALNAME__66_65_49_6D_61_67_65 } ;
+("alink") => { $ crate :: ATOM_LOCALNAME__61_6C_69_6E_6B } ;
+("yChannelSelector") => { $ crate :: ATOM_LOCALNAME__79_43_68_61_6E_6E_65_6C_53_65_6C_65_63_74_6F_72 } ;
+("ondeactivate") => { $ crate :: ATOM_LOCALNAME__6F_6E_64_65_61_63_74_69_76_61_74_65 } ;
+("footer") => { $ crate :: ATOM_LOCALNAME__66_6F_6F_74_65_72 } ;
+("aria-hidden") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_68_69_64_64_65_6E } ;
+("zoomAndPan") => { $ crate :: ATOM_LOCALNAME__7A_6F_6F_6D_41_6E_64_50_61_6E } ;
+("repeatCount") => { $ crate :: ATOM_LOCALNAME__72_65_70_65_61_74_43_6F_75_6E_74 } ;
+("irrelevant") => { $ crate :: ATOM_LOCALNAME__69_72_72_65_6C_65_76_61_6E_74 } ;
+("munder") => { $ crate :: ATOM_LOCALNAME__6D_75_6E_64_65_72 } ;
+("border") => { $ crate :: ATOM_LOCALNAME__62_6F_72_64_65_72 } ;
+("local") => { $ crate :: ATOM_LOCALNAME__6C_6F_63_61_6C } ;
+("charoff") => { $ crate :: ATOM_LOCALNAME__63_68_61_72_6F_66_66 } ;
+("aria-grab") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_67_72_61_62 } ;
+("img") => { $ crate :: ATOM_LOCALNAME__69_6D_67 } ;
+("restart") => { $ crate :: ATOM_LOCALNAME__72_65_73_74_61_72_74 } ;
+("x2") => { $ crate :: ATOM_LOCALNAME__78_32 } ;
+("hidefocus") => { $ crate :: ATOM_LOCALNAME__68_69_64_65_66_6F_63_75_73 } ;
+("median") => { $ crate :: ATOM_LOCALNAME__6D_65_64_69_61_6E } ;
+("optimum") => { $ crate :: ATOM_LOCALNAME__6F_70_74_69_6D_75_6D } ;
+("vectorproduct") => { $ crate :: ATOM_LOCALNAME__76_65_63_74_6F_72_70_72_6F_64_75_63_74 } ;
+("frameborder") => { $ crate :: ATOM_LOCALNAME__66_72_61_6D_65_62_6F_72_64_65_72 } ;
+("dfn") => { $ crate :: ATOM_LOCALNAME__64_66_6E } ;
+("formenctype") => { $ crate :: ATOM_LOCALNAME__66_6F_72_6D_65_6E_63_74_79_70_65 } ;
+("mtable") => { $ crate :: ATOM_LOCALNAME__6D_74_61_62_6C_65 } ;
+("refX") => { $ crate :: ATOM_LOCALNAME__72_65_66_58 } ;
+("isindex") => { $ crate :: ATOM_LOCALNAME__69_73_69_6E_64_65_78 } ;
+("onrowexit") => { $ crate :: ATOM_LOCALNAME__6F_6E_72_6F_77_65_78_69_74 } ;
+("glyphref") => { $ crate :: ATOM_LOCALNAME__67_6C_79_70_68_72_65_66 } ;
+("cellspacing") => { $ crate :: ATOM_LOCALNAME__63_65_6C_6C_73_70_61_63_69_6E_67 } ;
+("search") => { $ crate :: ATOM_LOCALNAME__73_65_61_72_63_68 } ;
+("xlink:role") => { $ crate :: ATOM_LOCALNAME__78_6C_69_6E_6B_3A_72_6F_6C_65 } ;
+("depth") => { $ crate :: ATOM_LOCALNAME__64_65_70_74_68 } ;
+("aria-level") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_6C_65_76_65_6C } ;
+("clip-rule") => { $ crate :: ATOM_LOCALNAME__63_6C_69_70_2D_72_75_6C_65 } ;
+("alphabetic") => { $ crate :: ATOM_LOCALNAME__61_6C_70_68_61_62_65_74_69_63 } ;
+("body") => { $ crate :: ATOM_LOCALNAME__62_6F_64_79 } ;
+("font-variant") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_2D_76_61_72_69_61_6E_74 } ;
+("vert-adv-y") => { $ crate :: ATOM_LOCALNAME__76_65_72_74_2D_61_64_76_2D_79 } ;
+("li") => { $ crate :: ATOM_LOCALNAME__6C_69 } ;
+("param") => { $ crate :: ATOM_LOCALNAME__70_61_72_61_6D } ;
+("fence") => { $ crate :: ATOM_LOCALNAME__66_65_6E_63_65 } ;
+("unselectable") => { $ crate :: ATOM_LOCALNAME__75_6E_73_65_6C_65_63_74_61_62_6C_65 } ;
+("onselect") => { $ crate :: ATOM_LOCALNAME__6F_6E_73_65_6C_65_63_74 } ;
+("glyph-name") => { $ crate :: ATOM_LOCALNAME__67_6C_79_70_68_2D_6E_61_6D_65 } ;
+("referrerpolicy") => { $ crate :: ATOM_LOCALNAME__72_65_66_65_72_72_65_72_70_6F_6C_69_63_79 } ;
+("equivalent") => { $ crate :: ATOM_LOCALNAME__65_71_75_69_76_61_6C_65_6E_74 } ;
+("glyph-orientation-horizontal") => { $ crate :: ATOM_LOCALNAME__67_6C_79_70_68_2D_6F_72_69_65_6E_74_61_74_69_6F_6E_2D_68_6F_72_69_7A_6F_6E_74_61_6C } ;
+("card") => { $ crate :: ATOM_LOCALNAME__63_61_72_64 } ;
+("onblur") => { $ crate :: ATOM_LOCALNAME__6F_6E_62_6C_75_72 } ;
+("x1") => { $ crate :: ATOM_LOCALNAME__78_31 } ;
+("mroot") => { $ crate :: ATOM_LOCALNAME__6D_72_6F_6F_74 } ;
+("startoffset") => { $ crate :: ATOM_LOCALNAME__73_74_61_72_74_6F_66_66_73_65_74 } ;
+("underline-position") => { $ crate :: ATOM_LOCALNAME__75_6E_64_65_72_6C_69_6E_65_2D_70_6F_73_69_74_69_6F_6E } ;
+("macros") => { $ crate :: ATOM_LOCALNAME__6D_61_63_72_6F_73 } ;
+("reversed") => { $ crate :: ATOM_LOCALNAME__72_65_76_65_72_73_65_64 } ;
+("ry") => { $ crate :: ATOM_LOCALNAME__72_79 } ;
+("other") => { $ crate :: ATOM_LOCALNAME__6F_74_68_65_72 } ;
+("spacing") => { $ crate :: ATOM_LOCALNAME__73_70_61_63_69_6E_67 } ;
+("mathvariant") => { $ crate :: ATOM_LOCALNAME__6D_61_74_68_76_61_72_69_61_6E_74 } ;
+("onbeforeunload") => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_75_6E_6C_6F_61_64 } ;
+("alignment-baseline") => { $ crate :: ATOM_LOCALNAME__61_6C_69_67_6E_6D_65_6E_74_2D_62_61_73_65_6C_69_6E_65 } ;
+("set") => { $ crate :: ATOM_LOCALNAME__73_65_74 } ;
+("integrity") => { $ crate :: ATOM_LOCALNAME__69_6E_74_65_67_72_69_74_79 } ;
+("acronym") => { $ crate :: ATOM_LOCALNAME__61_63_72_6F_6E_79_6D } ;
+("specularconstant") => { $ crate :: ATOM_LOCALNAME__73_70_65_63_75_6C_61_72_63_6F_6E_73_74_61_6E_74 } ;
+("nav") => { $ crate :: ATOM_LOCALNAME__6E_61_76 } ;
+("nomodule") => { $ crate :: ATOM_LOCALNAME__6E_6F_6D_6F_64_75_6C_65 } ;
+("patternTransform") => { $ crate :: ATOM_LOCALNAME__70_61_74_74_65_72_6E_54_72_61_6E_73_66_6F_72_6D } ;
+("refx") => { $ crate :: ATOM_LOCALNAME__72_65_66_78 } ;
+("thickmathspace") => { $ crate :: ATOM_LOCALNAME__74_68_69_63_6B_6D_61_74_68_73_70_61_63_65 } ;
+("laplacian") => { $ crate :: ATOM_LOCALNAME__6C_61_70_6C_61_63_69_61_6E } ;
+("animatetransform") => { $ crate :: ATOM_LOCALNAME__61_6E_69_6D_61_74_65_74_72_61_6E_73_66_6F_72_6D } ;
+("notin") => { $ crate :: ATOM_LOCALNAME__6E_6F_74_69_6E } ;
+("select") => { $ crate :: ATOM_LOCALNAME__73_65_6C_65_63_74 } ;
+("onclick") => { $ crate :: ATOM_LOCALNAME__6F_6E_63_6C_69_63_6B } ;
+("horiz-origin-x") => { $ crate :: ATOM_LOCALNAME__68_6F_72_69_7A_2D_6F_72_69_67_69_6E_2D_78 } ;
+("link") => { $ crate :: ATOM_LOCALNAME__6C_69_6E_6B } ;
+("reals") => { $ crate :: ATOM_LOCALNAME__72_65_61_6C_73 } ;
+("in") => { $ crate :: ATOM_LOCALNAME__69_6E } ;
+("bdo") => { $ crate :: ATOM_LOCALNAME__62_64_6F } ;
+("onbefordeactivate") => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_64_65_61_63_74_69_76_61_74_65 } ;
+("requiredextensions") => { $ crate :: ATOM_LOCALNAME__72_65_71_75_69_72_65_64_65_78_74_65_6E_73_69_6F_6E_73 } ;
+("ping") => { $ crate :: ATOM_LOCALNAME__70_69_6E_67 } ;
+("u1") => { $ crate :: ATOM_LOCALNAME__75_31 } ;
+("use") => { $ crate :: ATOM_LOCALNAME__75_73_65 } ;
+("for") => { $ crate :: ATOM_LOCALNAME__66_6F_72 } ;
+("format") => { $ crate :: ATOM_LOCALNAME__66_6F_72_6D_61_74 } ;
+("keyTimes") => { $ crate :: ATOM_LOCALNAME__6B_65_79_54_69_6D_65_73 } ;
+("feFuncB") => { $ crate :: ATOM_LOCALNAME__66_65_46_75_6E_63_42 } ;
+("specularConstant") => { $ crate :: ATOM_LOCALNAME__73_70_65_63_75_6C_61_72_43_6F_6E_73_74_61_6E_74 } ;
+("stdDeviation") => { $ crate :: ATOM_LOCALNAME__73_74_64_44_65_76_69_61_74_69_6F_6E } ;
+("rquote") => { $ crate :: ATOM_LOCALNAME__72_71_75_6F_74_65 } ;
+("ul") => { $ crate :: ATOM_LOCALNAME__75_6C } ;
+("animatecolor") => { $ crate :: ATOM_LOCALNAME__61_6E_69_6D_61_74_65_63_6F_6C_6F_72 } ;
+("v-ideographic") => { $ crate :: ATOM_LOCALNAME__76_2D_69_64_65_6F_67_72_61_70_68_69_63 } ;
+("letter-spacing") => { $ crate :: ATOM_LOCALNAME__6C_65_74_74_65_72_2D_73_70_61_63_69_6E_67 } ;
+("fontweight") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_77_65_69_67_68_74 } ;
+("char") => { $ crate :: ATOM_LOCALNAME__63_68_61_72 } ;
+("wrap") => { $ crate :: ATOM_LOCALNAME__77_72_61_70 } ;
+("id") => { $ crate :: ATOM_LOCALNAME__69_64 } ;
+("feMorphology") => { $ crate :: ATOM_LOCALNAME__66_65_4D_6F_72_70_68_6F_6C_6F_67_79 } ;
+("iframe") => { $ crate :: ATOM_LOCALNAME__69_66_72_61_6D_65 } ;
+("degree") => { $ crate :: ATOM_LOCALNAME__64_65_67_72_65_65 } ;
+("h2") => { $ crate :: ATOM_LOCALNAME__68_32 } ;
+("kind") => { $ crate :: ATOM_LOCALNAME__6B_69_6E_64 } ;
+("type") => { $ crate :: ATOM_LOCALNAME__74_79_70_65 } ;
+("k") => { $ crate :: ATOM_LOCALNAME__6B } ;
+("lighting-color") => { $ crate :: ATOM_LOCALNAME__6C_69_67_68_74_69_6E_67_2D_63_6F_6C_6F_72 } ;
+("minsize") => { $ crate :: ATOM_LOCALNAME__6D_69_6E_73_69_7A_65 } ;
+("onfocusin") => { $ crate :: ATOM_LOCALNAME__6F_6E_66_6F_63_75_73_69_6E } ;
+("stemh") => { $ crate :: ATOM_LOCALNAME__73_74_65_6D_68 } ;
+("decoding") => { $ crate :: ATOM_LOCALNAME__64_65_63_6F_64_69_6E_67 } ;
+("curl") => { $ crate :: ATOM_LOCALNAME__63_75_72_6C } ;
+("naturalnumbers") => { $ crate :: ATOM_LOCALNAME__6E_61_74_75_72_61_6C_6E_75_6D_62_65_72_73 } ;
+("targetx") => { $ crate :: ATOM_LOCALNAME__74_61_72_67_65_74_78 } ;
+("contentStyleType") => { $ crate :: ATOM_LOCALNAME__63_6F_6E_74_65_6E_74_53_74_79_6C_65_54_79_70_65 } ;
+("property") => { $ crate :: ATOM_LOCALNAME__70_72_6F_70_65_72_74_79 } ;
+("fetchpriority") => { $ crate :: ATOM_LOCALNAME__66_65_74_63_68_70_72_69_6F_72_69_74_79 } ;
+("aria-dropeffect") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_64_72_6F_70_65_66_66_65_63_74 } ;
+("y") => { $ crate :: ATOM_LOCALNAME__79 } ;
+("align") => { $ crate :: ATOM_LOCALNAME__61_6C_69_67_6E } ;
+("del") => { $ crate :: ATOM_LOCALNAME__64_65_6C } ;
+("rb") => { $ crate :: ATOM_LOCALNAME__72_62 } ;
+("reln") => { $ crate :: ATOM_LOCALNAME__72_65_6C_6E } ;
+("onerror") => { $ crate :: ATOM_LOCALNAME__6F_6E_65_72_72_6F_72 } ;
+("calcMode") => { $ crate :: ATOM_LOCALNAME__63_61_6C_63_4D_6F_64_65 } ;
+("clippathunits") => { $ crate :: ATOM_LOCALNAME__63_6C_69_70_70_61_74_68_75_6E_69_74_73 } ;
+("msqrt") => { $ crate :: ATOM_LOCALNAME__6D_73_71_72_74 } ;
+("baseprofile") => { $ crate :: ATOM_LOCALNAME__62_61_73_65_70_72_6F_66_69_6C_65 } ;
+("begin") => { $ crate :: ATOM_LOCALNAME__62_65_67_69_6E } ;
+("nonce") => { $ crate :: ATOM_LOCALNAME__6E_6F_6E_63_65 } ;
+("repeat-start") => { $ crate :: ATOM_LOCALNAME__72_65_70_65_61_74_2D_73_74_61_72_74 } ;
+("msub") => { $ crate :: ATOM_LOCALNAME__6D_73_75_62 } ;
+("time") => { $ crate :: ATOM_LOCALNAME__74_69_6D_65 } ;
+("xmlns") => { $ crate :: ATOM_LOCALNAME__78_6D_6C_6E_73 } ;
+("kerning") => { $ crate :: ATOM_LOCALNAME__6B_65_72_6E_69_6E_67 } ;
+("viewtarget") => { $ crate :: ATOM_LOCALNAME__76_69_65_77_74_61_72_67_65_74 } ;
+("formaction") => { $ crate :: ATOM_LOCALNAME__66_6F_72_6D_61_63_74_69_6F_6E } ;
+("view") => { $ crate :: ATOM_LOCALNAME__76_69_65_77 } ;
+("index") => { $ crate :: ATOM_LOCALNAME__69_6E_64_65_78 } ;
+("codetype") => { $ crate :: ATOM_LOCALNAME__63_6F_64_65_74_79_70_65 } ;
+("xor") => { $ crate :: ATOM_LOCALNAME__78_6F_72 } ;
+("method") => { $ crate :: ATOM_LOCALNAME__6D_65_74_68_6F_64 } ;
+("legend") => { $ crate :: ATOM_LOCALNAME__6C_65_67_65_6E_64 } ;
+("figure") => { $ crate :: ATOM_LOCALNAME__66_69_67_75_72_65 } ;
+("feblend") => { $ crate :: ATOM_LOCALNAME__66_65_62_6C_65_6E_64 } ;
+("h5") => { $ crate :: ATOM_LOCALNAME__68_35 } ;
+("oninvalid") => { $ crate :: ATOM_LOCALNAME__6F_6E_69_6E_76_61_6C_69_64 } ;
+("lowsrc") => { $ crate :: ATOM_LOCALNAME__6C_6F_77_73_72_63 } ;
+("basefont") => { $ crate :: ATOM_LOCALNAME__62_61_73_65_66_6F_6E_74 } ;
+("msubsup") => { $ crate :: ATOM_LOCALNAME__6D_73_75_62_73_75_70 } ;
+("picture") => { $ crate :: ATOM_LOCALNAME__70_69_63_74_75_72_65 } ;
+("feflood") => { $ crate :: ATOM_LOCALNAME__66_65_66_6C_6F_6F_64 } ;
+("implies") => { $ crate :: ATOM_LOCALNAME__69_6D_70_6C_69_65_73 } ;
+("stroke-width") => { $ crate :: ATOM_LOCALNAME__73_74_72_6F_6B_65_2D_77_69_64_74_68 } ;
+("address") => { $ crate :: ATOM_LOCALNAME__61_64_64_72_65_73_73 } ;
+("font-face") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_2D_66_61_63_65 } ;
+("sin") => { $ crate :: ATOM_LOCALNAME__73_69_6E } ;
+("ascent") => { $ crate :: ATOM_LOCALNAME__61_73_63_65_6E_74 } ;
+("imaginaryi") => { $ crate :: ATOM_LOCALNAME__69_6D_61_67_69_6E_61_72_79_69 } ;
+("pointsatz") => { $ crate :: ATOM_LOCALNAME__70_6F_69_6E_74_73_61_74_7A } ;
+("limitingConeAngle") => { $ crate :: ATOM_LOCALNAME__6C_69_6D_69_74_69_6E_67_43_6F_6E_65_41_6E_67_6C_65 } ;
+("surfacescale") => { $ crate :: ATOM_LOCALNAME__73_75_72_66_61_63_65_73_63_61_6C_65 } ;
+("aria-rowspan") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_72_6F_77_73_70_61_6E } ;
+("domain") => { $ crate :: ATOM_LOCALNAME__64_6F_6D_61_69_6E } ;
+("textarea") => { $ crate :: ATOM_LOCALNAME__74_65_78_74_61_72_65_61 } ;
+("marquee") => { $ crate :: ATOM_LOCALNAME__6D_61_72_71_75_65_65 } ;
+("aria-rowindex") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_72_6F_77_69_6E_64_65_78 } ;
+("cite") => { $ crate :: ATOM_LOCALNAME__63_69_74_65 } ;
+("fepointlight") => { $ crate :: ATOM_LOCALNAME__66_65_70_6F_69_6E_74_6C_69_67_68_74 } ;
+("accumulate") => { $ crate :: ATOM_LOCALNAME__61_63_63_75_6D_75_6C_61_74_65 } ;
+("ondblclick") => { $ crate :: ATOM_LOCALNAME__6F_6E_64_62_6C_63_6C_69_63_6B } ;
+("pi") => { $ crate :: ATOM_LOCALNAME__70_69 } ;
+("requiredFeatures") => { $ crate :: ATOM_LOCALNAME__72_65_71_75_69_72_65_64_46_65_61_74_75_72_65_73 } ;
+("divergence") => { $ crate :: ATOM_LOCALNAME__64_69_76_65_72_67_65_6E_63_65 } ;
+("attributeName") => { $ crate :: ATOM_LOCALNAME__61_74_74_72_69_62_75_74_65_4E_61_6D_65 } ;
+("div") => { $ crate :: ATOM_LOCALNAME__64_69_76 } ;
+("apply") => { $ crate :: ATOM_LOCALNAME__61_70_70_6C_79 } ;
+("columnalign") => { $ crate :: ATOM_LOCALNAME__63_6F_6C_75_6D_6E_61_6C_69_67_6E } ;
+("map") => { $ crate :: ATOM_LOCALNAME__6D_61_70 } ;
+("altimg") => { $ crate :: ATOM_LOCALNAME__61_6C_74_69_6D_67 } ;
+("desc") => { $ crate :: ATOM_LOCALNAME__64_65_73_63 } ;
+("h6") => { $ crate :: ATOM_LOCALNAME__68_36 } ;
+("autocomplete") => { $ crate :: ATOM_LOCALNAME__61_75_74_6F_63_6F_6D_70_6C_65_74_65 } ;
+("g") => { $ crate :: ATOM_LOCALNAME__67 } ;
+("high") => { $ crate :: ATOM_LOCALNAME__68_69_67_68 } ;
+("marker") => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B_65_72 } ;
+("spreadmethod") => { $ crate :: ATOM_LOCALNAME__73_70_72_65_61_64_6D_65_74_68_6F_64 } ;
+("specularexponent") => { $ crate :: ATOM_LOCALNAME__73_70_65_63_75_6C_61_72_65_78_70_6F_6E_65_6E_74 } ;
+("onzoom") => { $ crate :: ATOM_LOCALNAME__6F_6E_7A_6F_6F_6D } ;
+("onstop") => { $ crate :: ATOM_LOCALNAME__6F_6E_73_74_6F_70 } ;
+("onpropertychange") => { $ crate :: ATOM_LOCALNAME__6F_6E_70_72_6F_70_65_72_74_79_63_68_61_6E_67_65 } ;
+("animatemotion") => { $ crate :: ATOM_LOCALNAME__61_6E_69_6D_61_74_65_6D_6F_74_69_6F_6E } ;
+("audio") => { $ crate :: ATOM_LOCALNAME__61_75_64_69_6F } ;
+("col") => { $ crate :: ATOM_LOCALNAME__63_6F_6C } ;
+("onfinish") => { $ crate :: ATOM_LOCALNAME__6F_6E_66_69_6E_69_73_68 } ;
+("ins") => { $ crate :: ATOM_LOCALNAME__69_6E_73 } ;
+("aria-valuemin") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_76_61_6C_75_65_6D_69_6E } ;
+("feDiffuseLighting") => { $ crate :: ATOM_LOCALNAME__66_65_44_69_66_66_75_73_65_4C_69_67_68_74_69_6E_67 } ;
+("writing-mode") => { $ crate :: ATOM_LOCALNAME__77_72_69_74_69_6E_67_2D_6D_6F_64_65 } ;
+("archive") => { $ crate :: ATOM_LOCALNAME__61_72_63_68_69_76_65 } ;
+("dd") => { $ crate :: ATOM_LOCALNAME__64_64 } ;
+("*") => { $ crate :: ATOM_LOCALNAME__2A } ;
+("rel") => { $ crate :: ATOM_LOCALNAME__72_65_6C } ;
+("tspan") => { $ crate :: ATOM_LOCALNAME__74_73_70_61_6E } ;
+("v-hanging") => { $ crate :: ATOM_LOCALNAME__76_2D_68_61_6E_67_69_6E_67 } ;
+("onstorage") => { $ crate :: ATOM_LOCALNAME__6F_6E_73_74_6F_72_61_67_65 } ;
+("h4") => { $ crate :: ATOM_LOCALNAME__68_34 } ;
+("strong") => { $ crate :: ATOM_LOCALNAME__73_74_72_6F_6E_67 } ;
+("r") => { $ crate :: ATOM_LOCALNAME__72 } ;
+("controls") => { $ crate :: ATOM_LOCALNAME__63_6F_6E_74_72_6F_6C_73 } ;
+("rotate") => { $ crate :: ATOM_LOCALNAME__72_6F_74_61_74_65 } ;
+("viewbox") => { $ crate :: ATOM_LOCALNAME__76_69_65_77_62_6F_78 } ;
+("mathcolor") => { $ crate :: ATOM_LOCALNAME__6D_61_74_68_63_6F_6C_6F_72 } ;
+("annotation") => { $ crate :: ATOM_LOCALNAME__61_6E_6E_6F_74_61_74_69_6F_6E } ;
+("glyphRef") => { $ crate :: ATOM_LOCALNAME__67_6C_79_70_68_52_65_66 } ;
+("onbegin") => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_67_69_6E } ;
+("encoding") => { $ crate :: ATOM_LOCALNAME__65_6E_63_6F_64_69_6E_67 } ;
+("accept-charset") => { $ crate :: ATOM_LOCALNAME__61_63_63_65_70_74_2D_63_68_61_72_73_65_74 } ;
+("role") => { $ crate :: ATOM_LOCALNAME__72_6F_6C_65 } ;
+("animateMotion") => { $ crate :: ATOM_LOCALNAME__61_6E_69_6D_61_74_65_4D_6F_74_69_6F_6E } ;
+("discard") => { $ crate :: ATOM_LOCALNAME__64_69_73_63_61_72_64 } ;
+("cn") => { $ crate :: ATOM_LOCALNAME__63_6E } ;
+("blink") => { $ crate :: ATOM_LOCALNAME__62_6C_69_6E_6B } ;
+("exponentiale") => { $ crate :: ATOM_LOCALNAME__65_78_70_6F_6E_65_6E_74_69_61_6C_65 } ;
+("shape-rendering") => { $ crate :: ATOM_LOCALNAME__73_68_61_70_65_2D_72_65_6E_64_65_72_69_6E_67 } ;
+("abbr") => { $ crate :: ATOM_LOCALNAME__61_62_62_72 } ;
+("aria-rowindextext") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_72_6F_77_69_6E_64_65_78_74_65_78_74 } ;
+("aria-secret") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_73_65_63_72_65_74 } ;
+("close") => { $ crate :: ATOM_LOCALNAME__63_6C_6F_73_65 } ;
+("fontfamily") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_66_61_6D_69_6C_79 } ;
+("aria-errormessage") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_65_72_72_6F_72_6D_65_73_73_61_67_65 } ;
+("onabort") => { $ crate :: ATOM_LOCALNAME__6F_6E_61_62_6F_72_74 } ;
+("coords") => { $ crate :: ATOM_LOCALNAME__63_6F_6F_72_64_73 } ;
+("cartesianproduct") => { $ crate :: ATOM_LOCALNAME__63_61_72_74_65_73_69_61_6E_70_72_6F_64_75_63_74 } ;
+("ol") => { $ crate :: ATOM_LOCALNAME__6F_6C } ;
+("subscriptshift") => { $ crate :: ATOM_LOCALNAME__73_75_62_73_63_72_69_70_74_73_68_69_66_74 } ;
+("translate") => { $ crate :: ATOM_LOCALNAME__74_72_61_6E_73_6C_61_74_65 } ;
+("scrolldelay") => { $ crate :: ATOM_LOCALNAME__73_63_72_6F_6C_6C_64_65_6C_61_79 } ;
+("color-rendering") => { $ crate :: ATOM_LOCALNAME__63_6F_6C_6F_72_2D_72_65_6E_64_65_72_69_6E_67 } ;
+("keytimes") => { $ crate :: ATOM_LOCALNAME__6B_65_79_74_69_6D_65_73 } ;
+("handler") => { $ crate :: ATOM_LOCALNAME__68_61_6E_64_6C_65_72 } ;
+("action") => { $ crate :: ATOM_LOCALNAME__61_63_74_69_6F_6E } ;
+("xlink:href") => { $ crate :: ATOM_LOCALNAME__78_6C_69_6E_6B_3A_68_72_65_66 } ;
+("font-face-name") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_2D_66_61_63_65_2D_6E_61_6D_65 } ;
+("aria-modal") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_6D_6F_64_61_6C } ;
+("var") => { $ crate :: ATOM_LOCALNAME__76_61_72 } ;
+("list") => { $ crate :: ATOM_LOCALNAME__6C_69_73_74 } ;
+("enable-background") => { $ crate :: ATOM_LOCALNAME__65_6E_61_62_6C_65_2D_62_61_63_6B_67_72_6F_75_6E_64 } ;
+("real") => { $ crate :: ATOM_LOCALNAME__72_65_61_6C } ;
+("cursor") => { $ crate :: ATOM_LOCALNAME__63_75_72_73_6F_72 } ;
+("required") => { $ crate :: ATOM_LOCALNAME__72_65_71_75_69_72_65_64 } ;
+("font-style") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_2D_73_74_79_6C_65 } ;
+("rt") => { $ crate :: ATOM_LOCALNAME__72_74 } ;
+("menu") => { $ crate :: ATOM_LOCALNAME__6D_65_6E_75 } ;
+("rendering-intent") => { $ crate :: ATOM_LOCALNAME__72_65_6E_64_65_72_69_6E_67_2D_69_6E_74_65_6E_74 } ;
+("maxsize") => { $ crate :: ATOM_LOCALNAME__6D_61_78_73_69_7A_65 } ;
+("textLength") => { $ crate :: ATOM_LOCALNAME__74_65_78_74_4C_65_6E_67_74_68 } ;
+("onrowenter") => { $ crate :: ATOM_LOCALNAME__6F_6E_72_6F_77_65_6E_74_65_72 } ;
+("aria-channel") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_63_68_61_6E_6E_65_6C } ;
+("feConvolveMatrix") => { $ crate :: ATOM_LOCALNAME__66_65_43_6F_6E_76_6F_6C_76_65_4D_61_74_72_69_78 } ;
+("aria-colspan") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_63_6F_6C_73_70_61_6E } ;
+("menuitem") => { $ crate :: ATOM_LOCALNAME__6D_65_6E_75_69_74_65_6D } ;
+("dirname") => { $ crate :: ATOM_LOCALNAME__64_69_72_6E_61_6D_65 } ;
+("fn") => { $ crate :: ATOM_LOCALNAME__66_6E } ;
+("aria-details") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_64_65_74_61_69_6C_73 } ;
+("feTurbulence") => { $ crate :: ATOM_LOCALNAME__66_65_54_75_72_62_75_6C_65_6E_63_65 } ;
+("ondatasetcomplete") => { $ crate :: ATOM_LOCALNAME__6F_6E_64_61_74_61_73_65_74_63_6F_6D_70_6C_65_74_65 } ;
+("xChannelSelector") => { $ crate :: ATOM_LOCALNAME__78_43_68_61_6E_6E_65_6C_53_65_6C_65_63_74_6F_72 } ;
+("clipPath") => { $ crate :: ATOM_LOCALNAME__63_6C_69_70_50_61_74_68 } ;
+("max") => { $ crate :: ATOM_LOCALNAME__6D_61_78 } ;
+("onafterprint") => { $ crate :: ATOM_LOCALNAME__6F_6E_61_66_74_65_72_70_72_69_6E_74 } ;
+("lcm") => { $ crate :: ATOM_LOCALNAME__6C_63_6D } ;
+("merror") => { $ crate :: ATOM_LOCALNAME__6D_65_72_72_6F_72 } ;
+("fefuncr") => { $ crate :: ATOM_LOCALNAME__66_65_66_75_6E_63_72 } ;
+("gradientunits") => { $ crate :: ATOM_LOCALNAME__67_72_61_64_69_65_6E_74_75_6E_69_74_73 } ;
+("font-stretch") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_2D_73_74_72_65_74_63_68 } ;
+("largeop") => { $ crate :: ATOM_LOCALNAME__6C_61_72_67_65_6F_70 } ;
+("srcdoc") => { $ crate :: ATOM_LOCALNAME__73_72_63_64_6F_63 } ;
+("separator") => { $ crate :: ATOM_LOCALNAME__73_65_70_61_72_61_74_6F_72 } ;
+("spreadMethod") => { $ crate :: ATOM_LOCALNAME__73_70_72_65_61_64_4D_65_74_68_6F_64 } ;
+("viewTarget") => { $ crate :: ATOM_LOCALNAME__76_69_65_77_54_61_72_67_65_74 } ;
+("accent") => { $ crate :: ATOM_LOCALNAME__61_63_63_65_6E_74 } ;
+("open") => { $ crate :: ATOM_LOCALNAME__6F_70_65_6E } ;
+("feComposite") => { $ crate :: ATOM_LOCALNAME__66_65_43_6F_6D_70_6F_73_69_74_65 } ;
+("scope") => { $ crate :: ATOM_LOCALNAME__73_63_6F_70_65 } ;
+("elevation") => { $ crate :: ATOM_LOCALNAME__65_6C_65_76_61_74_69_6F_6E } ;
+("mi") => { $ crate :: ATOM_LOCALNAME__6D_69 } ;
+("exists") => { $ crate :: ATOM_LOCALNAME__65_78_69_73_74_73 } ;
+("aria-activedescendant") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_61_63_74_69_76_65_64_65_73_63_65_6E_64_61_6E_74 } ;
+("fieldset") => { $ crate :: ATOM_LOCALNAME__66_69_65_6C_64_73_65_74 } ;
+("fedistantlight") => { $ crate :: ATOM_LOCALNAME__66_65_64_69_73_74_61_6E_74_6C_69_67_68_74 } ;
+("color-profile") => { $ crate :: ATOM_LOCALNAME__63_6F_6C_6F_72_2D_70_72_6F_66_69_6C_65 } ;
+("geq") => { $ crate :: ATOM_LOCALNAME__67_65_71 } ;
+("onmessage") => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_65_73_73_61_67_65 } ;
+("onpageshow") => { $ crate :: ATOM_LOCALNAME__6F_6E_70_61_67_65_73_68_6F_77 } ;
+("") => { $ crate :: ATOM_LOCALNAME_ } ;
+("lowlimit") => { $ crate :: ATOM_LOCALNAME__6C_6F_77_6C_69_6D_69_74 } ;
+("mtext") => { $ crate :: ATOM_LOCALNAME__6D_74_65_78_74 } ;
+("cy") => { $ crate :: ATOM_LOCALNAME__63_79 } ;
+("specification") => { $ crate :: ATOM_LOCALNAME__73_70_65_63_69_66_69_63_61_74_69_6F_6E } ;
+("visibility") => { $ crate :: ATOM_LOCALNAME__76_69_73_69_62_69_6C_69_74_79 } ;
+("condition") => { $ crate :: ATOM_LOCALNAME__63_6F_6E_64_69_74_69_6F_6E } ;
+("integers") => { $ crate :: ATOM_LOCALNAME__69_6E_74_65_67_65_72_73 } ;
+("stroke-dasharray") => { $ crate :: ATOM_LOCALNAME__73_74_72_6F_6B_65_2D_64_61_73_68_61_72_72_61_79 } ;
+("y2") => { $ crate :: ATOM_LOCALNAME__79_32 } ;
+("subset") => { $ crate :: ATOM_LOCALNAME__73_75_62_73_65_74 } ;
+("loading") => { $ crate :: ATOM_LOCALNAME__6C_6F_61_64_69_6E_67 } ;
+("g2") => { $ crate :: ATOM_LOCALNAME__67_32 } ;
+("rtc") => { $ crate :: ATOM_LOCALNAME__72_74_63 } ;
+("mrow") => { $ crate :: ATOM_LOCALNAME__6D_72_6F_77 } ;
+("novalidate") => { $ crate :: ATOM_LOCALNAME__6E_6F_76_61_6C_69_64_61_74_65 } ;
+("clip-path") => { $ crate :: ATOM_LOCALNAME__63_6C_69_70_2D_70_61_74_68 } ;
+("muted") => { $ crate :: ATOM_LOCALNAME__6D_75_74_65_64 } ;
+("ellipse") => { $ crate :: ATOM_LOCALNAME__65_6C_6C_69_70_73_65 } ;
+("autosubmit") => { $ crate :: ATOM_LOCALNAME__61_75_74_6F_73_75_62_6D_69_74 } ;
+("aria-posinset") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_70_6F_73_69_6E_73_65_74 } ;
+("formmethod") => { $ crate :: ATOM_LOCALNAME__66_6F_72_6D_6D_65_74_68_6F_64 } ;
+("summary") => { $ crate :: ATOM_LOCALNAME__73_75_6D_6D_61_72_79 } ;
+("union") => { $ crate :: ATOM_LOCALNAME__75_6E_69_6F_6E } ;
+("abs") => { $ crate :: ATOM_LOCALNAME__61_62_73 } ;
+("fePointLight") => { $ crate :: ATOM_LOCALNAME__66_65_50_6F_69_6E_74_4C_69_67_68_74 } ;
+("onresize") => { $ crate :: ATOM_LOCALNAME__6F_6E_72_65_73_69_7A_65 } ;
+("accent-height") => { $ crate :: ATOM_LOCALNAME__61_63_63_65_6E_74_2D_68_65_69_67_68_74 } ;
+("input") => { $ crate :: ATOM_LOCALNAME__69_6E_70_75_74 } ;
+("onbeforepaste") => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_70_61_73_74_65 } ;
+("markerHeight") => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B_65_72_48_65_69_67_68_74 } ;
+("onreadystatechange") => { $ crate :: ATOM_LOCALNAME__6F_6E_72_65_61_64_79_73_74_61_74_65_63_68_61_6E_67_65 } ;
+("pointsAtZ") => { $ crate :: ATOM_LOCALNAME__70_6F_69_6E_74_73_41_74_5A } ;
+("fecolormatrix") => { $ crate :: ATOM_LOCALNAME__66_65_63_6F_6C_6F_72_6D_61_74_72_69_78 } ;
+("animation") => { $ crate :: ATOM_LOCALNAME__61_6E_69_6D_61_74_69_6F_6E } ;
+("progress") => { $ crate :: ATOM_LOCALNAME__70_72_6F_67_72_65_73_73 } ;
+("rspace") => { $ crate :: ATOM_LOCALNAME__72_73_70_61_63_65 } ;
+("onmoveend") => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_76_65_65_6E_64 } ;
+("cellpadding") => { $ crate :: ATOM_LOCALNAME__63_65_6C_6C_70_61_64_64_69_6E_67 } ;
+("femerge") => { $ crate :: ATOM_LOCALNAME__66_65_6D_65_72_67_65 } ;
+("eq") => { $ crate :: ATOM_LOCALNAME__65_71 } ;
+("color-interpolation-filters") => { $ crate :: ATOM_LOCALNAME__63_6F_6C_6F_72_2D_69_6E_74_65_72_70_6F_6C_61_74_69_6F_6E_2D_66_69_6C_74_65_72_73 } ;
+("movablelimits") => { $ crate :: ATOM_LOCALNAME__6D_6F_76_61_62_6C_65_6C_69_6D_69_74_73 } ;
+("filterunits") => { $ crate :: ATOM_LOCALNAME__66_69_6C_74_65_72_75_6E_69_74_73 } ;
+("unicode") => { $ crate :: ATOM_LOCALNAME__75_6E_69_63_6F_64_65 } ;
+("x-height") => { $ crate :: ATOM_LOCALNAME__78_2D_68_65_69_67_68_74 } ;
+("onreset") => { $ crate :: ATOM_LOCALNAME__6F_6E_72_65_73_65_74 } ;
+("onkeypress") => { $ crate :: ATOM_LOCALNAME__6F_6E_6B_65_79_70_72_65_73_73 } ;
+("notsubset") => { $ crate :: ATOM_LOCALNAME__6E_6F_74_73_75_62_73_65_74 } ;
+("blockquote") => { $ crate :: ATOM_LOCALNAME__62_6C_6F_63_6B_71_75_6F_74_65 } ;
+("munderover") => { $ crate :: ATOM_LOCALNAME__6D_75_6E_64_65_72_6F_76_65_72 } ;
+("vert-origin-y") => { $ crate :: ATOM_LOCALNAME__76_65_72_74_2D_6F_72_69_67_69_6E_2D_79 } ;
+("xchannelselector") => { $ crate :: ATOM_LOCALNAME__78_63_68_61_6E_6E_65_6C_73_65_6C_65_63_74_6F_72 } ;
+("aria-colcount") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_63_6F_6C_63_6F_75_6E_74 } ;
+("math") => { $ crate :: ATOM_LOCALNAME__6D_61_74_68 } ;
+("html") => { $ crate :: ATOM_LOCALNAME__68_74_6D_6C } ;
+("verythickmathspace") => { $ crate :: ATOM_LOCALNAME__76_65_72_79_74_68_69_63_6B_6D_61_74_68_73_70_61_63_65 } ;
+("foreignobject") => { $ crate :: ATOM_LOCALNAME__66_6F_72_65_69_67_6E_6F_62_6A_65_63_74 } ;
+("edgeMode") => { $ crate :: ATOM_LOCALNAME__65_64_67_65_4D_6F_64_65 } ;
+("onbeforeactivate") => { $ crate :: ATOM_LOCALNAME__6F_6E_62_65_66_6F_72_65_61_63_74_69_76_61_74_65 } ;
+("fegaussianblur") => { $ crate :: ATOM_LOCALNAME__66_65_67_61_75_73_73_69_61_6E_62_6C_75_72 } ;
+("href") => { $ crate :: ATOM_LOCALNAME__68_72_65_66 } ;
+("mo") => { $ crate :: ATOM_LOCALNAME__6D_6F } ;
+("arcsinh") => { $ crate :: ATOM_LOCALNAME__61_72_63_73_69_6E_68 } ;
+("version") => { $ crate :: ATOM_LOCALNAME__76_65_72_73_69_6F_6E } ;
+("onmousemove") => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_6D_6F_76_65 } ;
+("linearGradient") => { $ crate :: ATOM_LOCALNAME__6C_69_6E_65_61_72_47_72_61_64_69_65_6E_74 } ;
+("filterres") => { $ crate :: ATOM_LOCALNAME__66_69_6C_74_65_72_72_65_73 } ;
+("stop") => { $ crate :: ATOM_LOCALNAME__73_74_6F_70 } ;
+("compose") => { $ crate :: ATOM_LOCALNAME__63_6F_6D_70_6F_73_65 } ;
+("autofocus") => { $ crate :: ATOM_LOCALNAME__61_75_74_6F_66_6F_63_75_73 } ;
+("textPath") => { $ crate :: ATOM_LOCALNAME__74_65_78_74_50_61_74_68 } ;
+("arcrole") => { $ crate :: ATOM_LOCALNAME__61_72_63_72_6F_6C_65 } ;
+("article") => { $ crate :: ATOM_LOCALNAME__61_72_74_69_63_6C_65 } ;
+("dy") => { $ crate :: ATOM_LOCALNAME__64_79 } ;
+("filter") => { $ crate :: ATOM_LOCALNAME__66_69_6C_74_65_72 } ;
+("tbody") => { $ crate :: ATOM_LOCALNAME__74_62_6F_64_79 } ;
+("definitionurl") => { $ crate :: ATOM_LOCALNAME__64_65_66_69_6E_69_74_69_6F_6E_75_72_6C } ;
+("baseProfile") => { $ crate :: ATOM_LOCALNAME__62_61_73_65_50_72_6F_66_69_6C_65 } ;
+("line-height") => { $ crate :: ATOM_LOCALNAME__6C_69_6E_65_2D_68_65_69_67_68_74 } ;
+("pointsatx") => { $ crate :: ATOM_LOCALNAME__70_6F_69_6E_74_73_61_74_78 } ;
+("primitiveUnits") => { $ crate :: ATOM_LOCALNAME__70_72_69_6D_69_74_69_76_65_55_6E_69_74_73 } ;
+("sup") => { $ crate :: ATOM_LOCALNAME__73_75_70 } ;
+("marker-mid") => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B_65_72_2D_6D_69_64 } ;
+("media") => { $ crate :: ATOM_LOCALNAME__6D_65_64_69_61 } ;
+("piecewise") => { $ crate :: ATOM_LOCALNAME__70_69_65_63_65_77_69_73_65 } ;
+("refY") => { $ crate :: ATOM_LOCALNAME__72_65_66_59 } ;
+("dt") => { $ crate :: ATOM_LOCALNAME__64_74 } ;
+("hreflang") => { $ crate :: ATOM_LOCALNAME__68_72_65_66_6C_61_6E_67 } ;
+("onoffline") => { $ crate :: ATOM_LOCALNAME__6F_6E_6F_66_66_6C_69_6E_65 } ;
+("axis") => { $ crate :: ATOM_LOCALNAME__61_78_69_73 } ;
+("forall") => { $ crate :: ATOM_LOCALNAME__66_6F_72_61_6C_6C } ;
+("matrix") => { $ crate :: ATOM_LOCALNAME__6D_61_74_72_69_78 } ;
+("repeatDur") => { $ crate :: ATOM_LOCALNAME__72_65_70_65_61_74_44_75_72 } ;
+("onend") => { $ crate :: ATOM_LOCALNAME__6F_6E_65_6E_64 } ;
+("tbreak") => { $ crate :: ATOM_LOCALNAME__74_62_72_65_61_6B } ;
+("mpadded") => { $ crate :: ATOM_LOCALNAME__6D_70_61_64_64_65_64 } ;
+("datasrc") => { $ crate :: ATOM_LOCALNAME__64_61_74_61_73_72_63 } ;
+("slope") => { $ crate :: ATOM_LOCALNAME__73_6C_6F_70_65 } ;
+("leq") => { $ crate :: ATOM_LOCALNAME__6C_65_71 } ;
+("language") => { $ crate :: ATOM_LOCALNAME__6C_61_6E_67_75_61_67_65 } ;
+("equalcolumns") => { $ crate :: ATOM_LOCALNAME__65_71_75_61_6C_63_6F_6C_75_6D_6E_73 } ;
+("poster") => { $ crate :: ATOM_LOCALNAME__70_6F_73_74_65_72 } ;
+("main") => { $ crate :: ATOM_LOCALNAME__6D_61_69_6E } ;
+("k3") => { $ crate :: ATOM_LOCALNAME__6B_33 } ;
+("width") => { $ crate :: ATOM_LOCALNAME__77_69_64_74_68 } ;
+("sum") => { $ crate :: ATOM_LOCALNAME__73_75_6D } ;
+("aria-keyshortcuts") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_6B_65_79_73_68_6F_72_74_63_75_74_73 } ;
+("async") => { $ crate :: ATOM_LOCALNAME__61_73_79_6E_63 } ;
+("strike") => { $ crate :: ATOM_LOCALNAME__73_74_72_69_6B_65 } ;
+("circle") => { $ crate :: ATOM_LOCALNAME__63_69_72_63_6C_65 } ;
+("infinity") => { $ crate :: ATOM_LOCALNAME__69_6E_66_69_6E_69_74_79 } ;
+("oncontrolselect") => { $ crate :: ATOM_LOCALNAME__6F_6E_63_6F_6E_74_72_6F_6C_73_65_6C_65_63_74 } ;
+("table") => { $ crate :: ATOM_LOCALNAME__74_61_62_6C_65 } ;
+("mlabeledtr") => { $ crate :: ATOM_LOCALNAME__6D_6C_61_62_65_6C_65_64_74_72 } ;
+("baseline-shift") => { $ crate :: ATOM_LOCALNAME__62_61_73_65_6C_69_6E_65_2D_73_68_69_66_74 } ;
+("charset") => { $ crate :: ATOM_LOCALNAME__63_68_61_72_73_65_74 } ;
+("seamless") => { $ crate :: ATOM_LOCALNAME__73_65_61_6D_6C_65_73_73 } ;
+("mmultiscripts") => { $ crate :: ATOM_LOCALNAME__6D_6D_75_6C_74_69_73_63_72_69_70_74_73 } ;
+("opacity") => { $ crate :: ATOM_LOCALNAME__6F_70_61_63_69_74_79 } ;
+("mpath") => { $ crate :: ATOM_LOCALNAME__6D_70_61_74_68 } ;
+("notation") => { $ crate :: ATOM_LOCALNAME__6E_6F_74_61_74_69_6F_6E } ;
+("onerrorupdate") => { $ crate :: ATOM_LOCALNAME__6F_6E_65_72_72_6F_72_75_70_64_61_74_65 } ;
+("min") => { $ crate :: ATOM_LOCALNAME__6D_69_6E } ;
+("descent") => { $ crate :: ATOM_LOCALNAME__64_65_73_63_65_6E_74 } ;
+("domainofapplication") => { $ crate :: ATOM_LOCALNAME__64_6F_6D_61_69_6E_6F_66_61_70_70_6C_69_63_61_74_69_6F_6E } ;
+("none") => { $ crate :: ATOM_LOCALNAME__6E_6F_6E_65 } ;
+("determinant") => { $ crate :: ATOM_LOCALNAME__64_65_74_65_72_6D_69_6E_61_6E_74 } ;
+("stroke-miterlimit") => { $ crate :: ATOM_LOCALNAME__73_74_72_6F_6B_65_2D_6D_69_74_65_72_6C_69_6D_69_74 } ;
+("dir") => { $ crate :: ATOM_LOCALNAME__64_69_72 } ;
+("grad") => { $ crate :: ATOM_LOCALNAME__67_72_61_64 } ;
+("pointsAtY") => { $ crate :: ATOM_LOCALNAME__70_6F_69_6E_74_73_41_74_59 } ;
+("vkern") => { $ crate :: ATOM_LOCALNAME__76_6B_65_72_6E } ;
+("msup") => { $ crate :: ATOM_LOCALNAME__6D_73_75_70 } ;
+("attributetype") => { $ crate :: ATOM_LOCALNAME__61_74_74_72_69_62_75_74_65_74_79_70_65 } ;
+("fespotlight") => { $ crate :: ATOM_LOCALNAME__66_65_73_70_6F_74_6C_69_67_68_74 } ;
+("dur") => { $ crate :: ATOM_LOCALNAME__64_75_72 } ;
+("cx") => { $ crate :: ATOM_LOCALNAME__63_78 } ;
+("fecomponenttransfer") => { $ crate :: ATOM_LOCALNAME__66_65_63_6F_6D_70_6F_6E_65_6E_74_74_72_61_6E_73_66_65_72 } ;
+("keyPoints") => { $ crate :: ATOM_LOCALNAME__6B_65_79_50_6F_69_6E_74_73 } ;
+("marker-end") => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B_65_72_2D_65_6E_64 } ;
+("bgsound") => { $ crate :: ATOM_LOCALNAME__62_67_73_6F_75_6E_64 } ;
+("font") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74 } ;
+("scriptminsize") => { $ crate :: ATOM_LOCALNAME__73_63_72_69_70_74_6D_69_6E_73_69_7A_65 } ;
+("kernelUnitLength") => { $ crate :: ATOM_LOCALNAME__6B_65_72_6E_65_6C_55_6E_69_74_4C_65_6E_67_74_68 } ;
+("form") => { $ crate :: ATOM_LOCALNAME__66_6F_72_6D } ;
+("classid") => { $ crate :: ATOM_LOCALNAME__63_6C_61_73_73_69_64 } ;
+("overline-position") => { $ crate :: ATOM_LOCALNAME__6F_76_65_72_6C_69_6E_65_2D_70_6F_73_69_74_69_6F_6E } ;
+("output") => { $ crate :: ATOM_LOCALNAME__6F_75_74_70_75_74 } ;
+("orient") => { $ crate :: ATOM_LOCALNAME__6F_72_69_65_6E_74 } ;
+("lt") => { $ crate :: ATOM_LOCALNAME__6C_74 } ;
+("mn") => { $ crate :: ATOM_LOCALNAME__6D_6E } ;
+("nest") => { $ crate :: ATOM_LOCALNAME__6E_65_73_74 } ;
+("setdiff") => { $ crate :: ATOM_LOCALNAME__73_65_74_64_69_66_66 } ;
+("ruby") => { $ crate :: ATOM_LOCALNAME__72_75_62_79 } ;
+("mfrac") => { $ crate :: ATOM_LOCALNAME__6D_66_72_61_63 } ;
+("markerUnits") => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B_65_72_55_6E_69_74_73 } ;
+("wbr") => { $ crate :: ATOM_LOCALNAME__77_62_72 } ;
+("menclose") => { $ crate :: ATOM_LOCALNAME__6D_65_6E_63_6C_6F_73_65 } ;
+("thead") => { $ crate :: ATOM_LOCALNAME__74_68_65_61_64 } ;
+("bias") => { $ crate :: ATOM_LOCALNAME__62_69_61_73 } ;
+("standby") => { $ crate :: ATOM_LOCALNAME__73_74_61_6E_64_62_79 } ;
+("and") => { $ crate :: ATOM_LOCALNAME__61_6E_64 } ;
+("zoomandpan") => { $ crate :: ATOM_LOCALNAME__7A_6F_6F_6D_61_6E_64_70_61_6E } ;
+("additive") => { $ crate :: ATOM_LOCALNAME__61_64_64_69_74_69_76_65 } ;
+("arccos") => { $ crate :: ATOM_LOCALNAME__61_72_63_63_6F_73 } ;
+("limit") => { $ crate :: ATOM_LOCALNAME__6C_69_6D_69_74 } ;
+("order") => { $ crate :: ATOM_LOCALNAME__6F_72_64_65_72 } ;
+("femorphology") => { $ crate :: ATOM_LOCALNAME__66_65_6D_6F_72_70_68_6F_6C_6F_67_79 } ;
+("feGaussianBlur") => { $ crate :: ATOM_LOCALNAME__66_65_47_61_75_73_73_69_61_6E_42_6C_75_72 } ;
+("mover") => { $ crate :: ATOM_LOCALNAME__6D_6F_76_65_72 } ;
+("root") => { $ crate :: ATOM_LOCALNAME__72_6F_6F_74 } ;
+("v-mathematical") => { $ crate :: ATOM_LOCALNAME__76_2D_6D_61_74_68_65_6D_61_74_69_63_61_6C } ;
+("arccsch") => { $ crate :: ATOM_LOCALNAME__61_72_63_63_73_63_68 } ;
+("aria-owns") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_6F_77_6E_73 } ;
+("in2") => { $ crate :: ATOM_LOCALNAME__69_6E_32 } ;
+("p") => { $ crate :: ATOM_LOCALNAME__70 } ;
+("onunload") => { $ crate :: ATOM_LOCALNAME__6F_6E_75_6E_6C_6F_61_64 } ;
+("placeholder") => { $ crate :: ATOM_LOCALNAME__70_6C_61_63_65_68_6F_6C_64_65_72 } ;
+("csc") => { $ crate :: ATOM_LOCALNAME__63_73_63 } ;
+("text-anchor") => { $ crate :: ATOM_LOCALNAME__74_65_78_74_2D_61_6E_63_68_6F_72 } ;
+("displaystyle") => { $ crate :: ATOM_LOCALNAME__64_69_73_70_6C_61_79_73_74_79_6C_65 } ;
+("specularExponent") => { $ crate :: ATOM_LOCALNAME__73_70_65_63_75_6C_61_72_45_78_70_6F_6E_65_6E_74 } ;
+("object") => { $ crate :: ATOM_LOCALNAME__6F_62_6A_65_63_74 } ;
+("numoctaves") => { $ crate :: ATOM_LOCALNAME__6E_75_6D_6F_63_74_61_76_65_73 } ;
+("fefuncg") => { $ crate :: ATOM_LOCALNAME__66_65_66_75_6E_63_67 } ;
+("dataformatas") => { $ crate :: ATOM_LOCALNAME__64_61_74_61_66_6F_72_6D_61_74_61_73 } ;
+("arccosh") => { $ crate :: ATOM_LOCALNAME__61_72_63_63_6F_73_68 } ;
+("ondatasetchanged") => { $ crate :: ATOM_LOCALNAME__6F_6E_64_61_74_61_73_65_74_63_68_61_6E_67_65_64 } ;
+("pre") => { $ crate :: ATOM_LOCALNAME__70_72_65 } ;
+("externalResourcesRequired") => { $ crate :: ATOM_LOCALNAME__65_78_74_65_72_6E_61_6C_52_65_73_6F_75_72_63_65_73_52_65_71_75_69_72_65_64 } ;
+("columnlines") => { $ crate :: ATOM_LOCALNAME__63_6F_6C_75_6D_6E_6C_69_6E_65_73 } ;
+("onhashchange") => { $ crate :: ATOM_LOCALNAME__6F_6E_68_61_73_68_63_68_61_6E_67_65 } ;
+("baseFrequency") => { $ crate :: ATOM_LOCALNAME__62_61_73_65_46_72_65_71_75_65_6E_63_79 } ;
+("stretchy") => { $ crate :: ATOM_LOCALNAME__73_74_72_65_74_63_68_79 } ;
+("usemap") => { $ crate :: ATOM_LOCALNAME__75_73_65_6D_61_70 } ;
+("tendsto") => { $ crate :: ATOM_LOCALNAME__74_65_6E_64_73_74_6F } ;
+("notprsubset") => { $ crate :: ATOM_LOCALNAME__6E_6F_74_70_72_73_75_62_73_65_74 } ;
+("false") => { $ crate :: ATOM_LOCALNAME__66_61_6C_73_65 } ;
+("onfocus") => { $ crate :: ATOM_LOCALNAME__6F_6E_66_6F_63_75_73 } ;
+("ondrag") => { $ crate :: ATOM_LOCALNAME__6F_6E_64_72_61_67 } ;
+("scale") => { $ crate :: ATOM_LOCALNAME__73_63_61_6C_65 } ;
+("aria-rowcount") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_72_6F_77_63_6F_75_6E_74 } ;
+("altGlyph") => { $ crate :: ATOM_LOCALNAME__61_6C_74_47_6C_79_70_68 } ;
+("maskcontentunits") => { $ crate :: ATOM_LOCALNAME__6D_61_73_6B_63_6F_6E_74_65_6E_74_75_6E_69_74_73 } ;
+("frame") => { $ crate :: ATOM_LOCALNAME__66_72_61_6D_65 } ;
+("controllerchange") => { $ crate :: ATOM_LOCALNAME__63_6F_6E_74_72_6F_6C_6C_65_72_63_68_61_6E_67_65 } ;
+("frameset") => { $ crate :: ATOM_LOCALNAME__66_72_61_6D_65_73_65_74 } ;
+("headers") => { $ crate :: ATOM_LOCALNAME__68_65_61_64_65_72_73 } ;
+("ondrop") => { $ crate :: ATOM_LOCALNAME__6F_6E_64_72_6F_70 } ;
+("vlink") => { $ crate :: ATOM_LOCALNAME__76_6C_69_6E_6B } ;
+("markerWidth") => { $ crate :: ATOM_LOCALNAME__6D_61_72_6B_65_72_57_69_64_74_68 } ;
+("rem") => { $ crate :: ATOM_LOCALNAME__72_65_6D } ;
+("feMerge") => { $ crate :: ATOM_LOCALNAME__66_65_4D_65_72_67_65 } ;
+("allowfullscreen") => { $ crate :: ATOM_LOCALNAME__61_6C_6C_6F_77_66_75_6C_6C_73_63_72_65_65_6E } ;
+("toggle") => { $ crate :: ATOM_LOCALNAME__74_6F_67_67_6C_65 } ;
+("br") => { $ crate :: ATOM_LOCALNAME__62_72 } ;
+("track") => { $ crate :: ATOM_LOCALNAME__74_72_61_63_6B } ;
+("meter") => { $ crate :: ATOM_LOCALNAME__6D_65_74_65_72 } ;
+("xml:lang") => { $ crate :: ATOM_LOCALNAME__78_6D_6C_3A_6C_61_6E_67 } ;
+("q") => { $ crate :: ATOM_LOCALNAME__71 } ;
+("itemref") => { $ crate :: ATOM_LOCALNAME__69_74_65_6D_72_65_66 } ;
+("video") => { $ crate :: ATOM_LOCALNAME__76_69_64_65_6F } ;
+("verythinmathspace") => { $ crate :: ATOM_LOCALNAME__76_65_72_79_74_68_69_6E_6D_61_74_68_73_70_61_63_65 } ;
+("meta") => { $ crate :: ATOM_LOCALNAME__6D_65_74_61 } ;
+("profile") => { $ crate :: ATOM_LOCALNAME__70_72_6F_66_69_6C_65 } ;
+("onforminput") => { $ crate :: ATOM_LOCALNAME__6F_6E_66_6F_72_6D_69_6E_70_75_74 } ;
+("aria-controls") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_63_6F_6E_74_72_6F_6C_73 } ;
+("onload") => { $ crate :: ATOM_LOCALNAME__6F_6E_6C_6F_61_64 } ;
+("flood-opacity") => { $ crate :: ATOM_LOCALNAME__66_6C_6F_6F_64_2D_6F_70_61_63_69_74_79 } ;
+("src") => { $ crate :: ATOM_LOCALNAME__73_72_63 } ;
+("aria-roledescription") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_72_6F_6C_65_64_65_73_63_72_69_70_74_69_6F_6E } ;
+("aria-haspopup") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_68_61_73_70_6F_70_75_70 } ;
+("quotient") => { $ crate :: ATOM_LOCALNAME__71_75_6F_74_69_65_6E_74 } ;
+("repeat-min") => { $ crate :: ATOM_LOCALNAME__72_65_70_65_61_74_2D_6D_69_6E } ;
+("strikethrough-thickness") => { $ crate :: ATOM_LOCALNAME__73_74_72_69_6B_65_74_68_72_6F_75_67_68_2D_74_68_69_63_6B_6E_65_73_73 } ;
+("nohref") => { $ crate :: ATOM_LOCALNAME__6E_6F_68_72_65_66 } ;
+("mfenced") => { $ crate :: ATOM_LOCALNAME__6D_66_65_6E_63_65_64 } ;
+("patterntransform") => { $ crate :: ATOM_LOCALNAME__70_61_74_74_65_72_6E_74_72_61_6E_73_66_6F_72_6D } ;
+("vert-origin-x") => { $ crate :: ATOM_LOCALNAME__76_65_72_74_2D_6F_72_69_67_69_6E_2D_78 } ;
+("altglyphitem") => { $ crate :: ATOM_LOCALNAME__61_6C_74_67_6C_79_70_68_69_74_65_6D } ;
+("tref") => { $ crate :: ATOM_LOCALNAME__74_72_65_66 } ;
+("details") => { $ crate :: ATOM_LOCALNAME__64_65_74_61_69_6C_73 } ;
+("rows") => { $ crate :: ATOM_LOCALNAME__72_6F_77_73 } ;
+("overflow") => { $ crate :: ATOM_LOCALNAME__6F_76_65_72_66_6C_6F_77 } ;
+("horiz-origin-y") => { $ crate :: ATOM_LOCALNAME__68_6F_72_69_7A_2D_6F_72_69_67_69_6E_2D_79 } ;
+("lang") => { $ crate :: ATOM_LOCALNAME__6C_61_6E_67 } ;
+("section") => { $ crate :: ATOM_LOCALNAME__73_65_63_74_69_6F_6E } ;
+("patternunits") => { $ crate :: ATOM_LOCALNAME__70_61_74_74_65_72_6E_75_6E_69_74_73 } ;
+("ondragenter") => { $ crate :: ATOM_LOCALNAME__6F_6E_64_72_61_67_65_6E_74_65_72 } ;
+("marginheight") => { $ crate :: ATOM_LOCALNAME__6D_61_72_67_69_6E_68_65_69_67_68_74 } ;
+("ondragleave") => { $ crate :: ATOM_LOCALNAME__6F_6E_64_72_61_67_6C_65_61_76_65 } ;
+("background") => { $ crate :: ATOM_LOCALNAME__62_61_63_6B_67_72_6F_75_6E_64 } ;
+("text-rendering") => { $ crate :: ATOM_LOCALNAME__74_65_78_74_2D_72_65_6E_64_65_72_69_6E_67 } ;
+("bdi") => { $ crate :: ATOM_LOCALNAME__62_64_69 } ;
+("times") => { $ crate :: ATOM_LOCALNAME__74_69_6D_65_73 } ;
+("preserveAlpha") => { $ crate :: ATOM_LOCALNAME__70_72_65_73_65_72_76_65_41_6C_70_68_61 } ;
+("itemid") => { $ crate :: ATOM_LOCALNAME__69_74_65_6D_69_64 } ;
+("onmouseenter") => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_65_6E_74_65_72 } ;
+("h1") => { $ crate :: ATOM_LOCALNAME__68_31 } ;
+("image-rendering") => { $ crate :: ATOM_LOCALNAME__69_6D_61_67_65_2D_72_65_6E_64_65_72_69_6E_67 } ;
+("sinh") => { $ crate :: ATOM_LOCALNAME__73_69_6E_68 } ;
+("onmousewheel") => { $ crate :: ATOM_LOCALNAME__6F_6E_6D_6F_75_73_65_77_68_65_65_6C } ;
+("ondragdrop") => { $ crate :: ATOM_LOCALNAME__6F_6E_64_72_61_67_64_72_6F_70 } ;
+("kbd") => { $ crate :: ATOM_LOCALNAME__6B_62_64 } ;
+("feFuncA") => { $ crate :: ATOM_LOCALNAME__66_65_46_75_6E_63_41 } ;
+("i") => { $ crate :: ATOM_LOCALNAME__69 } ;
+("draggable") => { $ crate :: ATOM_LOCALNAME__64_72_61_67_67_61_62_6C_65 } ;
+("z") => { $ crate :: ATOM_LOCALNAME__7A } ;
+("rowlines") => { $ crate :: ATOM_LOCALNAME__72_6F_77_6C_69_6E_65_73 } ;
+("fill-opacity") => { $ crate :: ATOM_LOCALNAME__66_69_6C_6C_2D_6F_70_61_63_69_74_79 } ;
+("rules") => { $ crate :: ATOM_LOCALNAME__72_75_6C_65_73 } ;
+("definitionURL") => { $ crate :: ATOM_LOCALNAME__64_65_66_69_6E_69_74_69_6F_6E_55_52_4C } ;
+("aria-invalid") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_69_6E_76_61_6C_69_64 } ;
+("aria-valuemax") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_76_61_6C_75_65_6D_61_78 } ;
+("glyph-orientation-vertical") => { $ crate :: ATOM_LOCALNAME__67_6C_79_70_68_2D_6F_72_69_65_6E_74_61_74_69_6F_6E_2D_76_65_72_74_69_63_61_6C } ;
+("lambda") => { $ crate :: ATOM_LOCALNAME__6C_61_6D_62_64_61 } ;
+("onrowsdelete") => { $ crate :: ATOM_LOCALNAME__6F_6E_72_6F_77_73_64_65_6C_65_74_65 } ;
+("font-face-src") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_2D_66_61_63_65_2D_73_72_63 } ;
+("feFuncG") => { $ crate :: ATOM_LOCALNAME__66_65_46_75_6E_63_47 } ;
+("aria-readonly") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_72_65_61_64_6F_6E_6C_79 } ;
+("onafterupdate") => { $ crate :: ATOM_LOCALNAME__6F_6E_61_66_74_65_72_75_70_64_61_74_65 } ;
+("itemprop") => { $ crate :: ATOM_LOCALNAME__69_74_65_6D_70_72_6F_70 } ;
+("arccoth") => { $ crate :: ATOM_LOCALNAME__61_72_63_63_6F_74_68 } ;
+("keySplines") => { $ crate :: ATOM_LOCALNAME__6B_65_79_53_70_6C_69_6E_65_73 } ;
+("xlink:arcrole") => { $ crate :: ATOM_LOCALNAME__78_6C_69_6E_6B_3A_61_72_63_72_6F_6C_65 } ;
+("arcsec") => { $ crate :: ATOM_LOCALNAME__61_72_63_73_65_63 } ;
+("code") => { $ crate :: ATOM_LOCALNAME__63_6F_64_65 } ;
+("log") => { $ crate :: ATOM_LOCALNAME__6C_6F_67 } ;
+("aria-describedby") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_64_65_73_63_72_69_62_65_64_62_79 } ;
+("altGlyphDef") => { $ crate :: ATOM_LOCALNAME__61_6C_74_47_6C_79_70_68_44_65_66 } ;
+("lquote") => { $ crate :: ATOM_LOCALNAME__6C_71_75_6F_74_65 } ;
+("radius") => { $ crate :: ATOM_LOCALNAME__72_61_64_69_75_73 } ;
+("scrolling") => { $ crate :: ATOM_LOCALNAME__73_63_72_6F_6C_6C_69_6E_67 } ;
+("hr") => { $ crate :: ATOM_LOCALNAME__68_72 } ;
+("fespecularlighting") => { $ crate :: ATOM_LOCALNAME__66_65_73_70_65_63_75_6C_61_72_6C_69_67_68_74_69_6E_67 } ;
+("multicol") => { $ crate :: ATOM_LOCALNAME__6D_75_6C_74_69_63_6F_6C } ;
+("pathlength") => { $ crate :: ATOM_LOCALNAME__70_61_74_68_6C_65_6E_67_74_68 } ;
+("complexes") => { $ crate :: ATOM_LOCALNAME__63_6F_6D_70_6C_65_78_65_73 } ;
+("pointer-events") => { $ crate :: ATOM_LOCALNAME__70_6F_69_6E_74_65_72_2D_65_76_65_6E_74_73 } ;
+("multiple") => { $ crate :: ATOM_LOCALNAME__6D_75_6C_74_69_70_6C_65 } ;
+("mozbrowser") => { $ crate :: ATOM_LOCALNAME__6D_6F_7A_62_72_6F_77_73_65_72 } ;
+("aria-placeholder") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_70_6C_61_63_65_68_6F_6C_64_65_72 } ;
+("header") => { $ crate :: ATOM_LOCALNAME__68_65_61_64_65_72 } ;
+("path") => { $ crate :: ATOM_LOCALNAME__70_61_74_68 } ;
+("framespacing") => { $ crate :: ATOM_LOCALNAME__66_72_61_6D_65_73_70_61_63_69_6E_67 } ;
+("end") => { $ crate :: ATOM_LOCALNAME__65_6E_64 } ;
+("arccot") => { $ crate :: ATOM_LOCALNAME__61_72_63_63_6F_74 } ;
+("aria-templateid") => { $ crate :: ATOM_LOCALNAME__61_72_69_61_2D_74_65_6D_70_6C_61_74_65_69_64 } ;
+("ceiling") => { $ crate :: ATOM_LOCALNAME__63_65_69_6C_69_6E_67 } ;
+("filterUnits") => { $ crate :: ATOM_LOCALNAME__66_69_6C_74_65_72_55_6E_69_74_73 } ;
+("font-size-adjust") => { $ crate :: ATOM_LOCALNAME__66_6F_6E_74_2D_73_69_7A_65_2D_61_64_6A_75_73_74 } ;
+("contentscripttype") => { $ crate :: ATOM_LOCALNAME__63_6F_6E_74_65_6E_74_73_63_72_69_70_74_74_79_70_65 } ;
+("pointsAtX") => { $ crate :: ATOM_LOCALNAME__70_6F_69_6E_74_73_41_74_58 } ;
+("product") => { $ crate :: ATOM_LOCALNAME__70_72_6F_64_75_63_74 } ;
+("srclang") => { $ crate :: ATOM_LOCALNAME__73_72_63_6C_61_6E_67 } ;
+("vspace") => { $ crate :: ATOM_LOCALNAME__76_73_70_61_63_65 } ;
+("valign") => { $ crate :: ATOM_LOCALNAME__76_61_6C_69_67_6E } ;
+("base") => { $ crate :: ATOM_LOCALNAME__62_61_73_65 } ;
+("feBlend") => { $ crate :: ATOM_LOCALNAME__66_65_42_6C_65_6E_64 } ;
+("default") => { $ crate :: ATOM_LOCALNAME__64_65_66_61_75_6C_74 } ;
+}pub type Prefix = :: string_cache :: Atom < PrefixStaticSet > ;
+# [derive (PartialEq , Eq , PartialOrd , Ord)] pub struct PrefixStaticSet ;
+impl :: string_cache :: StaticAtomSet for PrefixStaticSet { fn get () -> & 'static :: string_cache :: PhfStrSet { static SET : :: string_cache :: PhfStrSet = :: string_cache :: PhfStrSet { key : 15467950696543387533u64 , disps : & [(1u32 , 0u32) , (0u32 , 6u32)] , atoms : & ["*",
+"html",
+"xml",
+"",
+"svg",
+"mathml",
+"xlink",
+"xmlns"] , hashes : & [1087184163u32 , 57765587u32 , 4084850464u32 , 811901650u32 , 2480327709u32 , 2091895163u32 , 2327862095u32 , 3256068999u32] } ;
+& SET } fn empty_string_index () -> u32 { 3u32 } } pub const ATOM_PREFIX__2A : Prefix = Prefix :: pack_static (0u32) ;
+pub const ATOM_PREFIX__68_74_6D_6C : Prefix = Prefix :: pack_static (1u32) ;
+pub const ATOM_PREFIX__78_6D_6C : Prefix = Prefix :: pack_static (2u32) ;
+pub const ATOM_PREFIX_ : Prefix = Prefix :: pack_static (3u32) ;
+pub const ATOM_PREFIX__73_76_67 : Prefix = Prefix :: pack_static (4u32) ;
+pub const ATOM_PREFIX__6D_61_74_68_6D_6C : Prefix = Prefix :: pack_static (5u32) ;
+pub const ATOM_PREFIX__78_6C_69_6E_6B : Prefix = Prefix :: pack_static (6u32) ;
+pub const ATOM_PREFIX__78_6D_6C_6E_73 : Prefix = Prefix :: pack_static (7u32) ;
+# [doc = "Takes a namespace prefix string and returns its key in a string cache."] # [macro_export] macro_rules ! namespace_prefix { ("*") => { $ crate :: ATOM_PREFIX__2A } ;
+("html") => { $ crate :: ATOM_PREFIX__68_74_6D_6C } ;
+("xml") => { $ crate :: ATOM_PREFIX__78_6D_6C } ;
+("") => { $ crate :: ATOM_PREFIX_ } ;
+("svg") => { $ crate :: ATOM_PREFIX__73_76_67 } ;
+("mathml") => { $ crate :: ATOM_PREFIX__6D_61_74_68_6D_6C } ;
+("xlink") => { $ crate :: ATOM_PREFIX__78_6C_69_6E_6B } ;
+("xmlns") => { $ crate :: ATOM_PREFIX__78_6D_6C_6E_73 } ;
+}pub type Namespace = :: string_cache :: Atom < NamespaceStaticSet > ;
+# [derive (PartialEq , Eq , PartialOrd , Ord)] pub struct NamespaceStaticSet ;
+impl :: string_cache :: StaticAtomSet for NamespaceStaticSet { fn get () -> & 'static :: string_cache :: PhfStrSet { static SET : :: string_cache :: PhfStrSet = :: string_cache :: PhfStrSet { key : 345707026197253659u64 , disps : & [(0u32 , 7u32) , (6u32 , 0u32)] , atoms : & ["http://www.w3.org/1999/xlink",
+"http://www.w3.org/1998/Math/MathML",
+"http://www.w3.org/XML/1998/namespace",
+"*",
+"http://www.w3.org/2000/svg",
+"http://www.w3.org/2000/xmlns/",
+"",
+"http://www.w3.org/1999/xhtml"] , hashes : & [2476384262u32 , 858104709u32 , 4140503004u32 , 1559514276u32 , 3566477928u32 , 1845400423u32 , 577764079u32 , 791746902u32] } ;
+& SET } fn empty_string_index () -> u32 { 6u32 } } pub const ATOM_NAMESPACE__68_74_74_70_3A_2F_2F_77_77_77_2E_77_33_2E_6F_72_67_2F_31_39_39_39_2F_78_6C_69_6E_6B : Namespace = Namespace :: pack_static (0u32) ;
+pub const ATOM_NAMESPACE__68_74_74_70_3A_2F_2F_77_77_77_2E_77_33_2E_6F_72_67_2F_31_39_39_38_2F_4D_61_74_68_2F_4D_61_74_68_4D_4C : Namespace = Namespace :: pack_static (1u32) ;
+pub const ATOM_NAMESPACE__68_74_74_70_3A_2F_2F_77_77_77_2E_77_33_2E_6F_72_67_2F_58_4D_4C_2F_31_39_39_38_2F_6E_61_6D_65_73_70_61_63_65 : Namespace = Namespace :: pack_static (2u32) ;
+pub const ATOM_NAMESPACE__2A : Namespace = Namespace :: pack_static (3u32) ;
+pub const ATOM_NAMESPACE__68_74_74_70_3A_2F_2F_77_77_77_2E_77_33_2E_6F_72_67_2F_32_30_30_30_2F_73_76_67 : Namespace = Namespace :: pack_static (4u32) ;
+pub const ATOM_NAMESPACE__68_74_74_70_3A_2F_2F_77_77_77_2E_77_33_2E_6F_72_67_2F_32_30_30_30_2F_78_6D_6C_6E_73_2F : Namespace = Namespace :: pack_static (5u32) ;
+pub const ATOM_NAMESPACE_ : Namespace = Namespace :: pack_static (6u32) ;
+pub const ATOM_NAMESPACE__68_74_74_70_3A_2F_2F_77_77_77_2E_77_33_2E_6F_72_67_2F_31_39_39_39_2F_78_68_74_6D_6C : Namespace = Namespace :: pack_static (7u32) ;
+# [doc = "Takes a namespace url string and returns its key in a string cache."] # [macro_export] macro_rules ! namespace_url { ("http://www.w3.org/1999/xlink") => { $ crate :: ATOM_NAMESPACE__68_74_74_70_3A_2F_2F_77_77_77_2E_77_33_2E_6F_72_67_2F_31_39_39_39_2F_78_6C_69_6E_6B } ;
+("http://www.w3.org/1998/Math/MathML") => { $ crate :: ATOM_NAMESPACE__68_74_74_70_3A_2F_2F_77_77_77_2E_77_33_2E_6F_72_67_2F_31_39_39_38_2F_4D_61_74_68_2F_4D_61_74_68_4D_4C } ;
+("http://www.w3.org/XML/1998/namespace") => { $ crate :: ATOM_NAMESPACE__68_74_74_70_3A_2F_2F_77_77_77_2E_77_33_2E_6F_72_67_2F_58_4D_4C_2F_31_39_39_38_2F_6E_61_6D_65_73_70_61_63_65 } ;
+("*") => { $ crate :: ATOM_NAMESPACE__2A } ;
+("http://www.w3.org/2000/svg") => { $ crate :: ATOM_NAMESPACE__68_74_74_70_3A_2F_2F_77_77_77_2E_77_33_2E_6F_72_67_2F_32_30_30_30_2F_73_76_67 } ;
+("http://www.w3.org/2000/xmlns/") => { $ crate :: ATOM_NAMESPACE__68_74_74_70_3A_2F_2F_77_77_77_2E_77_33_2E_6F_72_67_2F_32_30_30_30_2F_78_6D_6C_6E_73_2F } ;
+("") => { $ crate :: ATOM_NAMESPACE_ } ;
+("http://www.w3.org/1999/xhtml") => { $ crate :: ATOM_NAMESPACE__68_74_74_70_3A_2F_2F_77_77_77_2E_77_33_2E_6F_72_67_2F_31_39_39_39_2F_78_68_74_6D_6C } ;
+}
+        /// Maps the input of [`namespace_prefix!`](macro.namespace_prefix.html) to 
+        /// the output of [`namespace_url!`](macro.namespace_url.html).
+        ///
+        #[macro_export] macro_rules! ns {
+        
+() => { namespace_url!("") };
+(*) => { namespace_url!("*") };
+(html) => { namespace_url!("http://www.w3.org/1999/xhtml") };
+(xml) => { namespace_url!("http://www.w3.org/XML/1998/namespace") };
+(xmlns) => { namespace_url!("http://www.w3.org/2000/xmlns/") };
+(xlink) => { namespace_url!("http://www.w3.org/1999/xlink") };
+(svg) => { namespace_url!("http://www.w3.org/2000/svg") };
+(mathml) => { namespace_url!("http://www.w3.org/1998/Math/MathML") };
+}